@@ -73,6 +73,8 @@ class ClassDescriptor:
         else:
             self.check_valid_contents = False
         self.no_class = no_class
+        # Whether the encoding starts with a version byte (see common/serialization.rs)
+        self.versioned = self.check_valid_contents and not no_class
         self.no_serialize = no_serialize
         self.runtime_error_on_serialize = runtime_error_on_serialize
         self.string_contents = string_contents
@@ -105,6 +107,9 @@ def define_classes():
     c = ClassDescriptor("notary_signature", "", "simple_types::NotarySignatureBytes", 64, check_valid_contents=False)
     classes.append(c)
 
+    c = ClassDescriptor("rate_limit_tag", "auth", "simple_types::RateLimitTagBytes", 32, check_valid_contents=False)
+    classes.append(c)
+
     c = ClassDescriptor("profile_key", "profiles", "api::profiles::ProfileKey", 32, check_valid_contents=False)
     c.add_method("get_commitment", "class", "profile_key_commitment", [("UUID", "uuid")],
             """    let profile_key_commitment = profile_key.get_commitment(uuid);""");
@@ -143,35 +148,107 @@ def define_classes():
     c.add_method("decrypt_uuid", "UUID", "uuid", [("class", "uuid_ciphertext")], 
             """    let uuid = match group_secret_params.decrypt_uuid(uuid_ciphertext) {
         Ok(result) => result,
-        Err(_) => return FFI_RETURN_INPUT_ERROR,
+        Err(e) => return e.get_ffi_return_code(),
     };""")
 
     c.add_method("encrypt_profile_key", "class", "profile_key_ciphertext", [("class", "profile_key"), ("UUID", "uuid")], 
-            """    let profile_key_ciphertext = group_secret_params.encrypt_profile_key(profile_key, uuid);""", runtime_error=True)
+            """    let profile_key_ciphertext = group_secret_params.encrypt_profile_key(&profile_key, uuid);""", runtime_error=True)
 
     c.add_method("decrypt_profile_key", "class", "profile_key", [("class", "profile_key_ciphertext"), ("UUID", "uuid")], 
             """    let profile_key = match group_secret_params.decrypt_profile_key(profile_key_ciphertext, uuid) {
         Ok(result) => result,
-        Err(_) => return FFI_RETURN_INPUT_ERROR,
+        Err(e) => return e.get_ffi_return_code(),
     };""")
 
     c.add_method("encrypt_blob_deterministic", "byte[]", "blob_ciphertext", [("class", "randomness"), ("byte[]", "plaintext")],
              """    let blob_ciphertext = match group_secret_params.encrypt_blob(randomness, plaintext) {
          Ok(result) => result,
-         Err(_) => return FFI_RETURN_INPUT_ERROR,
+         Err(e) => return e.get_ffi_return_code(),
      };""", return_size_increment=+29)
 
     c.add_method("decrypt_blob", "byte[]", "plaintext", [("byte[]", "blob_ciphertext")], 
             """    let plaintext = match group_secret_params.decrypt_blob(blob_ciphertext) {
         Ok(result) => result,
-        Err(_) => return FFI_RETURN_INPUT_ERROR,
-    };""", return_size_increment=-29)
+        Err(e) => return e.get_ffi_return_code(),
+    };
+    // A padded blob's plaintext is shorter than the ciphertext suggests, so it
+    // has to go through decrypt_padded_blob instead
+    if plaintext.len() != plaintextOut.len() {
+        return FFI_RETURN_INPUT_ERROR;
+    }""", return_size_increment=-29)
+
+    c.add_method("encrypt_blob_with_padding_deterministic", "byte[]", "blob_ciphertext", [("class", "randomness"), ("byte[]", "plaintext"), ("int", "padded_length")],
+             """    // The caller sizes the output as padded_length + 29, so the plaintext and
+    // its padding marker have to fit in padded_length exactly
+    if plaintext.len() >= padded_length as usize {
+        return FFI_RETURN_INPUT_ERROR;
+    }
+    let padding_policy = api::groups::BlobPaddingPolicy::Buckets(vec![padded_length as usize]);
+    let blob_ciphertext = match group_secret_params.encrypt_blob_with_padding(randomness, plaintext, &padding_policy) {
+         Ok(result) => result,
+         Err(e) => return e.get_ffi_return_code(),
+     };""")
+
+    c.add_method("decrypt_padded_blob", "byte[]", "length_prefixed_plaintext", [("byte[]", "blob_ciphertext")],
+            """    let plaintext = match group_secret_params.decrypt_blob(blob_ciphertext) {
+        Ok(result) => result,
+        Err(e) => return e.get_ffi_return_code(),
+    };
+    // The caller can't know the unpadded length, so the output is sized as
+    // the ciphertext length - 25 and holds a 4-byte big-endian length, the
+    // plaintext, then zeros
+    if plaintext.len() + 4 > lengthPrefixedPlaintextOut.len() {
+        return FFI_RETURN_INPUT_ERROR;
+    }
+    let mut length_prefixed_plaintext = vec![0u8; lengthPrefixedPlaintextOut.len()];
+    length_prefixed_plaintext[..4].copy_from_slice(&(plaintext.len() as u32).to_be_bytes());
+    length_prefixed_plaintext[4..4 + plaintext.len()].copy_from_slice(&plaintext);""", return_size_increment=-25)
+
+    c.add_method("create_group_invite_link_presentation_deterministic", "class", "group_invite_link_presentation", [("class", "randomness"), ("class", "group_invite_link_password"), ("UUID", "uuid")],
+            """    let group_invite_link_presentation = match group_secret_params.create_group_invite_link_presentation(randomness, &group_invite_link_password, uuid) {
+        Ok(result) => result,
+        Err(e) => return e.get_ffi_return_code(),
+    };""", runtime_error=True)
+
+    c.add_method("encrypt_uuid_randomized_deterministic", "class", "randomized_uuid_ciphertext", [("class", "randomness"), ("UUID", "uuid")],
+            """    let randomized_uuid_ciphertext = group_secret_params.encrypt_uuid_randomized(randomness, uuid);""", runtime_error=True)
+
+    c.add_method("decrypt_randomized_uuid", "UUID", "uuid", [("class", "randomized_uuid_ciphertext")],
+            """    let uuid = match group_secret_params.decrypt_randomized_uuid(randomized_uuid_ciphertext) {
+        Ok(result) => result,
+        Err(e) => return e.get_ffi_return_code(),
+    };""")
+
+    c.add_method("create_uuid_ciphertext_match_proof_deterministic", "class", "uuid_ciphertext_match_proof", [("class", "randomness"), ("class", "uuid_ciphertext"), ("class", "randomized_uuid_ciphertext")],
+            """    let uuid_ciphertext_match_proof = match group_secret_params.create_uuid_ciphertext_match_proof(randomness, uuid_ciphertext, randomized_uuid_ciphertext) {
+        Ok(result) => result,
+        Err(e) => return e.get_ffi_return_code(),
+    };""", runtime_error=True)
+
+    c.add_method("encrypt_blob_with_ad_deterministic", "byte[]", "blob_ciphertext", [("class", "randomness"), ("byte[]", "plaintext"), ("byte[]", "associated_data")],
+             """    let blob_ciphertext = match group_secret_params.encrypt_blob_with_ad(randomness, plaintext, associated_data) {
+         Ok(result) => result,
+         Err(e) => return e.get_ffi_return_code(),
+     };""", return_size_increment=+29)
+
+    c.add_method("decrypt_blob_with_ad", "byte[]", "plaintext", [("byte[]", "blob_ciphertext"), ("byte[]", "associated_data")],
+            """    let plaintext = match group_secret_params.decrypt_blob_with_ad(blob_ciphertext, associated_data) {
+        Ok(result) => result,
+        Err(e) => return e.get_ffi_return_code(),
+    };
+    // As in decrypt_blob, a padded plaintext doesn't fill the output
+    if plaintext.len() != plaintextOut.len() {
+        return FFI_RETURN_INPUT_ERROR;
+    }""", return_size_increment=-29)
 
     classes.append(c)
 
-    c = ClassDescriptor("server_secret_params", "", "api::ServerSecretParams", 769, runtime_error_on_serialize=True)
+    c = ClassDescriptor("server_secret_params", "", "api::ServerSecretParams", 1346, runtime_error_on_serialize=True)
     c.add_static_method("generate_deterministic", "class", "server_secret_params", [("class", "randomness")],
-            """    let server_secret_params = api::ServerSecretParams::generate(randomness);""")
+            """    let server_secret_params = match api::ServerSecretParams::generate(randomness) {
+        Ok(result) => result,
+        Err(e) => return e.get_ffi_return_code(),
+    };""")
 
     c.add_method("get_public_params", "class", "server_public_params", [],
         """    let server_public_params = server_secret_params.get_public_params();""")
@@ -179,7 +256,7 @@ def define_classes():
     c.add_method("sign_deterministic", "class", "notary_signature", [("class", "randomness"), ("byte[]", "message") ],
         """    let notary_signature = match server_secret_params.sign(randomness, message) {
         Ok(result) => result,
-        Err(_) => return FFI_RETURN_INPUT_ERROR,
+        Err(e) => return e.get_ffi_return_code(),
     };""", runtime_error=True)
 
     classes.append(c)
@@ -189,39 +266,114 @@ def define_classes():
     c.add_method("receive_auth_credential", "class", "auth_credential", [("UUID", "uuid"), ("int", "redemption_time"), ("class", "auth_credential_response")],
      """    let auth_credential = match server_public_params.receive_auth_credential(uuid, redemption_time, &auth_credential_response) {
         Ok(result) => result,
-        Err(_) => return FFI_RETURN_INPUT_ERROR,
+        Err(e) => return e.get_ffi_return_code(),
     };""")
 
     c.add_method("create_auth_credential_presentation_deterministic", "class", "auth_credential_presentation", [("class", "randomness"), ("class", "group_secret_params"), ("class", "auth_credential")],
-    """    let auth_credential_presentation = server_public_params.create_auth_credential_presentation(randomness, group_secret_params, auth_credential);""", runtime_error=True)
+    """    let auth_credential_presentation = match server_public_params.create_auth_credential_presentation(randomness, &group_secret_params, auth_credential) {
+        Ok(result) => result,
+        Err(e) => return e.get_ffi_return_code(),
+    };""", runtime_error=True)
+
+    c.add_method("create_auth_credential_presentation_with_context_deterministic", "class", "auth_credential_presentation", [("class", "randomness"), ("class", "group_secret_params"), ("class", "auth_credential"), ("byte[]", "context")],
+    """    let auth_credential_presentation = match server_public_params.create_auth_credential_presentation_with_context(randomness, &group_secret_params, auth_credential, context) {
+        Ok(result) => result,
+        Err(e) => return e.get_ffi_return_code(),
+    };""", runtime_error=True)
+
+    c.add_method("create_rate_limit_credential_request_context_deterministic", "class", "rate_limit_credential_request_context", [("class", "randomness")],
+    """    let rate_limit_credential_request_context = server_public_params.create_rate_limit_credential_request_context(randomness);""")
+
+    c.add_method("receive_rate_limit_credential", "class", "rate_limit_credential", [("class", "rate_limit_credential_request_context"), ("class", "rate_limit_credential_response")],
+     """    let rate_limit_credential = match server_public_params.receive_rate_limit_credential(&rate_limit_credential_request_context, &rate_limit_credential_response) {
+        Ok(result) => result,
+        Err(e) => return e.get_ffi_return_code(),
+    };""")
+
+    c.add_method("create_rate_limit_token_deterministic", "class", "rate_limit_token", [("class", "randomness"), ("class", "rate_limit_credential")],
+    """    let rate_limit_token = match server_public_params.create_rate_limit_token(randomness, &rate_limit_credential) {
+        Ok(result) => result,
+        Err(e) => return e.get_ffi_return_code(),
+    };""", runtime_error=True)
 
     classes.append(c)
 
     c = ClassDescriptor("client_zk_profile_operations", "profiles", "api::profiles::ClientZkProfileOperations", 256, wrap_class="server_public_params")
     
     c.add_method("create_profile_key_credential_request_context_deterministic", "class", "profile_key_credential_request_context", [("class", "randomness"), ("UUID", "uuid"), ("class", "profile_key")],  
-    """    let profile_key_credential_request_context = server_public_params.create_profile_key_credential_request_context(randomness, uuid, profile_key);""", runtime_error=True)
+    """    let profile_key_credential_request_context = match server_public_params.create_profile_key_credential_request_context(randomness, uuid, &profile_key) {
+        Ok(result) => result,
+        Err(e) => return e.get_ffi_return_code(),
+    };""", runtime_error=True)
 
     c.add_method("receive_profile_key_credential", "class", "profile_key_credential", [("class", "profile_key_credential_request_context"), ("class",  "profile_key_credential_response")],
      """    let profile_key_credential = match server_public_params.receive_profile_key_credential(&profile_key_credential_request_context, &profile_key_credential_response) {
         Ok(result) => result,
-        Err(_) => return FFI_RETURN_INPUT_ERROR,
+        Err(e) => return e.get_ffi_return_code(),
     };""")
 
     c.add_method("create_profile_key_credential_presentation_deterministic", "class", "profile_key_credential_presentation", [("class", "randomness"), ("class", "group_secret_params"), ("class", "profile_key_credential") ],
-    """    let profile_key_credential_presentation = server_public_params.create_profile_key_credential_presentation(randomness, group_secret_params, profile_key_credential);""", runtime_error=True)
+    """    let profile_key_credential_presentation = match server_public_params.create_profile_key_credential_presentation(randomness, &group_secret_params, profile_key_credential) {
+        Ok(result) => result,
+        Err(e) => return e.get_ffi_return_code(),
+    };""", runtime_error=True)
+
+    c.add_method("create_profile_key_credential_presentation_with_context_deterministic", "class", "profile_key_credential_presentation", [("class", "randomness"), ("class", "group_secret_params"), ("class", "profile_key_credential"), ("byte[]", "context") ],
+    """    let profile_key_credential_presentation = match server_public_params.create_profile_key_credential_presentation_with_context(randomness, &group_secret_params, profile_key_credential, context) {
+        Ok(result) => result,
+        Err(e) => return e.get_ffi_return_code(),
+    };""", runtime_error=True)
+
+    c.add_method("create_profile_key_credential_selective_presentation_deterministic", "class", "profile_key_credential_selective_presentation", [("class", "randomness"), ("class", "group_secret_params"), ("class", "profile_key_credential") ],
+    """    let profile_key_credential_selective_presentation = match server_public_params.create_profile_key_credential_selective_presentation(randomness, &group_secret_params, profile_key_credential) {
+        Ok(result) => result,
+        Err(e) => return e.get_ffi_return_code(),
+    };""", runtime_error=True)
+
+    c.add_method("create_profile_key_credential_selective_presentation_with_context_deterministic", "class", "profile_key_credential_selective_presentation", [("class", "randomness"), ("class", "group_secret_params"), ("class", "profile_key_credential"), ("byte[]", "context") ],
+    """    let profile_key_credential_selective_presentation = match server_public_params.create_profile_key_credential_selective_presentation_with_context(randomness, &group_secret_params, profile_key_credential, context) {
+        Ok(result) => result,
+        Err(e) => return e.get_ffi_return_code(),
+    };""", runtime_error=True)
 
     classes.append(c)
 
     c = ClassDescriptor("server_zk_auth_operations", "auth", "api::auth::ServerZkAuthOperations", 544, wrap_class="server_secret_params")
 
     c.add_method("issue_auth_credential_deterministic", "class", "auth_credential_response", [("class", "randomness"), ("UUID", "uuid"), ("int", "redemption_time")],
-            """    let auth_credential_response = server_secret_params.issue_auth_credential(randomness, uuid, redemption_time);""", runtime_error=True)
+            """    let auth_credential_response = match server_secret_params.issue_auth_credential(randomness, uuid, redemption_time) {
+        Ok(result) => result,
+        Err(e) => return e.get_ffi_return_code(),
+    };""", runtime_error=True)
 
     c.add_method("verify_auth_credential_presentation", "boolean", "None", [("class", "group_public_params"), ("class", "auth_credential_presentation")  ],
     """    match server_secret_params.verify_auth_credential_presentation(group_public_params, &auth_credential_presentation) {
         Ok(_) => (),
-        Err(_) => return FFI_RETURN_INPUT_ERROR,
+        Err(e) => return e.get_ffi_return_code(),
+    }""")
+
+    c.add_method("verify_auth_credential_presentation_with_context", "boolean", "None", [("class", "group_public_params"), ("class", "auth_credential_presentation"), ("byte[]", "context")  ],
+    """    match server_secret_params.verify_auth_credential_presentation_with_context(group_public_params, &auth_credential_presentation, context) {
+        Ok(_) => (),
+        Err(e) => return e.get_ffi_return_code(),
+    }""")
+
+    c.add_method("issue_rate_limit_credential_deterministic", "class", "rate_limit_credential_response", [("class", "randomness"), ("class", "rate_limit_credential_request"), ("int", "redemption_time")],
+            """    let rate_limit_credential_response = match server_secret_params.issue_rate_limit_credential(randomness, &rate_limit_credential_request, redemption_time) {
+        Ok(result) => result,
+        Err(e) => return e.get_ffi_return_code(),
+    };""", runtime_error=True)
+
+    c.add_method("verify_rate_limit_token", "boolean", "None", [("class", "rate_limit_token")  ],
+    """    match server_secret_params.verify_rate_limit_token(&rate_limit_token) {
+        Ok(_) => (),
+        Err(e) => return e.get_ffi_return_code(),
+    }""")
+
+    c.add_method("verify_group_invite_link_presentation", "boolean", "None", [("class", "group_public_params"), ("class", "group_invite_link_password_commitment"), ("class", "group_invite_link_presentation")  ],
+    """    match server_secret_params.verify_group_invite_link_presentation(group_public_params, group_invite_link_password_commitment, &group_invite_link_presentation) {
+        Ok(_) => (),
+        Err(e) => return e.get_ffi_return_code(),
     }""")
 
     classes.append(c)
@@ -236,13 +388,31 @@ def define_classes():
         profile_key_commitment,
     ) {
         Ok(result) => result,
-        Err(_) => return FFI_RETURN_INPUT_ERROR,
+        Err(e) => return e.get_ffi_return_code(),
     };""")
 
     c.add_method("verify_profile_key_credential_presentation", "boolean", "None", [("class", "group_public_params"), ("class", "profile_key_credential_presentation") ],
     """    match server_secret_params.verify_profile_key_credential_presentation(group_public_params, &profile_key_credential_presentation) {
         Ok(_) => (),
-        Err(_) => return FFI_RETURN_INPUT_ERROR,
+        Err(e) => return e.get_ffi_return_code(),
+    }""")
+
+    c.add_method("verify_profile_key_credential_presentation_with_context", "boolean", "None", [("class", "group_public_params"), ("class", "profile_key_credential_presentation"), ("byte[]", "context") ],
+    """    match server_secret_params.verify_profile_key_credential_presentation_with_context(group_public_params, &profile_key_credential_presentation, context) {
+        Ok(_) => (),
+        Err(e) => return e.get_ffi_return_code(),
+    }""")
+
+    c.add_method("verify_profile_key_credential_selective_presentation", "boolean", "None", [("class", "group_public_params"), ("class", "profile_key_credential_selective_presentation") ],
+    """    match server_secret_params.verify_profile_key_credential_selective_presentation(group_public_params, &profile_key_credential_selective_presentation) {
+        Ok(_) => (),
+        Err(e) => return e.get_ffi_return_code(),
+    }""")
+
+    c.add_method("verify_profile_key_credential_selective_presentation_with_context", "boolean", "None", [("class", "group_public_params"), ("class", "profile_key_credential_selective_presentation"), ("byte[]", "context") ],
+    """    match server_secret_params.verify_profile_key_credential_selective_presentation_with_context(group_public_params, &profile_key_credential_selective_presentation, context) {
+        Ok(_) => (),
+        Err(e) => return e.get_ffi_return_code(),
     }""")
 
     classes.append(c)
@@ -251,14 +421,20 @@ def define_classes():
     c.add_method("get_group_identifier", "class", "group_identifier", [],
             """    let group_identifier = group_public_params.get_group_identifier();""")
 
+    c.add_method("verify_uuid_ciphertext_match", "boolean", "None", [("class", "uuid_ciphertext"), ("class", "randomized_uuid_ciphertext"), ("class", "uuid_ciphertext_match_proof")],
+    """    match group_public_params.verify_uuid_ciphertext_match(uuid_ciphertext, randomized_uuid_ciphertext, &uuid_ciphertext_match_proof) {
+        Ok(_) => (),
+        Err(e) => return e.get_ffi_return_code(),
+    }""")
+
     classes.append(c)
 
-    c = ClassDescriptor("server_public_params", "", "api::ServerPublicParams", 161, runtime_error_on_serialize=True)
+    c = ClassDescriptor("server_public_params", "", "api::ServerPublicParams", 290, runtime_error_on_serialize=True)
 
     c.add_method("verify_signature", "boolean", "None", [("byte[]", "message"), ("class", "notary_signature")],
             """    match server_public_params.verify_signature(message, notary_signature) {
         Ok(_) => (),
-        _ => return FFI_RETURN_INPUT_ERROR,
+        Err(e) => return e.get_ffi_return_code(),
     };""")
 
     classes.append(c)
@@ -266,7 +442,7 @@ def define_classes():
     c = ClassDescriptor("auth_credential_response", "auth", "api::auth::AuthCredentialResponse", 361)
     classes.append(c)
 
-    c = ClassDescriptor("auth_credential", "auth", "api::auth::AuthCredential", 342)
+    c = ClassDescriptor("auth_credential", "auth", "api::auth::AuthCredential", 471)
     classes.append(c)
 
     c = ClassDescriptor("auth_credential_presentation", "auth", "api::auth::AuthCredentialPresentation", 493)
@@ -276,6 +452,27 @@ def define_classes():
             """    let redemption_time = auth_credential_presentation.get_redemption_time();""");
     classes.append(c)
 
+    c = ClassDescriptor("rate_limit_credential_request_context", "auth", "api::auth::RateLimitCredentialRequestContext", 225)
+    c.add_method("get_request", "class", "rate_limit_credential_request", [],
+            """    let rate_limit_credential_request = rate_limit_credential_request_context.get_request();""" )
+    classes.append(c)
+
+    c = ClassDescriptor("rate_limit_credential_request", "auth", "api::auth::RateLimitCredentialRequest", 97)
+    classes.append(c)
+
+    c = ClassDescriptor("rate_limit_credential_response", "auth", "api::auth::RateLimitCredentialResponse", 398)
+    classes.append(c)
+
+    c = ClassDescriptor("rate_limit_credential", "auth", "api::auth::RateLimitCredential", 165)
+    classes.append(c)
+
+    c = ClassDescriptor("rate_limit_token", "auth", "api::auth::RateLimitToken", 366)
+    c.add_method("get_tag", "class", "rate_limit_tag", [],
+            """    let rate_limit_tag = rate_limit_token.get_tag();""");
+    c.add_method("get_redemption_time", "int", "redemption_time", [],
+            """    let redemption_time = rate_limit_token.get_redemption_time();""");
+    classes.append(c)
+
     c = ClassDescriptor("profile_key_credential_request_context", "profiles", "api::profiles::ProfileKeyCredentialRequestContext", 473)
     c.add_method("get_request", "class", "profile_key_credential_request", [],
             """    let profile_key_credential_request = profile_key_credential_request_context.get_request();""" )
@@ -298,12 +495,37 @@ def define_classes():
 
     classes.append(c)
 
+    c = ClassDescriptor("profile_key_credential_selective_presentation", "profiles", "api::profiles::ProfileKeyCredentialSelectivePresentation", 522)
+    c.add_method("get_uuid_ciphertext", "class", "uuid_ciphertext", [],
+            """    let uuid_ciphertext = profile_key_credential_selective_presentation.get_uuid_ciphertext();""");
+
+    classes.append(c)
+
+    c = ClassDescriptor("group_invite_link_password", "groups", "api::groups::GroupInviteLinkPassword", 16, check_valid_contents=False)
+    c.add_method("get_commitment", "class", "group_invite_link_password_commitment", [("class", "group_public_params")],
+            """    let group_invite_link_password_commitment = group_invite_link_password.get_commitment(group_public_params);""")
+    classes.append(c)
+
+    c = ClassDescriptor("group_invite_link_password_commitment", "groups", "api::groups::GroupInviteLinkPasswordCommitment", 33)
+    classes.append(c)
+
+    c = ClassDescriptor("group_invite_link_presentation", "groups", "api::groups::GroupInviteLinkPresentation", 137)
+    c.add_method("get_uuid_ciphertext", "class", "uuid_ciphertext", [],
+            """    let uuid_ciphertext = group_invite_link_presentation.get_uuid_ciphertext();""");
+    classes.append(c)
+
     c = ClassDescriptor("uuid_ciphertext", "groups", "api::groups::UuidCiphertext", 65)
     classes.append(c)
 
     c = ClassDescriptor("profile_key_ciphertext", "groups", "api::groups::ProfileKeyCiphertext", 65)
     classes.append(c)
 
+    c = ClassDescriptor("randomized_uuid_ciphertext", "groups", "api::groups::RandomizedUuidCiphertext", 65)
+    classes.append(c)
+
+    c = ClassDescriptor("uuid_ciphertext_match_proof", "groups", "api::groups::UuidCiphertextMatchProof", 105)
+    classes.append(c)
+
     c = ClassDescriptor("randomness", "", "simple_types::RandomnessBytes", 32, no_class=True)
     classes.append(c)
 
@@ -13,7 +13,15 @@ file_header = \
 
 use super::simpleapi;
 use std::{panic, slice};
+use crate::common::errors::{clear_last_error_code, get_last_error_code};
 use crate::ffi::constants::FFI_RETURN_INTERNAL_ERROR;
+
+// The ZkGroupError code behind this thread's last failed call; see
+// errors::get_last_error_code
+#[no_mangle]
+pub extern "C" fn FFI_getLastErrorCode() -> i32 {
+    get_last_error_code()
+}
 """
 
 template_method_start = \
@@ -27,7 +35,8 @@ template_method_decl_end = \
 """
 
 template_method_body_start = \
-    """    let result = panic::catch_unwind(|| {
+    """    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
 """
 
 template_method_body_end = \
@@ -13,6 +13,7 @@ file_header = \
 
 use std::panic;
 
+use crate::common::errors::{clear_last_error_code, get_last_error_code};
 use crate::ffi::constants::*;
 
 extern crate jni;
@@ -42,6 +43,16 @@ fn u8toi8(input: Vec<u8>) -> Vec<i8> {
     }
     out
 }
+
+// The ZkGroupError code behind this thread's last failed call; see
+// errors::get_last_error_code
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_getLastErrorCodeJNI(
+    _env: JNIEnv,
+    _class: JClass,
+) -> i32 {
+    get_last_error_code()
+}
 """
 
 template_method_start = \
@@ -57,7 +68,8 @@ template_method_decl_end = \
 """
 
 template_method_body_start = \
-    """    let result = panic::catch_unwind(|| {
+    """    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
 """
 
 template_method_body_end = \
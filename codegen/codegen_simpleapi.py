@@ -13,6 +13,7 @@ file_header = \
 
 use crate::api;
 use crate::common::constants::*;
+use crate::common::serialization;
 use crate::common::simple_types; 
 use crate::ffi::constants::*;
 """
@@ -23,15 +24,34 @@ pub fn %s_%s(%s) -> i32 {"""
 
 template_load = \
 """
-    let %s: %s = match bincode::deserialize(%sIn) {
+    let %s: %s = match %s(%sIn) {
         Ok(result) => result,
         Err(_) => return %s,
     };
 """
 
+# Versioned classes fail with a ZkGroupError, which is kept for getLastErrorCode
+template_load_versioned = \
+"""
+    let %s: %s = match %s(%sIn) {
+        Ok(result) => result,
+        Err(e) => return e.record_last_error(%s),
+    };
+"""
+
+def print_load(var_name, rust_class, deserializer, in_name, error_name):
+    if deserializer == "serialization::deserialize":
+        template = template_load_versioned
+    else:
+        template = template_load
+    return template % (var_name, rust_class, deserializer, in_name, error_name)
+
 template_ret = \
 """
-    %sOut.copy_from_slice(&bincode::serialize(&%s).unwrap());"""
+    match bincode::serialize(&%s) {
+        Ok(result) => %sOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }"""
 
 template_ret_simple = \
 """
@@ -63,7 +83,7 @@ def get_args(c, m, class_name, static=True):
     return s
 
 
-def print_method(c, m, rustClasses, static):
+def print_method(c, m, rustClasses, deserializers, static):
     s = ""
 
     if c.wrap_class == None:
@@ -82,9 +102,9 @@ def print_method(c, m, rustClasses, static):
         else:
             error_name = "FFI_RETURN_INPUT_ERROR"
         if m.unused_self == True:
-            s += template_load % ("_", rustClasses[class_name.snake()], class_name.lower_camel(), error_name)
+            s += print_load("_", rustClasses[class_name.snake()], deserializers[class_name.snake()], class_name.lower_camel(), error_name)
         else:
-            s += template_load % (class_name.snake(), rustClasses[class_name.snake()], class_name.lower_camel(), error_name)
+            s += print_load(class_name.snake(), rustClasses[class_name.snake()], deserializers[class_name.snake()], class_name.lower_camel(), error_name)
     
     if m.verification:
         error_name = "FFI_RETURN_VERIFICATION_FAILED"
@@ -98,7 +118,7 @@ def print_method(c, m, rustClasses, static):
             s += """    let mut notary_signature: simple_types::NotarySignatureBytes = [0u8; SIGNATURE_LEN];
     notary_signature.copy_from_slice(notarySignatureIn);\n""";
         else:
-            s += template_load % (param[1].snake(), rustClasses[param[1].snake()], param[1].lower_camel(), error_name)
+            s += print_load(param[1].snake(), rustClasses[param[1].snake()], deserializers[param[1].snake()], param[1].lower_camel(), error_name)
 
     s += m.rustCode
 
@@ -108,33 +128,39 @@ def print_method(c, m, rustClasses, static):
     elif m.return_name.snake() == "redemption_time":
         s += "\n    redemptionTimeOut.copy_from_slice(&redemption_time.to_be_bytes());"
     elif m.return_type != "boolean":
-        s += template_ret % (m.return_name.lower_camel(), m.return_name.snake())
+        s += template_ret % (m.return_name.snake(), m.return_name.lower_camel())
 
     s += template_method_decl_end
     return s
 
-def print_class(c, rustClasses):
+def print_class(c, rustClasses, deserializers):
     s = ""
     for method in c.static_methods:
-        s += print_method(c, method, rustClasses, True)
+        s += print_method(c, method, rustClasses, deserializers, True)
     for method in c.methods:
-        s += print_method(c, method, rustClasses, False)
+        s += print_method(c, method, rustClasses, deserializers, False)
     return s
 
 
 def produce_output(classes):
 
     rustClasses = {}
+    deserializers = {}
     for c in classes:
         if c.wrap_class == None:
             class_name = c.class_name
         else:
             class_name = c.wrap_class
         rustClasses[c.class_name.snake()] = c.rust_class_name
+        # Versioned classes go through the helper so an unknown version byte is rejected
+        if c.versioned:
+            deserializers[c.class_name.snake()] = "serialization::deserialize"
+        else:
+            deserializers[c.class_name.snake()] = "bincode::deserialize"
 
     s = file_header
     for c in classes:
-        s += print_class(c, rustClasses)
+        s += print_class(c, rustClasses, deserializers)
     f = open("simpleapi/simpleapi.rs", "w")
     f.write(s)
     f.close()
@@ -80,6 +80,24 @@ int32_t FFI_AuthCredentialResponse_checkValidContents(const uint8_t *authCredent
 int32_t FFI_AuthCredential_checkValidContents(const uint8_t *authCredential,
                                               uint32_t authCredentialLen);
 
+int32_t FFI_GroupInviteLinkPasswordCommitment_checkValidContents(const uint8_t *groupInviteLinkPasswordCommitment,
+                                                                 uint32_t groupInviteLinkPasswordCommitmentLen);
+
+int32_t FFI_GroupInviteLinkPassword_getCommitment(const uint8_t *groupInviteLinkPassword,
+                                                  uint32_t groupInviteLinkPasswordLen,
+                                                  const uint8_t *groupPublicParams,
+                                                  uint32_t groupPublicParamsLen,
+                                                  uint8_t *groupInviteLinkPasswordCommitmentOut,
+                                                  uint32_t groupInviteLinkPasswordCommitmentLen);
+
+int32_t FFI_GroupInviteLinkPresentation_checkValidContents(const uint8_t *groupInviteLinkPresentation,
+                                                           uint32_t groupInviteLinkPresentationLen);
+
+int32_t FFI_GroupInviteLinkPresentation_getUuidCiphertext(const uint8_t *groupInviteLinkPresentation,
+                                                          uint32_t groupInviteLinkPresentationLen,
+                                                          uint8_t *uuidCiphertextOut,
+                                                          uint32_t uuidCiphertextLen);
+
 int32_t FFI_GroupPublicParams_checkValidContents(const uint8_t *groupPublicParams,
                                                  uint32_t groupPublicParamsLen);
 
@@ -95,9 +113,40 @@ int32_t FFI_GroupPublicParams_verifySignature(const uint8_t *groupPublicParams,
                                               const uint8_t *changeSignature,
                                               uint32_t changeSignatureLen);
 
+int32_t FFI_GroupPublicParams_verifyUuidCiphertextMatch(const uint8_t *groupPublicParams,
+                                                        uint32_t groupPublicParamsLen,
+                                                        const uint8_t *uuidCiphertext,
+                                                        uint32_t uuidCiphertextLen,
+                                                        const uint8_t *randomizedUuidCiphertext,
+                                                        uint32_t randomizedUuidCiphertextLen,
+                                                        const uint8_t *uuidCiphertextMatchProof,
+                                                        uint32_t uuidCiphertextMatchProofLen);
+
 int32_t FFI_GroupSecretParams_checkValidContents(const uint8_t *groupSecretParams,
                                                  uint32_t groupSecretParamsLen);
 
+int32_t FFI_GroupSecretParams_createGroupInviteLinkPresentationDeterministic(const uint8_t *groupSecretParams,
+                                                                             uint32_t groupSecretParamsLen,
+                                                                             const uint8_t *randomness,
+                                                                             uint32_t randomnessLen,
+                                                                             const uint8_t *groupInviteLinkPassword,
+                                                                             uint32_t groupInviteLinkPasswordLen,
+                                                                             const uint8_t *uuid,
+                                                                             uint32_t uuidLen,
+                                                                             uint8_t *groupInviteLinkPresentationOut,
+                                                                             uint32_t groupInviteLinkPresentationLen);
+
+int32_t FFI_GroupSecretParams_createUuidCiphertextMatchProofDeterministic(const uint8_t *groupSecretParams,
+                                                                          uint32_t groupSecretParamsLen,
+                                                                          const uint8_t *randomness,
+                                                                          uint32_t randomnessLen,
+                                                                          const uint8_t *uuidCiphertext,
+                                                                          uint32_t uuidCiphertextLen,
+                                                                          const uint8_t *randomizedUuidCiphertext,
+                                                                          uint32_t randomizedUuidCiphertextLen,
+                                                                          uint8_t *uuidCiphertextMatchProofOut,
+                                                                          uint32_t uuidCiphertextMatchProofLen);
+
 int32_t FFI_GroupSecretParams_decryptBlob(const uint8_t *groupSecretParams,
                                           uint32_t groupSecretParamsLen,
                                           const uint8_t *blobCiphertext,
@@ -105,6 +154,22 @@ int32_t FFI_GroupSecretParams_decryptBlob(const uint8_t *groupSecretParams,
                                           uint8_t *plaintextOut,
                                           uint32_t plaintextLen);
 
+int32_t FFI_GroupSecretParams_decryptBlobWithAd(const uint8_t *groupSecretParams,
+                                                uint32_t groupSecretParamsLen,
+                                                const uint8_t *blobCiphertext,
+                                                uint32_t blobCiphertextLen,
+                                                const uint8_t *associatedData,
+                                                uint32_t associatedDataLen,
+                                                uint8_t *plaintextOut,
+                                                uint32_t plaintextLen);
+
+int32_t FFI_GroupSecretParams_decryptPaddedBlob(const uint8_t *groupSecretParams,
+                                                uint32_t groupSecretParamsLen,
+                                                const uint8_t *blobCiphertext,
+                                                uint32_t blobCiphertextLen,
+                                                uint8_t *lengthPrefixedPlaintextOut,
+                                                uint32_t lengthPrefixedPlaintextLen);
+
 int32_t FFI_GroupSecretParams_decryptProfileKey(const uint8_t *groupSecretParams,
                                                 uint32_t groupSecretParamsLen,
                                                 const uint8_t *profileKeyCiphertext,
@@ -112,6 +177,13 @@ int32_t FFI_GroupSecretParams_decryptProfileKey(const uint8_t *groupSecretParams
                                                 uint8_t *profileKeyOut,
                                                 uint32_t profileKeyLen);
 
+int32_t FFI_GroupSecretParams_decryptRandomizedUuid(const uint8_t *groupSecretParams,
+                                                    uint32_t groupSecretParamsLen,
+                                                    const uint8_t *randomizedUuidCiphertext,
+                                                    uint32_t randomizedUuidCiphertextLen,
+                                                    uint8_t *uuidOut,
+                                                    uint32_t uuidLen);
+
 int32_t FFI_GroupSecretParams_decryptUuid(const uint8_t *groupSecretParams,
                                           uint32_t groupSecretParamsLen,
                                           const uint8_t *uuidCiphertext,
@@ -131,6 +203,27 @@ int32_t FFI_GroupSecretParams_encryptBlob(const uint8_t *groupSecretParams,
                                           uint8_t *blobCiphertextOut,
                                           uint32_t blobCiphertextLen);
 
+int32_t FFI_GroupSecretParams_encryptBlobWithAdDeterministic(const uint8_t *groupSecretParams,
+                                                             uint32_t groupSecretParamsLen,
+                                                             const uint8_t *randomness,
+                                                             uint32_t randomnessLen,
+                                                             const uint8_t *plaintext,
+                                                             uint32_t plaintextLen,
+                                                             const uint8_t *associatedData,
+                                                             uint32_t associatedDataLen,
+                                                             uint8_t *blobCiphertextOut,
+                                                             uint32_t blobCiphertextLen);
+
+int32_t FFI_GroupSecretParams_encryptBlobWithPaddingDeterministic(const uint8_t *groupSecretParams,
+                                                                  uint32_t groupSecretParamsLen,
+                                                                  const uint8_t *randomness,
+                                                                  uint32_t randomnessLen,
+                                                                  const uint8_t *plaintext,
+                                                                  uint32_t plaintextLen,
+                                                                  uint32_t paddedLength,
+                                                                  uint8_t *blobCiphertextOut,
+                                                                  uint32_t blobCiphertextLen);
+
 int32_t FFI_GroupSecretParams_encryptProfileKeyDeterministic(const uint8_t *groupSecretParams,
                                                              uint32_t groupSecretParamsLen,
                                                              const uint8_t *randomness,
@@ -147,6 +240,15 @@ int32_t FFI_GroupSecretParams_encryptUuid(const uint8_t *groupSecretParams,
                                           uint8_t *uuidCiphertextOut,
                                           uint32_t uuidCiphertextLen);
 
+int32_t FFI_GroupSecretParams_encryptUuidRandomizedDeterministic(const uint8_t *groupSecretParams,
+                                                                 uint32_t groupSecretParamsLen,
+                                                                 const uint8_t *randomness,
+                                                                 uint32_t randomnessLen,
+                                                                 const uint8_t *uuid,
+                                                                 uint32_t uuidLen,
+                                                                 uint8_t *randomizedUuidCiphertextOut,
+                                                                 uint32_t randomizedUuidCiphertextLen);
+
 int32_t FFI_GroupSecretParams_generateDeterministic(const uint8_t *randomness,
                                                     uint32_t randomnessLen,
                                                     uint8_t *groupSecretParamsOut,
@@ -209,6 +311,14 @@ int32_t FFI_ProfileKeyCredentialRequest_checkValidContents(const uint8_t *profil
 int32_t FFI_ProfileKeyCredentialResponse_checkValidContents(const uint8_t *profileKeyCredentialResponse,
                                                             uint32_t profileKeyCredentialResponseLen);
 
+int32_t FFI_ProfileKeyCredentialSelectivePresentation_checkValidContents(const uint8_t *profileKeyCredentialSelectivePresentation,
+                                                                         uint32_t profileKeyCredentialSelectivePresentationLen);
+
+int32_t FFI_ProfileKeyCredentialSelectivePresentation_getUuidCiphertext(const uint8_t *profileKeyCredentialSelectivePresentation,
+                                                                        uint32_t profileKeyCredentialSelectivePresentationLen,
+                                                                        uint8_t *uuidCiphertextOut,
+                                                                        uint32_t uuidCiphertextLen);
+
 int32_t FFI_ProfileKeyCredential_checkValidContents(const uint8_t *profileKeyCredential,
                                                     uint32_t profileKeyCredentialLen);
 
@@ -222,8 +332,41 @@ int32_t FFI_ProfileKey_getProfileKeyVersion(const uint8_t *profileKey,
                                             uint8_t *profileKeyVersionOut,
                                             uint32_t profileKeyVersionLen);
 
+int32_t FFI_RandomizedUuidCiphertext_checkValidContents(const uint8_t *randomizedUuidCiphertext,
+                                                        uint32_t randomizedUuidCiphertextLen);
+
 int32_t FFI_Randomness_checkValidContents(const uint8_t *randomness, uint32_t randomnessLen);
 
+int32_t FFI_RateLimitCredentialRequestContext_checkValidContents(const uint8_t *rateLimitCredentialRequestContext,
+                                                                 uint32_t rateLimitCredentialRequestContextLen);
+
+int32_t FFI_RateLimitCredentialRequestContext_getRequest(const uint8_t *rateLimitCredentialRequestContext,
+                                                         uint32_t rateLimitCredentialRequestContextLen,
+                                                         uint8_t *rateLimitCredentialRequestOut,
+                                                         uint32_t rateLimitCredentialRequestLen);
+
+int32_t FFI_RateLimitCredentialRequest_checkValidContents(const uint8_t *rateLimitCredentialRequest,
+                                                          uint32_t rateLimitCredentialRequestLen);
+
+int32_t FFI_RateLimitCredentialResponse_checkValidContents(const uint8_t *rateLimitCredentialResponse,
+                                                           uint32_t rateLimitCredentialResponseLen);
+
+int32_t FFI_RateLimitCredential_checkValidContents(const uint8_t *rateLimitCredential,
+                                                   uint32_t rateLimitCredentialLen);
+
+int32_t FFI_RateLimitToken_checkValidContents(const uint8_t *rateLimitToken,
+                                              uint32_t rateLimitTokenLen);
+
+int32_t FFI_RateLimitToken_getRedemptionTime(const uint8_t *rateLimitToken,
+                                             uint32_t rateLimitTokenLen,
+                                             uint8_t *redemptionTimeOut,
+                                             uint32_t redemptionTimeLen);
+
+int32_t FFI_RateLimitToken_getTag(const uint8_t *rateLimitToken,
+                                  uint32_t rateLimitTokenLen,
+                                  uint8_t *rateLimitTagOut,
+                                  uint32_t rateLimitTagLen);
+
 int32_t FFI_ServerPublicParams_checkValidContents(const uint8_t *serverPublicParams,
                                                   uint32_t serverPublicParamsLen);
 
@@ -238,6 +381,19 @@ int32_t FFI_ServerPublicParams_createAuthCredentialPresentationDeterministic(con
                                                                              uint8_t *authCredentialPresentationOut,
                                                                              uint32_t authCredentialPresentationLen);
 
+int32_t FFI_ServerPublicParams_createAuthCredentialPresentationWithContextDeterministic(const uint8_t *serverPublicParams,
+                                                                                        uint32_t serverPublicParamsLen,
+                                                                                        const uint8_t *randomness,
+                                                                                        uint32_t randomnessLen,
+                                                                                        const uint8_t *groupSecretParams,
+                                                                                        uint32_t groupSecretParamsLen,
+                                                                                        const uint8_t *authCredential,
+                                                                                        uint32_t authCredentialLen,
+                                                                                        const uint8_t *context,
+                                                                                        uint32_t contextLen,
+                                                                                        uint8_t *authCredentialPresentationOut,
+                                                                                        uint32_t authCredentialPresentationLen);
+
 int32_t FFI_ServerPublicParams_createProfileKeyCredentialPresentationDeterministic(const uint8_t *serverPublicParams,
                                                                                    uint32_t serverPublicParamsLen,
                                                                                    const uint8_t *randomness,
@@ -249,6 +405,19 @@ int32_t FFI_ServerPublicParams_createProfileKeyCredentialPresentationDeterminist
                                                                                    uint8_t *profileKeyCredentialPresentationOut,
                                                                                    uint32_t profileKeyCredentialPresentationLen);
 
+int32_t FFI_ServerPublicParams_createProfileKeyCredentialPresentationWithContextDeterministic(const uint8_t *serverPublicParams,
+                                                                                              uint32_t serverPublicParamsLen,
+                                                                                              const uint8_t *randomness,
+                                                                                              uint32_t randomnessLen,
+                                                                                              const uint8_t *groupSecretParams,
+                                                                                              uint32_t groupSecretParamsLen,
+                                                                                              const uint8_t *profileKeyCredential,
+                                                                                              uint32_t profileKeyCredentialLen,
+                                                                                              const uint8_t *context,
+                                                                                              uint32_t contextLen,
+                                                                                              uint8_t *profileKeyCredentialPresentationOut,
+                                                                                              uint32_t profileKeyCredentialPresentationLen);
+
 int32_t FFI_ServerPublicParams_createProfileKeyCredentialRequestContextDeterministic(const uint8_t *serverPublicParams,
                                                                                      uint32_t serverPublicParamsLen,
                                                                                      const uint8_t *randomness,
@@ -260,6 +429,46 @@ int32_t FFI_ServerPublicParams_createProfileKeyCredentialRequestContextDetermini
                                                                                      uint8_t *profileKeyCredentialRequestContextOut,
                                                                                      uint32_t profileKeyCredentialRequestContextLen);
 
+int32_t FFI_ServerPublicParams_createProfileKeyCredentialSelectivePresentationDeterministic(const uint8_t *serverPublicParams,
+                                                                                            uint32_t serverPublicParamsLen,
+                                                                                            const uint8_t *randomness,
+                                                                                            uint32_t randomnessLen,
+                                                                                            const uint8_t *groupSecretParams,
+                                                                                            uint32_t groupSecretParamsLen,
+                                                                                            const uint8_t *profileKeyCredential,
+                                                                                            uint32_t profileKeyCredentialLen,
+                                                                                            uint8_t *profileKeyCredentialSelectivePresentationOut,
+                                                                                            uint32_t profileKeyCredentialSelectivePresentationLen);
+
+int32_t FFI_ServerPublicParams_createProfileKeyCredentialSelectivePresentationWithContextDeterministic(const uint8_t *serverPublicParams,
+                                                                                                       uint32_t serverPublicParamsLen,
+                                                                                                       const uint8_t *randomness,
+                                                                                                       uint32_t randomnessLen,
+                                                                                                       const uint8_t *groupSecretParams,
+                                                                                                       uint32_t groupSecretParamsLen,
+                                                                                                       const uint8_t *profileKeyCredential,
+                                                                                                       uint32_t profileKeyCredentialLen,
+                                                                                                       const uint8_t *context,
+                                                                                                       uint32_t contextLen,
+                                                                                                       uint8_t *profileKeyCredentialSelectivePresentationOut,
+                                                                                                       uint32_t profileKeyCredentialSelectivePresentationLen);
+
+int32_t FFI_ServerPublicParams_createRateLimitCredentialRequestContextDeterministic(const uint8_t *serverPublicParams,
+                                                                                    uint32_t serverPublicParamsLen,
+                                                                                    const uint8_t *randomness,
+                                                                                    uint32_t randomnessLen,
+                                                                                    uint8_t *rateLimitCredentialRequestContextOut,
+                                                                                    uint32_t rateLimitCredentialRequestContextLen);
+
+int32_t FFI_ServerPublicParams_createRateLimitTokenDeterministic(const uint8_t *serverPublicParams,
+                                                                 uint32_t serverPublicParamsLen,
+                                                                 const uint8_t *randomness,
+                                                                 uint32_t randomnessLen,
+                                                                 const uint8_t *rateLimitCredential,
+                                                                 uint32_t rateLimitCredentialLen,
+                                                                 uint8_t *rateLimitTokenOut,
+                                                                 uint32_t rateLimitTokenLen);
+
 int32_t FFI_ServerPublicParams_receiveAuthCredential(const uint8_t *serverPublicParams,
                                                      uint32_t serverPublicParamsLen,
                                                      const uint8_t *uuid,
@@ -279,6 +488,15 @@ int32_t FFI_ServerPublicParams_receiveProfileKeyCredential(const uint8_t *server
                                                            uint8_t *profileKeyCredentialOut,
                                                            uint32_t profileKeyCredentialLen);
 
+int32_t FFI_ServerPublicParams_receiveRateLimitCredential(const uint8_t *serverPublicParams,
+                                                          uint32_t serverPublicParamsLen,
+                                                          const uint8_t *rateLimitCredentialRequestContext,
+                                                          uint32_t rateLimitCredentialRequestContextLen,
+                                                          const uint8_t *rateLimitCredentialResponse,
+                                                          uint32_t rateLimitCredentialResponseLen,
+                                                          uint8_t *rateLimitCredentialOut,
+                                                          uint32_t rateLimitCredentialLen);
+
 int32_t FFI_ServerPublicParams_verifySignature(const uint8_t *serverPublicParams,
                                                uint32_t serverPublicParamsLen,
                                                const uint8_t *message,
@@ -322,6 +540,16 @@ int32_t FFI_ServerSecretParams_issueProfileKeyCredentialDeterministic(const uint
                                                                       uint8_t *profileKeyCredentialResponseOut,
                                                                       uint32_t profileKeyCredentialResponseLen);
 
+int32_t FFI_ServerSecretParams_issueRateLimitCredentialDeterministic(const uint8_t *serverSecretParams,
+                                                                     uint32_t serverSecretParamsLen,
+                                                                     const uint8_t *randomness,
+                                                                     uint32_t randomnessLen,
+                                                                     const uint8_t *rateLimitCredentialRequest,
+                                                                     uint32_t rateLimitCredentialRequestLen,
+                                                                     uint32_t redemptionTime,
+                                                                     uint8_t *rateLimitCredentialResponseOut,
+                                                                     uint32_t rateLimitCredentialResponseLen);
+
 int32_t FFI_ServerSecretParams_signDeterministic(const uint8_t *serverSecretParams,
                                                  uint32_t serverSecretParamsLen,
                                                  const uint8_t *randomness,
@@ -338,6 +566,24 @@ int32_t FFI_ServerSecretParams_verifyAuthCredentialPresentation(const uint8_t *s
                                                                 const uint8_t *authCredentialPresentation,
                                                                 uint32_t authCredentialPresentationLen);
 
+int32_t FFI_ServerSecretParams_verifyAuthCredentialPresentationWithContext(const uint8_t *serverSecretParams,
+                                                                           uint32_t serverSecretParamsLen,
+                                                                           const uint8_t *groupPublicParams,
+                                                                           uint32_t groupPublicParamsLen,
+                                                                           const uint8_t *authCredentialPresentation,
+                                                                           uint32_t authCredentialPresentationLen,
+                                                                           const uint8_t *context,
+                                                                           uint32_t contextLen);
+
+int32_t FFI_ServerSecretParams_verifyGroupInviteLinkPresentation(const uint8_t *serverSecretParams,
+                                                                 uint32_t serverSecretParamsLen,
+                                                                 const uint8_t *groupPublicParams,
+                                                                 uint32_t groupPublicParamsLen,
+                                                                 const uint8_t *groupInviteLinkPasswordCommitment,
+                                                                 uint32_t groupInviteLinkPasswordCommitmentLen,
+                                                                 const uint8_t *groupInviteLinkPresentation,
+                                                                 uint32_t groupInviteLinkPresentationLen);
+
 int32_t FFI_ServerSecretParams_verifyProfileKeyCredentialPresentation(const uint8_t *serverSecretParams,
                                                                       uint32_t serverSecretParamsLen,
                                                                       const uint8_t *groupPublicParams,
@@ -345,7 +591,42 @@ int32_t FFI_ServerSecretParams_verifyProfileKeyCredentialPresentation(const uint
                                                                       const uint8_t *profileKeyCredentialPresentation,
                                                                       uint32_t profileKeyCredentialPresentationLen);
 
+int32_t FFI_ServerSecretParams_verifyProfileKeyCredentialPresentationWithContext(const uint8_t *serverSecretParams,
+                                                                                 uint32_t serverSecretParamsLen,
+                                                                                 const uint8_t *groupPublicParams,
+                                                                                 uint32_t groupPublicParamsLen,
+                                                                                 const uint8_t *profileKeyCredentialPresentation,
+                                                                                 uint32_t profileKeyCredentialPresentationLen,
+                                                                                 const uint8_t *context,
+                                                                                 uint32_t contextLen);
+
+int32_t FFI_ServerSecretParams_verifyProfileKeyCredentialSelectivePresentation(const uint8_t *serverSecretParams,
+                                                                               uint32_t serverSecretParamsLen,
+                                                                               const uint8_t *groupPublicParams,
+                                                                               uint32_t groupPublicParamsLen,
+                                                                               const uint8_t *profileKeyCredentialSelectivePresentation,
+                                                                               uint32_t profileKeyCredentialSelectivePresentationLen);
+
+int32_t FFI_ServerSecretParams_verifyProfileKeyCredentialSelectivePresentationWithContext(const uint8_t *serverSecretParams,
+                                                                                          uint32_t serverSecretParamsLen,
+                                                                                          const uint8_t *groupPublicParams,
+                                                                                          uint32_t groupPublicParamsLen,
+                                                                                          const uint8_t *profileKeyCredentialSelectivePresentation,
+                                                                                          uint32_t profileKeyCredentialSelectivePresentationLen,
+                                                                                          const uint8_t *context,
+                                                                                          uint32_t contextLen);
+
+int32_t FFI_ServerSecretParams_verifyRateLimitToken(const uint8_t *serverSecretParams,
+                                                    uint32_t serverSecretParamsLen,
+                                                    const uint8_t *rateLimitToken,
+                                                    uint32_t rateLimitTokenLen);
+
 int32_t FFI_UuidCiphertext_checkValidContents(const uint8_t *uuidCiphertext,
                                               uint32_t uuidCiphertextLen);
 
+int32_t FFI_UuidCiphertextMatchProof_checkValidContents(const uint8_t *uuidCiphertextMatchProof,
+                                                        uint32_t uuidCiphertextMatchProofLen);
+
 int32_t FFI_Uuid_checkValidContents(const uint8_t *uuid, uint32_t uuidLen);
+
+int32_t FFI_getLastErrorCode(void);
@@ -71,4 +71,99 @@ public class ClientZkAuthOperations {
 
   }
 
+  public AuthCredentialPresentation createAuthCredentialPresentationWithContext(GroupSecretParams groupSecretParams, AuthCredential authCredential, byte[] context) {
+    return createAuthCredentialPresentationWithContext(new SecureRandom(), groupSecretParams, authCredential, context);
+  }
+
+  public AuthCredentialPresentation createAuthCredentialPresentationWithContext(SecureRandom secureRandom, GroupSecretParams groupSecretParams, AuthCredential authCredential, byte[] context) {
+    byte[] newContents = new byte[AuthCredentialPresentation.SIZE];
+    byte[] random      = new byte[Native.RANDOM_LENGTH];
+
+    secureRandom.nextBytes(random);
+
+    int ffi_return = Native.serverPublicParamsCreateAuthCredentialPresentationWithContextDeterministicJNI(serverPublicParams.getInternalContentsForJNI(), random, groupSecretParams.getInternalContentsForJNI(), authCredential.getInternalContentsForJNI(), context, newContents);
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+    }
+
+    try {
+      return new AuthCredentialPresentation(newContents);
+    } catch (InvalidInputException e) {
+      throw new AssertionError(e);
+    }
+
+  }
+
+  public RateLimitCredentialRequestContext createRateLimitCredentialRequestContext() throws VerificationFailedException {
+    return createRateLimitCredentialRequestContext(new SecureRandom());
+  }
+
+  public RateLimitCredentialRequestContext createRateLimitCredentialRequestContext(SecureRandom secureRandom) throws VerificationFailedException {
+    byte[] newContents = new byte[RateLimitCredentialRequestContext.SIZE];
+    byte[] random      = new byte[Native.RANDOM_LENGTH];
+
+    secureRandom.nextBytes(random);
+
+    int ffi_return = Native.serverPublicParamsCreateRateLimitCredentialRequestContextDeterministicJNI(serverPublicParams.getInternalContentsForJNI(), random, newContents);
+    if (ffi_return == Native.FFI_RETURN_INPUT_ERROR) {
+      throw new VerificationFailedException();
+    }
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+    }
+
+    try {
+      return new RateLimitCredentialRequestContext(newContents);
+    } catch (InvalidInputException e) {
+      throw new AssertionError(e);
+    }
+
+  }
+
+  public RateLimitCredential receiveRateLimitCredential(RateLimitCredentialRequestContext rateLimitCredentialRequestContext, RateLimitCredentialResponse rateLimitCredentialResponse) throws VerificationFailedException {
+    byte[] newContents = new byte[RateLimitCredential.SIZE];
+
+    int ffi_return = Native.serverPublicParamsReceiveRateLimitCredentialJNI(serverPublicParams.getInternalContentsForJNI(), rateLimitCredentialRequestContext.getInternalContentsForJNI(), rateLimitCredentialResponse.getInternalContentsForJNI(), newContents);
+    if (ffi_return == Native.FFI_RETURN_INPUT_ERROR) {
+      throw new VerificationFailedException();
+    }
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+    }
+
+    try {
+      return new RateLimitCredential(newContents);
+    } catch (InvalidInputException e) {
+      throw new AssertionError(e);
+    }
+
+  }
+
+  public RateLimitToken createRateLimitToken(RateLimitCredential rateLimitCredential) {
+    return createRateLimitToken(new SecureRandom(), rateLimitCredential);
+  }
+
+  public RateLimitToken createRateLimitToken(SecureRandom secureRandom, RateLimitCredential rateLimitCredential) {
+    byte[] newContents = new byte[RateLimitToken.SIZE];
+    byte[] random      = new byte[Native.RANDOM_LENGTH];
+
+    secureRandom.nextBytes(random);
+
+    int ffi_return = Native.serverPublicParamsCreateRateLimitTokenDeterministicJNI(serverPublicParams.getInternalContentsForJNI(), random, rateLimitCredential.getInternalContentsForJNI(), newContents);
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+    }
+
+    try {
+      return new RateLimitToken(newContents);
+    } catch (InvalidInputException e) {
+      throw new AssertionError(e);
+    }
+
+  }
+
 }
@@ -0,0 +1,56 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+
+// Generated by zkgroup/codegen/codegen.py - do not edit
+
+package org.signal.zkgroup.auth;
+
+import org.signal.zkgroup.InvalidInputException;
+import org.signal.zkgroup.ZkGroupError;
+import org.signal.zkgroup.internal.ByteArray;
+import org.signal.zkgroup.internal.Native;
+
+public final class RateLimitCredentialRequestContext extends ByteArray {
+
+  public static final int SIZE = 225;
+
+  public RateLimitCredentialRequestContext(byte[] contents) throws InvalidInputException {
+    super(contents, SIZE);
+    
+    int ffi_return = Native.rateLimitCredentialRequestContextCheckValidContentsJNI(contents);
+
+    if (ffi_return == Native.FFI_RETURN_INPUT_ERROR) {
+      throw new InvalidInputException("FFI_RETURN_INPUT_ERROR");
+    }
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+    }
+  }
+
+  public RateLimitCredentialRequest getRequest() {
+    byte[] newContents = new byte[RateLimitCredentialRequest.SIZE];
+
+    int ffi_return = Native.rateLimitCredentialRequestContextGetRequestJNI(contents, newContents);
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+    }
+
+    try {
+      return new RateLimitCredentialRequest(newContents);
+    } catch (InvalidInputException e) {
+      throw new AssertionError(e);
+    }
+
+  }
+
+  public byte[] serialize() {
+    return contents.clone();
+  }
+
+}
@@ -0,0 +1,27 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+
+// Generated by zkgroup/codegen/codegen.py - do not edit
+
+package org.signal.zkgroup.auth;
+
+import org.signal.zkgroup.InvalidInputException;
+import org.signal.zkgroup.internal.ByteArray;
+
+public final class RateLimitTag extends ByteArray {
+
+  public static final int SIZE = 32;
+
+  public RateLimitTag(byte[] contents) throws InvalidInputException {
+    super(contents, SIZE);
+  }
+
+  public byte[] serialize() {
+    return contents.clone();
+  }
+
+}
@@ -0,0 +1,69 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+
+// Generated by zkgroup/codegen/codegen.py - do not edit
+
+package org.signal.zkgroup.auth;
+
+import java.nio.ByteBuffer;
+import org.signal.zkgroup.InvalidInputException;
+import org.signal.zkgroup.ZkGroupError;
+import org.signal.zkgroup.internal.ByteArray;
+import org.signal.zkgroup.internal.Native;
+
+public final class RateLimitToken extends ByteArray {
+
+  public static final int SIZE = 366;
+
+  public RateLimitToken(byte[] contents) throws InvalidInputException {
+    super(contents, SIZE);
+    
+    int ffi_return = Native.rateLimitTokenCheckValidContentsJNI(contents);
+
+    if (ffi_return == Native.FFI_RETURN_INPUT_ERROR) {
+      throw new InvalidInputException("FFI_RETURN_INPUT_ERROR");
+    }
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+    }
+  }
+
+  public RateLimitTag getTag() {
+    byte[] newContents = new byte[RateLimitTag.SIZE];
+
+    int ffi_return = Native.rateLimitTokenGetTagJNI(contents, newContents);
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+    }
+
+    try {
+      return new RateLimitTag(newContents);
+    } catch (InvalidInputException e) {
+      throw new AssertionError(e);
+    }
+
+  }
+
+  public int getRedemptionTime() {
+    byte[] newContents = new byte[4];
+
+    int ffi_return = Native.rateLimitTokenGetRedemptionTimeJNI(contents, newContents);
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+     }
+
+    return ByteBuffer.wrap(newContents).getInt();
+  }
+
+  public byte[] serialize() {
+    return contents.clone();
+  }
+
+}
@@ -17,6 +17,8 @@ import org.signal.zkgroup.ServerSecretParams;
 import org.signal.zkgroup.VerificationFailedException;
 import org.signal.zkgroup.InvalidRedemptionTimeException;
 import org.signal.zkgroup.ZkGroupError;
+import org.signal.zkgroup.groups.GroupInviteLinkPasswordCommitment;
+import org.signal.zkgroup.groups.GroupInviteLinkPresentation;
 import org.signal.zkgroup.groups.GroupPublicParams;
 import org.signal.zkgroup.internal.Native;
 import org.signal.zkgroup.util.UUIDUtil;
@@ -75,4 +77,61 @@ public class ServerZkAuthOperations {
     }
   }
 
+  public void verifyAuthCredentialPresentationWithContext(GroupPublicParams groupPublicParams, AuthCredentialPresentation authCredentialPresentation, byte[] context) throws VerificationFailedException {
+    int ffi_return = Native.serverSecretParamsVerifyAuthCredentialPresentationWithContextJNI(serverSecretParams.getInternalContentsForJNI(), groupPublicParams.getInternalContentsForJNI(), authCredentialPresentation.getInternalContentsForJNI(), context);
+    if (ffi_return == Native.FFI_RETURN_INPUT_ERROR) {
+      throw new VerificationFailedException();
+    }
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+    }
+  }
+
+  public RateLimitCredentialResponse issueRateLimitCredential(RateLimitCredentialRequest rateLimitCredentialRequest, int redemptionTime) {
+    return issueRateLimitCredential(new SecureRandom(), rateLimitCredentialRequest, redemptionTime);
+  }
+
+  public RateLimitCredentialResponse issueRateLimitCredential(SecureRandom secureRandom, RateLimitCredentialRequest rateLimitCredentialRequest, int redemptionTime) {
+    byte[] newContents = new byte[RateLimitCredentialResponse.SIZE];
+    byte[] random      = new byte[Native.RANDOM_LENGTH];
+
+    secureRandom.nextBytes(random);
+
+    int ffi_return = Native.serverSecretParamsIssueRateLimitCredentialDeterministicJNI(serverSecretParams.getInternalContentsForJNI(), random, rateLimitCredentialRequest.getInternalContentsForJNI(), redemptionTime, newContents);
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+    }
+
+    try {
+      return new RateLimitCredentialResponse(newContents);
+    } catch (InvalidInputException e) {
+      throw new AssertionError(e);
+    }
+
+  }
+
+  public void verifyRateLimitToken(RateLimitToken rateLimitToken) throws VerificationFailedException {
+    int ffi_return = Native.serverSecretParamsVerifyRateLimitTokenJNI(serverSecretParams.getInternalContentsForJNI(), rateLimitToken.getInternalContentsForJNI());
+    if (ffi_return == Native.FFI_RETURN_INPUT_ERROR) {
+      throw new VerificationFailedException();
+    }
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+    }
+  }
+
+  public void verifyGroupInviteLinkPresentation(GroupPublicParams groupPublicParams, GroupInviteLinkPasswordCommitment groupInviteLinkPasswordCommitment, GroupInviteLinkPresentation groupInviteLinkPresentation) throws VerificationFailedException {
+    int ffi_return = Native.serverSecretParamsVerifyGroupInviteLinkPresentationJNI(serverSecretParams.getInternalContentsForJNI(), groupPublicParams.getInternalContentsForJNI(), groupInviteLinkPasswordCommitment.getInternalContentsForJNI(), groupInviteLinkPresentation.getInternalContentsForJNI());
+    if (ffi_return == Native.FFI_RETURN_INPUT_ERROR) {
+      throw new VerificationFailedException();
+    }
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+    }
+  }
+
 }
@@ -151,4 +151,193 @@ public class ClientZkGroupCipher {
     return depaddedContents;
   }
 
+  public byte[] encryptBlobWithPadding(byte[] plaintext, int paddedLength) throws VerificationFailedException {
+    return encryptBlobWithPadding(new SecureRandom(), plaintext, paddedLength);
+  }
+
+  public byte[] encryptBlobWithPadding(SecureRandom secureRandom, byte[] plaintext, int paddedLength) throws VerificationFailedException {
+    if (paddedLength <= plaintext.length) {
+      throw new VerificationFailedException();
+    }
+
+    byte[] newContents = new byte[paddedLength+29];
+    byte[] random      = new byte[Native.RANDOM_LENGTH];
+
+    secureRandom.nextBytes(random);
+
+    int ffi_return = Native.groupSecretParamsEncryptBlobWithPaddingDeterministicJNI(groupSecretParams.getInternalContentsForJNI(), random, plaintext, paddedLength, newContents);
+    if (ffi_return == Native.FFI_RETURN_INPUT_ERROR) {
+      throw new VerificationFailedException();
+    }
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+    }
+
+    return newContents;
+  }
+
+  public byte[] decryptPaddedBlob(byte[] blobCiphertext) throws VerificationFailedException {
+    if (blobCiphertext.length < 29) {
+      throw new VerificationFailedException();
+    }
+
+    byte[] newContents = new byte[blobCiphertext.length-25];
+
+    int ffi_return = Native.groupSecretParamsDecryptPaddedBlobJNI(groupSecretParams.getInternalContentsForJNI(), blobCiphertext, newContents);
+    if (ffi_return == Native.FFI_RETURN_INPUT_ERROR) {
+      throw new VerificationFailedException();
+    }
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+    }
+
+    int plaintextLen = ByteBuffer.wrap(newContents).getInt();
+    byte[] plaintext = new byte[plaintextLen];
+    System.arraycopy(newContents, 4, plaintext, 0, plaintextLen);
+
+    return plaintext;
+  }
+
+  public GroupInviteLinkPresentation createGroupInviteLinkPresentation(GroupInviteLinkPassword groupInviteLinkPassword, UUID uuid) {
+    return createGroupInviteLinkPresentation(new SecureRandom(), groupInviteLinkPassword, uuid);
+  }
+
+  public GroupInviteLinkPresentation createGroupInviteLinkPresentation(SecureRandom secureRandom, GroupInviteLinkPassword groupInviteLinkPassword, UUID uuid) {
+    byte[] newContents = new byte[GroupInviteLinkPresentation.SIZE];
+    byte[] random      = new byte[Native.RANDOM_LENGTH];
+
+    secureRandom.nextBytes(random);
+
+    int ffi_return = Native.groupSecretParamsCreateGroupInviteLinkPresentationDeterministicJNI(groupSecretParams.getInternalContentsForJNI(), random, groupInviteLinkPassword.getInternalContentsForJNI(), UUIDUtil.serialize(uuid), newContents);
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+    }
+
+    try {
+      return new GroupInviteLinkPresentation(newContents);
+    } catch (InvalidInputException e) {
+      throw new AssertionError(e);
+    }
+
+  }
+
+  public RandomizedUuidCiphertext encryptUuidRandomized(UUID uuid) {
+    return encryptUuidRandomized(new SecureRandom(), uuid);
+  }
+
+  public RandomizedUuidCiphertext encryptUuidRandomized(SecureRandom secureRandom, UUID uuid) {
+    byte[] newContents = new byte[RandomizedUuidCiphertext.SIZE];
+    byte[] random      = new byte[Native.RANDOM_LENGTH];
+
+    secureRandom.nextBytes(random);
+
+    int ffi_return = Native.groupSecretParamsEncryptUuidRandomizedDeterministicJNI(groupSecretParams.getInternalContentsForJNI(), random, UUIDUtil.serialize(uuid), newContents);
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+    }
+
+    try {
+      return new RandomizedUuidCiphertext(newContents);
+    } catch (InvalidInputException e) {
+      throw new AssertionError(e);
+    }
+
+  }
+
+  public UUID decryptRandomizedUuid(RandomizedUuidCiphertext randomizedUuidCiphertext) throws VerificationFailedException {
+    byte[] newContents = new byte[UUIDUtil.UUID_LENGTH];
+
+    int ffi_return = Native.groupSecretParamsDecryptRandomizedUuidJNI(groupSecretParams.getInternalContentsForJNI(), randomizedUuidCiphertext.getInternalContentsForJNI(), newContents);
+    if (ffi_return == Native.FFI_RETURN_INPUT_ERROR) {
+      throw new VerificationFailedException();
+    }
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+    }
+
+    return UUIDUtil.deserialize(newContents);
+  }
+
+  public UuidCiphertextMatchProof createUuidCiphertextMatchProof(UuidCiphertext uuidCiphertext, RandomizedUuidCiphertext randomizedUuidCiphertext) {
+    return createUuidCiphertextMatchProof(new SecureRandom(), uuidCiphertext, randomizedUuidCiphertext);
+  }
+
+  public UuidCiphertextMatchProof createUuidCiphertextMatchProof(SecureRandom secureRandom, UuidCiphertext uuidCiphertext, RandomizedUuidCiphertext randomizedUuidCiphertext) {
+    byte[] newContents = new byte[UuidCiphertextMatchProof.SIZE];
+    byte[] random      = new byte[Native.RANDOM_LENGTH];
+
+    secureRandom.nextBytes(random);
+
+    int ffi_return = Native.groupSecretParamsCreateUuidCiphertextMatchProofDeterministicJNI(groupSecretParams.getInternalContentsForJNI(), random, uuidCiphertext.getInternalContentsForJNI(), randomizedUuidCiphertext.getInternalContentsForJNI(), newContents);
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+    }
+
+    try {
+      return new UuidCiphertextMatchProof(newContents);
+    } catch (InvalidInputException e) {
+      throw new AssertionError(e);
+    }
+
+  }
+
+  public byte[] encryptBlobWithAd(byte[] plaintext, byte[] associatedData) throws VerificationFailedException {
+    return encryptBlobWithAd(new SecureRandom(), plaintext, associatedData);
+  }
+
+  public byte[] encryptBlobWithAd(SecureRandom secureRandom, byte[] plaintext, byte[] associatedData) throws VerificationFailedException {
+
+    byte[] paddedPlaintext = new byte[plaintext.length + 4];
+    System.arraycopy(plaintext, 0, paddedPlaintext, 4, plaintext.length);
+
+    byte[] newContents = new byte[paddedPlaintext.length+29];
+    byte[] random      = new byte[Native.RANDOM_LENGTH];
+
+    secureRandom.nextBytes(random);
+
+    int ffi_return = Native.groupSecretParamsEncryptBlobWithAdDeterministicJNI(groupSecretParams.getInternalContentsForJNI(), random, paddedPlaintext, associatedData, newContents);
+    if (ffi_return == Native.FFI_RETURN_INPUT_ERROR) {
+      throw new VerificationFailedException();
+    }
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+    }
+
+    return newContents;
+  }
+
+  public byte[] decryptBlobWithAd(byte[] blobCiphertext, byte[] associatedData) throws VerificationFailedException {
+    byte[] newContents = new byte[blobCiphertext.length-29];
+
+    int ffi_return = Native.groupSecretParamsDecryptBlobWithAdJNI(groupSecretParams.getInternalContentsForJNI(), blobCiphertext, associatedData, newContents);
+    if (ffi_return == Native.FFI_RETURN_INPUT_ERROR) {
+      throw new VerificationFailedException();
+    }
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+    }
+
+    if (newContents.length < 4) {
+        throw new VerificationFailedException();
+    }
+
+    int padLen = ByteBuffer.wrap(newContents).getInt();
+    if (newContents.length < (4 + padLen))  {
+        throw new VerificationFailedException();
+    }
+
+    byte[] depaddedContents = new byte[newContents.length - (4 + padLen)];
+    System.arraycopy(newContents, 4, depaddedContents, 0, newContents.length - (4 + padLen));
+
+    return depaddedContents;
+  }
+
 }
@@ -0,0 +1,50 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+
+// Generated by zkgroup/codegen/codegen.py - do not edit
+
+package org.signal.zkgroup.groups;
+
+import org.signal.zkgroup.InvalidInputException;
+import org.signal.zkgroup.VerificationFailedException;
+import org.signal.zkgroup.ZkGroupError;
+import org.signal.zkgroup.internal.ByteArray;
+import org.signal.zkgroup.internal.Native;
+
+public final class GroupInviteLinkPassword extends ByteArray {
+
+  public static final int SIZE = 16;
+
+  public GroupInviteLinkPassword(byte[] contents) throws InvalidInputException {
+    super(contents, SIZE);
+  }
+
+  public GroupInviteLinkPasswordCommitment getCommitment(GroupPublicParams groupPublicParams) throws VerificationFailedException {
+    byte[] newContents = new byte[GroupInviteLinkPasswordCommitment.SIZE];
+
+    int ffi_return = Native.groupInviteLinkPasswordGetCommitmentJNI(contents, groupPublicParams.getInternalContentsForJNI(), newContents);
+    if (ffi_return == Native.FFI_RETURN_INPUT_ERROR) {
+      throw new VerificationFailedException();
+    }
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+    }
+
+    try {
+      return new GroupInviteLinkPasswordCommitment(newContents);
+    } catch (InvalidInputException e) {
+      throw new AssertionError(e);
+    }
+
+  }
+
+  public byte[] serialize() {
+    return contents.clone();
+  }
+
+}
@@ -10,6 +10,7 @@
 package org.signal.zkgroup.groups;
 
 import org.signal.zkgroup.InvalidInputException;
+import org.signal.zkgroup.VerificationFailedException;
 import org.signal.zkgroup.ZkGroupError;
 import org.signal.zkgroup.internal.ByteArray;
 import org.signal.zkgroup.internal.Native;
@@ -49,6 +50,17 @@ public final class GroupPublicParams extends ByteArray {
 
   }
 
+  public void verifyUuidCiphertextMatch(UuidCiphertext uuidCiphertext, RandomizedUuidCiphertext randomizedUuidCiphertext, UuidCiphertextMatchProof uuidCiphertextMatchProof) throws VerificationFailedException {
+    int ffi_return = Native.groupPublicParamsVerifyUuidCiphertextMatchJNI(contents, uuidCiphertext.getInternalContentsForJNI(), randomizedUuidCiphertext.getInternalContentsForJNI(), uuidCiphertextMatchProof.getInternalContentsForJNI());
+    if (ffi_return == Native.FFI_RETURN_INPUT_ERROR) {
+      throw new VerificationFailedException();
+    }
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+    }
+  }
+
   public byte[] serialize() {
     return contents.clone();
   }
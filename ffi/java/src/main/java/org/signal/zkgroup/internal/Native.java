@@ -70,20 +70,43 @@ public final class Native {
   public static native int groupSecretParamsDecryptProfileKeyJNI(byte[] self, byte[] profileKeyCiphertext, byte[] uuid, byte[] output);
   public static native int groupSecretParamsEncryptBlobDeterministicJNI(byte[] self, byte[] randomness, byte[] plaintext, byte[] output);
   public static native int groupSecretParamsDecryptBlobJNI(byte[] self, byte[] blobCiphertext, byte[] output);
+  public static native int groupSecretParamsEncryptBlobWithPaddingDeterministicJNI(byte[] self, byte[] randomness, byte[] plaintext, int paddedLength, byte[] output);
+  public static native int groupSecretParamsDecryptPaddedBlobJNI(byte[] self, byte[] blobCiphertext, byte[] output);
+  public static native int groupSecretParamsCreateGroupInviteLinkPresentationDeterministicJNI(byte[] self, byte[] randomness, byte[] groupInviteLinkPassword, byte[] uuid, byte[] output);
+  public static native int groupSecretParamsEncryptUuidRandomizedDeterministicJNI(byte[] self, byte[] randomness, byte[] uuid, byte[] output);
+  public static native int groupSecretParamsDecryptRandomizedUuidJNI(byte[] self, byte[] randomizedUuidCiphertext, byte[] output);
+  public static native int groupSecretParamsCreateUuidCiphertextMatchProofDeterministicJNI(byte[] self, byte[] randomness, byte[] uuidCiphertext, byte[] randomizedUuidCiphertext, byte[] output);
+  public static native int groupSecretParamsEncryptBlobWithAdDeterministicJNI(byte[] self, byte[] randomness, byte[] plaintext, byte[] associatedData, byte[] output);
+  public static native int groupSecretParamsDecryptBlobWithAdJNI(byte[] self, byte[] blobCiphertext, byte[] associatedData, byte[] output);
   public static native int serverSecretParamsGenerateDeterministicJNI(byte[] randomness, byte[] output);
   public static native int serverSecretParamsGetPublicParamsJNI(byte[] self, byte[] output);
   public static native int serverSecretParamsSignDeterministicJNI(byte[] self, byte[] randomness, byte[] message, byte[] output);
   public static native int serverSecretParamsCheckValidContentsJNI(byte[] self);
   public static native int serverPublicParamsReceiveAuthCredentialJNI(byte[] self, byte[] uuid, int redemptionTime, byte[] authCredentialResponse, byte[] output);
   public static native int serverPublicParamsCreateAuthCredentialPresentationDeterministicJNI(byte[] self, byte[] randomness, byte[] groupSecretParams, byte[] authCredential, byte[] output);
+  public static native int serverPublicParamsCreateAuthCredentialPresentationWithContextDeterministicJNI(byte[] self, byte[] randomness, byte[] groupSecretParams, byte[] authCredential, byte[] context, byte[] output);
+  public static native int serverPublicParamsCreateRateLimitCredentialRequestContextDeterministicJNI(byte[] self, byte[] randomness, byte[] output);
+  public static native int serverPublicParamsReceiveRateLimitCredentialJNI(byte[] self, byte[] rateLimitCredentialRequestContext, byte[] rateLimitCredentialResponse, byte[] output);
+  public static native int serverPublicParamsCreateRateLimitTokenDeterministicJNI(byte[] self, byte[] randomness, byte[] rateLimitCredential, byte[] output);
   public static native int serverPublicParamsCreateProfileKeyCredentialRequestContextDeterministicJNI(byte[] self, byte[] randomness, byte[] uuid, byte[] profileKey, byte[] output);
   public static native int serverPublicParamsReceiveProfileKeyCredentialJNI(byte[] self, byte[] profileKeyCredentialRequestContext, byte[] profileKeyCredentialResponse, byte[] output);
   public static native int serverPublicParamsCreateProfileKeyCredentialPresentationDeterministicJNI(byte[] self, byte[] randomness, byte[] groupSecretParams, byte[] profileKeyCredential, byte[] output);
+  public static native int serverPublicParamsCreateProfileKeyCredentialPresentationWithContextDeterministicJNI(byte[] self, byte[] randomness, byte[] groupSecretParams, byte[] profileKeyCredential, byte[] context, byte[] output);
+  public static native int serverPublicParamsCreateProfileKeyCredentialSelectivePresentationDeterministicJNI(byte[] self, byte[] randomness, byte[] groupSecretParams, byte[] profileKeyCredential, byte[] output);
+  public static native int serverPublicParamsCreateProfileKeyCredentialSelectivePresentationWithContextDeterministicJNI(byte[] self, byte[] randomness, byte[] groupSecretParams, byte[] profileKeyCredential, byte[] context, byte[] output);
   public static native int serverSecretParamsIssueAuthCredentialDeterministicJNI(byte[] self, byte[] randomness, byte[] uuid, int redemptionTime, byte[] output);
   public static native int serverSecretParamsVerifyAuthCredentialPresentationJNI(byte[] self, byte[] groupPublicParams, byte[] authCredentialPresentation);
+  public static native int serverSecretParamsVerifyAuthCredentialPresentationWithContextJNI(byte[] self, byte[] groupPublicParams, byte[] authCredentialPresentation, byte[] context);
+  public static native int serverSecretParamsIssueRateLimitCredentialDeterministicJNI(byte[] self, byte[] randomness, byte[] rateLimitCredentialRequest, int redemptionTime, byte[] output);
+  public static native int serverSecretParamsVerifyRateLimitTokenJNI(byte[] self, byte[] rateLimitToken);
+  public static native int serverSecretParamsVerifyGroupInviteLinkPresentationJNI(byte[] self, byte[] groupPublicParams, byte[] groupInviteLinkPasswordCommitment, byte[] groupInviteLinkPresentation);
   public static native int serverSecretParamsIssueProfileKeyCredentialDeterministicJNI(byte[] self, byte[] randomness, byte[] profileKeyCredentialRequest, byte[] uuid, byte[] profileKeyCommitment, byte[] output);
   public static native int serverSecretParamsVerifyProfileKeyCredentialPresentationJNI(byte[] self, byte[] groupPublicParams, byte[] profileKeyCredentialPresentation);
+  public static native int serverSecretParamsVerifyProfileKeyCredentialPresentationWithContextJNI(byte[] self, byte[] groupPublicParams, byte[] profileKeyCredentialPresentation, byte[] context);
+  public static native int serverSecretParamsVerifyProfileKeyCredentialSelectivePresentationJNI(byte[] self, byte[] groupPublicParams, byte[] profileKeyCredentialSelectivePresentation);
+  public static native int serverSecretParamsVerifyProfileKeyCredentialSelectivePresentationWithContextJNI(byte[] self, byte[] groupPublicParams, byte[] profileKeyCredentialSelectivePresentation, byte[] context);
   public static native int groupPublicParamsGetGroupIdentifierJNI(byte[] self, byte[] output);
+  public static native int groupPublicParamsVerifyUuidCiphertextMatchJNI(byte[] self, byte[] uuidCiphertext, byte[] randomizedUuidCiphertext, byte[] uuidCiphertextMatchProof);
   public static native int groupPublicParamsCheckValidContentsJNI(byte[] self);
   public static native int serverPublicParamsVerifySignatureJNI(byte[] self, byte[] message, byte[] notarySignature);
   public static native int serverPublicParamsCheckValidContentsJNI(byte[] self);
@@ -92,6 +115,14 @@ public final class Native {
   public static native int authCredentialPresentationGetUuidCiphertextJNI(byte[] self, byte[] output);
   public static native int authCredentialPresentationGetRedemptionTimeJNI(byte[] self, byte[] output);
   public static native int authCredentialPresentationCheckValidContentsJNI(byte[] self);
+  public static native int rateLimitCredentialRequestContextGetRequestJNI(byte[] self, byte[] output);
+  public static native int rateLimitCredentialRequestContextCheckValidContentsJNI(byte[] self);
+  public static native int rateLimitCredentialRequestCheckValidContentsJNI(byte[] self);
+  public static native int rateLimitCredentialResponseCheckValidContentsJNI(byte[] self);
+  public static native int rateLimitCredentialCheckValidContentsJNI(byte[] self);
+  public static native int rateLimitTokenGetTagJNI(byte[] self, byte[] output);
+  public static native int rateLimitTokenGetRedemptionTimeJNI(byte[] self, byte[] output);
+  public static native int rateLimitTokenCheckValidContentsJNI(byte[] self);
   public static native int profileKeyCredentialRequestContextGetRequestJNI(byte[] self, byte[] output);
   public static native int profileKeyCredentialRequestContextCheckValidContentsJNI(byte[] self);
   public static native int profileKeyCredentialRequestCheckValidContentsJNI(byte[] self);
@@ -100,8 +131,19 @@ public final class Native {
   public static native int profileKeyCredentialPresentationGetUuidCiphertextJNI(byte[] self, byte[] output);
   public static native int profileKeyCredentialPresentationGetProfileKeyCiphertextJNI(byte[] self, byte[] output);
   public static native int profileKeyCredentialPresentationCheckValidContentsJNI(byte[] self);
+  public static native int profileKeyCredentialSelectivePresentationGetUuidCiphertextJNI(byte[] self, byte[] output);
+  public static native int profileKeyCredentialSelectivePresentationCheckValidContentsJNI(byte[] self);
+  public static native int groupInviteLinkPasswordGetCommitmentJNI(byte[] self, byte[] groupPublicParams, byte[] output);
+  public static native int groupInviteLinkPasswordCommitmentCheckValidContentsJNI(byte[] self);
+  public static native int groupInviteLinkPresentationGetUuidCiphertextJNI(byte[] self, byte[] output);
+  public static native int groupInviteLinkPresentationCheckValidContentsJNI(byte[] self);
   public static native int uuidCiphertextCheckValidContentsJNI(byte[] self);
   public static native int profileKeyCiphertextCheckValidContentsJNI(byte[] self);
+  public static native int randomizedUuidCiphertextCheckValidContentsJNI(byte[] self);
+  public static native int uuidCiphertextMatchProofCheckValidContentsJNI(byte[] self);
+
+  // The ZkGroupError code behind this thread's last failed call, or 0
+  public static native int getLastErrorCodeJNI();
 
   private static void copy(InputStream in, OutputStream out) throws IOException {
     byte[] buffer = new byte[4096];
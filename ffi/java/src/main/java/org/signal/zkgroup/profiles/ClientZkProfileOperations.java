@@ -99,4 +99,76 @@ public class ClientZkProfileOperations {
 
   }
 
+  public ProfileKeyCredentialPresentation createProfileKeyCredentialPresentationWithContext(GroupSecretParams groupSecretParams, ProfileKeyCredential profileKeyCredential, byte[] context) {
+    return createProfileKeyCredentialPresentationWithContext(new SecureRandom(), groupSecretParams, profileKeyCredential, context);
+  }
+
+  public ProfileKeyCredentialPresentation createProfileKeyCredentialPresentationWithContext(SecureRandom secureRandom, GroupSecretParams groupSecretParams, ProfileKeyCredential profileKeyCredential, byte[] context) {
+    byte[] newContents = new byte[ProfileKeyCredentialPresentation.SIZE];
+    byte[] random      = new byte[Native.RANDOM_LENGTH];
+
+    secureRandom.nextBytes(random);
+
+    int ffi_return = Native.serverPublicParamsCreateProfileKeyCredentialPresentationWithContextDeterministicJNI(serverPublicParams.getInternalContentsForJNI(), random, groupSecretParams.getInternalContentsForJNI(), profileKeyCredential.getInternalContentsForJNI(), context, newContents);
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+    }
+
+    try {
+      return new ProfileKeyCredentialPresentation(newContents);
+    } catch (InvalidInputException e) {
+      throw new AssertionError(e);
+    }
+
+  }
+
+  public ProfileKeyCredentialSelectivePresentation createProfileKeyCredentialSelectivePresentation(GroupSecretParams groupSecretParams, ProfileKeyCredential profileKeyCredential) {
+    return createProfileKeyCredentialSelectivePresentation(new SecureRandom(), groupSecretParams, profileKeyCredential);
+  }
+
+  public ProfileKeyCredentialSelectivePresentation createProfileKeyCredentialSelectivePresentation(SecureRandom secureRandom, GroupSecretParams groupSecretParams, ProfileKeyCredential profileKeyCredential) {
+    byte[] newContents = new byte[ProfileKeyCredentialSelectivePresentation.SIZE];
+    byte[] random      = new byte[Native.RANDOM_LENGTH];
+
+    secureRandom.nextBytes(random);
+
+    int ffi_return = Native.serverPublicParamsCreateProfileKeyCredentialSelectivePresentationDeterministicJNI(serverPublicParams.getInternalContentsForJNI(), random, groupSecretParams.getInternalContentsForJNI(), profileKeyCredential.getInternalContentsForJNI(), newContents);
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+    }
+
+    try {
+      return new ProfileKeyCredentialSelectivePresentation(newContents);
+    } catch (InvalidInputException e) {
+      throw new AssertionError(e);
+    }
+
+  }
+
+  public ProfileKeyCredentialSelectivePresentation createProfileKeyCredentialSelectivePresentationWithContext(GroupSecretParams groupSecretParams, ProfileKeyCredential profileKeyCredential, byte[] context) {
+    return createProfileKeyCredentialSelectivePresentationWithContext(new SecureRandom(), groupSecretParams, profileKeyCredential, context);
+  }
+
+  public ProfileKeyCredentialSelectivePresentation createProfileKeyCredentialSelectivePresentationWithContext(SecureRandom secureRandom, GroupSecretParams groupSecretParams, ProfileKeyCredential profileKeyCredential, byte[] context) {
+    byte[] newContents = new byte[ProfileKeyCredentialSelectivePresentation.SIZE];
+    byte[] random      = new byte[Native.RANDOM_LENGTH];
+
+    secureRandom.nextBytes(random);
+
+    int ffi_return = Native.serverPublicParamsCreateProfileKeyCredentialSelectivePresentationWithContextDeterministicJNI(serverPublicParams.getInternalContentsForJNI(), random, groupSecretParams.getInternalContentsForJNI(), profileKeyCredential.getInternalContentsForJNI(), context, newContents);
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+    }
+
+    try {
+      return new ProfileKeyCredentialSelectivePresentation(newContents);
+    } catch (InvalidInputException e) {
+      throw new AssertionError(e);
+    }
+
+  }
+
 }
@@ -0,0 +1,57 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+
+// Generated by zkgroup/codegen/codegen.py - do not edit
+
+package org.signal.zkgroup.profiles;
+
+import org.signal.zkgroup.InvalidInputException;
+import org.signal.zkgroup.ZkGroupError;
+import org.signal.zkgroup.groups.UuidCiphertext;
+import org.signal.zkgroup.internal.ByteArray;
+import org.signal.zkgroup.internal.Native;
+
+public final class ProfileKeyCredentialSelectivePresentation extends ByteArray {
+
+  public static final int SIZE = 522;
+
+  public ProfileKeyCredentialSelectivePresentation(byte[] contents) throws InvalidInputException {
+    super(contents, SIZE);
+    
+    int ffi_return = Native.profileKeyCredentialSelectivePresentationCheckValidContentsJNI(contents);
+
+    if (ffi_return == Native.FFI_RETURN_INPUT_ERROR) {
+      throw new InvalidInputException("FFI_RETURN_INPUT_ERROR");
+    }
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+    }
+  }
+
+  public UuidCiphertext getUuidCiphertext() {
+    byte[] newContents = new byte[UuidCiphertext.SIZE];
+
+    int ffi_return = Native.profileKeyCredentialSelectivePresentationGetUuidCiphertextJNI(contents, newContents);
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+    }
+
+    try {
+      return new UuidCiphertext(newContents);
+    } catch (InvalidInputException e) {
+      throw new AssertionError(e);
+    }
+
+  }
+
+  public byte[] serialize() {
+    return contents.clone();
+  }
+
+}
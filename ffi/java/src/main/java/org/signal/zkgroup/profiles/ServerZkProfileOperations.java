@@ -65,4 +65,37 @@ public class ServerZkProfileOperations {
     }
   }
 
+  public void verifyProfileKeyCredentialPresentationWithContext(GroupPublicParams groupPublicParams, ProfileKeyCredentialPresentation profileKeyCredentialPresentation, byte[] context) throws VerificationFailedException {
+    int ffi_return = Native.serverSecretParamsVerifyProfileKeyCredentialPresentationWithContextJNI(serverSecretParams.getInternalContentsForJNI(), groupPublicParams.getInternalContentsForJNI(), profileKeyCredentialPresentation.getInternalContentsForJNI(), context);
+    if (ffi_return == Native.FFI_RETURN_INPUT_ERROR) {
+      throw new VerificationFailedException();
+    }
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+    }
+  }
+
+  public void verifyProfileKeyCredentialSelectivePresentation(GroupPublicParams groupPublicParams, ProfileKeyCredentialSelectivePresentation profileKeyCredentialSelectivePresentation) throws VerificationFailedException {
+    int ffi_return = Native.serverSecretParamsVerifyProfileKeyCredentialSelectivePresentationJNI(serverSecretParams.getInternalContentsForJNI(), groupPublicParams.getInternalContentsForJNI(), profileKeyCredentialSelectivePresentation.getInternalContentsForJNI());
+    if (ffi_return == Native.FFI_RETURN_INPUT_ERROR) {
+      throw new VerificationFailedException();
+    }
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+    }
+  }
+
+  public void verifyProfileKeyCredentialSelectivePresentationWithContext(GroupPublicParams groupPublicParams, ProfileKeyCredentialSelectivePresentation profileKeyCredentialSelectivePresentation, byte[] context) throws VerificationFailedException {
+    int ffi_return = Native.serverSecretParamsVerifyProfileKeyCredentialSelectivePresentationWithContextJNI(serverSecretParams.getInternalContentsForJNI(), groupPublicParams.getInternalContentsForJNI(), profileKeyCredentialSelectivePresentation.getInternalContentsForJNI(), context);
+    if (ffi_return == Native.FFI_RETURN_INPUT_ERROR) {
+      throw new VerificationFailedException();
+    }
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+    }
+  }
+
 }
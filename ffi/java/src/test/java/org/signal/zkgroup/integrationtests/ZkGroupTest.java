@@ -22,13 +22,23 @@ import org.signal.zkgroup.auth.AuthCredential;
 import org.signal.zkgroup.auth.AuthCredentialPresentation;
 import org.signal.zkgroup.auth.AuthCredentialResponse;
 import org.signal.zkgroup.auth.ClientZkAuthOperations;
+import org.signal.zkgroup.auth.RateLimitCredential;
+import org.signal.zkgroup.auth.RateLimitCredentialRequestContext;
+import org.signal.zkgroup.auth.RateLimitCredentialResponse;
+import org.signal.zkgroup.auth.RateLimitToken;
 import org.signal.zkgroup.auth.ServerZkAuthOperations;
 import org.signal.zkgroup.groups.ClientZkGroupCipher;
+import org.signal.zkgroup.groups.GroupInviteLinkPassword;
+import org.signal.zkgroup.groups.GroupInviteLinkPasswordCommitment;
+import org.signal.zkgroup.groups.GroupInviteLinkPresentation;
 import org.signal.zkgroup.groups.GroupMasterKey;
 import org.signal.zkgroup.groups.GroupPublicParams;
 import org.signal.zkgroup.groups.GroupSecretParams;
 import org.signal.zkgroup.groups.ProfileKeyCiphertext;
+import org.signal.zkgroup.groups.RandomizedUuidCiphertext;
 import org.signal.zkgroup.groups.UuidCiphertext;
+import org.signal.zkgroup.groups.UuidCiphertextMatchProof;
+import org.signal.zkgroup.internal.Native;
 import org.signal.zkgroup.profiles.ClientZkProfileOperations;
 import org.signal.zkgroup.profiles.ProfileKey;
 import org.signal.zkgroup.profiles.ProfileKeyCommitment;
@@ -37,6 +47,7 @@ import org.signal.zkgroup.profiles.ProfileKeyCredentialPresentation;
 import org.signal.zkgroup.profiles.ProfileKeyCredentialRequest;
 import org.signal.zkgroup.profiles.ProfileKeyCredentialRequestContext;
 import org.signal.zkgroup.profiles.ProfileKeyCredentialResponse;
+import org.signal.zkgroup.profiles.ProfileKeyCredentialSelectivePresentation;
 import org.signal.zkgroup.profiles.ProfileKeyVersion;
 import org.signal.zkgroup.profiles.ServerZkProfileOperations;
 import org.signal.zkgroup.util.UUIDUtil;
@@ -47,11 +58,14 @@ import java.util.UUID;
 
 import static org.junit.Assert.assertArrayEquals;
 import static org.junit.Assert.assertEquals;
+import static org.junit.Assert.assertFalse;
 
 public final class ZkGroupTest extends SecureRandomTest {
 
   private static final byte[] TEST_ARRAY_16   = Hex.fromStringCondensedAssert("000102030405060708090a0b0c0d0e0f");
 
+  private static final byte[] TEST_ARRAY_16_1 = Hex.fromStringCondensedAssert("6465666768696a6b6c6d6e6f70717273");
+
   private static final byte[] TEST_ARRAY_32   = Hex.fromStringCondensedAssert("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f");
 
   private static final byte[] TEST_ARRAY_32_1 = Hex.fromStringCondensedAssert("6465666768696a6b6c6d6e6f707172737475767778797a7b7c7d7e7f80818283");
@@ -269,6 +283,11 @@ private static final byte[] profileKeyPresentationResult = Hex.fromStringCondens
     UuidCiphertext uuidCiphertextRecv = presentation.getUuidCiphertext();
     assertArrayEquals(uuidCiphertext.serialize(), uuidCiphertextRecv.serialize());
 
+    // Present again, revealing only the UUID
+    ProfileKeyCredentialSelectivePresentation selectivePresentation = clientZkProfileCipher.createProfileKeyCredentialSelectivePresentation(createSecureRandom(TEST_ARRAY_32_5), groupSecretParams, profileKeyCredential);
+    serverZkProfile.verifyProfileKeyCredentialSelectivePresentation(groupPublicParams, selectivePresentation);
+    assertArrayEquals(uuidCiphertext.serialize(), selectivePresentation.getUuidCiphertext().serialize());
+
     ProfileKeyVersion pkvB = profileKey.getProfileKeyVersion(uuid);
     ProfileKeyVersion pkvC = new ProfileKeyVersion(pkvB.serialize());
     if (!pkvB.serialize().equals(pkvC.serialize()))
@@ -336,6 +355,200 @@ private static final byte[] profileKeyPresentationResult = Hex.fromStringCondens
     assertArrayEquals(plaintext, plaintext257);
   }
 
+  @Test
+  public void testBlobEncryptionWithPadding() throws InvalidInputException, VerificationFailedException {
+
+    GroupMasterKey    masterKey         = new GroupMasterKey(TEST_ARRAY_32_1);
+    GroupSecretParams groupSecretParams = GroupSecretParams.deriveFromMasterKey(masterKey);
+    ClientZkGroupCipher clientZkGroupCipher = new ClientZkGroupCipher(groupSecretParams);
+
+    byte[] plaintext = Hex.fromStringCondensedAssert("0102030405060708111213141516171819");
+
+    byte[] ciphertext = clientZkGroupCipher.encryptBlobWithPadding(createSecureRandom(TEST_ARRAY_32_2), plaintext, 64);
+    assertEquals(64 + 29, ciphertext.length);
+    assertArrayEquals(plaintext, clientZkGroupCipher.decryptPaddedBlob(ciphertext));
+
+    // The unpadded plaintext no longer fills the output decryptBlob sizes
+    try {
+      clientZkGroupCipher.decryptBlob(ciphertext);
+      throw new AssertionError("Padded blob decrypted without removing the padding");
+    } catch (VerificationFailedException e) {
+      // good
+    }
+
+    try {
+      clientZkGroupCipher.encryptBlobWithPadding(createSecureRandom(TEST_ARRAY_32_2), plaintext, plaintext.length);
+      throw new AssertionError("Plaintext padded to its own length");
+    } catch (VerificationFailedException e) {
+      // good
+    }
+  }
+
+  @Test
+  public void testLastErrorCode() throws VerificationFailedException {
+
+    GroupSecretParams   groupSecretParams   = GroupSecretParams.generate(createSecureRandom(TEST_ARRAY_32));
+    ClientZkGroupCipher clientZkGroupCipher = new ClientZkGroupCipher(groupSecretParams);
+
+    byte[] plaintext  = Hex.fromStringCondensedAssert("0102030405060708111213141516171819");
+    byte[] ciphertext = clientZkGroupCipher.encryptBlob(createSecureRandom(TEST_ARRAY_32_2), plaintext);
+    ciphertext[0] ^= 1;
+
+    try {
+      clientZkGroupCipher.decryptBlob(ciphertext);
+      throw new AssertionError("Decrypted a tampered blob");
+    } catch (VerificationFailedException e) {
+      // DecryptionFailure
+      assertEquals(101, Native.getLastErrorCodeJNI());
+    }
+
+    ciphertext[0] ^= 1;
+    clientZkGroupCipher.decryptBlob(ciphertext);
+    assertEquals(0, Native.getLastErrorCodeJNI());
+  }
+
+  @Test
+  public void testBlobEncryptionWithAd() throws InvalidInputException, VerificationFailedException {
+
+    GroupMasterKey    masterKey         = new GroupMasterKey(TEST_ARRAY_32_1);
+    GroupSecretParams groupSecretParams = GroupSecretParams.deriveFromMasterKey(masterKey);
+    ClientZkGroupCipher clientZkGroupCipher = new ClientZkGroupCipher(groupSecretParams);
+
+    byte[] plaintext = Hex.fromStringCondensedAssert("0102030405060708111213141516171819");
+    byte[] title     = "title:1".getBytes();
+
+    byte[] ciphertext = clientZkGroupCipher.encryptBlobWithAd(createSecureRandom(TEST_ARRAY_32_2), plaintext, title);
+    assertArrayEquals(plaintext, clientZkGroupCipher.decryptBlobWithAd(ciphertext, title));
+
+    try {
+      clientZkGroupCipher.decryptBlobWithAd(ciphertext, "description:1".getBytes());
+      throw new AssertionError("Decrypted with the wrong associated data");
+    } catch (VerificationFailedException e) {
+      // good
+    }
+  }
+
+  @Test
+  public void testGroupInviteLink() throws InvalidInputException, VerificationFailedException {
+
+    UUID uuid = UUIDUtil.deserialize(TEST_ARRAY_16);
+
+    ServerSecretParams     serverSecretParams = ServerSecretParams.generate(createSecureRandom(TEST_ARRAY_32));
+    ServerZkAuthOperations serverZkAuth       = new ServerZkAuthOperations(serverSecretParams);
+
+    GroupMasterKey      masterKey           = new GroupMasterKey(TEST_ARRAY_32_1);
+    GroupSecretParams   groupSecretParams   = GroupSecretParams.deriveFromMasterKey(masterKey);
+    GroupPublicParams   groupPublicParams   = groupSecretParams.getPublicParams();
+    ClientZkGroupCipher clientZkGroupCipher = new ClientZkGroupCipher(groupSecretParams);
+
+    GroupInviteLinkPassword           password   = new GroupInviteLinkPassword(Hex.fromStringCondensedAssert("a0a1a2a3a4a5a6a7a8a9aaabacadaeaf"));
+    GroupInviteLinkPasswordCommitment commitment = password.getCommitment(groupPublicParams);
+
+    GroupInviteLinkPresentation presentation = clientZkGroupCipher.createGroupInviteLinkPresentation(createSecureRandom(TEST_ARRAY_32_2), password, uuid);
+    assertArrayEquals(clientZkGroupCipher.encryptUuid(uuid).serialize(), presentation.getUuidCiphertext().serialize());
+
+    serverZkAuth.verifyGroupInviteLinkPresentation(groupPublicParams, commitment, presentation);
+
+    GroupInviteLinkPassword otherPassword = new GroupInviteLinkPassword(Hex.fromStringCondensedAssert("b0b1b2b3b4b5b6b7b8b9babbbcbdbebf"));
+    try {
+      serverZkAuth.verifyGroupInviteLinkPresentation(groupPublicParams, otherPassword.getCommitment(groupPublicParams), presentation);
+      throw new AssertionError("Verified with the wrong password");
+    } catch (VerificationFailedException e) {
+      // good
+    }
+  }
+
+  @Test
+  public void testUuidCiphertextMatch() throws VerificationFailedException {
+
+    UUID uuid      = UUIDUtil.deserialize(TEST_ARRAY_16);
+    UUID otherUuid = UUIDUtil.deserialize(TEST_ARRAY_16_1);
+
+    GroupMasterKey      masterKey           = new GroupMasterKey(TEST_ARRAY_32_1);
+    GroupSecretParams   groupSecretParams   = GroupSecretParams.deriveFromMasterKey(masterKey);
+    GroupPublicParams   groupPublicParams   = groupSecretParams.getPublicParams();
+    ClientZkGroupCipher clientZkGroupCipher = new ClientZkGroupCipher(groupSecretParams);
+
+    RandomizedUuidCiphertext entry      = clientZkGroupCipher.encryptUuidRandomized(createSecureRandom(TEST_ARRAY_32_2), uuid);
+    RandomizedUuidCiphertext otherEntry = clientZkGroupCipher.encryptUuidRandomized(createSecureRandom(TEST_ARRAY_32_3), otherUuid);
+    assertEquals(uuid, clientZkGroupCipher.decryptRandomizedUuid(entry));
+
+    UuidCiphertext           uuidCiphertext = clientZkGroupCipher.encryptUuid(uuid);
+    UuidCiphertextMatchProof proof          = clientZkGroupCipher.createUuidCiphertextMatchProof(createSecureRandom(TEST_ARRAY_32_4), uuidCiphertext, entry);
+
+    groupPublicParams.verifyUuidCiphertextMatch(uuidCiphertext, entry, proof);
+
+    try {
+      groupPublicParams.verifyUuidCiphertextMatch(uuidCiphertext, otherEntry, proof);
+      throw new AssertionError("Verified against the wrong entry");
+    } catch (VerificationFailedException e) {
+      // good
+    }
+  }
+
+  @Test
+  public void testPresentationContext() throws VerificationFailedException, InvalidInputException {
+
+    UUID uuid           = UUIDUtil.deserialize(TEST_ARRAY_16);
+    int  redemptionTime = 123456;
+
+    ServerSecretParams     serverSecretParams = ServerSecretParams.generate(createSecureRandom(TEST_ARRAY_32));
+    ServerPublicParams     serverPublicParams = serverSecretParams.getPublicParams();
+    ServerZkAuthOperations serverZkAuth       = new ServerZkAuthOperations(serverSecretParams);
+
+    GroupMasterKey    masterKey         = new GroupMasterKey(TEST_ARRAY_32_1);
+    GroupSecretParams groupSecretParams = GroupSecretParams.deriveFromMasterKey(masterKey);
+    GroupPublicParams groupPublicParams = groupSecretParams.getPublicParams();
+
+    AuthCredentialResponse authCredentialResponse = serverZkAuth.issueAuthCredential(createSecureRandom(TEST_ARRAY_32_2), uuid, redemptionTime);
+    ClientZkAuthOperations clientZkAuthCipher     = new ClientZkAuthOperations(serverPublicParams);
+    AuthCredential         authCredential         = clientZkAuthCipher.receiveAuthCredential(uuid, redemptionTime, authCredentialResponse);
+
+    AuthCredentialPresentation presentation = clientZkAuthCipher.createAuthCredentialPresentationWithContext(createSecureRandom(TEST_ARRAY_32_5), groupSecretParams, authCredential, TEST_ARRAY_16);
+
+    serverZkAuth.verifyAuthCredentialPresentationWithContext(groupPublicParams, presentation, TEST_ARRAY_16);
+
+    try {
+      serverZkAuth.verifyAuthCredentialPresentationWithContext(groupPublicParams, presentation, TEST_ARRAY_16_1);
+      throw new AssertionError("Verified with the wrong context");
+    } catch (VerificationFailedException e) {
+      // good
+    }
+  }
+
+  @Test
+  public void testRateLimitToken() throws VerificationFailedException, InvalidInputException {
+
+    int redemptionTime = 123456;
+
+    ServerSecretParams     serverSecretParams = ServerSecretParams.generate(createSecureRandom(TEST_ARRAY_32));
+    ServerPublicParams     serverPublicParams = serverSecretParams.getPublicParams();
+    ServerZkAuthOperations serverZkAuth       = new ServerZkAuthOperations(serverSecretParams);
+    ClientZkAuthOperations clientZkAuthCipher = new ClientZkAuthOperations(serverPublicParams);
+
+    RateLimitCredentialRequestContext context             = clientZkAuthCipher.createRateLimitCredentialRequestContext(createSecureRandom(TEST_ARRAY_32_1));
+    RateLimitCredentialResponse       response            = serverZkAuth.issueRateLimitCredential(createSecureRandom(TEST_ARRAY_32_2), context.getRequest(), redemptionTime);
+    RateLimitCredential               rateLimitCredential = clientZkAuthCipher.receiveRateLimitCredential(context, response);
+
+    RateLimitToken token      = clientZkAuthCipher.createRateLimitToken(createSecureRandom(TEST_ARRAY_32_3), rateLimitCredential);
+    RateLimitToken tokenAgain = clientZkAuthCipher.createRateLimitToken(createSecureRandom(TEST_ARRAY_32_4), rateLimitCredential);
+
+    serverZkAuth.verifyRateLimitToken(token);
+    serverZkAuth.verifyRateLimitToken(tokenAgain);
+
+    assertEquals(redemptionTime, token.getRedemptionTime());
+    assertArrayEquals(token.getTag().serialize(), tokenAgain.getTag().serialize());
+
+    // A second credential for the same day has its own secret, and so its own tag
+    RateLimitCredentialRequestContext otherContext    = clientZkAuthCipher.createRateLimitCredentialRequestContext(createSecureRandom(TEST_ARRAY_32_5));
+    RateLimitCredentialResponse       otherResponse   = serverZkAuth.issueRateLimitCredential(createSecureRandom(TEST_ARRAY_32_2), otherContext.getRequest(), redemptionTime);
+    RateLimitCredential               otherCredential = clientZkAuthCipher.receiveRateLimitCredential(otherContext, otherResponse);
+    RateLimitToken                    otherToken      = clientZkAuthCipher.createRateLimitToken(createSecureRandom(TEST_ARRAY_32_3), otherCredential);
+
+    serverZkAuth.verifyRateLimitToken(otherToken);
+    assertFalse(Arrays.equals(token.getTag().serialize(), otherToken.getTag().serialize()));
+  }
+
   private void assertByteArray(String expectedAsHex, byte[] actual) {
     byte[] expectedBytes = Hex.fromStringCondensedAssert(expectedAsHex);
 
@@ -11,16 +11,27 @@ export { default as ServerZkAuthOperations } from './zkgroup/auth/ServerZkAuthOp
 export { default as AuthCredential } from './zkgroup/auth/AuthCredential'
 export { default as AuthCredentialResponse } from './zkgroup/auth/AuthCredentialResponse'
 export { default as AuthCredentialPresentation } from './zkgroup/auth/AuthCredentialPresentation'
+export { default as RateLimitCredential } from './zkgroup/auth/RateLimitCredential'
+export { default as RateLimitCredentialRequest } from './zkgroup/auth/RateLimitCredentialRequest'
+export { default as RateLimitCredentialRequestContext } from './zkgroup/auth/RateLimitCredentialRequestContext'
+export { default as RateLimitCredentialResponse } from './zkgroup/auth/RateLimitCredentialResponse'
+export { default as RateLimitTag } from './zkgroup/auth/RateLimitTag'
+export { default as RateLimitToken } from './zkgroup/auth/RateLimitToken'
 
 // Groups
 export { default as ClientZkGroupCipher } from './zkgroup/groups/ClientZkGroupCipher';
 
 export { default as GroupIdentifier } from './zkgroup/groups/GroupIdentifier';
+export { default as GroupInviteLinkPassword } from './zkgroup/groups/GroupInviteLinkPassword';
+export { default as GroupInviteLinkPasswordCommitment } from './zkgroup/groups/GroupInviteLinkPasswordCommitment';
+export { default as GroupInviteLinkPresentation } from './zkgroup/groups/GroupInviteLinkPresentation';
 export { default as GroupMasterKey } from './zkgroup/groups/GroupMasterKey';
 export { default as GroupPublicParams } from './zkgroup/groups/GroupPublicParams';
 export { default as GroupSecretParams } from './zkgroup/groups/GroupSecretParams';
 export { default as ProfileKeyCiphertext } from './zkgroup/groups/ProfileKeyCiphertext';
+export { default as RandomizedUuidCiphertext } from './zkgroup/groups/RandomizedUuidCiphertext';
 export { default as UuidCiphertext } from './zkgroup/groups/UuidCiphertext';
+export { default as UuidCiphertextMatchProof } from './zkgroup/groups/UuidCiphertextMatchProof';
 
 // Internal
 export { default as FFICompatArray, FFICompatArrayType } from './zkgroup/internal/FFICompatArray';
@@ -37,4 +48,5 @@ export { default as ProfileKeyCredential } from './zkgroup/profiles/ProfileKeyCr
 export { default as ProfileKeyCredentialPresentation } from './zkgroup/profiles/ProfileKeyCredentialPresentation';
 export { default as ProfileKeyCredentialRequestContext } from './zkgroup/profiles/ProfileKeyCredentialRequestContext';
 export { default as ProfileKeyCredentialResponse } from './zkgroup/profiles/ProfileKeyCredentialResponse';
+export { default as ProfileKeyCredentialSelectivePresentation } from './zkgroup/profiles/ProfileKeyCredentialSelectivePresentation';
 export { default as ProfileKeyVersion } from './zkgroup/profiles/ProfileKeyVersion';
@@ -1,6 +1,7 @@
 import { assert } from 'chai';
 import { toUUID, fromUUID } from '../zkgroup/internal/UUIDUtil';
 import FFICompatArray, { FFICompatArrayType } from '../zkgroup/internal/FFICompatArray';
+import Native from '../zkgroup/internal/Native';
 
 import AssertionError from '../zkgroup/errors/AssertionError';
 
@@ -8,6 +9,7 @@ import ServerSecretParams from '../zkgroup/ServerSecretParams';
 import ServerZkAuthOperations from '../zkgroup/auth/ServerZkAuthOperations';
 import GroupMasterKey from '../zkgroup/groups/GroupMasterKey';
 import GroupSecretParams from '../zkgroup/groups/GroupSecretParams';
+import GroupInviteLinkPassword from '../zkgroup/groups/GroupInviteLinkPassword';
 import ClientZkAuthOperations from '../zkgroup/auth/ClientZkAuthOperations';
 import ClientZkGroupCipher from '../zkgroup/groups/ClientZkGroupCipher';
 import ServerZkProfileOperations from '../zkgroup/profiles/ServerZkProfileOperations';
@@ -50,6 +52,7 @@ function clone(data: FFICompatArrayType) {
 
 describe('ZKGroup', () => {
   const TEST_ARRAY_16   = hexToCompatArray('000102030405060708090a0b0c0d0e0f');
+  const TEST_ARRAY_16_1 = hexToCompatArray('6465666768696a6b6c6d6e6f70717273');
   const TEST_ARRAY_32   = hexToCompatArray('000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f');
   const TEST_ARRAY_32_1 = hexToCompatArray('6465666768696a6b6c6d6e6f707172737475767778797a7b7c7d7e7f80818283');
   const TEST_ARRAY_32_2 = hexToCompatArray('c8c9cacbcccdcecfd0d1d2d3d4d5d6d7d8d9dadbdcdddedfe0e1e2e3e4e5e6e7');
@@ -164,6 +167,11 @@ describe('ZKGroup', () => {
     const uuidCiphertextRecv = presentation.getUuidCiphertext();
     assertArrayEquals(uuidCiphertext.serialize(), uuidCiphertextRecv.serialize());
 
+    // Present again, revealing only the UUID
+    const selectivePresentation = clientZkProfileCipher.createProfileKeyCredentialSelectivePresentationWithRandom(TEST_ARRAY_32_5, groupSecretParams, profileKeyCredential);
+    serverZkProfile.verifyProfileKeyCredentialSelectivePresentation(groupPublicParams, selectivePresentation);
+    assertArrayEquals(uuidCiphertext.serialize(), selectivePresentation.getUuidCiphertext().serialize());
+
     const pkvB = profileKey.getProfileKeyVersion(uuid);
     const pkvC = new ProfileKeyVersion(pkvB.serialize());
     assertArrayEquals(pkvB.serialize(), pkvC.serialize());
@@ -237,4 +245,178 @@ describe('ZKGroup', () => {
     const plaintext257 = clientZkGroupCipher.decryptBlob(ciphertextPaddedWith257);
     assertArrayEquals(plaintext, plaintext257);
   });
+
+  it('testGroupInviteLink', () => {
+    const uuid = toUUID(TEST_ARRAY_16);
+
+    const serverSecretParams = ServerSecretParams.generateWithRandom(TEST_ARRAY_32);
+    const serverZkAuth       = new ServerZkAuthOperations(serverSecretParams);
+
+    const masterKey           = new GroupMasterKey(TEST_ARRAY_32_1);
+    const groupSecretParams   = GroupSecretParams.deriveFromMasterKey(masterKey);
+    const groupPublicParams   = groupSecretParams.getPublicParams();
+    const clientZkGroupCipher = new ClientZkGroupCipher(groupSecretParams);
+
+    const password   = new GroupInviteLinkPassword(hexToCompatArray('a0a1a2a3a4a5a6a7a8a9aaabacadaeaf'));
+    const commitment = password.getCommitment(groupPublicParams);
+
+    const presentation = clientZkGroupCipher.createGroupInviteLinkPresentationWithRandom(TEST_ARRAY_32_2, password, uuid);
+    assertArrayEquals(clientZkGroupCipher.encryptUuid(uuid).serialize(), presentation.getUuidCiphertext().serialize());
+
+    serverZkAuth.verifyGroupInviteLinkPresentation(groupPublicParams, commitment, presentation);
+
+    const otherPassword = new GroupInviteLinkPassword(hexToCompatArray('b0b1b2b3b4b5b6b7b8b9babbbcbdbebf'));
+    try {
+      serverZkAuth.verifyGroupInviteLinkPresentation(groupPublicParams, otherPassword.getCommitment(groupPublicParams), presentation);
+      assert.fail();
+    } catch (error) {
+      // good
+    }
+  });
+
+  it('testUuidCiphertextMatch', () => {
+    const uuid      = toUUID(TEST_ARRAY_16);
+    const otherUuid = toUUID(TEST_ARRAY_16_1);
+
+    const masterKey           = new GroupMasterKey(TEST_ARRAY_32_1);
+    const groupSecretParams   = GroupSecretParams.deriveFromMasterKey(masterKey);
+    const groupPublicParams   = groupSecretParams.getPublicParams();
+    const clientZkGroupCipher = new ClientZkGroupCipher(groupSecretParams);
+
+    const entry      = clientZkGroupCipher.encryptUuidRandomizedWithRandom(TEST_ARRAY_32_2, uuid);
+    const otherEntry = clientZkGroupCipher.encryptUuidRandomizedWithRandom(TEST_ARRAY_32_3, otherUuid);
+    assert.strictEqual(uuid, clientZkGroupCipher.decryptRandomizedUuid(entry));
+
+    const uuidCiphertext = clientZkGroupCipher.encryptUuid(uuid);
+    const proof          = clientZkGroupCipher.createUuidCiphertextMatchProofWithRandom(TEST_ARRAY_32_4, uuidCiphertext, entry);
+
+    groupPublicParams.verifyUuidCiphertextMatch(uuidCiphertext, entry, proof);
+
+    try {
+      groupPublicParams.verifyUuidCiphertextMatch(uuidCiphertext, otherEntry, proof);
+      assert.fail();
+    } catch (error) {
+      // good
+    }
+  });
+
+  it('testPresentationContext', () => {
+    const uuid           = toUUID(TEST_ARRAY_16);
+    const redemptionTime = 123456;
+
+    const serverSecretParams = ServerSecretParams.generateWithRandom(TEST_ARRAY_32);
+    const serverPublicParams = serverSecretParams.getPublicParams();
+    const serverZkAuth       = new ServerZkAuthOperations(serverSecretParams);
+
+    const masterKey         = new GroupMasterKey(TEST_ARRAY_32_1);
+    const groupSecretParams = GroupSecretParams.deriveFromMasterKey(masterKey);
+    const groupPublicParams = groupSecretParams.getPublicParams();
+
+    const authCredentialResponse = serverZkAuth.issueAuthCredentialWithRandom(TEST_ARRAY_32_2, uuid, redemptionTime);
+    const clientZkAuthCipher     = new ClientZkAuthOperations(serverPublicParams);
+    const authCredential         = clientZkAuthCipher.receiveAuthCredential(uuid, redemptionTime, authCredentialResponse);
+
+    const presentation = clientZkAuthCipher.createAuthCredentialPresentationWithContextWithRandom(TEST_ARRAY_32_5, groupSecretParams, authCredential, TEST_ARRAY_16);
+
+    serverZkAuth.verifyAuthCredentialPresentationWithContext(groupPublicParams, presentation, TEST_ARRAY_16);
+
+    try {
+      serverZkAuth.verifyAuthCredentialPresentationWithContext(groupPublicParams, presentation, TEST_ARRAY_16_1);
+      assert.fail();
+    } catch (error) {
+      // good
+    }
+  });
+
+  it('testRateLimitToken', () => {
+    const redemptionTime = 123456;
+
+    const serverSecretParams = ServerSecretParams.generateWithRandom(TEST_ARRAY_32);
+    const serverPublicParams = serverSecretParams.getPublicParams();
+    const serverZkAuth       = new ServerZkAuthOperations(serverSecretParams);
+    const clientZkAuthCipher = new ClientZkAuthOperations(serverPublicParams);
+
+    const context             = clientZkAuthCipher.createRateLimitCredentialRequestContextWithRandom(TEST_ARRAY_32_1);
+    const response            = serverZkAuth.issueRateLimitCredentialWithRandom(TEST_ARRAY_32_2, context.getRequest(), redemptionTime);
+    const rateLimitCredential = clientZkAuthCipher.receiveRateLimitCredential(context, response);
+
+    const token      = clientZkAuthCipher.createRateLimitTokenWithRandom(TEST_ARRAY_32_3, rateLimitCredential);
+    const tokenAgain = clientZkAuthCipher.createRateLimitTokenWithRandom(TEST_ARRAY_32_4, rateLimitCredential);
+
+    serverZkAuth.verifyRateLimitToken(token);
+    serverZkAuth.verifyRateLimitToken(tokenAgain);
+
+    assert.strictEqual(token.getRedemptionTime(), redemptionTime);
+    assertArrayEquals(token.getTag().serialize(), tokenAgain.getTag().serialize());
+
+    // A second credential for the same day has its own secret, and so its own tag
+    const otherContext    = clientZkAuthCipher.createRateLimitCredentialRequestContextWithRandom(TEST_ARRAY_32_5);
+    const otherResponse   = serverZkAuth.issueRateLimitCredentialWithRandom(TEST_ARRAY_32_2, otherContext.getRequest(), redemptionTime);
+    const otherCredential = clientZkAuthCipher.receiveRateLimitCredential(otherContext, otherResponse);
+    const otherToken      = clientZkAuthCipher.createRateLimitTokenWithRandom(TEST_ARRAY_32_3, otherCredential);
+
+    serverZkAuth.verifyRateLimitToken(otherToken);
+    assertArrayNotEquals(token.getTag().serialize(), otherToken.getTag().serialize());
+  });
+
+  it('testBlobEncryptionWithPadding', () => {
+    const masterKey           = new GroupMasterKey(TEST_ARRAY_32_1);
+    const groupSecretParams   = GroupSecretParams.deriveFromMasterKey(masterKey);
+    const clientZkGroupCipher = new ClientZkGroupCipher(groupSecretParams);
+
+    const plaintext = hexToCompatArray('0102030405060708111213141516171819');
+
+    const ciphertext = clientZkGroupCipher.encryptBlobWithPaddingWithRandom(TEST_ARRAY_32_2, plaintext, 64);
+    assert.strictEqual(ciphertext.length, 64 + 29);
+    assertArrayEquals(plaintext, clientZkGroupCipher.decryptPaddedBlob(ciphertext));
+
+    // The unpadded plaintext no longer fills the output decryptBlob sizes
+    try {
+      clientZkGroupCipher.decryptBlob(ciphertext);
+      assert.fail();
+    } catch (error) {
+      // good
+    }
+  });
+
+  it('testLastErrorCode', () => {
+    const groupSecretParams   = GroupSecretParams.generate();
+    const clientZkGroupCipher = new ClientZkGroupCipher(groupSecretParams);
+
+    const plaintext = arrayToCompatArray([0,1,2,3,4]);
+    const ciphertext = clientZkGroupCipher.encryptBlob(plaintext);
+    ciphertext.buffer[0] ^= 1;
+
+    try {
+      clientZkGroupCipher.decryptBlob(ciphertext);
+      assert.fail();
+    } catch (error) {
+      // DecryptionFailure
+      assert.strictEqual(Native.FFI_getLastErrorCode(), 101);
+    }
+
+    ciphertext.buffer[0] ^= 1;
+    clientZkGroupCipher.decryptBlob(ciphertext);
+    assert.strictEqual(Native.FFI_getLastErrorCode(), 0);
+  });
+
+  it('testBlobEncryptionWithAd', () => {
+    const masterKey           = new GroupMasterKey(TEST_ARRAY_32_1);
+    const groupSecretParams   = GroupSecretParams.deriveFromMasterKey(masterKey);
+    const clientZkGroupCipher = new ClientZkGroupCipher(groupSecretParams);
+
+    const plaintext = hexToCompatArray('0102030405060708111213141516171819');
+    const title = arrayToCompatArray(Array.from(Buffer.from('title:1')));
+
+    const ciphertext = clientZkGroupCipher.encryptBlobWithAdWithRandom(TEST_ARRAY_32_2, plaintext, title);
+    assertArrayEquals(plaintext, clientZkGroupCipher.decryptBlobWithAd(ciphertext, title));
+
+    const description = arrayToCompatArray(Array.from(Buffer.from('description:1')));
+    try {
+      clientZkGroupCipher.decryptBlobWithAd(ciphertext, description);
+      assert.fail();
+    } catch (error) {
+      // good
+    }
+  });
 });
@@ -11,7 +11,7 @@ import { FFICompatArrayType } from './internal/FFICompatArray'
 
 export default class ServerPublicParams extends ByteArray {
 
-  static SIZE = 161;
+  static SIZE = 290;
 
   constructor (contents: FFICompatArrayType)  {
     super(contents, ServerPublicParams.SIZE, true);
@@ -15,6 +15,10 @@ import ServerPublicParams from '../ServerPublicParams';
 import AuthCredential from './AuthCredential';
 import AuthCredentialPresentation from './AuthCredentialPresentation';
 import AuthCredentialResponse from './AuthCredentialResponse';
+import RateLimitCredential from './RateLimitCredential';
+import RateLimitCredentialRequestContext from './RateLimitCredentialRequestContext';
+import RateLimitCredentialResponse from './RateLimitCredentialResponse';
+import RateLimitToken from './RateLimitToken';
 import GroupSecretParams from '../groups/GroupSecretParams';
 import { UUID_LENGTH, UUIDType, fromUUID, toUUID } from '../internal/UUIDUtil';
 
@@ -67,4 +71,86 @@ export default class ClientZkAuthOperations {
     return new AuthCredentialPresentation(newContents);
   }
 
+  createAuthCredentialPresentationWithContext(groupSecretParams: GroupSecretParams, authCredential: AuthCredential, context: FFICompatArrayType): AuthCredentialPresentation {
+    const random = new FFICompatArray(randomBytes(RANDOM_LENGTH));
+
+    return this.createAuthCredentialPresentationWithContextWithRandom(random, groupSecretParams, authCredential, context);
+  }
+
+  createAuthCredentialPresentationWithContextWithRandom(random: FFICompatArrayType, groupSecretParams: GroupSecretParams, authCredential: AuthCredential, context: FFICompatArrayType): AuthCredentialPresentation {
+    const newContents = new FFICompatArray(AuthCredentialPresentation.SIZE);
+
+    const serverPublicParamsContents = this.serverPublicParams.getContents()
+    const groupSecretParamsContents = groupSecretParams.getContents();
+    const authCredentialContents = authCredential.getContents();
+
+    const ffi_return = Native.FFI_ServerPublicParams_createAuthCredentialPresentationWithContextDeterministic(serverPublicParamsContents, serverPublicParamsContents.length, random, random.length, groupSecretParamsContents, groupSecretParamsContents.length, authCredentialContents, authCredentialContents.length, context, context.length, newContents, newContents.length);
+
+    if (ffi_return != FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+    }
+
+    return new AuthCredentialPresentation(newContents);
+  }
+
+  createRateLimitCredentialRequestContext(): RateLimitCredentialRequestContext {
+    const random = new FFICompatArray(randomBytes(RANDOM_LENGTH));
+
+    return this.createRateLimitCredentialRequestContextWithRandom(random);
+  }
+
+  createRateLimitCredentialRequestContextWithRandom(random: FFICompatArrayType): RateLimitCredentialRequestContext {
+    const newContents = new FFICompatArray(RateLimitCredentialRequestContext.SIZE);
+
+    const serverPublicParamsContents = this.serverPublicParams.getContents()
+
+    const ffi_return = Native.FFI_ServerPublicParams_createRateLimitCredentialRequestContextDeterministic(serverPublicParamsContents, serverPublicParamsContents.length, random, random.length, newContents, newContents.length);
+
+    if (ffi_return != FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+    }
+
+    return new RateLimitCredentialRequestContext(newContents);
+  }
+
+  receiveRateLimitCredential(rateLimitCredentialRequestContext: RateLimitCredentialRequestContext, rateLimitCredentialResponse: RateLimitCredentialResponse): RateLimitCredential {
+    const newContents = new FFICompatArray(RateLimitCredential.SIZE);
+
+    const serverPublicParamsContents = this.serverPublicParams.getContents()
+    const rateLimitCredentialRequestContextContents = rateLimitCredentialRequestContext.getContents();
+    const rateLimitCredentialResponseContents = rateLimitCredentialResponse.getContents();
+
+    const ffi_return = Native.FFI_ServerPublicParams_receiveRateLimitCredential(serverPublicParamsContents, serverPublicParamsContents.length, rateLimitCredentialRequestContextContents, rateLimitCredentialRequestContextContents.length, rateLimitCredentialResponseContents, rateLimitCredentialResponseContents.length, newContents, newContents.length);
+    if (ffi_return == FFI_RETURN_INPUT_ERROR) {
+      throw new VerificationFailedException("FFI_RETURN_INPUT_ERROR");
+    }
+
+    if (ffi_return != FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+    }
+
+    return new RateLimitCredential(newContents);
+  }
+
+  createRateLimitToken(rateLimitCredential: RateLimitCredential): RateLimitToken {
+    const random = new FFICompatArray(randomBytes(RANDOM_LENGTH));
+
+    return this.createRateLimitTokenWithRandom(random, rateLimitCredential);
+  }
+
+  createRateLimitTokenWithRandom(random: FFICompatArrayType, rateLimitCredential: RateLimitCredential): RateLimitToken {
+    const newContents = new FFICompatArray(RateLimitToken.SIZE);
+
+    const serverPublicParamsContents = this.serverPublicParams.getContents()
+    const rateLimitCredentialContents = rateLimitCredential.getContents();
+
+    const ffi_return = Native.FFI_ServerPublicParams_createRateLimitTokenDeterministic(serverPublicParamsContents, serverPublicParamsContents.length, random, random.length, rateLimitCredentialContents, rateLimitCredentialContents.length, newContents, newContents.length);
+
+    if (ffi_return != FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+    }
+
+    return new RateLimitToken(newContents);
+  }
+
 }
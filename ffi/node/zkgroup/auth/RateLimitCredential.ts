@@ -0,0 +1,27 @@
+import ByteArray from '../internal/ByteArray';
+import FFICompatArray, { FFICompatArrayType } from '../internal/FFICompatArray';
+
+import InvalidInputException from '../errors/InvalidInputException';
+import ZkGroupError from '../errors/ZkGroupError';
+
+import Native, { FFI_RETURN_OK, FFI_RETURN_INPUT_ERROR } from '../internal/Native';
+
+export default class RateLimitCredential extends ByteArray {
+
+  static SIZE = 165;
+
+  constructor(contents: FFICompatArrayType) {
+    super(contents, RateLimitCredential.SIZE, true);
+
+    const ffi_return = Native.FFI_RateLimitCredential_checkValidContents(this.contents, this.contents.length);
+
+    if (ffi_return == FFI_RETURN_INPUT_ERROR) {
+      throw new InvalidInputException('FFI_RETURN_INPUT_ERROR');
+    }
+
+    if (ffi_return != FFI_RETURN_OK) {
+      throw new ZkGroupError('FFI_RETURN!=OK');
+    }
+  }
+
+}
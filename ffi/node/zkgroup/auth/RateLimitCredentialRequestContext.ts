@@ -0,0 +1,41 @@
+import ByteArray from '../internal/ByteArray';
+import FFICompatArray, { FFICompatArrayType } from '../internal/FFICompatArray';
+
+import InvalidInputException from '../errors/InvalidInputException';
+import ZkGroupError from '../errors/ZkGroupError';
+
+import Native, { FFI_RETURN_OK, FFI_RETURN_INPUT_ERROR } from '../internal/Native';
+
+import RateLimitCredentialRequest from './RateLimitCredentialRequest';
+
+
+export default class RateLimitCredentialRequestContext extends ByteArray {
+
+  static SIZE = 225;
+
+  constructor(contents: FFICompatArrayType) {
+    super(contents, RateLimitCredentialRequestContext.SIZE, true);
+
+    const ffi_return = Native.FFI_RateLimitCredentialRequestContext_checkValidContents(this.contents, this.contents.length);
+
+    if (ffi_return == FFI_RETURN_INPUT_ERROR) {
+      throw new InvalidInputException('FFI_RETURN_INPUT_ERROR');
+    }
+
+    if (ffi_return != FFI_RETURN_OK) {
+      throw new ZkGroupError('FFI_RETURN!=OK');
+    }
+  }
+
+  getRequest(): RateLimitCredentialRequest {
+    const newContents = new FFICompatArray(RateLimitCredentialRequest.SIZE);
+
+    const ffi_return = Native.FFI_RateLimitCredentialRequestContext_getRequest(this.contents, this.contents.length, newContents, newContents.length);
+
+    if (ffi_return != FFI_RETURN_OK) {
+      throw new ZkGroupError('FFI_RETURN!=OK');
+    }
+
+    return new RateLimitCredentialRequest(newContents);
+  }
+}
@@ -0,0 +1,11 @@
+import { FFICompatArrayType } from '../internal/FFICompatArray';
+import ByteArray from '../internal/ByteArray';
+
+export default class RateLimitTag extends ByteArray {
+
+  static SIZE = 32;
+
+  constructor(contents: FFICompatArrayType) {
+    super(contents, RateLimitTag.SIZE, true);
+  }
+}
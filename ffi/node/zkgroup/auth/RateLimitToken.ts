@@ -0,0 +1,52 @@
+import ByteArray from '../internal/ByteArray';
+import FFICompatArray, { FFICompatArrayType } from '../internal/FFICompatArray';
+
+import InvalidInputException from '../errors/InvalidInputException';
+import ZkGroupError from '../errors/ZkGroupError';
+
+import Native, { FFI_RETURN_OK, FFI_RETURN_INPUT_ERROR } from '../internal/Native';
+
+import RateLimitTag from './RateLimitTag';
+
+export default class RateLimitToken extends ByteArray {
+
+  static SIZE = 366;
+
+  constructor(contents: FFICompatArrayType) {
+    super(contents, RateLimitToken.SIZE, true);
+
+    const ffi_return = Native.FFI_RateLimitToken_checkValidContents(contents, contents.length);
+
+    if (ffi_return == FFI_RETURN_INPUT_ERROR) {
+      throw new InvalidInputException("FFI_RETURN_INPUT_ERROR");
+    }
+
+    if (ffi_return != FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+    }
+  }
+
+  getTag(): RateLimitTag {
+    const newContents = new FFICompatArray(RateLimitTag.SIZE);
+
+    const ffi_return = Native.FFI_RateLimitToken_getTag(this.contents, this.contents.length, newContents, newContents.length);
+
+    if (ffi_return != FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+    }
+
+    return new RateLimitTag(newContents);
+  }
+
+  getRedemptionTime(): number {
+    const newContents = new FFICompatArray(Buffer.alloc(4));
+
+    const ffi_return = Native.FFI_RateLimitToken_getRedemptionTime(this.contents, this.contents.length, newContents, newContents.length);
+
+    if (ffi_return != FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+     }
+
+    return newContents.buffer.readInt32BE(0);
+  }
+}
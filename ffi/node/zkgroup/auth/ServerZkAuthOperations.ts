@@ -14,7 +14,12 @@ import UuidCiphertext from '../groups/UuidCiphertext';
 import ServerSecretParams from '../ServerSecretParams';
 import AuthCredentialResponse from './AuthCredentialResponse';
 import AuthCredentialPresentation from './AuthCredentialPresentation';
+import RateLimitCredentialRequest from './RateLimitCredentialRequest';
+import RateLimitCredentialResponse from './RateLimitCredentialResponse';
+import RateLimitToken from './RateLimitToken';
 import GroupPublicParams from '../groups/GroupPublicParams';
+import GroupInviteLinkPasswordCommitment from '../groups/GroupInviteLinkPasswordCommitment';
+import GroupInviteLinkPresentation from '../groups/GroupInviteLinkPresentation';
 import { UUID_LENGTH, UUIDType, fromUUID, toUUID } from '../internal/UUIDUtil';
 
 export default class ServerZkAuthOperations {
@@ -62,4 +67,73 @@ export default class ServerZkAuthOperations {
     }
   }
 
+  verifyAuthCredentialPresentationWithContext(groupPublicParams: GroupPublicParams, authCredentialPresentation: AuthCredentialPresentation, context: FFICompatArrayType) {
+    const serverParamContents = this.serverSecretParams.getContents();
+    const groupPublicContents = groupPublicParams.getContents();
+    const authCredentialPresentationContents = authCredentialPresentation.getContents();
+
+    const ffi_return = Native.FFI_ServerSecretParams_verifyAuthCredentialPresentationWithContext(serverParamContents, serverParamContents.length, groupPublicContents, groupPublicContents.length, authCredentialPresentationContents, authCredentialPresentationContents.length, context, context.length);
+
+    if (ffi_return == FFI_RETURN_INPUT_ERROR) {
+      throw new VerificationFailedException('FFI_RETURN_INPUT_ERROR');
+    }
+
+    if (ffi_return != FFI_RETURN_OK) {
+      throw new ZkGroupError('FFI_RETURN!=OK');
+    }
+  }
+
+  issueRateLimitCredential(rateLimitCredentialRequest: RateLimitCredentialRequest, redemptionTime: number): RateLimitCredentialResponse {
+    const random = new FFICompatArray(randomBytes(RANDOM_LENGTH));
+
+    return this.issueRateLimitCredentialWithRandom(random, rateLimitCredentialRequest, redemptionTime);
+  }
+
+  issueRateLimitCredentialWithRandom(random: FFICompatArrayType, rateLimitCredentialRequest: RateLimitCredentialRequest, redemptionTime: number): RateLimitCredentialResponse {
+    const newContents = new FFICompatArray(RateLimitCredentialResponse.SIZE);
+
+    const serverParamContents = this.serverSecretParams.getContents();
+    const rateLimitCredentialRequestContents = rateLimitCredentialRequest.getContents();
+
+    const ffi_return = Native.FFI_ServerSecretParams_issueRateLimitCredentialDeterministic(serverParamContents, serverParamContents.length, random, random.length, rateLimitCredentialRequestContents, rateLimitCredentialRequestContents.length, redemptionTime, newContents, newContents.length);
+
+    if (ffi_return != FFI_RETURN_OK) {
+      throw new ZkGroupError('FFI_RETURN!=OK');
+    }
+
+    return new RateLimitCredentialResponse(newContents);
+  }
+
+  verifyRateLimitToken(rateLimitToken: RateLimitToken) {
+    const serverParamContents = this.serverSecretParams.getContents();
+    const rateLimitTokenContents = rateLimitToken.getContents();
+
+    const ffi_return = Native.FFI_ServerSecretParams_verifyRateLimitToken(serverParamContents, serverParamContents.length, rateLimitTokenContents, rateLimitTokenContents.length);
+
+    if (ffi_return == FFI_RETURN_INPUT_ERROR) {
+      throw new VerificationFailedException('FFI_RETURN_INPUT_ERROR');
+    }
+
+    if (ffi_return != FFI_RETURN_OK) {
+      throw new ZkGroupError('FFI_RETURN!=OK');
+    }
+  }
+
+  verifyGroupInviteLinkPresentation(groupPublicParams: GroupPublicParams, groupInviteLinkPasswordCommitment: GroupInviteLinkPasswordCommitment, groupInviteLinkPresentation: GroupInviteLinkPresentation) {
+    const serverParamContents = this.serverSecretParams.getContents();
+    const groupPublicContents = groupPublicParams.getContents();
+    const commitmentContents = groupInviteLinkPasswordCommitment.getContents();
+    const presentationContents = groupInviteLinkPresentation.getContents();
+
+    const ffi_return = Native.FFI_ServerSecretParams_verifyGroupInviteLinkPresentation(serverParamContents, serverParamContents.length, groupPublicContents, groupPublicContents.length, commitmentContents, commitmentContents.length, presentationContents, presentationContents.length);
+
+    if (ffi_return == FFI_RETURN_INPUT_ERROR) {
+      throw new VerificationFailedException('FFI_RETURN_INPUT_ERROR');
+    }
+
+    if (ffi_return != FFI_RETURN_OK) {
+      throw new ZkGroupError('FFI_RETURN!=OK');
+    }
+  }
+
 }
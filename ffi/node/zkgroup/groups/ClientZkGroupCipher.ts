@@ -10,10 +10,14 @@ import { RANDOM_LENGTH } from '../internal/Constants';
 import Native, { FFI_RETURN_OK, FFI_RETURN_INPUT_ERROR } from '../internal/Native';
 
 import UuidCiphertext from './UuidCiphertext';
+import RandomizedUuidCiphertext from './RandomizedUuidCiphertext';
+import UuidCiphertextMatchProof from './UuidCiphertextMatchProof';
 
 import ProfileKeyCiphertext from './ProfileKeyCiphertext';
 import ProfileKey from '../profiles/ProfileKey';
 import GroupSecretParams from './GroupSecretParams';
+import GroupInviteLinkPassword from './GroupInviteLinkPassword';
+import GroupInviteLinkPresentation from './GroupInviteLinkPresentation';
 import { UUID_LENGTH, UUIDType, fromUUID, toUUID } from '../internal/UUIDUtil';
 
 export default class ClientZkGroupCipher {
@@ -149,4 +153,192 @@ export default class ClientZkGroupCipher {
     return depaddedContents;
   }
 
+  encryptBlobWithPadding(plaintext: FFICompatArrayType, paddedLength: number): FFICompatArrayType {
+    const random = new FFICompatArray(randomBytes(RANDOM_LENGTH));
+
+    return this.encryptBlobWithPaddingWithRandom(random, plaintext, paddedLength);
+  }
+
+  encryptBlobWithPaddingWithRandom(random: FFICompatArrayType, plaintext: FFICompatArrayType, paddedLength: number): FFICompatArrayType {
+    if (paddedLength <= plaintext.length) {
+      throw new VerificationFailedException('BAD LENGTH');
+    }
+
+    const newContents = new FFICompatArray(paddedLength+29);
+
+    const groupSecretParamsContents = this.groupSecretParams.getContents();
+
+    const ffi_return = Native.FFI_GroupSecretParams_encryptBlobWithPaddingDeterministic(groupSecretParamsContents, groupSecretParamsContents.length, random, random.length, plaintext, plaintext.length, paddedLength, newContents, newContents.length);
+    if (ffi_return == FFI_RETURN_INPUT_ERROR) {
+      throw new VerificationFailedException('FFI_RETURN_INPUT_ERROR');
+    }
+
+    if (ffi_return != FFI_RETURN_OK) {
+      throw new ZkGroupError('FFI_RETURN!=OK');
+    }
+
+    return newContents;
+  }
+
+  decryptPaddedBlob(blobCiphertext: FFICompatArrayType): FFICompatArrayType {
+    if (blobCiphertext.length < 29) {
+      throw new VerificationFailedException('BAD LENGTH');
+    }
+
+    const newContents = new FFICompatArray(blobCiphertext.length-25);
+
+    const groupSecretParamsContents = this.groupSecretParams.getContents()
+
+    const ffi_return = Native.FFI_GroupSecretParams_decryptPaddedBlob(groupSecretParamsContents, groupSecretParamsContents.length, blobCiphertext, blobCiphertext.length, newContents, newContents.length);
+    if (ffi_return == FFI_RETURN_INPUT_ERROR) {
+      throw new VerificationFailedException('FFI_RETURN_INPUT_ERROR');
+    }
+
+    if (ffi_return != FFI_RETURN_OK) {
+      throw new ZkGroupError('FFI_RETURN!=OK');
+    }
+
+    const plaintextLen = newContents.buffer.readInt32BE(0);
+    let plaintext = new FFICompatArray(plaintextLen);
+    this.myArrayCopy(newContents, 4, plaintext, 0, plaintextLen);
+
+    return plaintext;
+  }
+
+  createGroupInviteLinkPresentation(groupInviteLinkPassword: GroupInviteLinkPassword, uuid: UUIDType): GroupInviteLinkPresentation {
+    const random = new FFICompatArray(randomBytes(RANDOM_LENGTH));
+
+    return this.createGroupInviteLinkPresentationWithRandom(random, groupInviteLinkPassword, uuid);
+  }
+
+  createGroupInviteLinkPresentationWithRandom(random: FFICompatArrayType, groupInviteLinkPassword: GroupInviteLinkPassword, uuid: UUIDType): GroupInviteLinkPresentation {
+    const newContents = new FFICompatArray(GroupInviteLinkPresentation.SIZE);
+
+    const groupSecretParamsContents = this.groupSecretParams.getContents();
+    const passwordContents = groupInviteLinkPassword.getContents();
+    const uuidContents = fromUUID(uuid);
+
+    const ffi_return = Native.FFI_GroupSecretParams_createGroupInviteLinkPresentationDeterministic(groupSecretParamsContents, groupSecretParamsContents.length, random, random.length, passwordContents, passwordContents.length, uuidContents, uuidContents.length, newContents, newContents.length);
+
+    if (ffi_return != FFI_RETURN_OK) {
+      throw new ZkGroupError('FFI_RETURN!=OK');
+    }
+
+    return new GroupInviteLinkPresentation(newContents);
+  }
+
+  encryptUuidRandomized(uuid: UUIDType): RandomizedUuidCiphertext {
+    const random = new FFICompatArray(randomBytes(RANDOM_LENGTH));
+
+    return this.encryptUuidRandomizedWithRandom(random, uuid);
+  }
+
+  encryptUuidRandomizedWithRandom(random: FFICompatArrayType, uuid: UUIDType): RandomizedUuidCiphertext {
+    const newContents = new FFICompatArray(RandomizedUuidCiphertext.SIZE);
+
+    const groupSecretParamsContents = this.groupSecretParams.getContents();
+    const uuidContents = fromUUID(uuid);
+
+    const ffi_return = Native.FFI_GroupSecretParams_encryptUuidRandomizedDeterministic(groupSecretParamsContents, groupSecretParamsContents.length, random, random.length, uuidContents, uuidContents.length, newContents, newContents.length);
+
+    if (ffi_return != FFI_RETURN_OK) {
+      throw new ZkGroupError('FFI_RETURN!=OK');
+    }
+
+    return new RandomizedUuidCiphertext(newContents);
+  }
+
+  decryptRandomizedUuid(randomizedUuidCiphertext: RandomizedUuidCiphertext): UUIDType {
+    const newContents = new FFICompatArray(UUID_LENGTH);
+
+    const groupSecretParamsContents = this.groupSecretParams.getContents();
+    const ciphertextContents = randomizedUuidCiphertext.getContents();
+
+    const ffi_return = Native.FFI_GroupSecretParams_decryptRandomizedUuid(groupSecretParamsContents, groupSecretParamsContents.length, ciphertextContents, ciphertextContents.length, newContents, newContents.length);
+    if (ffi_return == FFI_RETURN_INPUT_ERROR) {
+      throw new VerificationFailedException('FFI_RETURN_INPUT_ERROR');
+    }
+
+    if (ffi_return != FFI_RETURN_OK) {
+      throw new ZkGroupError('FFI_RETURN!=OK');
+    }
+
+    return toUUID(newContents);
+  }
+
+  createUuidCiphertextMatchProof(uuidCiphertext: UuidCiphertext, randomizedUuidCiphertext: RandomizedUuidCiphertext): UuidCiphertextMatchProof {
+    const random = new FFICompatArray(randomBytes(RANDOM_LENGTH));
+
+    return this.createUuidCiphertextMatchProofWithRandom(random, uuidCiphertext, randomizedUuidCiphertext);
+  }
+
+  createUuidCiphertextMatchProofWithRandom(random: FFICompatArrayType, uuidCiphertext: UuidCiphertext, randomizedUuidCiphertext: RandomizedUuidCiphertext): UuidCiphertextMatchProof {
+    const newContents = new FFICompatArray(UuidCiphertextMatchProof.SIZE);
+
+    const groupSecretParamsContents = this.groupSecretParams.getContents();
+    const uuidCiphertextContents = uuidCiphertext.getContents();
+    const randomizedContents = randomizedUuidCiphertext.getContents();
+
+    const ffi_return = Native.FFI_GroupSecretParams_createUuidCiphertextMatchProofDeterministic(groupSecretParamsContents, groupSecretParamsContents.length, random, random.length, uuidCiphertextContents, uuidCiphertextContents.length, randomizedContents, randomizedContents.length, newContents, newContents.length);
+
+    if (ffi_return != FFI_RETURN_OK) {
+      throw new ZkGroupError('FFI_RETURN!=OK');
+    }
+
+    return new UuidCiphertextMatchProof(newContents);
+  }
+
+  encryptBlobWithAd(plaintext: FFICompatArrayType, associatedData: FFICompatArrayType): FFICompatArrayType {
+    const random = new FFICompatArray(randomBytes(RANDOM_LENGTH));
+
+    return this.encryptBlobWithAdWithRandom(random, plaintext, associatedData);
+  }
+
+  encryptBlobWithAdWithRandom(random: FFICompatArrayType, plaintext: FFICompatArrayType, associatedData: FFICompatArrayType): FFICompatArrayType {
+
+    let paddedPlaintext = new FFICompatArray(plaintext.length+4);
+    this.myArrayCopy(plaintext, 0, paddedPlaintext, 4, plaintext.length);
+
+    const newContents = FFICompatArray(paddedPlaintext.length+29);
+
+    const groupSecretParamsContents = this.groupSecretParams.getContents();
+
+    const ffi_return = Native.FFI_GroupSecretParams_encryptBlobWithAdDeterministic(groupSecretParamsContents, groupSecretParamsContents.length, random, random.length, paddedPlaintext, paddedPlaintext.length, associatedData, associatedData.length, newContents, newContents.length);
+
+    if (ffi_return != FFI_RETURN_OK) {
+      throw new ZkGroupError('FFI_RETURN!=OK');
+    }
+
+    return newContents;
+  }
+
+  decryptBlobWithAd(blobCiphertext: FFICompatArrayType, associatedData: FFICompatArrayType): FFICompatArrayType {
+    const newContents = new FFICompatArray(blobCiphertext.length-29);
+
+    const groupSecretParamsContents = this.groupSecretParams.getContents()
+
+    const ffi_return = Native.FFI_GroupSecretParams_decryptBlobWithAd(groupSecretParamsContents, groupSecretParamsContents.length, blobCiphertext, blobCiphertext.length, associatedData, associatedData.length, newContents, newContents.length);
+    if (ffi_return == FFI_RETURN_INPUT_ERROR) {
+      throw new VerificationFailedException('FFI_RETURN_INPUT_ERROR');
+    }
+
+    if (ffi_return != FFI_RETURN_OK) {
+      throw new ZkGroupError('FFI_RETURN!=OK');
+    }
+
+    if (newContents.length < 4) {
+        throw new VerificationFailedException('BAD LENGTH');
+    }
+
+    const padLen = newContents.buffer.readInt32BE(0);
+    if (newContents.length < (4 + padLen)) {
+        throw new VerificationFailedException('BAD LENGTH');
+    }
+
+    let depaddedContents = new FFICompatArray(newContents.length - (4 + padLen));
+    this.myArrayCopy(newContents, 4, depaddedContents, 0, newContents.length - (4 + padLen));
+
+    return depaddedContents;
+  }
+
 }
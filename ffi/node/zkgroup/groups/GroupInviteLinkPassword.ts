@@ -0,0 +1,32 @@
+import ByteArray from '../internal/ByteArray';
+import FFICompatArray, { FFICompatArrayType } from '../internal/FFICompatArray';
+
+import InvalidInputException from '../errors/InvalidInputException';
+import ZkGroupError from '../errors/ZkGroupError';
+
+import Native, { FFI_RETURN_OK, FFI_RETURN_INPUT_ERROR } from '../internal/Native';
+
+import GroupInviteLinkPasswordCommitment from './GroupInviteLinkPasswordCommitment';
+import GroupPublicParams from './GroupPublicParams';
+
+export default class GroupInviteLinkPassword extends ByteArray {
+
+  static SIZE = 16;
+
+  constructor(contents: FFICompatArrayType) {
+    super(contents, GroupInviteLinkPassword.SIZE, true);
+  }
+
+  getCommitment(groupPublicParams: GroupPublicParams): GroupInviteLinkPasswordCommitment {
+    const newContents = new FFICompatArray(GroupInviteLinkPasswordCommitment.SIZE);
+    const groupPublicParamsContents = groupPublicParams.getContents();
+
+    const ffi_return = Native.FFI_GroupInviteLinkPassword_getCommitment(this.contents, this.contents.length, groupPublicParamsContents, groupPublicParamsContents.length, newContents, newContents.length);
+
+    if (ffi_return != FFI_RETURN_OK) {
+      throw new ZkGroupError('FFI_RETURN!=OK');
+    }
+
+    return new GroupInviteLinkPasswordCommitment(newContents);
+  }
+}
@@ -8,6 +8,9 @@ import VerificationFailedException from '../errors/VerificationFailedException';
 import Native, { FFI_RETURN_OK, FFI_RETURN_INPUT_ERROR } from '../internal/Native';
 
 import GroupIdentifier from './GroupIdentifier';
+import UuidCiphertext from './UuidCiphertext';
+import RandomizedUuidCiphertext from './RandomizedUuidCiphertext';
+import UuidCiphertextMatchProof from './UuidCiphertextMatchProof';
 
 
 export default class GroupPublicParams extends ByteArray {
@@ -40,4 +43,20 @@ export default class GroupPublicParams extends ByteArray {
     return new GroupIdentifier(newContents);
   }
 
+  verifyUuidCiphertextMatch(uuidCiphertext: UuidCiphertext, randomizedUuidCiphertext: RandomizedUuidCiphertext, uuidCiphertextMatchProof: UuidCiphertextMatchProof) {
+    const uuidCiphertextContents = uuidCiphertext.getContents();
+    const randomizedContents = randomizedUuidCiphertext.getContents();
+    const proofContents = uuidCiphertextMatchProof.getContents();
+
+    const ffi_return = Native.FFI_GroupPublicParams_verifyUuidCiphertextMatch(this.contents, this.contents.length, uuidCiphertextContents, uuidCiphertextContents.length, randomizedContents, randomizedContents.length, proofContents, proofContents.length);
+
+    if (ffi_return == FFI_RETURN_INPUT_ERROR) {
+      throw new VerificationFailedException('FFI_RETURN_INPUT_ERROR');
+    }
+
+    if (ffi_return != FFI_RETURN_OK) {
+      throw new ZkGroupError('FFI_RETURN!=OK');
+    }
+  }
+
 }
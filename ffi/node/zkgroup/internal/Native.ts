@@ -33,20 +33,43 @@ interface NativeCalls {
   FFI_GroupSecretParams_decryptProfileKey: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type, param5: FFICompatArrayType, param6: UInt32Type, param7: FFICompatArrayType, param8: UInt32Type) => IntType,
   FFI_GroupSecretParams_encryptBlobDeterministic: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type, param5: FFICompatArrayType, param6: UInt32Type, param7: FFICompatArrayType, param8: UInt32Type) => IntType,
   FFI_GroupSecretParams_decryptBlob: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type, param5: FFICompatArrayType, param6: UInt32Type) => IntType,
+  FFI_GroupSecretParams_encryptBlobWithPaddingDeterministic: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type, param5: FFICompatArrayType, param6: UInt32Type, param7: IntType, param8: FFICompatArrayType, param9: UInt32Type) => IntType,
+  FFI_GroupSecretParams_decryptPaddedBlob: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type, param5: FFICompatArrayType, param6: UInt32Type) => IntType,
+  FFI_GroupSecretParams_createGroupInviteLinkPresentationDeterministic: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type, param5: FFICompatArrayType, param6: UInt32Type, param7: FFICompatArrayType, param8: UInt32Type, param9: FFICompatArrayType, param10: UInt32Type) => IntType,
+  FFI_GroupSecretParams_encryptUuidRandomizedDeterministic: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type, param5: FFICompatArrayType, param6: UInt32Type, param7: FFICompatArrayType, param8: UInt32Type) => IntType,
+  FFI_GroupSecretParams_decryptRandomizedUuid: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type, param5: FFICompatArrayType, param6: UInt32Type) => IntType,
+  FFI_GroupSecretParams_createUuidCiphertextMatchProofDeterministic: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type, param5: FFICompatArrayType, param6: UInt32Type, param7: FFICompatArrayType, param8: UInt32Type, param9: FFICompatArrayType, param10: UInt32Type) => IntType,
+  FFI_GroupSecretParams_encryptBlobWithAdDeterministic: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type, param5: FFICompatArrayType, param6: UInt32Type, param7: FFICompatArrayType, param8: UInt32Type, param9: FFICompatArrayType, param10: UInt32Type) => IntType,
+  FFI_GroupSecretParams_decryptBlobWithAd: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type, param5: FFICompatArrayType, param6: UInt32Type, param7: FFICompatArrayType, param8: UInt32Type) => IntType,
   FFI_ServerSecretParams_generateDeterministic: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type) => IntType,
   FFI_ServerSecretParams_getPublicParams: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type) => IntType,
   FFI_ServerSecretParams_signDeterministic: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type, param5: FFICompatArrayType, param6: UInt32Type, param7: FFICompatArrayType, param8: UInt32Type) => IntType,
   FFI_ServerSecretParams_checkValidContents: (param2: FFICompatArrayType, param3: UInt32Type) => IntType,
   FFI_ServerPublicParams_receiveAuthCredential: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type, param5: IntType, param6: FFICompatArrayType, param7: UInt32Type, param8: FFICompatArrayType, param9: UInt32Type) => IntType;
   FFI_ServerPublicParams_createAuthCredentialPresentationDeterministic: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type, param5: FFICompatArrayType, param6: UInt32Type, param7: FFICompatArrayType, param8: UInt32Type, param9: FFICompatArrayType, param10: UInt32Type) => IntType,
+  FFI_ServerPublicParams_createAuthCredentialPresentationWithContextDeterministic: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type, param5: FFICompatArrayType, param6: UInt32Type, param7: FFICompatArrayType, param8: UInt32Type, param9: FFICompatArrayType, param10: UInt32Type, param11: FFICompatArrayType, param12: UInt32Type) => IntType,
   FFI_ServerPublicParams_createProfileKeyCredentialRequestContextDeterministic: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type, param5: FFICompatArrayType, param6: UInt32Type, param7: FFICompatArrayType, param8: UInt32Type, param9: FFICompatArrayType, param10: UInt32Type) => IntType,
   FFI_ServerPublicParams_receiveProfileKeyCredential: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type, param5: FFICompatArrayType, param6: UInt32Type, param7: FFICompatArrayType, param8: UInt32Type) => IntType,
   FFI_ServerPublicParams_createProfileKeyCredentialPresentationDeterministic: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type, param5: FFICompatArrayType, param6: UInt32Type, param7: FFICompatArrayType, param8: UInt32Type, param9: FFICompatArrayType, param10: UInt32Type) => IntType,
+  FFI_ServerPublicParams_createProfileKeyCredentialPresentationWithContextDeterministic: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type, param5: FFICompatArrayType, param6: UInt32Type, param7: FFICompatArrayType, param8: UInt32Type, param9: FFICompatArrayType, param10: UInt32Type, param11: FFICompatArrayType, param12: UInt32Type) => IntType,
+  FFI_ServerPublicParams_createProfileKeyCredentialSelectivePresentationDeterministic: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type, param5: FFICompatArrayType, param6: UInt32Type, param7: FFICompatArrayType, param8: UInt32Type, param9: FFICompatArrayType, param10: UInt32Type) => IntType,
+  FFI_ServerPublicParams_createProfileKeyCredentialSelectivePresentationWithContextDeterministic: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type, param5: FFICompatArrayType, param6: UInt32Type, param7: FFICompatArrayType, param8: UInt32Type, param9: FFICompatArrayType, param10: UInt32Type, param11: FFICompatArrayType, param12: UInt32Type) => IntType,
+  FFI_ServerPublicParams_createRateLimitCredentialRequestContextDeterministic: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type, param5: FFICompatArrayType, param6: UInt32Type) => IntType,
+  FFI_ServerPublicParams_receiveRateLimitCredential: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type, param5: FFICompatArrayType, param6: UInt32Type, param7: FFICompatArrayType, param8: UInt32Type) => IntType,
+  FFI_ServerPublicParams_createRateLimitTokenDeterministic: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type, param5: FFICompatArrayType, param6: UInt32Type, param7: FFICompatArrayType, param8: UInt32Type) => IntType,
   FFI_ServerSecretParams_issueAuthCredentialDeterministic: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type, param5: FFICompatArrayType, param6: UInt32Type, param7: IntType, param8: FFICompatArrayType, param9: UInt32Type) => IntType,
   FFI_ServerSecretParams_verifyAuthCredentialPresentation: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type, param5: FFICompatArrayType, param6: UInt32Type) => IntType,
+  FFI_ServerSecretParams_verifyAuthCredentialPresentationWithContext: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type, param5: FFICompatArrayType, param6: UInt32Type, param7: FFICompatArrayType, param8: UInt32Type) => IntType,
+  FFI_ServerSecretParams_verifyGroupInviteLinkPresentation: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type, param5: FFICompatArrayType, param6: UInt32Type, param7: FFICompatArrayType, param8: UInt32Type) => IntType,
   FFI_ServerSecretParams_issueProfileKeyCredentialDeterministic: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type, param5: FFICompatArrayType, param6: UInt32Type, param7: FFICompatArrayType, param8: UInt32Type, param9: FFICompatArrayType, param10: UInt32Type, param11: FFICompatArrayType, param12: UInt32Type) => IntType,
   FFI_ServerSecretParams_verifyProfileKeyCredentialPresentation: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type, param5: FFICompatArrayType, param6: UInt32Type) => IntType,
+  FFI_ServerSecretParams_verifyProfileKeyCredentialPresentationWithContext: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type, param5: FFICompatArrayType, param6: UInt32Type, param7: FFICompatArrayType, param8: UInt32Type) => IntType,
+  FFI_ServerSecretParams_verifyProfileKeyCredentialSelectivePresentation: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type, param5: FFICompatArrayType, param6: UInt32Type) => IntType,
+  FFI_ServerSecretParams_verifyProfileKeyCredentialSelectivePresentationWithContext: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type, param5: FFICompatArrayType, param6: UInt32Type, param7: FFICompatArrayType, param8: UInt32Type) => IntType,
+  FFI_ServerSecretParams_issueRateLimitCredentialDeterministic: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type, param5: FFICompatArrayType, param6: UInt32Type, param7: IntType, param8: FFICompatArrayType, param9: UInt32Type) => IntType,
+  FFI_ServerSecretParams_verifyRateLimitToken: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type) => IntType,
   FFI_GroupPublicParams_getGroupIdentifier: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type) => IntType,
+  FFI_GroupPublicParams_verifyUuidCiphertextMatch: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type, param5: FFICompatArrayType, param6: UInt32Type, param7: FFICompatArrayType, param8: UInt32Type) => IntType,
   FFI_GroupPublicParams_checkValidContents: (param2: FFICompatArrayType, param3: UInt32Type) => IntType,
   FFI_ServerPublicParams_verifySignature: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type, param5: FFICompatArrayType, param6: UInt32Type) => IntType,
   FFI_ServerPublicParams_checkValidContents: (param2: FFICompatArrayType, param3: UInt32Type) => IntType,
@@ -55,6 +78,14 @@ interface NativeCalls {
   FFI_AuthCredentialPresentation_getUuidCiphertext: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type) => IntType,
   FFI_AuthCredentialPresentation_getRedemptionTime: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type) => IntType,
   FFI_AuthCredentialPresentation_checkValidContents: (param2: FFICompatArrayType, param3: UInt32Type) => IntType,
+  FFI_RateLimitCredentialRequestContext_getRequest: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type) => IntType,
+  FFI_RateLimitCredentialRequestContext_checkValidContents: (param2: FFICompatArrayType, param3: UInt32Type) => IntType,
+  FFI_RateLimitCredentialRequest_checkValidContents: (param2: FFICompatArrayType, param3: UInt32Type) => IntType,
+  FFI_RateLimitCredentialResponse_checkValidContents: (param2: FFICompatArrayType, param3: UInt32Type) => IntType,
+  FFI_RateLimitCredential_checkValidContents: (param2: FFICompatArrayType, param3: UInt32Type) => IntType,
+  FFI_RateLimitToken_getTag: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type) => IntType,
+  FFI_RateLimitToken_getRedemptionTime: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type) => IntType,
+  FFI_RateLimitToken_checkValidContents: (param2: FFICompatArrayType, param3: UInt32Type) => IntType,
   FFI_ProfileKeyCredentialRequestContext_getRequest: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type) => IntType,
   FFI_ProfileKeyCredentialRequestContext_checkValidContents: (param2: FFICompatArrayType, param3: UInt32Type) => IntType,
   FFI_ProfileKeyCredentialRequest_checkValidContents: (param2: FFICompatArrayType, param3: UInt32Type) => IntType,
@@ -63,8 +94,17 @@ interface NativeCalls {
   FFI_ProfileKeyCredentialPresentation_getUuidCiphertext: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type) => IntType,
   FFI_ProfileKeyCredentialPresentation_getProfileKeyCiphertext: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type) => IntType,
   FFI_ProfileKeyCredentialPresentation_checkValidContents: (param2: FFICompatArrayType, param3: UInt32Type) => IntType,
+  FFI_ProfileKeyCredentialSelectivePresentation_getUuidCiphertext: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type) => IntType,
+  FFI_ProfileKeyCredentialSelectivePresentation_checkValidContents: (param2: FFICompatArrayType, param3: UInt32Type) => IntType,
+  FFI_GroupInviteLinkPassword_getCommitment: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type, param5: FFICompatArrayType, param6: UInt32Type) => IntType,
+  FFI_GroupInviteLinkPasswordCommitment_checkValidContents: (param2: FFICompatArrayType, param3: UInt32Type) => IntType,
+  FFI_GroupInviteLinkPresentation_getUuidCiphertext: (param1: FFICompatArrayType, param2: UInt32Type, param3: FFICompatArrayType, param4: UInt32Type) => IntType,
+  FFI_GroupInviteLinkPresentation_checkValidContents: (param2: FFICompatArrayType, param3: UInt32Type) => IntType,
   FFI_UuidCiphertext_checkValidContents: (param2: FFICompatArrayType, param3: UInt32Type) => IntType,
   FFI_ProfileKeyCiphertext_checkValidContents: (param2: FFICompatArrayType, param3: UInt32Type) => IntType,
+  FFI_RandomizedUuidCiphertext_checkValidContents: (param2: FFICompatArrayType, param3: UInt32Type) => IntType,
+  FFI_UuidCiphertextMatchProof_checkValidContents: (param2: FFICompatArrayType, param3: UInt32Type) => IntType,
+  FFI_getLastErrorCode: () => IntType,
 }
 
 const library: NativeCalls = Library(libraryPath, {
@@ -82,20 +122,43 @@ const library: NativeCalls = Library(libraryPath, {
   'FFI_GroupSecretParams_decryptProfileKey': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', ] ],
   'FFI_GroupSecretParams_encryptBlobDeterministic': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', ] ],
   'FFI_GroupSecretParams_decryptBlob': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32',  ] ],
+  'FFI_GroupSecretParams_encryptBlobWithPaddingDeterministic': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', 'int', FFICompatArray, 'uint32', ] ],
+  'FFI_GroupSecretParams_decryptPaddedBlob': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32',  ] ],
+  'FFI_GroupSecretParams_createGroupInviteLinkPresentationDeterministic': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', ] ],
+  'FFI_GroupSecretParams_encryptUuidRandomizedDeterministic': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', ] ],
+  'FFI_GroupSecretParams_decryptRandomizedUuid': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', ] ],
+  'FFI_GroupSecretParams_createUuidCiphertextMatchProofDeterministic': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', ] ],
+  'FFI_GroupSecretParams_encryptBlobWithAdDeterministic': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', ] ],
+  'FFI_GroupSecretParams_decryptBlobWithAd': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', ] ],
   'FFI_ServerSecretParams_generateDeterministic': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32',  ] ],
   'FFI_ServerSecretParams_getPublicParams': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32',  ] ],
   'FFI_ServerSecretParams_signDeterministic': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32',  ] ],
   'FFI_ServerSecretParams_checkValidContents': [ 'int', [ FFICompatArray, 'uint32' ] ],
   'FFI_ServerPublicParams_receiveAuthCredential': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32', 'int', FFICompatArray, 'uint32', FFICompatArray, 'uint32',  ] ],
   'FFI_ServerPublicParams_createAuthCredentialPresentationDeterministic': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32',  ] ],
+  'FFI_ServerPublicParams_createAuthCredentialPresentationWithContextDeterministic': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32' ] ],
   'FFI_ServerPublicParams_createProfileKeyCredentialRequestContextDeterministic': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32',  ] ],
   'FFI_ServerPublicParams_receiveProfileKeyCredential': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32',  ] ],
   'FFI_ServerPublicParams_createProfileKeyCredentialPresentationDeterministic': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32',  ] ],
+  'FFI_ServerPublicParams_createProfileKeyCredentialPresentationWithContextDeterministic': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32' ] ],
+  'FFI_ServerPublicParams_createProfileKeyCredentialSelectivePresentationDeterministic': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32',  ] ],
+  'FFI_ServerPublicParams_createProfileKeyCredentialSelectivePresentationWithContextDeterministic': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32' ] ],
+  'FFI_ServerPublicParams_createRateLimitCredentialRequestContextDeterministic': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32',  ] ],
+  'FFI_ServerPublicParams_receiveRateLimitCredential': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32',  ] ],
+  'FFI_ServerPublicParams_createRateLimitTokenDeterministic': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', ] ],
   'FFI_ServerSecretParams_issueAuthCredentialDeterministic': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', 'int', FFICompatArray, 'uint32',  ] ],
   'FFI_ServerSecretParams_verifyAuthCredentialPresentation': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32' ] ],
+  'FFI_ServerSecretParams_verifyAuthCredentialPresentationWithContext': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32' ] ],
+  'FFI_ServerSecretParams_verifyGroupInviteLinkPresentation': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', ] ],
   'FFI_ServerSecretParams_issueProfileKeyCredentialDeterministic': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32',  ] ],
   'FFI_ServerSecretParams_verifyProfileKeyCredentialPresentation': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32' ] ],
+  'FFI_ServerSecretParams_verifyProfileKeyCredentialPresentationWithContext': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32' ] ],
+  'FFI_ServerSecretParams_verifyProfileKeyCredentialSelectivePresentation': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32' ] ],
+  'FFI_ServerSecretParams_verifyProfileKeyCredentialSelectivePresentationWithContext': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32' ] ],
+  'FFI_ServerSecretParams_issueRateLimitCredentialDeterministic': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', 'int', FFICompatArray, 'uint32',  ] ],
+  'FFI_ServerSecretParams_verifyRateLimitToken': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32' ] ],
   'FFI_GroupPublicParams_getGroupIdentifier': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32',  ] ],
+  'FFI_GroupPublicParams_verifyUuidCiphertextMatch': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', ] ],
   'FFI_GroupPublicParams_checkValidContents': [ 'int', [ FFICompatArray, 'uint32' ] ],
   'FFI_ServerPublicParams_verifySignature': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32' ] ],
   'FFI_ServerPublicParams_checkValidContents': [ 'int', [ FFICompatArray, 'uint32' ] ],
@@ -104,6 +167,14 @@ const library: NativeCalls = Library(libraryPath, {
   'FFI_AuthCredentialPresentation_getUuidCiphertext': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32',  ] ],
   'FFI_AuthCredentialPresentation_getRedemptionTime': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32',  ] ],
   'FFI_AuthCredentialPresentation_checkValidContents': [ 'int', [ FFICompatArray, 'uint32' ] ],
+  'FFI_RateLimitCredentialRequestContext_getRequest': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32',  ] ],
+  'FFI_RateLimitCredentialRequestContext_checkValidContents': [ 'int', [ FFICompatArray, 'uint32' ] ],
+  'FFI_RateLimitCredentialRequest_checkValidContents': [ 'int', [ FFICompatArray, 'uint32' ] ],
+  'FFI_RateLimitCredentialResponse_checkValidContents': [ 'int', [ FFICompatArray, 'uint32' ] ],
+  'FFI_RateLimitCredential_checkValidContents': [ 'int', [ FFICompatArray, 'uint32' ] ],
+  'FFI_RateLimitToken_getTag': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32',  ] ],
+  'FFI_RateLimitToken_getRedemptionTime': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32',  ] ],
+  'FFI_RateLimitToken_checkValidContents': [ 'int', [ FFICompatArray, 'uint32' ] ],
   'FFI_ProfileKeyCredentialRequestContext_getRequest': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32',  ] ],
   'FFI_ProfileKeyCredentialRequestContext_checkValidContents': [ 'int', [ FFICompatArray, 'uint32' ] ],
   'FFI_ProfileKeyCredentialRequest_checkValidContents': [ 'int', [ FFICompatArray, 'uint32' ] ],
@@ -112,8 +183,17 @@ const library: NativeCalls = Library(libraryPath, {
   'FFI_ProfileKeyCredentialPresentation_getUuidCiphertext': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32',  ] ],
   'FFI_ProfileKeyCredentialPresentation_getProfileKeyCiphertext': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32',  ] ],
   'FFI_ProfileKeyCredentialPresentation_checkValidContents': [ 'int', [ FFICompatArray, 'uint32' ] ],
+  'FFI_ProfileKeyCredentialSelectivePresentation_getUuidCiphertext': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32',  ] ],
+  'FFI_ProfileKeyCredentialSelectivePresentation_checkValidContents': [ 'int', [ FFICompatArray, 'uint32' ] ],
+  'FFI_GroupInviteLinkPassword_getCommitment': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32', FFICompatArray, 'uint32', ] ],
+  'FFI_GroupInviteLinkPasswordCommitment_checkValidContents': [ 'int', [ FFICompatArray, 'uint32' ] ],
+  'FFI_GroupInviteLinkPresentation_getUuidCiphertext': [ 'int', [ FFICompatArray, 'uint32', FFICompatArray, 'uint32', ] ],
+  'FFI_GroupInviteLinkPresentation_checkValidContents': [ 'int', [ FFICompatArray, 'uint32' ] ],
   'FFI_UuidCiphertext_checkValidContents': [ 'int', [ FFICompatArray, 'uint32' ] ],
   'FFI_ProfileKeyCiphertext_checkValidContents': [ 'int', [ FFICompatArray, 'uint32' ] ],
+  'FFI_RandomizedUuidCiphertext_checkValidContents': [ 'int', [ FFICompatArray, 'uint32' ] ],
+  'FFI_UuidCiphertextMatchProof_checkValidContents': [ 'int', [ FFICompatArray, 'uint32' ] ],
+  'FFI_getLastErrorCode': [ 'int', [] ],
 });
 
 export default library;
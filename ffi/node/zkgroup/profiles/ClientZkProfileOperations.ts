@@ -17,6 +17,7 @@ import ProfileKeyCredentialRequestContext from './ProfileKeyCredentialRequestCon
 import ProfileKey from './ProfileKey';
 import ProfileKeyCredential from './ProfileKeyCredential';
 import ProfileKeyCredentialPresentation from './ProfileKeyCredentialPresentation';
+import ProfileKeyCredentialSelectivePresentation from './ProfileKeyCredentialSelectivePresentation';
 import GroupSecretParams from '../groups/GroupSecretParams';
 import ProfileKeyCredentialResponse from './ProfileKeyCredentialResponse';
 
@@ -93,4 +94,70 @@ export default class ClientZkProfileOperations {
     return new ProfileKeyCredentialPresentation(newContents);
   }
 
+  createProfileKeyCredentialPresentationWithContext(groupSecretParams: GroupSecretParams, profileKeyCredential: ProfileKeyCredential, context: FFICompatArrayType): ProfileKeyCredentialPresentation {
+    const random = new FFICompatArray(randomBytes(RANDOM_LENGTH));
+
+    return this.createProfileKeyCredentialPresentationWithContextWithRandom(random, groupSecretParams, profileKeyCredential, context);
+  }
+
+  createProfileKeyCredentialPresentationWithContextWithRandom(random: FFICompatArrayType, groupSecretParams: GroupSecretParams, profileKeyCredential: ProfileKeyCredential, context: FFICompatArrayType): ProfileKeyCredentialPresentation {
+    const newContents = new FFICompatArray(ProfileKeyCredentialPresentation.SIZE);
+
+    const serverPublicParamsContents = this.serverPublicParams.getContents();
+    const groupSecretParamsContents = groupSecretParams.getContents();
+    const profileKeyCredentialContents = profileKeyCredential.getContents();
+
+    const ffi_return = Native.FFI_ServerPublicParams_createProfileKeyCredentialPresentationWithContextDeterministic(serverPublicParamsContents, serverPublicParamsContents.length, random, random.length, groupSecretParamsContents, groupSecretParamsContents.length, profileKeyCredentialContents, profileKeyCredentialContents.length, context, context.length, newContents, newContents.length);
+
+    if (ffi_return != FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+    }
+
+    return new ProfileKeyCredentialPresentation(newContents);
+  }
+
+  createProfileKeyCredentialSelectivePresentation(groupSecretParams: GroupSecretParams, profileKeyCredential: ProfileKeyCredential): ProfileKeyCredentialSelectivePresentation {
+    const random = new FFICompatArray(randomBytes(RANDOM_LENGTH));
+
+    return this.createProfileKeyCredentialSelectivePresentationWithRandom(random, groupSecretParams, profileKeyCredential);
+  }
+
+  createProfileKeyCredentialSelectivePresentationWithRandom(random: FFICompatArrayType, groupSecretParams: GroupSecretParams, profileKeyCredential: ProfileKeyCredential): ProfileKeyCredentialSelectivePresentation {
+    const newContents = new FFICompatArray(ProfileKeyCredentialSelectivePresentation.SIZE);
+
+    const serverPublicParamsContents = this.serverPublicParams.getContents();
+    const groupSecretParamsContents = groupSecretParams.getContents();
+    const profileKeyCredentialContents = profileKeyCredential.getContents();
+
+    const ffi_return = Native.FFI_ServerPublicParams_createProfileKeyCredentialSelectivePresentationDeterministic(serverPublicParamsContents, serverPublicParamsContents.length, random, random.length, groupSecretParamsContents, groupSecretParamsContents.length, profileKeyCredentialContents, profileKeyCredentialContents.length, newContents, newContents.length);
+
+    if (ffi_return != FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+    }
+
+    return new ProfileKeyCredentialSelectivePresentation(newContents);
+  }
+
+  createProfileKeyCredentialSelectivePresentationWithContext(groupSecretParams: GroupSecretParams, profileKeyCredential: ProfileKeyCredential, context: FFICompatArrayType): ProfileKeyCredentialSelectivePresentation {
+    const random = new FFICompatArray(randomBytes(RANDOM_LENGTH));
+
+    return this.createProfileKeyCredentialSelectivePresentationWithContextWithRandom(random, groupSecretParams, profileKeyCredential, context);
+  }
+
+  createProfileKeyCredentialSelectivePresentationWithContextWithRandom(random: FFICompatArrayType, groupSecretParams: GroupSecretParams, profileKeyCredential: ProfileKeyCredential, context: FFICompatArrayType): ProfileKeyCredentialSelectivePresentation {
+    const newContents = new FFICompatArray(ProfileKeyCredentialSelectivePresentation.SIZE);
+
+    const serverPublicParamsContents = this.serverPublicParams.getContents();
+    const groupSecretParamsContents = groupSecretParams.getContents();
+    const profileKeyCredentialContents = profileKeyCredential.getContents();
+
+    const ffi_return = Native.FFI_ServerPublicParams_createProfileKeyCredentialSelectivePresentationWithContextDeterministic(serverPublicParamsContents, serverPublicParamsContents.length, random, random.length, groupSecretParamsContents, groupSecretParamsContents.length, profileKeyCredentialContents, profileKeyCredentialContents.length, context, context.length, newContents, newContents.length);
+
+    if (ffi_return != FFI_RETURN_OK) {
+      throw new ZkGroupError("FFI_RETURN!=OK");
+    }
+
+    return new ProfileKeyCredentialSelectivePresentation(newContents);
+  }
+
 }
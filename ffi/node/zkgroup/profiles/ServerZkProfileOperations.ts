@@ -16,6 +16,7 @@ import ProfileKeyCredentialRequest from './ProfileKeyCredentialRequest';
 import ProfileKeyCommitment from './ProfileKeyCommitment';
 import GroupPublicParams from '../groups/GroupPublicParams';
 import ProfileKeyCredentialPresentation from './ProfileKeyCredentialPresentation';
+import ProfileKeyCredentialSelectivePresentation from './ProfileKeyCredentialSelectivePresentation';
 
 import { UUID_LENGTH, UUIDType, fromUUID, toUUID } from '../internal/UUIDUtil';
 
@@ -68,4 +69,49 @@ export default class ServerZkProfileOperations {
     }
   }
 
+  verifyProfileKeyCredentialPresentationWithContext(groupPublicParams: GroupPublicParams, profileKeyCredentialPresentation: ProfileKeyCredentialPresentation, context: FFICompatArrayType) {
+    const serverSecretParamsContents = this.serverSecretParams.getContents()
+    const groupPublicParamsContents = groupPublicParams.getContents()
+    const profileKeyCredentialPresentationContents = profileKeyCredentialPresentation.getContents();
+
+    const ffi_return = Native.FFI_ServerSecretParams_verifyProfileKeyCredentialPresentationWithContext(serverSecretParamsContents, serverSecretParamsContents.length, groupPublicParamsContents, groupPublicParamsContents.length, profileKeyCredentialPresentationContents, profileKeyCredentialPresentationContents.length, context, context.length);
+    if (ffi_return == FFI_RETURN_INPUT_ERROR) {
+      throw new VerificationFailedException('FFI_RETURN_INPUT_ERROR');
+    }
+
+    if (ffi_return != FFI_RETURN_OK) {
+      throw new ZkGroupError('FFI_RETURN!=OK');
+    }
+  }
+
+  verifyProfileKeyCredentialSelectivePresentation(groupPublicParams: GroupPublicParams, profileKeyCredentialSelectivePresentation: ProfileKeyCredentialSelectivePresentation) {
+    const serverSecretParamsContents = this.serverSecretParams.getContents()
+    const groupPublicParamsContents = groupPublicParams.getContents()
+    const presentationContents = profileKeyCredentialSelectivePresentation.getContents();
+
+    const ffi_return = Native.FFI_ServerSecretParams_verifyProfileKeyCredentialSelectivePresentation(serverSecretParamsContents, serverSecretParamsContents.length, groupPublicParamsContents, groupPublicParamsContents.length, presentationContents, presentationContents.length);
+    if (ffi_return == FFI_RETURN_INPUT_ERROR) {
+      throw new VerificationFailedException('FFI_RETURN_INPUT_ERROR');
+    }
+
+    if (ffi_return != FFI_RETURN_OK) {
+      throw new ZkGroupError('FFI_RETURN!=OK');
+    }
+  }
+
+  verifyProfileKeyCredentialSelectivePresentationWithContext(groupPublicParams: GroupPublicParams, profileKeyCredentialSelectivePresentation: ProfileKeyCredentialSelectivePresentation, context: FFICompatArrayType) {
+    const serverSecretParamsContents = this.serverSecretParams.getContents()
+    const groupPublicParamsContents = groupPublicParams.getContents()
+    const presentationContents = profileKeyCredentialSelectivePresentation.getContents();
+
+    const ffi_return = Native.FFI_ServerSecretParams_verifyProfileKeyCredentialSelectivePresentationWithContext(serverSecretParamsContents, serverSecretParamsContents.length, groupPublicParamsContents, groupPublicParamsContents.length, presentationContents, presentationContents.length, context, context.length);
+    if (ffi_return == FFI_RETURN_INPUT_ERROR) {
+      throw new VerificationFailedException('FFI_RETURN_INPUT_ERROR');
+    }
+
+    if (ffi_return != FFI_RETURN_OK) {
+      throw new ZkGroupError('FFI_RETURN!=OK');
+    }
+  }
+
 }
@@ -58,4 +58,108 @@ public class ClientZkAuthOperations {
 
   }
 
+  public func createAuthCredentialPresentationWithContext(groupSecretParams: GroupSecretParams, authCredential: AuthCredential, context: [UInt8]) throws  -> AuthCredentialPresentation {
+    var randomness: [UInt8] = Array(repeating: 0, count: Int(32))
+    let result = SecRandomCopyBytes(kSecRandomDefault, randomness.count, &randomness)
+    guard result == errSecSuccess else {
+      throw ZkGroupException.AssertionError
+    }
+
+    return try createAuthCredentialPresentationWithContext(randomness: randomness, groupSecretParams: groupSecretParams, authCredential: authCredential, context: context)
+  }
+
+  public func createAuthCredentialPresentationWithContext(randomness: [UInt8], groupSecretParams: GroupSecretParams, authCredential: AuthCredential, context: [UInt8]) throws  -> AuthCredentialPresentation {
+    var newContents: [UInt8] = Array(repeating: 0, count: AuthCredentialPresentation.SIZE)
+
+    let ffi_return = FFI_ServerPublicParams_createAuthCredentialPresentationWithContextDeterministic(serverPublicParams.getInternalContentsForFFI(), UInt32(serverPublicParams.getInternalContentsForFFI().count), randomness, UInt32(randomness.count), groupSecretParams.getInternalContentsForFFI(), UInt32(groupSecretParams.getInternalContentsForFFI().count), authCredential.getInternalContentsForFFI(), UInt32(authCredential.getInternalContentsForFFI().count), context, UInt32(context.count), &newContents, UInt32(newContents.count))
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw ZkGroupException.ZkGroupError
+    }
+
+    do {
+      return try AuthCredentialPresentation(contents: newContents)
+    } catch ZkGroupException.InvalidInput {
+      throw ZkGroupException.AssertionError
+    }
+
+  }
+
+  public func createRateLimitCredentialRequestContext() throws  -> RateLimitCredentialRequestContext {
+    var randomness: [UInt8] = Array(repeating: 0, count: Int(32))
+    let result = SecRandomCopyBytes(kSecRandomDefault, randomness.count, &randomness)
+    guard result == errSecSuccess else {
+      throw ZkGroupException.AssertionError
+    }
+
+    return try createRateLimitCredentialRequestContext(randomness: randomness)
+  }
+
+  public func createRateLimitCredentialRequestContext(randomness: [UInt8]) throws  -> RateLimitCredentialRequestContext {
+    var newContents: [UInt8] = Array(repeating: 0, count: RateLimitCredentialRequestContext.SIZE)
+
+    let ffi_return = FFI_ServerPublicParams_createRateLimitCredentialRequestContextDeterministic(serverPublicParams.getInternalContentsForFFI(), UInt32(serverPublicParams.getInternalContentsForFFI().count), randomness, UInt32(randomness.count), &newContents, UInt32(newContents.count))
+    if (ffi_return == Native.FFI_RETURN_INPUT_ERROR) {
+      throw ZkGroupException.VerificationFailed
+    }
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw ZkGroupException.ZkGroupError
+    }
+
+    do {
+      return try RateLimitCredentialRequestContext(contents: newContents)
+    } catch ZkGroupException.InvalidInput {
+      throw ZkGroupException.AssertionError
+    }
+
+  }
+
+  public func receiveRateLimitCredential(rateLimitCredentialRequestContext: RateLimitCredentialRequestContext, rateLimitCredentialResponse: RateLimitCredentialResponse) throws  -> RateLimitCredential {
+    var newContents: [UInt8] = Array(repeating: 0, count: RateLimitCredential.SIZE)
+
+    let ffi_return = FFI_ServerPublicParams_receiveRateLimitCredential(serverPublicParams.getInternalContentsForFFI(), UInt32(serverPublicParams.getInternalContentsForFFI().count), rateLimitCredentialRequestContext.getInternalContentsForFFI(), UInt32(rateLimitCredentialRequestContext.getInternalContentsForFFI().count), rateLimitCredentialResponse.getInternalContentsForFFI(), UInt32(rateLimitCredentialResponse.getInternalContentsForFFI().count), &newContents, UInt32(newContents.count))
+    if (ffi_return == Native.FFI_RETURN_INPUT_ERROR) {
+      throw ZkGroupException.VerificationFailed
+    }
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw ZkGroupException.ZkGroupError
+    }
+
+    do {
+      return try RateLimitCredential(contents: newContents)
+    } catch ZkGroupException.InvalidInput {
+      throw ZkGroupException.AssertionError
+    }
+
+  }
+
+  public func createRateLimitToken(rateLimitCredential: RateLimitCredential) throws  -> RateLimitToken {
+    var randomness: [UInt8] = Array(repeating: 0, count: Int(32))
+    let result = SecRandomCopyBytes(kSecRandomDefault, randomness.count, &randomness)
+    guard result == errSecSuccess else {
+      throw ZkGroupException.AssertionError
+    }
+
+    return try createRateLimitToken(randomness: randomness, rateLimitCredential: rateLimitCredential)
+  }
+
+  public func createRateLimitToken(randomness: [UInt8], rateLimitCredential: RateLimitCredential) throws  -> RateLimitToken {
+    var newContents: [UInt8] = Array(repeating: 0, count: RateLimitToken.SIZE)
+
+    let ffi_return = FFI_ServerPublicParams_createRateLimitTokenDeterministic(serverPublicParams.getInternalContentsForFFI(), UInt32(serverPublicParams.getInternalContentsForFFI().count), randomness, UInt32(randomness.count), rateLimitCredential.getInternalContentsForFFI(), UInt32(rateLimitCredential.getInternalContentsForFFI().count), &newContents, UInt32(newContents.count))
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw ZkGroupException.ZkGroupError
+    }
+
+    do {
+      return try RateLimitToken(contents: newContents)
+    } catch ZkGroupException.InvalidInput {
+      throw ZkGroupException.AssertionError
+    }
+
+  }
+
 }
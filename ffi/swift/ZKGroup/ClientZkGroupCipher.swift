@@ -139,4 +139,211 @@ public class ClientZkGroupCipher {
     return Array(newContents[4 ..< newContents.endIndex - Int(paddingLen)])
   }
 
+  public func encryptBlobWithPadding(plaintext: [UInt8], paddedLength: UInt32) throws  -> [UInt8] {
+    var randomness: [UInt8] = Array(repeating: 0, count: Int(32))
+    let result = SecRandomCopyBytes(kSecRandomDefault, randomness.count, &randomness)
+    guard result == errSecSuccess else {
+      throw ZkGroupException.AssertionError
+    }
+
+    return try encryptBlobWithPadding(randomness: randomness, plaintext: plaintext, paddedLength: paddedLength)
+  }
+
+  public func encryptBlobWithPadding(randomness: [UInt8], plaintext: [UInt8], paddedLength: UInt32) throws  -> [UInt8] {
+    if paddedLength <= plaintext.count {
+      throw ZkGroupException.VerificationFailed
+    }
+
+    var newContents: [UInt8] = Array(repeating: 0, count: Int(paddedLength)+29)
+
+    let ffi_return = FFI_GroupSecretParams_encryptBlobWithPaddingDeterministic(groupSecretParams.getInternalContentsForFFI(), UInt32(groupSecretParams.getInternalContentsForFFI().count), randomness, UInt32(randomness.count), plaintext, UInt32(plaintext.count), paddedLength, &newContents, UInt32(newContents.count))
+    if (ffi_return == Native.FFI_RETURN_INPUT_ERROR) {
+      throw ZkGroupException.VerificationFailed
+    }
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw ZkGroupException.ZkGroupError
+    }
+
+    return newContents
+  }
+
+  public func decryptPaddedBlob(blobCiphertext: [UInt8]) throws  -> [UInt8] {
+    if blobCiphertext.count < 29 {
+      throw ZkGroupException.VerificationFailed
+    }
+
+    var newContents: [UInt8] = Array(repeating: 0, count: Int(blobCiphertext.count-25))
+
+    let ffi_return = FFI_GroupSecretParams_decryptPaddedBlob(groupSecretParams.getInternalContentsForFFI(), UInt32(groupSecretParams.getInternalContentsForFFI().count), blobCiphertext, UInt32(blobCiphertext.count), &newContents, UInt32(newContents.count))
+
+    if (ffi_return == Native.FFI_RETURN_INPUT_ERROR) {
+      throw ZkGroupException.VerificationFailed
+    }
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw ZkGroupException.ZkGroupError
+    }
+
+    var plaintextLen = newContents.withUnsafeBytes({ $0.load(fromByteOffset:0, as: UInt32.self) })
+    plaintextLen = UInt32(bigEndian: plaintextLen)
+
+    return Array(newContents[4 ..< 4 + Int(plaintextLen)])
+  }
+
+  public func createGroupInviteLinkPresentation(groupInviteLinkPassword: GroupInviteLinkPassword, uuid: ZKGUuid) throws  -> GroupInviteLinkPresentation {
+    var randomness: [UInt8] = Array(repeating: 0, count: Int(32))
+    let result = SecRandomCopyBytes(kSecRandomDefault, randomness.count, &randomness)
+    guard result == errSecSuccess else {
+      throw ZkGroupException.AssertionError
+    }
+
+    return try createGroupInviteLinkPresentation(randomness: randomness, groupInviteLinkPassword: groupInviteLinkPassword, uuid: uuid)
+  }
+
+  public func createGroupInviteLinkPresentation(randomness: [UInt8], groupInviteLinkPassword: GroupInviteLinkPassword, uuid: ZKGUuid) throws  -> GroupInviteLinkPresentation {
+    var newContents: [UInt8] = Array(repeating: 0, count: GroupInviteLinkPresentation.SIZE)
+
+    let ffi_return = FFI_GroupSecretParams_createGroupInviteLinkPresentationDeterministic(groupSecretParams.getInternalContentsForFFI(), UInt32(groupSecretParams.getInternalContentsForFFI().count), randomness, UInt32(randomness.count), groupInviteLinkPassword.getInternalContentsForFFI(), UInt32(groupInviteLinkPassword.getInternalContentsForFFI().count), uuid.getInternalContentsForFFI(), UInt32(uuid.getInternalContentsForFFI().count), &newContents, UInt32(newContents.count))
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw ZkGroupException.ZkGroupError
+    }
+
+    do {
+      return try GroupInviteLinkPresentation(contents: newContents)
+    } catch ZkGroupException.InvalidInput {
+      throw ZkGroupException.AssertionError
+    }
+
+  }
+
+  public func encryptUuidRandomized(uuid: ZKGUuid) throws  -> RandomizedUuidCiphertext {
+    var randomness: [UInt8] = Array(repeating: 0, count: Int(32))
+    let result = SecRandomCopyBytes(kSecRandomDefault, randomness.count, &randomness)
+    guard result == errSecSuccess else {
+      throw ZkGroupException.AssertionError
+    }
+
+    return try encryptUuidRandomized(randomness: randomness, uuid: uuid)
+  }
+
+  public func encryptUuidRandomized(randomness: [UInt8], uuid: ZKGUuid) throws  -> RandomizedUuidCiphertext {
+    var newContents: [UInt8] = Array(repeating: 0, count: RandomizedUuidCiphertext.SIZE)
+
+    let ffi_return = FFI_GroupSecretParams_encryptUuidRandomizedDeterministic(groupSecretParams.getInternalContentsForFFI(), UInt32(groupSecretParams.getInternalContentsForFFI().count), randomness, UInt32(randomness.count), uuid.getInternalContentsForFFI(), UInt32(uuid.getInternalContentsForFFI().count), &newContents, UInt32(newContents.count))
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw ZkGroupException.ZkGroupError
+    }
+
+    do {
+      return try RandomizedUuidCiphertext(contents: newContents)
+    } catch ZkGroupException.InvalidInput {
+      throw ZkGroupException.AssertionError
+    }
+
+  }
+
+  public func decryptRandomizedUuid(randomizedUuidCiphertext: RandomizedUuidCiphertext) throws  -> ZKGUuid {
+    var newContents: [UInt8] = Array(repeating: 0, count: ZKGUuid.SIZE)
+
+    let ffi_return = FFI_GroupSecretParams_decryptRandomizedUuid(groupSecretParams.getInternalContentsForFFI(), UInt32(groupSecretParams.getInternalContentsForFFI().count), randomizedUuidCiphertext.getInternalContentsForFFI(), UInt32(randomizedUuidCiphertext.getInternalContentsForFFI().count), &newContents, UInt32(newContents.count))
+    if (ffi_return == Native.FFI_RETURN_INPUT_ERROR) {
+      throw ZkGroupException.VerificationFailed
+    }
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw ZkGroupException.ZkGroupError
+    }
+
+    do {
+      return try ZKGUuid(contents: newContents)
+    } catch ZkGroupException.InvalidInput {
+      throw ZkGroupException.AssertionError
+    }
+
+  }
+
+  public func createUuidCiphertextMatchProof(uuidCiphertext: UuidCiphertext, randomizedUuidCiphertext: RandomizedUuidCiphertext) throws  -> UuidCiphertextMatchProof {
+    var randomness: [UInt8] = Array(repeating: 0, count: Int(32))
+    let result = SecRandomCopyBytes(kSecRandomDefault, randomness.count, &randomness)
+    guard result == errSecSuccess else {
+      throw ZkGroupException.AssertionError
+    }
+
+    return try createUuidCiphertextMatchProof(randomness: randomness, uuidCiphertext: uuidCiphertext, randomizedUuidCiphertext: randomizedUuidCiphertext)
+  }
+
+  public func createUuidCiphertextMatchProof(randomness: [UInt8], uuidCiphertext: UuidCiphertext, randomizedUuidCiphertext: RandomizedUuidCiphertext) throws  -> UuidCiphertextMatchProof {
+    var newContents: [UInt8] = Array(repeating: 0, count: UuidCiphertextMatchProof.SIZE)
+
+    let ffi_return = FFI_GroupSecretParams_createUuidCiphertextMatchProofDeterministic(groupSecretParams.getInternalContentsForFFI(), UInt32(groupSecretParams.getInternalContentsForFFI().count), randomness, UInt32(randomness.count), uuidCiphertext.getInternalContentsForFFI(), UInt32(uuidCiphertext.getInternalContentsForFFI().count), randomizedUuidCiphertext.getInternalContentsForFFI(), UInt32(randomizedUuidCiphertext.getInternalContentsForFFI().count), &newContents, UInt32(newContents.count))
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw ZkGroupException.ZkGroupError
+    }
+
+    do {
+      return try UuidCiphertextMatchProof(contents: newContents)
+    } catch ZkGroupException.InvalidInput {
+      throw ZkGroupException.AssertionError
+    }
+
+  }
+
+  public func encryptBlobWithAd(plaintext: [UInt8], associatedData: [UInt8]) throws  -> [UInt8] {
+    var randomness: [UInt8] = Array(repeating: 0, count: Int(32))
+    let result = SecRandomCopyBytes(kSecRandomDefault, randomness.count, &randomness)
+    guard result == errSecSuccess else {
+      throw ZkGroupException.AssertionError
+    }
+
+    return try encryptBlobWithAd(randomness: randomness, plaintext: plaintext, associatedData: associatedData)
+  }
+
+  public func encryptBlobWithAd(randomness: [UInt8], plaintext: [UInt8], associatedData: [UInt8]) throws  -> [UInt8] {
+    let paddedPlaintext = Array(repeating:0, count: 4) + plaintext
+
+    var newContents: [UInt8] = Array(repeating: 0, count: Int(paddedPlaintext.count+29))
+
+    let ffi_return = FFI_GroupSecretParams_encryptBlobWithAdDeterministic(groupSecretParams.getInternalContentsForFFI(), UInt32(groupSecretParams.getInternalContentsForFFI().count), randomness, UInt32(randomness.count), paddedPlaintext, UInt32(paddedPlaintext.count), associatedData, UInt32(associatedData.count), &newContents, UInt32(newContents.count))
+    if (ffi_return == Native.FFI_RETURN_INPUT_ERROR) {
+      throw ZkGroupException.VerificationFailed
+    }
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw ZkGroupException.ZkGroupError
+    }
+
+    return newContents
+  }
+
+  public func decryptBlobWithAd(blobCiphertext: [UInt8], associatedData: [UInt8]) throws  -> [UInt8] {
+    var newContents: [UInt8] = Array(repeating: 0, count: Int(blobCiphertext.count-29))
+
+    let ffi_return = FFI_GroupSecretParams_decryptBlobWithAd(groupSecretParams.getInternalContentsForFFI(), UInt32(groupSecretParams.getInternalContentsForFFI().count), blobCiphertext, UInt32(blobCiphertext.count), associatedData, UInt32(associatedData.count), &newContents, UInt32(newContents.count))
+
+    if (ffi_return == Native.FFI_RETURN_INPUT_ERROR) {
+      throw ZkGroupException.VerificationFailed
+    }
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw ZkGroupException.ZkGroupError
+    }
+
+    if newContents.count < 4 {
+        throw ZkGroupException.VerificationFailed
+    }
+
+    var paddingLen = newContents.withUnsafeBytes({ $0.load(fromByteOffset:0, as: UInt32.self) })
+    paddingLen = UInt32(bigEndian: paddingLen)
+
+    if (newContents.count < (4 + paddingLen))  {
+        throw ZkGroupException.VerificationFailed
+    }
+
+    return Array(newContents[4 ..< newContents.endIndex - Int(paddingLen)])
+  }
+
 }
@@ -85,4 +85,85 @@ public class ClientZkProfileOperations {
 
   }
 
+  public func createProfileKeyCredentialPresentationWithContext(groupSecretParams: GroupSecretParams, profileKeyCredential: ProfileKeyCredential, context: [UInt8]) throws  -> ProfileKeyCredentialPresentation {
+    var randomness: [UInt8] = Array(repeating: 0, count: Int(32))
+    let result = SecRandomCopyBytes(kSecRandomDefault, randomness.count, &randomness)
+    guard result == errSecSuccess else {
+      throw ZkGroupException.AssertionError
+    }
+
+    return try createProfileKeyCredentialPresentationWithContext(randomness: randomness, groupSecretParams: groupSecretParams, profileKeyCredential: profileKeyCredential, context: context)
+  }
+
+  public func createProfileKeyCredentialPresentationWithContext(randomness: [UInt8], groupSecretParams: GroupSecretParams, profileKeyCredential: ProfileKeyCredential, context: [UInt8]) throws  -> ProfileKeyCredentialPresentation {
+    var newContents: [UInt8] = Array(repeating: 0, count: ProfileKeyCredentialPresentation.SIZE)
+
+    let ffi_return = FFI_ServerPublicParams_createProfileKeyCredentialPresentationWithContextDeterministic(serverPublicParams.getInternalContentsForFFI(), UInt32(serverPublicParams.getInternalContentsForFFI().count), randomness, UInt32(randomness.count), groupSecretParams.getInternalContentsForFFI(), UInt32(groupSecretParams.getInternalContentsForFFI().count), profileKeyCredential.getInternalContentsForFFI(), UInt32(profileKeyCredential.getInternalContentsForFFI().count), context, UInt32(context.count), &newContents, UInt32(newContents.count))
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw ZkGroupException.ZkGroupError
+    }
+
+    do {
+      return try ProfileKeyCredentialPresentation(contents: newContents)
+    } catch ZkGroupException.InvalidInput {
+      throw ZkGroupException.AssertionError
+    }
+
+  }
+
+  public func createProfileKeyCredentialSelectivePresentation(groupSecretParams: GroupSecretParams, profileKeyCredential: ProfileKeyCredential) throws  -> ProfileKeyCredentialSelectivePresentation {
+    var randomness: [UInt8] = Array(repeating: 0, count: Int(32))
+    let result = SecRandomCopyBytes(kSecRandomDefault, randomness.count, &randomness)
+    guard result == errSecSuccess else {
+      throw ZkGroupException.AssertionError
+    }
+
+    return try createProfileKeyCredentialSelectivePresentation(randomness: randomness, groupSecretParams: groupSecretParams, profileKeyCredential: profileKeyCredential)
+  }
+
+  public func createProfileKeyCredentialSelectivePresentation(randomness: [UInt8], groupSecretParams: GroupSecretParams, profileKeyCredential: ProfileKeyCredential) throws  -> ProfileKeyCredentialSelectivePresentation {
+    var newContents: [UInt8] = Array(repeating: 0, count: ProfileKeyCredentialSelectivePresentation.SIZE)
+
+    let ffi_return = FFI_ServerPublicParams_createProfileKeyCredentialSelectivePresentationDeterministic(serverPublicParams.getInternalContentsForFFI(), UInt32(serverPublicParams.getInternalContentsForFFI().count), randomness, UInt32(randomness.count), groupSecretParams.getInternalContentsForFFI(), UInt32(groupSecretParams.getInternalContentsForFFI().count), profileKeyCredential.getInternalContentsForFFI(), UInt32(profileKeyCredential.getInternalContentsForFFI().count), &newContents, UInt32(newContents.count))
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw ZkGroupException.ZkGroupError
+    }
+
+    do {
+      return try ProfileKeyCredentialSelectivePresentation(contents: newContents)
+    } catch ZkGroupException.InvalidInput {
+      throw ZkGroupException.AssertionError
+    }
+
+  }
+
+  public func createProfileKeyCredentialSelectivePresentationWithContext(groupSecretParams: GroupSecretParams, profileKeyCredential: ProfileKeyCredential, context: [UInt8]) throws  -> ProfileKeyCredentialSelectivePresentation {
+    var randomness: [UInt8] = Array(repeating: 0, count: Int(32))
+    let result = SecRandomCopyBytes(kSecRandomDefault, randomness.count, &randomness)
+    guard result == errSecSuccess else {
+      throw ZkGroupException.AssertionError
+    }
+
+    return try createProfileKeyCredentialSelectivePresentationWithContext(randomness: randomness, groupSecretParams: groupSecretParams, profileKeyCredential: profileKeyCredential, context: context)
+  }
+
+  public func createProfileKeyCredentialSelectivePresentationWithContext(randomness: [UInt8], groupSecretParams: GroupSecretParams, profileKeyCredential: ProfileKeyCredential, context: [UInt8]) throws  -> ProfileKeyCredentialSelectivePresentation {
+    var newContents: [UInt8] = Array(repeating: 0, count: ProfileKeyCredentialSelectivePresentation.SIZE)
+
+    let ffi_return = FFI_ServerPublicParams_createProfileKeyCredentialSelectivePresentationWithContextDeterministic(serverPublicParams.getInternalContentsForFFI(), UInt32(serverPublicParams.getInternalContentsForFFI().count), randomness, UInt32(randomness.count), groupSecretParams.getInternalContentsForFFI(), UInt32(groupSecretParams.getInternalContentsForFFI().count), profileKeyCredential.getInternalContentsForFFI(), UInt32(profileKeyCredential.getInternalContentsForFFI().count), context, UInt32(context.count), &newContents, UInt32(newContents.count))
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw ZkGroupException.ZkGroupError
+    }
+
+    do {
+      return try ProfileKeyCredentialSelectivePresentation(contents: newContents)
+    } catch ZkGroupException.InvalidInput {
+      throw ZkGroupException.AssertionError
+    }
+
+  }
+
 }
@@ -0,0 +1,45 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+// Generated by zkgroup/codegen/codegen.py - do not edit
+
+import Foundation
+import libzkgroup
+
+public class GroupInviteLinkPassword : ByteArray {
+
+  public static let SIZE: Int = 16
+
+  public init(contents: [UInt8]) throws  {
+    try super.init(newContents: contents, expectedLength: GroupInviteLinkPassword.SIZE)
+
+  }
+
+  public func getCommitment(groupPublicParams: GroupPublicParams) throws  -> GroupInviteLinkPasswordCommitment {
+    var newContents: [UInt8] = Array(repeating: 0, count: GroupInviteLinkPasswordCommitment.SIZE)
+
+    let ffi_return = FFI_GroupInviteLinkPassword_getCommitment(self.contents, UInt32(self.contents.count), groupPublicParams.getInternalContentsForFFI(), UInt32(groupPublicParams.getInternalContentsForFFI().count), &newContents, UInt32(newContents.count))
+    if (ffi_return == Native.FFI_RETURN_INPUT_ERROR) {
+      throw ZkGroupException.VerificationFailed
+    }
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw ZkGroupException.ZkGroupError
+    }
+
+    do {
+      return try GroupInviteLinkPasswordCommitment(contents: newContents)
+    } catch ZkGroupException.InvalidInput {
+      throw ZkGroupException.AssertionError
+    }
+
+  }
+
+  public func serialize() -> [UInt8] {
+    return contents
+  }
+
+}
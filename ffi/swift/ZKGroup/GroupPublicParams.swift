@@ -45,6 +45,17 @@ public class GroupPublicParams : ByteArray {
 
   }
 
+  public func verifyUuidCiphertextMatch(uuidCiphertext: UuidCiphertext, randomizedUuidCiphertext: RandomizedUuidCiphertext, uuidCiphertextMatchProof: UuidCiphertextMatchProof) throws {
+    let ffi_return = FFI_GroupPublicParams_verifyUuidCiphertextMatch(self.contents, UInt32(self.contents.count), uuidCiphertext.getInternalContentsForFFI(), UInt32(uuidCiphertext.getInternalContentsForFFI().count), randomizedUuidCiphertext.getInternalContentsForFFI(), UInt32(randomizedUuidCiphertext.getInternalContentsForFFI().count), uuidCiphertextMatchProof.getInternalContentsForFFI(), UInt32(uuidCiphertextMatchProof.getInternalContentsForFFI().count))
+    if (ffi_return == Native.FFI_RETURN_INPUT_ERROR) {
+      throw ZkGroupException.VerificationFailed
+    }
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw ZkGroupException.ZkGroupError
+    }
+  }
+
   public func serialize() -> [UInt8] {
     return contents
   }
@@ -10,4 +10,9 @@ public class Native {
   static let FFI_RETURN_INTERNAL_ERROR = 1; // ZkGroupError
   static let FFI_RETURN_INPUT_ERROR    = 2;
   static let RANDOM_LENGTH = 32;
+
+  // The ZkGroupError code behind this thread's last failed call, or 0
+  public static func lastErrorCode() -> Int {
+    return Int(FFI_getLastErrorCode())
+  }
 }
@@ -0,0 +1,66 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+// Generated by zkgroup/codegen/codegen.py - do not edit
+
+import Foundation
+import libzkgroup
+
+public class RateLimitToken : ByteArray {
+
+  public static let SIZE: Int = 366
+
+  public init(contents: [UInt8]) throws  {
+    try super.init(newContents: contents, expectedLength: RateLimitToken.SIZE)
+
+    
+    let ffi_return = FFI_RateLimitToken_checkValidContents(self.contents, UInt32(self.contents.count))
+
+    if (ffi_return == Native.FFI_RETURN_INPUT_ERROR) {
+      throw ZkGroupException.InvalidInput
+    }
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw ZkGroupException.ZkGroupError
+    }
+  }
+
+  public func getTag() throws  -> RateLimitTag {
+    var newContents: [UInt8] = Array(repeating: 0, count: RateLimitTag.SIZE)
+
+    let ffi_return = FFI_RateLimitToken_getTag(self.contents, UInt32(self.contents.count), &newContents, UInt32(newContents.count))
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw ZkGroupException.ZkGroupError
+    }
+
+    do {
+      return try RateLimitTag(contents: newContents)
+    } catch ZkGroupException.InvalidInput {
+      throw ZkGroupException.AssertionError
+    }
+
+  }
+
+  public func getRedemptionTime() throws  -> UInt32 {
+    var newContents: [UInt8] = Array(repeating: 0, count: Int(4))
+
+    let ffi_return = FFI_RateLimitToken_getRedemptionTime(self.contents, UInt32(self.contents.count), &newContents, UInt32(newContents.count))
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw ZkGroupException.ZkGroupError
+     }
+
+    let data = Data(bytes: newContents)
+    let value = UInt32(bigEndian: data.withUnsafeBytes { $0.pointee })
+    return value
+  }
+
+  public func serialize() -> [UInt8] {
+    return contents
+  }
+
+}
@@ -49,4 +49,64 @@ public class ServerZkAuthOperations {
     }
   }
 
+  public func verifyAuthCredentialPresentationWithContext(groupPublicParams: GroupPublicParams, authCredentialPresentation: AuthCredentialPresentation, context: [UInt8]) throws {
+    let ffi_return = FFI_ServerSecretParams_verifyAuthCredentialPresentationWithContext(serverSecretParams.getInternalContentsForFFI(), UInt32(serverSecretParams.getInternalContentsForFFI().count), groupPublicParams.getInternalContentsForFFI(), UInt32(groupPublicParams.getInternalContentsForFFI().count), authCredentialPresentation.getInternalContentsForFFI(), UInt32(authCredentialPresentation.getInternalContentsForFFI().count), context, UInt32(context.count))
+    if (ffi_return == Native.FFI_RETURN_INPUT_ERROR) {
+      throw ZkGroupException.VerificationFailed
+    }
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw ZkGroupException.ZkGroupError
+    }
+  }
+
+  public func issueRateLimitCredential(rateLimitCredentialRequest: RateLimitCredentialRequest, redemptionTime: UInt32) throws  -> RateLimitCredentialResponse {
+    var randomness: [UInt8] = Array(repeating: 0, count: Int(32))
+    let result = SecRandomCopyBytes(kSecRandomDefault, randomness.count, &randomness)
+    guard result == errSecSuccess else {
+      throw ZkGroupException.AssertionError
+    }
+
+    return try issueRateLimitCredential(randomness: randomness, rateLimitCredentialRequest: rateLimitCredentialRequest, redemptionTime: redemptionTime)
+  }
+
+  public func issueRateLimitCredential(randomness: [UInt8], rateLimitCredentialRequest: RateLimitCredentialRequest, redemptionTime: UInt32) throws  -> RateLimitCredentialResponse {
+    var newContents: [UInt8] = Array(repeating: 0, count: RateLimitCredentialResponse.SIZE)
+
+    let ffi_return = FFI_ServerSecretParams_issueRateLimitCredentialDeterministic(serverSecretParams.getInternalContentsForFFI(), UInt32(serverSecretParams.getInternalContentsForFFI().count), randomness, UInt32(randomness.count), rateLimitCredentialRequest.getInternalContentsForFFI(), UInt32(rateLimitCredentialRequest.getInternalContentsForFFI().count), redemptionTime, &newContents, UInt32(newContents.count))
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw ZkGroupException.ZkGroupError
+    }
+
+    do {
+      return try RateLimitCredentialResponse(contents: newContents)
+    } catch ZkGroupException.InvalidInput {
+      throw ZkGroupException.AssertionError
+    }
+
+  }
+
+  public func verifyRateLimitToken(rateLimitToken: RateLimitToken) throws {
+    let ffi_return = FFI_ServerSecretParams_verifyRateLimitToken(serverSecretParams.getInternalContentsForFFI(), UInt32(serverSecretParams.getInternalContentsForFFI().count), rateLimitToken.getInternalContentsForFFI(), UInt32(rateLimitToken.getInternalContentsForFFI().count))
+    if (ffi_return == Native.FFI_RETURN_INPUT_ERROR) {
+      throw ZkGroupException.VerificationFailed
+    }
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw ZkGroupException.ZkGroupError
+    }
+  }
+
+  public func verifyGroupInviteLinkPresentation(groupPublicParams: GroupPublicParams, groupInviteLinkPasswordCommitment: GroupInviteLinkPasswordCommitment, groupInviteLinkPresentation: GroupInviteLinkPresentation) throws {
+    let ffi_return = FFI_ServerSecretParams_verifyGroupInviteLinkPresentation(serverSecretParams.getInternalContentsForFFI(), UInt32(serverSecretParams.getInternalContentsForFFI().count), groupPublicParams.getInternalContentsForFFI(), UInt32(groupPublicParams.getInternalContentsForFFI().count), groupInviteLinkPasswordCommitment.getInternalContentsForFFI(), UInt32(groupInviteLinkPasswordCommitment.getInternalContentsForFFI().count), groupInviteLinkPresentation.getInternalContentsForFFI(), UInt32(groupInviteLinkPresentation.getInternalContentsForFFI().count))
+    if (ffi_return == Native.FFI_RETURN_INPUT_ERROR) {
+      throw ZkGroupException.VerificationFailed
+    }
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw ZkGroupException.ZkGroupError
+    }
+  }
+
 }
@@ -52,4 +52,37 @@ public class ServerZkProfileOperations {
     }
   }
 
+  public func verifyProfileKeyCredentialPresentationWithContext(groupPublicParams: GroupPublicParams, profileKeyCredentialPresentation: ProfileKeyCredentialPresentation, context: [UInt8]) throws {
+    let ffi_return = FFI_ServerSecretParams_verifyProfileKeyCredentialPresentationWithContext(serverSecretParams.getInternalContentsForFFI(), UInt32(serverSecretParams.getInternalContentsForFFI().count), groupPublicParams.getInternalContentsForFFI(), UInt32(groupPublicParams.getInternalContentsForFFI().count), profileKeyCredentialPresentation.getInternalContentsForFFI(), UInt32(profileKeyCredentialPresentation.getInternalContentsForFFI().count), context, UInt32(context.count))
+    if (ffi_return == Native.FFI_RETURN_INPUT_ERROR) {
+      throw ZkGroupException.VerificationFailed
+    }
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw ZkGroupException.ZkGroupError
+    }
+  }
+
+  public func verifyProfileKeyCredentialSelectivePresentation(groupPublicParams: GroupPublicParams, profileKeyCredentialSelectivePresentation: ProfileKeyCredentialSelectivePresentation) throws {
+    let ffi_return = FFI_ServerSecretParams_verifyProfileKeyCredentialSelectivePresentation(serverSecretParams.getInternalContentsForFFI(), UInt32(serverSecretParams.getInternalContentsForFFI().count), groupPublicParams.getInternalContentsForFFI(), UInt32(groupPublicParams.getInternalContentsForFFI().count), profileKeyCredentialSelectivePresentation.getInternalContentsForFFI(), UInt32(profileKeyCredentialSelectivePresentation.getInternalContentsForFFI().count))
+    if (ffi_return == Native.FFI_RETURN_INPUT_ERROR) {
+      throw ZkGroupException.VerificationFailed
+    }
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw ZkGroupException.ZkGroupError
+    }
+  }
+
+  public func verifyProfileKeyCredentialSelectivePresentationWithContext(groupPublicParams: GroupPublicParams, profileKeyCredentialSelectivePresentation: ProfileKeyCredentialSelectivePresentation, context: [UInt8]) throws {
+    let ffi_return = FFI_ServerSecretParams_verifyProfileKeyCredentialSelectivePresentationWithContext(serverSecretParams.getInternalContentsForFFI(), UInt32(serverSecretParams.getInternalContentsForFFI().count), groupPublicParams.getInternalContentsForFFI(), UInt32(groupPublicParams.getInternalContentsForFFI().count), profileKeyCredentialSelectivePresentation.getInternalContentsForFFI(), UInt32(profileKeyCredentialSelectivePresentation.getInternalContentsForFFI().count), context, UInt32(context.count))
+    if (ffi_return == Native.FFI_RETURN_INPUT_ERROR) {
+      throw ZkGroupException.VerificationFailed
+    }
+
+    if (ffi_return != Native.FFI_RETURN_OK) {
+      throw ZkGroupException.ZkGroupError
+    }
+  }
+
 }
@@ -8,6 +8,7 @@ import XCTest
 class ZKGroupTests: XCTestCase {
 
   let TEST_ARRAY_16: [UInt8]   = [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f]
+  let TEST_ARRAY_16_1: [UInt8] = [0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0x6a, 0x6b, 0x6c, 0x6d, 0x6e, 0x6f, 0x70, 0x71, 0x72, 0x73]
 
   let TEST_ARRAY_32: [UInt8]   = [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
                                   0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f]
@@ -226,6 +227,11 @@ class ZKGroupTests: XCTestCase {
     let uuidCiphertextRecv = try presentation.getUuidCiphertext()
     XCTAssertEqual(uuidCiphertext.serialize(), uuidCiphertextRecv.serialize())
 
+    // Present again, revealing only the UUID
+    let selectivePresentation = try clientZkProfileCipher.createProfileKeyCredentialSelectivePresentation(randomness: TEST_ARRAY_32_5, groupSecretParams: groupSecretParams, profileKeyCredential: profileKeyCredential)
+    try serverZkProfile.verifyProfileKeyCredentialSelectivePresentation(groupPublicParams: groupPublicParams, profileKeyCredentialSelectivePresentation: selectivePresentation)
+    XCTAssertEqual(uuidCiphertext.serialize(), try selectivePresentation.getUuidCiphertext().serialize())
+
     let pkvB = try profileKey.getProfileKeyVersion(uuid: uuid)
     let pkvC = try ProfileKeyVersion(contents: pkvB.serialize())
     XCTAssertEqual(pkvB.serialize(), pkvC.serialize())
@@ -273,6 +279,179 @@ class ZKGroupTests: XCTestCase {
     XCTAssertEqual(plaintext, plaintext2)
   }
 
+  func testGroupInviteLink() throws {
+    let uuid: ZKGUuid = try ZKGUuid(contents: TEST_ARRAY_16)
+
+    let serverSecretParams = try ServerSecretParams.generate(randomness: TEST_ARRAY_32)
+    let serverZkAuth       = ServerZkAuthOperations(serverSecretParams: serverSecretParams)
+
+    let masterKey           = try GroupMasterKey(contents: TEST_ARRAY_32_1)
+    let groupSecretParams   = try GroupSecretParams.deriveFromMasterKey(groupMasterKey: masterKey)
+    let groupPublicParams   = try groupSecretParams.getPublicParams()
+    let clientZkGroupCipher = ClientZkGroupCipher(groupSecretParams: groupSecretParams)
+
+    let password   = try GroupInviteLinkPassword(contents: [UInt8](repeating: 0xa0, count: 16))
+    let commitment = try password.getCommitment(groupPublicParams: groupPublicParams)
+
+    let presentation = try clientZkGroupCipher.createGroupInviteLinkPresentation(randomness: TEST_ARRAY_32_2, groupInviteLinkPassword: password, uuid: uuid)
+    XCTAssertEqual(try clientZkGroupCipher.encryptUuid(uuid: uuid).serialize(), try presentation.getUuidCiphertext().serialize())
+
+    try serverZkAuth.verifyGroupInviteLinkPresentation(groupPublicParams: groupPublicParams, groupInviteLinkPasswordCommitment: commitment, groupInviteLinkPresentation: presentation)
+
+    let otherPassword = try GroupInviteLinkPassword(contents: [UInt8](repeating: 0xb0, count: 16))
+    do {
+      try serverZkAuth.verifyGroupInviteLinkPresentation(groupPublicParams: groupPublicParams, groupInviteLinkPasswordCommitment: try otherPassword.getCommitment(groupPublicParams: groupPublicParams), groupInviteLinkPresentation: presentation)
+      XCTFail("Verified with the wrong password")
+    } catch ZkGroupException.VerificationFailed {
+      // good
+    }
+  }
+
+  func testUuidCiphertextMatch() throws {
+    let uuid: ZKGUuid      = try ZKGUuid(contents: TEST_ARRAY_16)
+    let otherUuid: ZKGUuid = try ZKGUuid(contents: TEST_ARRAY_16_1)
+
+    let masterKey           = try GroupMasterKey(contents: TEST_ARRAY_32_1)
+    let groupSecretParams   = try GroupSecretParams.deriveFromMasterKey(groupMasterKey: masterKey)
+    let groupPublicParams   = try groupSecretParams.getPublicParams()
+    let clientZkGroupCipher = ClientZkGroupCipher(groupSecretParams: groupSecretParams)
+
+    let entry      = try clientZkGroupCipher.encryptUuidRandomized(randomness: TEST_ARRAY_32_2, uuid: uuid)
+    let otherEntry = try clientZkGroupCipher.encryptUuidRandomized(randomness: TEST_ARRAY_32_3, uuid: otherUuid)
+    XCTAssertEqual(uuid.serialize(), try clientZkGroupCipher.decryptRandomizedUuid(randomizedUuidCiphertext: entry).serialize())
+
+    let uuidCiphertext = try clientZkGroupCipher.encryptUuid(uuid: uuid)
+    let proof          = try clientZkGroupCipher.createUuidCiphertextMatchProof(randomness: TEST_ARRAY_32_4, uuidCiphertext: uuidCiphertext, randomizedUuidCiphertext: entry)
+
+    try groupPublicParams.verifyUuidCiphertextMatch(uuidCiphertext: uuidCiphertext, randomizedUuidCiphertext: entry, uuidCiphertextMatchProof: proof)
+
+    do {
+      try groupPublicParams.verifyUuidCiphertextMatch(uuidCiphertext: uuidCiphertext, randomizedUuidCiphertext: otherEntry, uuidCiphertextMatchProof: proof)
+      XCTFail("Verified against the wrong entry")
+    } catch ZkGroupException.VerificationFailed {
+      // good
+    }
+  }
+
+  func testPresentationContext() throws {
+    let uuid: ZKGUuid          = try ZKGUuid(contents: TEST_ARRAY_16)
+    let redemptionTime: UInt32 = 123456
+
+    let serverSecretParams = try ServerSecretParams.generate(randomness: TEST_ARRAY_32)
+    let serverPublicParams = try serverSecretParams.getPublicParams()
+    let serverZkAuth       = ServerZkAuthOperations(serverSecretParams: serverSecretParams)
+
+    let masterKey         = try GroupMasterKey(contents: TEST_ARRAY_32_1)
+    let groupSecretParams = try GroupSecretParams.deriveFromMasterKey(groupMasterKey: masterKey)
+    let groupPublicParams = try groupSecretParams.getPublicParams()
+
+    let authCredentialResponse = try serverZkAuth.issueAuthCredential(randomness: TEST_ARRAY_32_2, uuid: uuid, redemptionTime: redemptionTime)
+    let clientZkAuthCipher     = ClientZkAuthOperations(serverPublicParams: serverPublicParams)
+    let authCredential         = try clientZkAuthCipher.receiveAuthCredential(uuid: uuid, redemptionTime: redemptionTime, authCredentialResponse: authCredentialResponse)
+
+    let presentation = try clientZkAuthCipher.createAuthCredentialPresentationWithContext(randomness: TEST_ARRAY_32_5, groupSecretParams: groupSecretParams, authCredential: authCredential, context: TEST_ARRAY_16)
+
+    try serverZkAuth.verifyAuthCredentialPresentationWithContext(groupPublicParams: groupPublicParams, authCredentialPresentation: presentation, context: TEST_ARRAY_16)
+
+    do {
+      try serverZkAuth.verifyAuthCredentialPresentationWithContext(groupPublicParams: groupPublicParams, authCredentialPresentation: presentation, context: TEST_ARRAY_16_1)
+      XCTFail("Verified with the wrong context")
+    } catch ZkGroupException.VerificationFailed {
+      // good
+    }
+  }
+
+  func testRateLimitToken() throws {
+    let redemptionTime: UInt32 = 123456
+
+    let serverSecretParams = try ServerSecretParams.generate(randomness: TEST_ARRAY_32)
+    let serverPublicParams = try serverSecretParams.getPublicParams()
+    let serverZkAuth       = ServerZkAuthOperations(serverSecretParams: serverSecretParams)
+    let clientZkAuthCipher = ClientZkAuthOperations(serverPublicParams: serverPublicParams)
+
+    let context             = try clientZkAuthCipher.createRateLimitCredentialRequestContext(randomness: TEST_ARRAY_32_1)
+    let response            = try serverZkAuth.issueRateLimitCredential(randomness: TEST_ARRAY_32_2, rateLimitCredentialRequest: try context.getRequest(), redemptionTime: redemptionTime)
+    let rateLimitCredential = try clientZkAuthCipher.receiveRateLimitCredential(rateLimitCredentialRequestContext: context, rateLimitCredentialResponse: response)
+
+    let token      = try clientZkAuthCipher.createRateLimitToken(randomness: TEST_ARRAY_32_3, rateLimitCredential: rateLimitCredential)
+    let tokenAgain = try clientZkAuthCipher.createRateLimitToken(randomness: TEST_ARRAY_32_4, rateLimitCredential: rateLimitCredential)
+
+    try serverZkAuth.verifyRateLimitToken(rateLimitToken: token)
+    try serverZkAuth.verifyRateLimitToken(rateLimitToken: tokenAgain)
+
+    XCTAssertEqual(try token.getRedemptionTime(), redemptionTime)
+    XCTAssertEqual(try token.getTag().serialize(), try tokenAgain.getTag().serialize())
+
+    // A second credential for the same day has its own secret, and so its own tag
+    let otherContext    = try clientZkAuthCipher.createRateLimitCredentialRequestContext(randomness: TEST_ARRAY_32_5)
+    let otherResponse   = try serverZkAuth.issueRateLimitCredential(randomness: TEST_ARRAY_32_2, rateLimitCredentialRequest: try otherContext.getRequest(), redemptionTime: redemptionTime)
+    let otherCredential = try clientZkAuthCipher.receiveRateLimitCredential(rateLimitCredentialRequestContext: otherContext, rateLimitCredentialResponse: otherResponse)
+    let otherToken      = try clientZkAuthCipher.createRateLimitToken(randomness: TEST_ARRAY_32_3, rateLimitCredential: otherCredential)
+
+    try serverZkAuth.verifyRateLimitToken(rateLimitToken: otherToken)
+    XCTAssertNotEqual(try token.getTag().serialize(), try otherToken.getTag().serialize())
+  }
+
+  func testBlobEncryptionWithPadding() throws {
+    let groupSecretParams = try GroupSecretParams.generate()
+    let clientZkGroupCipher = ClientZkGroupCipher(groupSecretParams: groupSecretParams)
+
+    let plaintext: [UInt8] = [0,1,2,3,4]
+    let ciphertext = try clientZkGroupCipher.encryptBlobWithPadding(plaintext: plaintext, paddedLength: 64)
+    XCTAssertEqual(ciphertext.count, 64 + 29)
+
+    let plaintext2 = try clientZkGroupCipher.decryptPaddedBlob(blobCiphertext: ciphertext)
+    XCTAssertEqual(plaintext, plaintext2)
+
+    // The unpadded plaintext no longer fills the output decryptBlob sizes
+    do {
+      _ = try clientZkGroupCipher.decryptBlob(blobCiphertext: ciphertext)
+      XCTFail("Padded blob decrypted without removing the padding")
+    } catch ZkGroupException.VerificationFailed {
+      // good
+    }
+  }
+
+  func testLastErrorCode() throws {
+    let groupSecretParams = try GroupSecretParams.generate()
+    let clientZkGroupCipher = ClientZkGroupCipher(groupSecretParams: groupSecretParams)
+
+    let plaintext: [UInt8] = [0,1,2,3,4]
+    var ciphertext = try clientZkGroupCipher.encryptBlob(plaintext: plaintext)
+    ciphertext[0] ^= 1
+
+    do {
+      _ = try clientZkGroupCipher.decryptBlob(blobCiphertext: ciphertext)
+      XCTFail("Decrypted a tampered blob")
+    } catch ZkGroupException.VerificationFailed {
+      // DecryptionFailure
+      XCTAssertEqual(Native.lastErrorCode(), 101)
+    }
+
+    ciphertext[0] ^= 1
+    _ = try clientZkGroupCipher.decryptBlob(blobCiphertext: ciphertext)
+    XCTAssertEqual(Native.lastErrorCode(), 0)
+  }
+
+  func testBlobEncryptionWithAd() throws {
+    let groupSecretParams = try GroupSecretParams.generate()
+    let clientZkGroupCipher = ClientZkGroupCipher(groupSecretParams: groupSecretParams)
+
+    let plaintext: [UInt8] = [0,1,2,3,4]
+    let title = Array("title:1".utf8)
+    let ciphertext = try clientZkGroupCipher.encryptBlobWithAd(plaintext: plaintext, associatedData: title)
+    let plaintext2 = try clientZkGroupCipher.decryptBlobWithAd(blobCiphertext: ciphertext, associatedData: title)
+
+    XCTAssertEqual(plaintext, plaintext2)
+
+    do {
+      _ = try clientZkGroupCipher.decryptBlobWithAd(blobCiphertext: ciphertext, associatedData: Array("description:1".utf8))
+      XCTFail("Decrypted with the wrong associated data")
+    } catch ZkGroupException.VerificationFailed {
+      // good
+    }
+  }
+
   func testBlobEncryptionWithRandom() throws {
     let masterKey           = try GroupMasterKey(contents: TEST_ARRAY_32_1)
     let groupSecretParams   = try GroupSecretParams.deriveFromMasterKey(groupMasterKey: masterKey)
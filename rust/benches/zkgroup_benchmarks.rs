@@ -9,7 +9,8 @@ use curve25519_dalek::ristretto::RistrettoPoint;
 use sha2::Sha256;
 
 fn benchmark_integration_auth(c: &mut Criterion) {
-    let server_secret_params = zkgroup::ServerSecretParams::generate(zkgroup::TEST_ARRAY_32);
+    let server_secret_params =
+        zkgroup::ServerSecretParams::generate(zkgroup::TEST_ARRAY_32).unwrap();
     let server_public_params = server_secret_params.get_public_params();
 
     let master_key = zkgroup::groups::GroupMasterKey::new(zkgroup::TEST_ARRAY_32_1);
@@ -24,11 +25,16 @@ fn benchmark_integration_auth(c: &mut Criterion) {
     // SERVER
     // Issue credential
     let randomness = zkgroup::TEST_ARRAY_32_2;
-    let auth_credential_response =
-        server_secret_params.issue_auth_credential(randomness, uid, redemption_time);
+    let auth_credential_response = server_secret_params
+        .issue_auth_credential(randomness, uid, redemption_time)
+        .unwrap();
 
     c.bench_function("issue_auth_credential", |b| {
-        b.iter(|| server_secret_params.issue_auth_credential(randomness, uid, redemption_time))
+        b.iter(|| {
+            server_secret_params
+                .issue_auth_credential(randomness, uid, redemption_time)
+                .unwrap()
+        })
     });
 
     // CLIENT
@@ -44,6 +50,28 @@ fn benchmark_integration_auth(c: &mut Criterion) {
         })
     });
 
+    // A week of credentials in one response
+    let redemption_times: Vec<u32> = (redemption_time..redemption_time + 7).collect();
+    let auth_credential_batch_response = server_secret_params
+        .issue_auth_credentials(randomness, uid, &redemption_times)
+        .unwrap();
+
+    c.bench_function("issue_auth_credentials x7", |b| {
+        b.iter(|| {
+            server_secret_params
+                .issue_auth_credentials(randomness, uid, &redemption_times)
+                .unwrap()
+        })
+    });
+
+    c.bench_function("receive_auth_credentials x7", |b| {
+        b.iter(|| {
+            server_public_params
+                .receive_auth_credentials(uid, &redemption_times, &auth_credential_batch_response)
+                .unwrap()
+        })
+    });
+
     // Create and decrypt user entry
     let uuid_ciphertext = group_secret_params.encrypt_uuid(uid);
     let plaintext = group_secret_params.decrypt_uuid(uuid_ciphertext).unwrap();
@@ -52,19 +80,19 @@ fn benchmark_integration_auth(c: &mut Criterion) {
     // Create and receive presentation
     let randomness = zkgroup::TEST_ARRAY_32_5;
 
-    let presentation = server_public_params.create_auth_credential_presentation(
-        randomness,
-        group_secret_params,
-        auth_credential,
-    );
+    let presentation = server_public_params
+        .create_auth_credential_presentation(randomness, &group_secret_params, auth_credential)
+        .unwrap();
 
     c.bench_function("create_auth_credential_presentation", |b| {
         b.iter(|| {
-            server_public_params.create_auth_credential_presentation(
-                randomness,
-                group_secret_params,
-                auth_credential,
-            )
+            server_public_params
+                .create_auth_credential_presentation(
+                    randomness,
+                    &group_secret_params,
+                    auth_credential,
+                )
+                .unwrap()
         })
     });
 
@@ -90,7 +118,8 @@ pub fn benchmark_integration_profile(c: &mut Criterion) {
     let _uid = zkgroup::TEST_ARRAY_16;
 
     // SERVER
-    let server_secret_params = zkgroup::ServerSecretParams::generate(zkgroup::TEST_ARRAY_32);
+    let server_secret_params =
+        zkgroup::ServerSecretParams::generate(zkgroup::TEST_ARRAY_32).unwrap();
     let server_public_params = server_secret_params.get_public_params();
 
     // CLIENT
@@ -107,19 +136,15 @@ pub fn benchmark_integration_profile(c: &mut Criterion) {
     // Create context and request
     let randomness = zkgroup::TEST_ARRAY_32_3;
 
-    let context = server_public_params.create_profile_key_credential_request_context(
-        randomness,
-        uid,
-        profile_key,
-    );
+    let context = server_public_params
+        .create_profile_key_credential_request_context(randomness, uid, &profile_key)
+        .unwrap();
 
     c.bench_function("create_profile_key_credential_request_context", |b| {
         b.iter(|| {
-            server_public_params.create_profile_key_credential_request_context(
-                randomness,
-                uid,
-                profile_key,
-            )
+            server_public_params
+                .create_profile_key_credential_request_context(randomness, uid, &profile_key)
+                .unwrap()
         })
     });
 
@@ -169,10 +194,10 @@ pub fn benchmark_integration_profile(c: &mut Criterion) {
 
     assert!(plaintext == uid);
 
-    let profile_key_ciphertext = group_secret_params.encrypt_profile_key(profile_key, uid);
+    let profile_key_ciphertext = group_secret_params.encrypt_profile_key(&profile_key, uid);
 
     c.bench_function("encrypt_profile_key", |b| {
-        b.iter(|| group_secret_params.encrypt_profile_key(profile_key, uid))
+        b.iter(|| group_secret_params.encrypt_profile_key(&profile_key, uid))
     });
 
     let decrypted_profile_key = group_secret_params
@@ -188,19 +213,23 @@ pub fn benchmark_integration_profile(c: &mut Criterion) {
     // Create presentation
     let randomness = zkgroup::TEST_ARRAY_32_5;
 
-    let presentation = server_public_params.create_profile_key_credential_presentation(
-        randomness,
-        group_secret_params,
-        profile_key_credential,
-    );
+    let presentation = server_public_params
+        .create_profile_key_credential_presentation(
+            randomness,
+            &group_secret_params,
+            profile_key_credential,
+        )
+        .unwrap();
 
     c.bench_function("create_profile_key_credential_presentation", |b| {
         b.iter(|| {
-            server_public_params.create_profile_key_credential_presentation(
-                randomness,
-                group_secret_params,
-                profile_key_credential,
-            )
+            server_public_params
+                .create_profile_key_credential_presentation(
+                    randomness,
+                    &group_secret_params,
+                    profile_key_credential,
+                )
+                .unwrap()
         })
     });
 
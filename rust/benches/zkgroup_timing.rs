@@ -0,0 +1,205 @@
+// dudect-style timing checks for operations on secret data.
+//
+// Each target runs on one fixed input (class 0) and on fresh random inputs
+// (class 1), interleaved in random order, and Welch's t-test compares the two
+// timing distributions. As in dudect, the test is repeated on the samples
+// below the 95th percentile to drop interrupts and other outliers, and
+// |t| > 4.5 on either is reported as a possible leak. Run with
+//
+//     cargo bench --bench zkgroup_timing
+//
+// A pass only means no leak showed up at this sample size; raise
+// ZKGROUP_TIMING_SAMPLES for a more sensitive run.
+
+use criterion::black_box;
+
+extern crate zkgroup;
+
+use sha2::{Digest, Sha256};
+use std::env;
+use std::process;
+use std::time::Instant;
+
+const T_THRESHOLD: f64 = 4.5;
+const DEFAULT_SAMPLES: usize = 20000;
+
+// Presentations are orders of magnitude slower than decryption, and each
+// random sample needs its own credential
+const PRESENTATION_SAMPLE_DIVISOR: usize = 20;
+
+// Running mean and variance (Welford)
+#[derive(Default)]
+struct Stats {
+    n: f64,
+    mean: f64,
+    m2: f64,
+}
+
+impl Stats {
+    fn push(&mut self, x: f64) {
+        self.n += 1.0;
+        let delta = x - self.mean;
+        self.mean += delta / self.n;
+        self.m2 += delta * (x - self.mean);
+    }
+
+    fn variance(&self) -> f64 {
+        if self.n < 2.0 {
+            0.0
+        } else {
+            self.m2 / (self.n - 1.0)
+        }
+    }
+}
+
+fn welch_t(a: &Stats, b: &Stats) -> f64 {
+    let denominator = (a.variance() / a.n + b.variance() / b.n).sqrt();
+    if denominator == 0.0 || denominator.is_nan() {
+        0.0
+    } else {
+        (a.mean - b.mean) / denominator
+    }
+}
+
+// A deterministic stream of input bytes, so runs are repeatable
+struct InputStream {
+    counter: u64,
+}
+
+impl InputStream {
+    fn next_32(&mut self) -> [u8; 32] {
+        self.counter += 1;
+        let mut bytes = [0u8; 32];
+        bytes.copy_from_slice(&Sha256::digest(&self.counter.to_le_bytes()));
+        bytes
+    }
+
+    fn next_16(&mut self) -> [u8; 16] {
+        let mut bytes = [0u8; 16];
+        bytes.copy_from_slice(&self.next_32()[..16]);
+        bytes
+    }
+
+    fn next_class(&mut self) -> usize {
+        (self.next_32()[0] & 1) as usize
+    }
+}
+
+// Times f on each (class, input) pair and reports the larger |t| of the
+// uncropped and cropped tests; returns whether it crossed the threshold
+fn measure<I, R, F: Fn(&I) -> R>(name: &str, inputs: &[(usize, I)], f: F) -> bool {
+    let mut times = Vec::with_capacity(inputs.len());
+    for (class, input) in inputs {
+        let start = Instant::now();
+        black_box(f(input));
+        times.push((*class, start.elapsed().as_nanos() as f64));
+    }
+
+    let mut sorted: Vec<f64> = times.iter().map(|(_, time)| *time).collect();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let cutoff = sorted[sorted.len() * 95 / 100];
+
+    let mut max_t = 0f64;
+    for &limit in &[std::f64::INFINITY, cutoff] {
+        let mut stats = [Stats::default(), Stats::default()];
+        for (class, time) in &times {
+            if *time <= limit {
+                stats[*class].push(*time);
+            }
+        }
+        max_t = max_t.max(welch_t(&stats[0], &stats[1]).abs());
+    }
+
+    let leaky = max_t > T_THRESHOLD;
+    println!(
+        "{:<56} n = {:>6}  max |t| = {:>7.2}  {}",
+        name,
+        inputs.len(),
+        max_t,
+        if leaky { "POSSIBLE LEAK" } else { "ok" }
+    );
+    leaky
+}
+
+fn main() {
+    let samples = env::var("ZKGROUP_TIMING_SAMPLES")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(DEFAULT_SAMPLES);
+    let mut stream = InputStream { counter: 0 };
+
+    let server_secret_params =
+        zkgroup::ServerSecretParams::generate(zkgroup::TEST_ARRAY_32).unwrap();
+    let server_public_params = server_secret_params.get_public_params();
+    let master_key = zkgroup::groups::GroupMasterKey::new(zkgroup::TEST_ARRAY_32_1);
+    let group_secret_params =
+        zkgroup::groups::GroupSecretParams::derive_from_master_key(master_key);
+    let redemption_time = 123456u32;
+
+    let mut leaky = false;
+
+    let fixed_uuid_ciphertext = group_secret_params.encrypt_uuid(zkgroup::TEST_ARRAY_16);
+    let inputs: Vec<_> = (0..samples)
+        .map(|_| match stream.next_class() {
+            0 => (0, fixed_uuid_ciphertext),
+            _ => (1, group_secret_params.encrypt_uuid(stream.next_16())),
+        })
+        .collect();
+    leaky |= measure("GroupSecretParams::decrypt_uuid", &inputs, |ciphertext| {
+        group_secret_params.decrypt_uuid(*ciphertext)
+    });
+
+    let uid = zkgroup::TEST_ARRAY_16;
+    let fixed_profile_key = zkgroup::profiles::ProfileKey::create(zkgroup::TEST_ARRAY_32_2);
+    let fixed_profile_key_ciphertext =
+        group_secret_params.encrypt_profile_key(&fixed_profile_key, uid);
+    let inputs: Vec<_> = (0..samples)
+        .map(|_| match stream.next_class() {
+            0 => (0, fixed_profile_key_ciphertext),
+            _ => {
+                let profile_key = zkgroup::profiles::ProfileKey::create(stream.next_32());
+                (
+                    1,
+                    group_secret_params.encrypt_profile_key(&profile_key, uid),
+                )
+            }
+        })
+        .collect();
+    leaky |= measure(
+        "GroupSecretParams::decrypt_profile_key",
+        &inputs,
+        |ciphertext| group_secret_params.decrypt_profile_key(*ciphertext, uid),
+    );
+
+    // The credential is the secret; the randomness is fresh for both classes
+    let issue = |uid| {
+        let response = server_secret_params
+            .issue_auth_credential(zkgroup::TEST_ARRAY_32_3, uid, redemption_time)
+            .unwrap();
+        server_public_params
+            .receive_auth_credential(uid, redemption_time, &response)
+            .unwrap()
+    };
+    let fixed_auth_credential = issue(zkgroup::TEST_ARRAY_16);
+    let inputs: Vec<_> = (0..samples / PRESENTATION_SAMPLE_DIVISOR)
+        .map(|_| match stream.next_class() {
+            0 => (0, (stream.next_32(), fixed_auth_credential)),
+            _ => (1, (stream.next_32(), issue(stream.next_16()))),
+        })
+        .collect();
+    leaky |= measure(
+        "ServerPublicParams::create_auth_credential_presentation",
+        &inputs,
+        |(randomness, auth_credential)| {
+            server_public_params.create_auth_credential_presentation(
+                *randomness,
+                &group_secret_params,
+                *auth_credential,
+            )
+        },
+    );
+
+    if leaky {
+        process::exit(1);
+    }
+}
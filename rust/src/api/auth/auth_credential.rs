@@ -8,15 +8,46 @@
 #![allow(non_snake_case)]
 
 use crate::api;
+use crate::common::errors::*;
+use crate::common::serialization::{deserialize_unversioned, ensure, Validate, Versioned};
 use crate::common::simple_types::*;
 use crate::crypto;
 use serde::{Deserialize, Serialize};
 
 #[derive(Copy, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde_human_readable", serde(remote = "Self"))]
 pub struct AuthCredential {
-    pub(crate) reserved: ReservedBytes,
+    pub(crate) version: VersionByte,
     pub(crate) credential: crypto::credentials::AuthCredential,
     pub(crate) server_public_params: api::ServerPublicParams,
     pub(crate) uid: crypto::uid_struct::UidStruct,
     pub(crate) redemption_time: RedemptionTime,
 }
+
+human_readable_serde!(AuthCredential);
+
+// The same version as the ServerPublicParams it embeds
+impl Versioned for AuthCredential {
+    const VERSION: VersionByte = 1;
+
+    fn is_supported_version(version: VersionByte) -> bool {
+        version <= Self::VERSION
+    }
+
+    // ServerPublicParams reads its version 0 too
+    fn deserialize_old_version(_version: VersionByte, bytes: &[u8]) -> Result<Self, ZkGroupError> {
+        deserialize_unversioned(bytes)
+    }
+}
+
+impl Validate for AuthCredential {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        ensure::<Self>(
+            self.version == self.server_public_params.get_version(),
+            "version differs from the server public params",
+        )?;
+        self.credential.validate()?;
+        self.server_public_params.validate()?;
+        self.uid.validate()
+    }
+}
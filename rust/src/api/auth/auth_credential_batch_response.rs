@@ -0,0 +1,46 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+
+#![allow(non_snake_case)]
+
+use crate::common::constants::*;
+use crate::common::errors::*;
+use crate::common::serialization::{ensure, Validate, Versioned};
+use crate::common::simple_types::*;
+use crate::crypto;
+use serde::{Deserialize, Serialize};
+
+// Credentials for one uid over several redemption times, in the order the
+// redemption times were requested, covered by a single issuance proof
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "serde_human_readable", serde(remote = "Self"))]
+pub struct AuthCredentialBatchResponse {
+    pub(crate) version: VersionByte,
+    pub(crate) key_id: ServerKeyId,
+    pub(crate) credentials: Vec<crypto::credentials::AuthCredential>,
+    pub(crate) proof: crypto::proofs::AuthCredentialBatchIssuanceProof,
+}
+
+human_readable_serde!(AuthCredentialBatchResponse);
+
+impl Versioned for AuthCredentialBatchResponse {
+    const VERSION: VersionByte = 0;
+}
+
+impl Validate for AuthCredentialBatchResponse {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        ensure::<Self>(
+            !self.credentials.is_empty()
+                && self.credentials.len() <= MAX_AUTH_CREDENTIAL_BATCH_SIZE,
+            "batch size is out of range",
+        )?;
+        for credential in &self.credentials {
+            credential.validate()?;
+        }
+        self.proof.validate()
+    }
+}
@@ -8,22 +8,47 @@
 #![allow(non_snake_case)]
 
 use crate::api;
+use crate::common::errors::*;
+use crate::common::serialization::{deserialize_unversioned, KeyIdVersion, Validate, Versioned};
 use crate::common::simple_types::*;
 use crate::crypto;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "serde_human_readable", serde(remote = "Self"))]
 pub struct AuthCredentialPresentation {
-    pub(crate) reserved: ReservedBytes,
+    pub(crate) version: KeyIdVersion,
     pub(crate) proof: crypto::proofs::AuthCredentialPresentationProof,
     pub(crate) ciphertext: crypto::uid_encryption::Ciphertext,
     pub(crate) redemption_time: RedemptionTime,
 }
 
+human_readable_serde!(AuthCredentialPresentation);
+
+impl Versioned for AuthCredentialPresentation {
+    const VERSION: VersionByte = KeyIdVersion::NEWEST;
+
+    fn is_supported_version(version: VersionByte) -> bool {
+        version <= Self::VERSION
+    }
+
+    // KeyIdVersion reads the baseline layout too
+    fn deserialize_old_version(_version: VersionByte, bytes: &[u8]) -> Result<Self, ZkGroupError> {
+        deserialize_unversioned(bytes)
+    }
+}
+
+impl Validate for AuthCredentialPresentation {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        self.proof.validate()?;
+        self.ciphertext.validate()
+    }
+}
+
 impl AuthCredentialPresentation {
     pub fn get_uuid_ciphertext(&self) -> api::groups::UuidCiphertext {
         api::groups::UuidCiphertext {
-            reserved: Default::default(),
+            version: api::groups::UuidCiphertext::VERSION,
             ciphertext: self.ciphertext,
         }
     }
@@ -31,4 +56,8 @@ impl AuthCredentialPresentation {
     pub fn get_redemption_time(&self) -> RedemptionTime {
         self.redemption_time
     }
+
+    pub fn get_key_id(&self) -> ServerKeyId {
+        self.version.key_id
+    }
 }
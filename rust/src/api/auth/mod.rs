@@ -6,9 +6,23 @@
 //
 
 pub mod auth_credential;
+pub mod auth_credential_batch_response;
 pub mod auth_credential_presentation;
 pub mod auth_credential_response;
+pub mod rate_limit_credential;
+pub mod rate_limit_credential_request;
+pub mod rate_limit_credential_request_context;
+pub mod rate_limit_credential_response;
+pub mod rate_limit_token;
+pub mod redemption_window;
 
 pub use auth_credential::AuthCredential;
+pub use auth_credential_batch_response::AuthCredentialBatchResponse;
 pub use auth_credential_presentation::AuthCredentialPresentation;
 pub use auth_credential_response::AuthCredentialResponse;
+pub use rate_limit_credential::RateLimitCredential;
+pub use rate_limit_credential_request::RateLimitCredentialRequest;
+pub use rate_limit_credential_request_context::RateLimitCredentialRequestContext;
+pub use rate_limit_credential_response::RateLimitCredentialResponse;
+pub use rate_limit_token::RateLimitToken;
+pub use redemption_window::RedemptionWindow;
@@ -0,0 +1,43 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+
+#![allow(non_snake_case)]
+
+use crate::common::errors::*;
+use crate::common::serialization::{Validate, Versioned};
+use crate::common::simple_types::*;
+use crate::crypto;
+use serde::{Deserialize, Serialize};
+
+// Not Copy: it holds the client's secret for its rate limit tags
+#[derive(Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde_human_readable", serde(remote = "Self"))]
+pub struct RateLimitCredential {
+    pub(crate) version: VersionByte,
+    pub(crate) credential: crypto::credentials::RateLimitCredential,
+    pub(crate) rate_limit: crypto::rate_limit_struct::RateLimitStruct,
+    pub(crate) redemption_time: RedemptionTime,
+}
+
+human_readable_serde!(RateLimitCredential);
+
+impl Versioned for RateLimitCredential {
+    const VERSION: VersionByte = 0;
+}
+
+impl Validate for RateLimitCredential {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        self.credential.validate()?;
+        self.rate_limit.validate()
+    }
+}
+
+impl RateLimitCredential {
+    pub fn get_redemption_time(&self) -> RedemptionTime {
+        self.redemption_time
+    }
+}
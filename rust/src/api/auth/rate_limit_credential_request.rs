@@ -0,0 +1,37 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+
+#![allow(non_snake_case)]
+
+use crate::common::errors::*;
+use crate::common::serialization::{Validate, Versioned};
+use crate::common::simple_types::*;
+use crate::crypto;
+use serde::{Deserialize, Serialize};
+
+// Carries no proof: a client that asks for a point it can't open only loses
+// its own tokens for the day
+#[derive(Copy, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde_human_readable", serde(remote = "Self"))]
+pub struct RateLimitCredentialRequest {
+    pub(crate) version: VersionByte,
+    pub(crate) public_key: crypto::rate_limit_credential_request::PublicKey,
+    pub(crate) ciphertext: crypto::rate_limit_credential_request::Ciphertext,
+}
+
+human_readable_serde!(RateLimitCredentialRequest);
+
+impl Versioned for RateLimitCredentialRequest {
+    const VERSION: VersionByte = 0;
+}
+
+impl Validate for RateLimitCredentialRequest {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        self.public_key.validate()?;
+        self.ciphertext.validate()
+    }
+}
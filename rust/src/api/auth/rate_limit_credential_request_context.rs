@@ -0,0 +1,51 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+
+#![allow(non_snake_case)]
+
+use crate::api;
+use crate::common::errors::*;
+use crate::common::serialization::{Validate, Versioned};
+use crate::common::simple_types::*;
+use crate::crypto;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "serde_human_readable", serde(remote = "Self"))]
+pub struct RateLimitCredentialRequestContext {
+    pub(crate) version: VersionByte,
+    pub(crate) rate_limit: crypto::rate_limit_struct::RateLimitStruct,
+    pub(crate) key_pair: crypto::rate_limit_credential_request::KeyPair,
+    pub(crate) ciphertext_with_secret_nonce:
+        crypto::rate_limit_credential_request::CiphertextWithSecretNonce,
+}
+
+human_readable_serde!(RateLimitCredentialRequestContext);
+
+impl Versioned for RateLimitCredentialRequestContext {
+    const VERSION: VersionByte = 0;
+}
+
+impl Validate for RateLimitCredentialRequestContext {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        self.rate_limit.validate()?;
+        self.key_pair.validate()?;
+        self.ciphertext_with_secret_nonce.validate()
+    }
+}
+
+impl RateLimitCredentialRequestContext {
+    pub fn get_request(&self) -> api::auth::RateLimitCredentialRequest {
+        let ciphertext = self.ciphertext_with_secret_nonce.get_ciphertext();
+        let public_key = self.key_pair.get_public_key();
+        api::auth::RateLimitCredentialRequest {
+            version: api::auth::RateLimitCredentialRequest::VERSION,
+            public_key,
+            ciphertext,
+        }
+    }
+}
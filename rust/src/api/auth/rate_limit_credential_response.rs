@@ -0,0 +1,37 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+
+#![allow(non_snake_case)]
+
+use crate::common::errors::*;
+use crate::common::serialization::{Validate, Versioned};
+use crate::common::simple_types::*;
+use crate::crypto;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "serde_human_readable", serde(remote = "Self"))]
+pub struct RateLimitCredentialResponse {
+    pub(crate) version: VersionByte,
+    pub(crate) key_id: ServerKeyId,
+    pub(crate) redemption_time: RedemptionTime,
+    pub(crate) blinded_credential: crypto::credentials::BlindedRateLimitCredential,
+    pub(crate) proof: crypto::proofs::RateLimitCredentialIssuanceProof,
+}
+
+human_readable_serde!(RateLimitCredentialResponse);
+
+impl Versioned for RateLimitCredentialResponse {
+    const VERSION: VersionByte = 0;
+}
+
+impl Validate for RateLimitCredentialResponse {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        self.blinded_credential.validate()?;
+        self.proof.validate()
+    }
+}
@@ -0,0 +1,58 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+
+#![allow(non_snake_case)]
+
+use crate::common::errors::*;
+use crate::common::serialization::{ensure_not_identity, Validate, Versioned};
+use crate::common::simple_types::*;
+use crate::crypto;
+use curve25519_dalek::ristretto::RistrettoPoint;
+use serde::{Deserialize, Serialize};
+
+// Presents a rate limit credential, carrying a tag that is the same for every
+// token made from it, so the server can count operations per credential
+// without knowing who made them.  The tag is derived from the client's secret,
+// not its UID, so even a server that knows the UID can't compute it; counting
+// per user relies on the issuer giving each user one credential per day.
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "serde_human_readable", serde(remote = "Self"))]
+pub struct RateLimitToken {
+    pub(crate) version: VersionByte,
+    pub(crate) key_id: ServerKeyId,
+    pub(crate) proof: crypto::proofs::RateLimitTokenProof,
+    pub(crate) tag: RistrettoPoint,
+    pub(crate) redemption_time: RedemptionTime,
+}
+
+human_readable_serde!(RateLimitToken);
+
+impl Versioned for RateLimitToken {
+    const VERSION: VersionByte = 0;
+}
+
+impl Validate for RateLimitToken {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        self.proof.validate()?;
+        ensure_not_identity::<Self>(&self.tag, "tag is the identity")
+    }
+}
+
+impl RateLimitToken {
+    // Only meaningful once the token has verified
+    pub fn get_tag(&self) -> RateLimitTagBytes {
+        self.tag.compress().to_bytes()
+    }
+
+    pub fn get_redemption_time(&self) -> RedemptionTime {
+        self.redemption_time
+    }
+
+    pub fn get_key_id(&self) -> ServerKeyId {
+        self.key_id
+    }
+}
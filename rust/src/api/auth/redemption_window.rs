@@ -0,0 +1,57 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+
+#![allow(non_snake_case)]
+
+use crate::common::constants::*;
+use crate::common::errors::*;
+use crate::common::simple_types::*;
+
+// Range of days around a redemption time (in days since the epoch) during
+// which an auth credential presentation is accepted
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RedemptionWindow {
+    pub(crate) days_before: u32,
+    pub(crate) days_after: u32,
+}
+
+impl RedemptionWindow {
+    pub fn new(days_before: u32, days_after: u32) -> Self {
+        Self {
+            days_before,
+            days_after,
+        }
+    }
+
+    // Might return InvalidRedemptionTime
+    pub fn check(
+        &self,
+        redemption_time: RedemptionTime,
+        current_time_in_seconds: u64,
+    ) -> Result<(), ZkGroupError> {
+        let redemption_day = u64::from(redemption_time);
+        let acceptable_start_time =
+            redemption_day.saturating_sub(u64::from(self.days_before)) * SECONDS_PER_DAY;
+        let acceptable_end_time = (redemption_day + u64::from(self.days_after)) * SECONDS_PER_DAY;
+
+        if current_time_in_seconds < acceptable_start_time
+            || current_time_in_seconds > acceptable_end_time
+        {
+            return Err(ZkGroupError::InvalidRedemptionTime);
+        }
+        Ok(())
+    }
+}
+
+impl Default for RedemptionWindow {
+    fn default() -> Self {
+        Self::new(
+            DEFAULT_REDEMPTION_DAYS_BEFORE,
+            DEFAULT_REDEMPTION_DAYS_AFTER,
+        )
+    }
+}
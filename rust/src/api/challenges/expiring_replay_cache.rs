@@ -0,0 +1,114 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+
+use crate::api::challenges::ReplayCache;
+use crate::common::errors::*;
+use crate::common::simple_types::*;
+use std::collections::{BTreeSet, HashSet};
+
+// In-memory ReplayCache holding at most `capacity` nonces.  A nonce is kept
+// until its challenge expires, and only then dropped; once every slot holds a
+// live nonce, new ones fail with ReplayCacheFull rather than pushing out one
+// that could then be redeemed again.  The capacity should cover every
+// challenge issued within one expiration period.
+pub struct ExpiringReplayCache {
+    capacity: usize,
+    nonces: HashSet<ChallengeNonceBytes>,
+    // The same nonces, soonest to expire first
+    by_expiration: BTreeSet<(ChallengeExpirationTime, ChallengeNonceBytes)>,
+}
+
+impl ExpiringReplayCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            nonces: HashSet::with_capacity(capacity),
+            by_expiration: BTreeSet::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.nonces.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nonces.is_empty()
+    }
+
+    // A challenge is still accepted at its expiration time, so only nonces
+    // whose expiration time has passed are dropped
+    fn remove_expired(&mut self, current_time_in_seconds: u64) {
+        while let Some(&(expiration_time, nonce)) = self.by_expiration.iter().next() {
+            if expiration_time >= current_time_in_seconds {
+                break;
+            }
+            self.by_expiration.remove(&(expiration_time, nonce));
+            self.nonces.remove(&nonce);
+        }
+    }
+}
+
+impl ReplayCache for ExpiringReplayCache {
+    fn insert(
+        &mut self,
+        nonce: ChallengeNonceBytes,
+        expiration_time: ChallengeExpirationTime,
+        current_time_in_seconds: u64,
+    ) -> Result<(), ZkGroupError> {
+        self.remove_expired(current_time_in_seconds);
+        if self.nonces.contains(&nonce) {
+            return Err(ZkGroupError::ChallengeReplayed);
+        }
+        if self.nonces.len() >= self.capacity {
+            return Err(ZkGroupError::ReplayCacheFull);
+        }
+        self.nonces.insert(nonce);
+        self.by_expiration.insert((expiration_time, nonce));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expiring_replay_cache() {
+        let mut cache = ExpiringReplayCache::new(2);
+        assert!(cache.is_empty());
+
+        assert!(cache.insert([1u8; 32], 100, 0).is_ok());
+        assert!(cache.insert([2u8; 32], 200, 0).is_ok());
+        match cache.insert([1u8; 32], 100, 0) {
+            Err(ZkGroupError::ChallengeReplayed) => (),
+            _ => panic!(),
+        }
+        assert!(cache.len() == 2);
+
+        // Full of live nonces, so a new one is refused and none is evicted
+        match cache.insert([3u8; 32], 300, 100) {
+            Err(ZkGroupError::ReplayCacheFull) => (),
+            _ => panic!(),
+        }
+        assert!(cache.len() == 2);
+        match cache.insert([1u8; 32], 100, 100) {
+            Err(ZkGroupError::ChallengeReplayed) => (),
+            _ => panic!(),
+        }
+
+        // Once [1]'s challenge has expired, its slot is reused
+        assert!(cache.insert([3u8; 32], 300, 101).is_ok());
+        assert!(cache.len() == 2);
+        match cache.insert([2u8; 32], 200, 101) {
+            Err(ZkGroupError::ChallengeReplayed) => (),
+            _ => panic!(),
+        }
+
+        assert!(cache.insert([4u8; 32], 400, 301).is_ok());
+        assert!(cache.len() == 1);
+    }
+}
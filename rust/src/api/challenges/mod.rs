@@ -0,0 +1,14 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+
+pub mod expiring_replay_cache;
+pub mod presentation_challenge;
+pub mod replay_cache;
+
+pub use expiring_replay_cache::ExpiringReplayCache;
+pub use presentation_challenge::PresentationChallenge;
+pub use replay_cache::ReplayCache;
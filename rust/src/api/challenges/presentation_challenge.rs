@@ -0,0 +1,92 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+
+use crate::common::constants::*;
+use crate::common::errors::*;
+use crate::common::serialization::{Validate, Versioned};
+use crate::common::simple_types::*;
+use serde::{Deserialize, Serialize};
+
+// A signed nonce the server hands out before asking for a presentation.  The
+// client passes get_context() as the presentation's context, and the server
+// accepts each challenge for one presentation, until its expiration time.
+#[derive(Copy, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde_human_readable", serde(remote = "Self"))]
+pub struct PresentationChallenge {
+    pub(crate) version: VersionByte,
+    pub(crate) key_id: ServerKeyId,
+    pub(crate) nonce: ChallengeNonceBytes,
+    pub(crate) expiration_time: ChallengeExpirationTime,
+    // serde only derives arrays of up to 32 elements, so the signature is
+    // stored in halves; bincode encodes this the same as [u8; 64]
+    pub(crate) signature: [[u8; SIGNATURE_LEN / 2]; 2],
+}
+
+human_readable_serde!(PresentationChallenge);
+
+impl Versioned for PresentationChallenge {
+    const VERSION: VersionByte = 0;
+}
+
+impl Validate for PresentationChallenge {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        Ok(())
+    }
+}
+
+impl PresentationChallenge {
+    pub(crate) fn new(
+        key_id: ServerKeyId,
+        nonce: ChallengeNonceBytes,
+        expiration_time: ChallengeExpirationTime,
+    ) -> Self {
+        Self {
+            version: Self::VERSION,
+            key_id,
+            nonce,
+            expiration_time,
+            signature: [[0u8; SIGNATURE_LEN / 2]; 2],
+        }
+    }
+
+    // The bytes the server signs, which are also the presentation context.
+    // The server signs them with a key of its own for challenges (see
+    // ServerSecretParams::issue_presentation_challenge), not the one behind
+    // ServerSecretParams::sign; the label only marks them as a challenge.
+    pub fn get_context(&self) -> Vec<u8> {
+        let mut context = b"Signal_ZKGroup_20200424_PresentationChallenge".to_vec();
+        context.push(self.version);
+        context.push(self.key_id);
+        context.extend_from_slice(&self.nonce);
+        context.extend_from_slice(&self.expiration_time.to_be_bytes());
+        context
+    }
+
+    pub(crate) fn get_signature(&self) -> NotarySignatureBytes {
+        let mut signature = [0u8; SIGNATURE_LEN];
+        signature[..SIGNATURE_LEN / 2].copy_from_slice(&self.signature[0]);
+        signature[SIGNATURE_LEN / 2..].copy_from_slice(&self.signature[1]);
+        signature
+    }
+
+    pub(crate) fn set_signature(&mut self, signature: NotarySignatureBytes) {
+        self.signature[0].copy_from_slice(&signature[..SIGNATURE_LEN / 2]);
+        self.signature[1].copy_from_slice(&signature[SIGNATURE_LEN / 2..]);
+    }
+
+    pub fn get_nonce(&self) -> ChallengeNonceBytes {
+        self.nonce
+    }
+
+    pub fn get_expiration_time(&self) -> ChallengeExpirationTime {
+        self.expiration_time
+    }
+
+    pub fn get_key_id(&self) -> ServerKeyId {
+        self.key_id
+    }
+}
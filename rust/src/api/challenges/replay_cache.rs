@@ -0,0 +1,26 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+
+use crate::common::errors::*;
+use crate::common::simple_types::*;
+
+// Remembers the nonces of redeemed presentation challenges.  An expired
+// challenge is rejected before the cache is consulted, so an implementation
+// only has to keep each nonce until its expiration time; one shared by several
+// servers (e.g. backed by a database) makes a challenge single-use across all
+// of them.
+pub trait ReplayCache {
+    // Records the nonce, failing with ChallengeReplayed if it was already
+    // recorded.  A cache that can't record it must fail too (e.g. with
+    // ReplayCacheFull), since an unrecorded nonce could be redeemed again.
+    fn insert(
+        &mut self,
+        nonce: ChallengeNonceBytes,
+        expiration_time: ChallengeExpirationTime,
+        current_time_in_seconds: u64,
+    ) -> Result<(), ZkGroupError>;
+}
@@ -0,0 +1,155 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+
+#![allow(non_snake_case)]
+
+use crate::common::constants::*;
+use crate::common::errors::*;
+
+// Padding is a single 0x80 byte followed by zero bytes, so it can be removed
+// unambiguously whatever the plaintext ends with
+const PADDING_MARKER: u8 = 0x80;
+
+// How far to round up a blob's plaintext (plus one byte of padding marker)
+// before encryption
+#[derive(Clone, Debug, PartialEq)]
+pub enum BlobPaddingPolicy {
+    // The next power of two, but at least min_len
+    PowerOfTwo { min_len: usize },
+    // The smallest bucket that fits; past the largest bucket, the next
+    // multiple of the largest bucket.  Buckets must be nonzero and ascending.
+    Buckets(Vec<usize>),
+}
+
+impl BlobPaddingPolicy {
+    // Might return BadArgs for an invalid policy or a length that can't be
+    // rounded up without overflowing
+    pub fn get_padded_len(&self, len: usize) -> Result<usize, ZkGroupError> {
+        match self {
+            BlobPaddingPolicy::PowerOfTwo { min_len } => match len.checked_next_power_of_two() {
+                Some(padded_len) => Ok(std::cmp::max(padded_len, *min_len)),
+                None => Err(ZkGroupError::BadArgs),
+            },
+            BlobPaddingPolicy::Buckets(buckets) => {
+                if buckets.is_empty()
+                    || buckets[0] == 0
+                    || buckets.windows(2).any(|pair| pair[0] >= pair[1])
+                {
+                    return Err(ZkGroupError::BadArgs);
+                }
+                if let Some(bucket) = buckets.iter().find(|bucket| **bucket >= len) {
+                    return Ok(*bucket);
+                }
+                let largest = buckets[buckets.len() - 1];
+                let num_buckets = (len - 1) / largest + 1;
+                match num_buckets.checked_mul(largest) {
+                    Some(padded_len) => Ok(padded_len),
+                    None => Err(ZkGroupError::BadArgs),
+                }
+            }
+        }
+    }
+}
+
+// Might return BadArgs if the padded length is over MAX_PADDED_BLOB_LEN
+pub(crate) fn pad(plaintext: &[u8], policy: &BlobPaddingPolicy) -> Result<Vec<u8>, ZkGroupError> {
+    let unpadded_len = match plaintext.len().checked_add(1) {
+        Some(len) => len,
+        None => return Err(ZkGroupError::BadArgs),
+    };
+    let padded_len = policy.get_padded_len(unpadded_len)?;
+    if padded_len > MAX_PADDED_BLOB_LEN {
+        return Err(ZkGroupError::BadArgs);
+    }
+
+    let mut padded = Vec::with_capacity(padded_len);
+    padded.extend_from_slice(plaintext);
+    padded.push(PADDING_MARKER);
+    padded.resize(padded_len, 0);
+    Ok(padded)
+}
+
+pub(crate) fn unpad(mut padded: Vec<u8>) -> Result<Vec<u8>, ZkGroupError> {
+    match padded.iter().rposition(|b| *b != 0) {
+        Some(marker_index) if padded[marker_index] == PADDING_MARKER => {
+            padded.truncate(marker_index);
+            Ok(padded)
+        }
+        _ => Err(ZkGroupError::DecryptionFailure),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_padded_len() {
+        let policy = BlobPaddingPolicy::PowerOfTwo { min_len: 16 };
+        assert!(policy.get_padded_len(1).unwrap() == 16);
+        assert!(policy.get_padded_len(16).unwrap() == 16);
+        assert!(policy.get_padded_len(17).unwrap() == 32);
+        assert!(policy.get_padded_len(1000).unwrap() == 1024);
+        assert!(policy.get_padded_len(usize::max_value()).is_err());
+
+        let policy = BlobPaddingPolicy::Buckets(vec![32, 128, 512]);
+        assert!(policy.get_padded_len(1).unwrap() == 32);
+        assert!(policy.get_padded_len(33).unwrap() == 128);
+        assert!(policy.get_padded_len(512).unwrap() == 512);
+        assert!(policy.get_padded_len(513).unwrap() == 1024);
+        assert!(policy.get_padded_len(1025).unwrap() == 1536);
+
+        assert!(BlobPaddingPolicy::Buckets(vec![])
+            .get_padded_len(1)
+            .is_err());
+        assert!(BlobPaddingPolicy::Buckets(vec![0, 8])
+            .get_padded_len(1)
+            .is_err());
+        assert!(BlobPaddingPolicy::Buckets(vec![64, 32])
+            .get_padded_len(1)
+            .is_err());
+    }
+
+    #[test]
+    fn test_pad_unpad() {
+        let policy = BlobPaddingPolicy::Buckets(vec![8, 16]);
+        for plaintext in &[
+            vec![],
+            vec![0u8],
+            vec![0x80u8],
+            vec![1u8, 0x80, 0, 0],
+            vec![0u8; 7],
+            vec![0xffu8; 40],
+        ] {
+            let padded = pad(plaintext, &policy).unwrap();
+            assert!(padded.len() == policy.get_padded_len(plaintext.len() + 1).unwrap());
+            assert!(unpad(padded).unwrap() == *plaintext);
+        }
+
+        // Policies can't make the library allocate more than the cap
+        let policy = BlobPaddingPolicy::PowerOfTwo {
+            min_len: MAX_PADDED_BLOB_LEN,
+        };
+        assert!(pad(&[], &policy).unwrap().len() == MAX_PADDED_BLOB_LEN);
+        let policy = BlobPaddingPolicy::PowerOfTwo {
+            min_len: usize::max_value(),
+        };
+        match pad(&[], &policy) {
+            Err(ZkGroupError::BadArgs) => (),
+            _ => assert!(false),
+        }
+        let policy = BlobPaddingPolicy::Buckets(vec![MAX_PADDED_BLOB_LEN + 1]);
+        match pad(&[], &policy) {
+            Err(ZkGroupError::BadArgs) => (),
+            _ => assert!(false),
+        }
+
+        assert!(unpad(vec![]).is_err());
+        assert!(unpad(vec![0u8; 8]).is_err());
+        assert!(unpad(vec![1u8, 2, 0, 0]).is_err());
+    }
+}
@@ -0,0 +1,238 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+
+#![allow(non_snake_case)]
+
+// Chunked blob encryption for data too large to hold in memory.
+//
+// Layout:
+//   nonce_prefix (BLOB_STREAM_NONCE_PREFIX_LEN bytes)
+//   chunk_0 || ... || chunk_n  (each chunk is AES-GCM-SIV ciphertext + tag)
+//   format byte (BLOB_FORMAT_STREAM)
+//
+// Every chunk but the last holds exactly BLOB_STREAM_CHUNK_LEN bytes of
+// plaintext.  Each chunk's nonce is nonce_prefix || chunk index || last flag,
+// so reordered, dropped, or truncated chunks fail to authenticate.  The
+// trailing format byte tells this layout apart from encrypt_blob's.
+
+use crate::common::constants::*;
+use crate::common::errors::*;
+use aead::{generic_array::GenericArray, Aead, NewAead};
+use aes_gcm_siv::Aes256GcmSiv;
+use std::io::{ErrorKind, Read, Write};
+
+const CHUNK_CIPHERTEXT_LEN: usize = BLOB_STREAM_CHUNK_LEN + AESGCM_TAG_LEN;
+
+pub(crate) fn encrypt<R: Read, W: Write>(
+    key: &[u8],
+    nonce_prefix: &[u8],
+    mut reader: R,
+    mut writer: W,
+) -> Result<(), ZkGroupError> {
+    let aead_cipher = Aes256GcmSiv::new(*GenericArray::from_slice(key));
+    write_all(&mut writer, nonce_prefix)?;
+
+    let mut chunk = vec![0u8; BLOB_STREAM_CHUNK_LEN];
+    let mut next_chunk = vec![0u8; BLOB_STREAM_CHUNK_LEN];
+    let mut chunk_len = read_fully(&mut reader, &mut chunk)?;
+    let mut index = 0u32;
+    loop {
+        // Look ahead so the last chunk can be marked as such
+        let next_chunk_len = if chunk_len == BLOB_STREAM_CHUNK_LEN {
+            read_fully(&mut reader, &mut next_chunk)?
+        } else {
+            0
+        };
+        let is_last = next_chunk_len == 0;
+
+        let nonce = chunk_nonce(nonce_prefix, index, is_last);
+        let ciphertext =
+            match aead_cipher.encrypt(GenericArray::from_slice(&nonce), &chunk[..chunk_len]) {
+                Ok(ciphertext_vec) => ciphertext_vec,
+                Err(_) => return Err(ZkGroupError::BadArgs),
+            };
+        write_all(&mut writer, &ciphertext)?;
+
+        if is_last {
+            break;
+        }
+        std::mem::swap(&mut chunk, &mut next_chunk);
+        chunk_len = next_chunk_len;
+        index = match index.checked_add(1) {
+            Some(index) => index,
+            None => return Err(ZkGroupError::BadArgs),
+        };
+    }
+
+    write_all(&mut writer, &[BLOB_FORMAT_STREAM])
+}
+
+// Plaintext is written out one authenticated chunk at a time, so on failure
+// the writer may already hold a prefix of the plaintext; callers must discard
+// it unless Ok is returned.
+pub(crate) fn decrypt<R: Read, W: Write>(
+    key: &[u8],
+    mut reader: R,
+    mut writer: W,
+) -> Result<(), ZkGroupError> {
+    let aead_cipher = Aes256GcmSiv::new(*GenericArray::from_slice(key));
+
+    let mut nonce_prefix = [0u8; BLOB_STREAM_NONCE_PREFIX_LEN];
+    if read_fully(&mut reader, &mut nonce_prefix)? != BLOB_STREAM_NONCE_PREFIX_LEN {
+        return Err(ZkGroupError::DecryptionFailure);
+    }
+
+    // A full chunk followed by at least two more bytes can't be the last
+    // chunk, since the last chunk is followed only by the format byte
+    let mut buffer = vec![0u8; CHUNK_CIPHERTEXT_LEN + 2];
+    let mut buffer_len = read_fully(&mut reader, &mut buffer)?;
+    let mut index = 0u32;
+    loop {
+        let is_last = buffer_len < buffer.len();
+        let chunk_len = if is_last {
+            if buffer_len < AESGCM_TAG_LEN + 1 || buffer[buffer_len - 1] != BLOB_FORMAT_STREAM {
+                return Err(ZkGroupError::DecryptionFailure);
+            }
+            buffer_len - 1
+        } else {
+            CHUNK_CIPHERTEXT_LEN
+        };
+
+        let nonce = chunk_nonce(&nonce_prefix, index, is_last);
+        let plaintext =
+            match aead_cipher.decrypt(GenericArray::from_slice(&nonce), &buffer[..chunk_len]) {
+                Ok(plaintext_vec) => plaintext_vec,
+                Err(_) => return Err(ZkGroupError::DecryptionFailure),
+            };
+        write_all(&mut writer, &plaintext)?;
+
+        if is_last {
+            return Ok(());
+        }
+        buffer.copy_within(CHUNK_CIPHERTEXT_LEN.., 0);
+        buffer_len = 2 + read_fully(&mut reader, &mut buffer[2..])?;
+        index = match index.checked_add(1) {
+            Some(index) => index,
+            None => return Err(ZkGroupError::DecryptionFailure),
+        };
+    }
+}
+
+fn chunk_nonce(nonce_prefix: &[u8], index: u32, is_last: bool) -> [u8; AESGCM_NONCE_LEN] {
+    let prefix_len = BLOB_STREAM_NONCE_PREFIX_LEN;
+    let mut nonce = [0u8; AESGCM_NONCE_LEN];
+    nonce[..prefix_len].copy_from_slice(nonce_prefix);
+    nonce[prefix_len..prefix_len + 4].copy_from_slice(&index.to_be_bytes());
+    nonce[prefix_len + 4] = is_last as u8;
+    nonce
+}
+
+// Like Read::read_exact, but returns the number of bytes read if the reader
+// hits EOF first
+fn read_fully<R: Read>(reader: &mut R, buffer: &mut [u8]) -> Result<usize, ZkGroupError> {
+    let mut len = 0;
+    while len < buffer.len() {
+        match reader.read(&mut buffer[len..]) {
+            Ok(0) => break,
+            Ok(n) => len += n,
+            Err(ref e) if e.kind() == ErrorKind::Interrupted => {}
+            Err(e) => return Err(ZkGroupError::IoFailure(e)),
+        }
+    }
+    Ok(len)
+}
+
+fn write_all<W: Write>(writer: &mut W, bytes: &[u8]) -> Result<(), ZkGroupError> {
+    match writer.write_all(bytes) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(ZkGroupError::IoFailure(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: [u8; 32] = [7u8; 32];
+    const NONCE_PREFIX: [u8; BLOB_STREAM_NONCE_PREFIX_LEN] = [9u8; BLOB_STREAM_NONCE_PREFIX_LEN];
+
+    fn encrypt_vec(plaintext: &[u8]) -> Vec<u8> {
+        let mut ciphertext = Vec::new();
+        encrypt(&KEY, &NONCE_PREFIX, plaintext, &mut ciphertext).unwrap();
+        ciphertext
+    }
+
+    fn decrypt_vec(ciphertext: &[u8]) -> Result<Vec<u8>, ZkGroupError> {
+        let mut plaintext = Vec::new();
+        decrypt(&KEY, ciphertext, &mut plaintext)?;
+        Ok(plaintext)
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for len in &[
+            0,
+            1,
+            BLOB_STREAM_CHUNK_LEN - 1,
+            BLOB_STREAM_CHUNK_LEN,
+            BLOB_STREAM_CHUNK_LEN + 1,
+            3 * BLOB_STREAM_CHUNK_LEN,
+            3 * BLOB_STREAM_CHUNK_LEN + 17,
+        ] {
+            let plaintext: Vec<u8> = (0..*len).map(|i| i as u8).collect();
+            let ciphertext = encrypt_vec(&plaintext);
+            let num_chunks =
+                std::cmp::max(1, (len + BLOB_STREAM_CHUNK_LEN - 1) / BLOB_STREAM_CHUNK_LEN);
+            assert!(
+                ciphertext.len()
+                    == BLOB_STREAM_NONCE_PREFIX_LEN + len + num_chunks * AESGCM_TAG_LEN + 1
+            );
+            assert!(*ciphertext.last().unwrap() == BLOB_FORMAT_STREAM);
+            assert!(decrypt_vec(&ciphertext).unwrap() == plaintext);
+        }
+    }
+
+    #[test]
+    fn test_truncation_and_reordering() {
+        let plaintext = vec![42u8; 3 * BLOB_STREAM_CHUNK_LEN + 5];
+        let ciphertext = encrypt_vec(&plaintext);
+        let chunks_start = BLOB_STREAM_NONCE_PREFIX_LEN;
+
+        // Dropping the trailing chunk, with the format byte kept
+        let mut truncated = ciphertext[..chunks_start + 3 * CHUNK_CIPHERTEXT_LEN].to_vec();
+        truncated.push(BLOB_FORMAT_STREAM);
+        assert!(decrypt_vec(&truncated).is_err());
+
+        // Cutting the stream anywhere
+        for len in &[
+            0,
+            chunks_start,
+            chunks_start + CHUNK_CIPHERTEXT_LEN,
+            ciphertext.len() - 1,
+        ] {
+            assert!(decrypt_vec(&ciphertext[..*len]).is_err());
+        }
+
+        // Swapping the first two chunks
+        let mut reordered = ciphertext.clone();
+        let first = chunks_start..chunks_start + CHUNK_CIPHERTEXT_LEN;
+        let second = chunks_start + CHUNK_CIPHERTEXT_LEN..chunks_start + 2 * CHUNK_CIPHERTEXT_LEN;
+        reordered[first.clone()].copy_from_slice(&ciphertext[second.clone()]);
+        reordered[second].copy_from_slice(&ciphertext[first]);
+        assert!(decrypt_vec(&reordered).is_err());
+
+        // Trailing garbage
+        let mut extended = ciphertext.clone();
+        extended.push(0);
+        assert!(decrypt_vec(&extended).is_err());
+
+        // Wrong format byte
+        let mut wrong_format = ciphertext;
+        *wrong_format.last_mut().unwrap() = 0;
+        assert!(decrypt_vec(&wrong_format).is_err());
+    }
+}
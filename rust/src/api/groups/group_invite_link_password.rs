@@ -0,0 +1,68 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+
+#![allow(non_snake_case)]
+
+use crate::api;
+use crate::common::constants::*;
+use crate::common::serialization::Versioned;
+use crate::common::sho::*;
+use crate::common::simple_types::*;
+use crate::crypto;
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
+
+// A secret shared through a group's invite link.  Admins give the server the
+// password's commitment, and joiners prove they know the password with a
+// GroupInviteLinkPresentation, so the server never learns the password itself.
+#[derive(Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde_human_readable", serde(remote = "Self"))]
+pub struct GroupInviteLinkPassword {
+    pub(crate) bytes: GroupInviteLinkPasswordBytes,
+}
+
+human_readable_serde!(unversioned GroupInviteLinkPassword);
+
+impl Drop for GroupInviteLinkPassword {
+    fn drop(&mut self) {
+        self.bytes.zeroize();
+    }
+}
+
+impl GroupInviteLinkPassword {
+    pub fn generate(randomness: RandomnessBytes) -> Self {
+        let mut sho = Sho::new(
+            b"Signal_ZKGroup_20200424_Random_GroupInviteLinkPassword_Generate",
+            &randomness,
+        );
+        let mut bytes = [0u8; GROUP_INVITE_LINK_PASSWORD_LEN];
+        bytes.copy_from_slice(&sho.squeeze(GROUP_INVITE_LINK_PASSWORD_LEN)[..]);
+        Self { bytes }
+    }
+
+    pub fn create(bytes: GroupInviteLinkPasswordBytes) -> Self {
+        Self { bytes }
+    }
+
+    pub fn get_bytes(&self) -> GroupInviteLinkPasswordBytes {
+        self.bytes
+    }
+
+    pub fn get_commitment(
+        &self,
+        group_public_params: api::groups::GroupPublicParams,
+    ) -> api::groups::GroupInviteLinkPasswordCommitment {
+        let key_pair = crypto::invite_link_password::KeyPair::derive_from(
+            self.bytes,
+            group_public_params.get_group_identifier(),
+        );
+        api::groups::GroupInviteLinkPasswordCommitment {
+            version: api::groups::GroupInviteLinkPasswordCommitment::VERSION,
+            public_key: key_pair.get_public_key(),
+        }
+    }
+}
@@ -0,0 +1,33 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+
+#![allow(non_snake_case)]
+
+use crate::common::errors::*;
+use crate::common::serialization::{Validate, Versioned};
+use crate::common::simple_types::*;
+use crate::crypto;
+use serde::{Deserialize, Serialize};
+
+#[derive(Copy, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serde_human_readable", serde(remote = "Self"))]
+pub struct GroupInviteLinkPasswordCommitment {
+    pub(crate) version: VersionByte,
+    pub(crate) public_key: crypto::invite_link_password::PublicKey,
+}
+
+human_readable_serde!(GroupInviteLinkPasswordCommitment);
+
+impl Versioned for GroupInviteLinkPasswordCommitment {
+    const VERSION: VersionByte = 0;
+}
+
+impl Validate for GroupInviteLinkPasswordCommitment {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        self.public_key.validate()
+    }
+}
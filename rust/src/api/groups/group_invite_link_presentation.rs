@@ -0,0 +1,59 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+
+#![allow(non_snake_case)]
+
+use crate::api;
+use crate::common::errors::*;
+use crate::common::serialization;
+use crate::common::serialization::{Validate, Versioned};
+use crate::common::simple_types::*;
+use crate::crypto;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "serde_human_readable", serde(remote = "Self"))]
+pub struct GroupInviteLinkPresentation {
+    pub(crate) version: VersionByte,
+    pub(crate) proof: crypto::proofs::GroupInviteLinkPasswordProof,
+    pub(crate) ciphertext: crypto::uid_encryption::Ciphertext,
+}
+
+human_readable_serde!(GroupInviteLinkPresentation);
+
+impl Versioned for GroupInviteLinkPresentation {
+    const VERSION: VersionByte = 0;
+}
+
+impl Validate for GroupInviteLinkPresentation {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        self.proof.validate()?;
+        self.ciphertext.validate()
+    }
+}
+
+impl GroupInviteLinkPresentation {
+    pub fn get_uuid_ciphertext(&self) -> api::groups::UuidCiphertext {
+        api::groups::UuidCiphertext {
+            version: api::groups::UuidCiphertext::VERSION,
+            ciphertext: self.ciphertext,
+        }
+    }
+
+    // The proof only shows knowledge of the password, so the group and the
+    // joiner's UuidCiphertext are bound to it through the poksho message.
+    // The server should still check the UuidCiphertext against the joiner's
+    // AuthCredentialPresentation.
+    pub(crate) fn get_poksho_message(
+        group_id: GroupIdentifierBytes,
+        ciphertext: crypto::uid_encryption::Ciphertext,
+    ) -> Result<Vec<u8>, ZkGroupError> {
+        let mut message = group_id.to_vec();
+        message.extend(serialization::serialize(&ciphertext)?);
+        Ok(message)
+    }
+}
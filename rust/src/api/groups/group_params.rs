@@ -10,21 +10,34 @@
 use crate::api;
 use crate::common::constants::*;
 use crate::common::errors::*;
+use crate::common::serialization::{ensure, Validate, Versioned};
 use crate::common::sho::*;
 use crate::common::simple_types::*;
 use crate::crypto;
-use aead::{generic_array::GenericArray, Aead, NewAead};
+use aead::{generic_array::GenericArray, Aead, NewAead, Payload};
 use aes_gcm_siv::Aes256GcmSiv;
 use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+use zeroize::Zeroize;
 
-#[derive(Copy, Clone, Serialize, Deserialize, Default)]
+#[derive(Clone, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "serde_human_readable", serde(remote = "Self"))]
 pub struct GroupMasterKey {
     pub(crate) bytes: [u8; GROUP_MASTER_KEY_LEN],
 }
 
-#[derive(Copy, Clone, Serialize, Deserialize)]
+human_readable_serde!(unversioned GroupMasterKey);
+
+impl Drop for GroupMasterKey {
+    fn drop(&mut self) {
+        self.bytes.zeroize();
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde_human_readable", serde(remote = "Self"))]
 pub struct GroupSecretParams {
-    reserved: ReservedBytes,
+    version: VersionByte,
     master_key: GroupMasterKey,
     group_id: GroupIdentifierBytes,
     blob_key: AesKeyBytes,
@@ -32,14 +45,55 @@ pub struct GroupSecretParams {
     pub(crate) profile_key_enc_key_pair: crypto::profile_key_encryption::KeyPair,
 }
 
+human_readable_serde!(GroupSecretParams);
+
+// The master key and key pairs wipe themselves
+impl Drop for GroupSecretParams {
+    fn drop(&mut self) {
+        self.blob_key.zeroize();
+    }
+}
+
+impl Versioned for GroupSecretParams {
+    const VERSION: VersionByte = 0;
+}
+
+impl Validate for GroupSecretParams {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        // Everything but the master key is derived from it, so re-derive and compare
+        let expected = Self::derive_from_master_key(self.master_key.clone());
+        ensure::<Self>(
+            self.group_id == expected.group_id
+                && self.blob_key == expected.blob_key
+                && self.uid_enc_key_pair == expected.uid_enc_key_pair
+                && self.profile_key_enc_key_pair == expected.profile_key_enc_key_pair,
+            "contents don't match the master key",
+        )
+    }
+}
+
 #[derive(Copy, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde_human_readable", serde(remote = "Self"))]
 pub struct GroupPublicParams {
-    reserved: ReservedBytes,
+    version: VersionByte,
     group_id: GroupIdentifierBytes,
     pub(crate) uid_enc_public_key: crypto::uid_encryption::PublicKey,
     pub(crate) profile_key_enc_public_key: crypto::profile_key_encryption::PublicKey,
 }
 
+human_readable_serde!(GroupPublicParams);
+
+impl Versioned for GroupPublicParams {
+    const VERSION: VersionByte = 0;
+}
+
+impl Validate for GroupPublicParams {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        self.uid_enc_public_key.validate()?;
+        self.profile_key_enc_public_key.validate()
+    }
+}
+
 impl GroupMasterKey {
     pub fn new(bytes: [u8; GROUP_MASTER_KEY_LEN]) -> Self {
         GroupMasterKey { bytes }
@@ -73,7 +127,7 @@ impl GroupSecretParams {
             crypto::profile_key_encryption::KeyPair::derive_from(&mut sho);
 
         Self {
-            reserved: Default::default(),
+            version: Self::VERSION,
             master_key,
             group_id,
             blob_key,
@@ -82,8 +136,8 @@ impl GroupSecretParams {
         }
     }
 
-    pub fn get_master_key(&self) -> GroupMasterKey {
-        self.master_key
+    pub fn get_master_key(&self) -> &GroupMasterKey {
+        &self.master_key
     }
 
     pub fn get_group_identifier(&self) -> GroupIdentifierBytes {
@@ -92,7 +146,7 @@ impl GroupSecretParams {
 
     pub fn get_public_params(&self) -> GroupPublicParams {
         GroupPublicParams {
-            reserved: Default::default(),
+            version: GroupPublicParams::VERSION,
             uid_enc_public_key: self.uid_enc_key_pair.get_public_key(),
             profile_key_enc_public_key: self.profile_key_enc_key_pair.get_public_key(),
             group_id: self.group_id,
@@ -110,7 +164,7 @@ impl GroupSecretParams {
     ) -> api::groups::UuidCiphertext {
         let ciphertext = self.uid_enc_key_pair.encrypt(uid);
         api::groups::UuidCiphertext {
-            reserved: Default::default(),
+            version: api::groups::UuidCiphertext::VERSION,
             ciphertext,
         }
     }
@@ -123,9 +177,184 @@ impl GroupSecretParams {
         Ok(uid.to_bytes())
     }
 
+    // Unlike encrypt_uuid, gives a different ciphertext each time, so entries
+    // written this way can't be linked across group revisions by anyone
+    // without these params; see create_uuid_ciphertext_match_proof for how the
+    // server can still check membership
+    pub fn encrypt_uuid_randomized(
+        &self,
+        randomness: RandomnessBytes,
+        uid_bytes: UidBytes,
+    ) -> api::groups::RandomizedUuidCiphertext {
+        let mut sho = Sho::new(
+            b"Signal_ZKGroup_20200424_Random_GroupSecretParams_EncryptUuidRandomized",
+            &randomness,
+        );
+        let uid = crypto::uid_struct::UidStruct::new(uid_bytes);
+        let ciphertext = self
+            .uid_enc_key_pair
+            .encrypt_randomized(uid, sho.get_scalar());
+        api::groups::RandomizedUuidCiphertext {
+            version: api::groups::RandomizedUuidCiphertext::VERSION,
+            ciphertext,
+        }
+    }
+
+    pub fn decrypt_randomized_uuid(
+        &self,
+        ciphertext: api::groups::RandomizedUuidCiphertext,
+    ) -> Result<UidBytes, ZkGroupError> {
+        let uid = self
+            .uid_enc_key_pair
+            .decrypt_randomized(ciphertext.ciphertext)?;
+        Ok(uid.to_bytes())
+    }
+
+    // The index of the first entry that encrypts uid_bytes, if any, e.g. to
+    // find which entry of the group state a match proof should point at
+    pub fn find_randomized_uuid(
+        &self,
+        uid_bytes: UidBytes,
+        entries: &[api::groups::RandomizedUuidCiphertext],
+    ) -> Option<usize> {
+        let uid = crypto::uid_struct::UidStruct::new(uid_bytes);
+        entries.iter().position(|entry| {
+            self.uid_enc_key_pair
+                .is_randomized_encryption_of(entry.ciphertext, uid)
+        })
+    }
+
+    // Proves that two ciphertexts under these params hold the same UID, for
+    // a verifier who can't compare them directly (e.g. if the encryption
+    // isn't deterministic).  Might return DecryptionFailure if either
+    // ciphertext isn't under these params, or BadArgs if their UIDs differ.
+    pub fn create_uuid_ciphertext_equality_proof(
+        &self,
+        randomness: RandomnessBytes,
+        ciphertext: api::groups::UuidCiphertext,
+        other_ciphertext: api::groups::UuidCiphertext,
+    ) -> Result<api::groups::UuidCiphertextEqualityProof, ZkGroupError> {
+        self.create_uuid_ciphertext_equality_proof_across_groups(
+            randomness,
+            ciphertext,
+            self,
+            other_ciphertext,
+        )
+    }
+
+    // Like create_uuid_ciphertext_equality_proof, but other_ciphertext is
+    // under other_params, e.g. a different group or the same group after
+    // re-keying
+    pub fn create_uuid_ciphertext_equality_proof_across_groups(
+        &self,
+        randomness: RandomnessBytes,
+        ciphertext: api::groups::UuidCiphertext,
+        other_params: &GroupSecretParams,
+        other_ciphertext: api::groups::UuidCiphertext,
+    ) -> Result<api::groups::UuidCiphertextEqualityProof, ZkGroupError> {
+        let mut sho = Sho::new(
+            b"Signal_ZKGroup_20200424_Random_GroupSecretParams_CreateUuidCiphertextEqualityProof",
+            &randomness,
+        );
+        let uid = self.uid_enc_key_pair.decrypt(ciphertext.ciphertext)?;
+        let other_uid = other_params
+            .uid_enc_key_pair
+            .decrypt(other_ciphertext.ciphertext)?;
+        if uid.to_bytes() != other_uid.to_bytes() {
+            return Err(ZkGroupError::BadArgs);
+        }
+        let proof = crypto::proofs::UidEncryptionEqualityProof::new(
+            &self.uid_enc_key_pair,
+            ciphertext.ciphertext,
+            &other_params.uid_enc_key_pair,
+            other_ciphertext.ciphertext,
+            &mut sho,
+        )?;
+        Ok(api::groups::UuidCiphertextEqualityProof {
+            version: api::groups::UuidCiphertextEqualityProof::VERSION,
+            proof,
+        })
+    }
+
+    // Proves that ciphertext (e.g. from an auth credential presentation) and
+    // the randomized entry hold the same UID, so the server can tell which
+    // member a request comes from without the entries being linkable.  Might
+    // return DecryptionFailure if either isn't under these params, or BadArgs
+    // if their UIDs differ.
+    pub fn create_uuid_ciphertext_match_proof(
+        &self,
+        randomness: RandomnessBytes,
+        ciphertext: api::groups::UuidCiphertext,
+        entry: api::groups::RandomizedUuidCiphertext,
+    ) -> Result<api::groups::UuidCiphertextMatchProof, ZkGroupError> {
+        let mut sho = Sho::new(
+            b"Signal_ZKGroup_20200424_Random_GroupSecretParams_CreateUuidCiphertextMatchProof",
+            &randomness,
+        );
+        let uid = self.uid_enc_key_pair.decrypt(ciphertext.ciphertext)?;
+        if !self
+            .uid_enc_key_pair
+            .is_randomized_encryption_of(entry.ciphertext, uid)
+        {
+            return Err(ZkGroupError::BadArgs);
+        }
+        let proof = crypto::proofs::UidEncryptionMatchProof::new(
+            &self.uid_enc_key_pair,
+            ciphertext.ciphertext,
+            entry.ciphertext,
+            &mut sho,
+        )?;
+        Ok(api::groups::UuidCiphertextMatchProof {
+            version: api::groups::UuidCiphertextMatchProof::VERSION,
+            proof,
+        })
+    }
+
+    // Proves to the server that the joiner with uid_bytes knows the group's
+    // invite link password, without revealing it
+    pub fn create_group_invite_link_presentation(
+        &self,
+        randomness: RandomnessBytes,
+        password: &api::groups::GroupInviteLinkPassword,
+        uid_bytes: UidBytes,
+    ) -> Result<api::groups::GroupInviteLinkPresentation, ZkGroupError> {
+        let mut sho = Sho::new(
+            b"Signal_ZKGroup_20200424_Random_GroupSecretParams_CreateGroupInviteLinkPresentation",
+            &randomness,
+        );
+        let key_pair =
+            crypto::invite_link_password::KeyPair::derive_from(password.bytes, self.group_id);
+        let ciphertext = self.encrypt_uuid(uid_bytes).ciphertext;
+        let message = api::groups::GroupInviteLinkPresentation::get_poksho_message(
+            self.group_id,
+            ciphertext,
+        )?;
+        let proof =
+            crypto::proofs::GroupInviteLinkPasswordProof::new(&key_pair, &message, &mut sho)?;
+        Ok(api::groups::GroupInviteLinkPresentation {
+            version: api::groups::GroupInviteLinkPresentation::VERSION,
+            proof,
+            ciphertext,
+        })
+    }
+
+    // Lets a member invite someone whose profile key they don't have yet; the
+    // invitee later accepts with a ProfileKeyCredentialPresentation
+    pub fn create_pending_member_invitation(
+        &self,
+        invitee_uid_bytes: UidBytes,
+        inviter_uid_bytes: UidBytes,
+    ) -> api::groups::PendingMemberInvitation {
+        api::groups::PendingMemberInvitation {
+            version: api::groups::PendingMemberInvitation::VERSION,
+            uid_enc_ciphertext: self.encrypt_uuid(invitee_uid_bytes).ciphertext,
+            inviter_uid_enc_ciphertext: self.encrypt_uuid(inviter_uid_bytes).ciphertext,
+        }
+    }
+
     pub fn encrypt_profile_key(
         &self,
-        profile_key: api::profiles::ProfileKey,
+        profile_key: &api::profiles::ProfileKey,
         uid_bytes: UidBytes,
     ) -> api::groups::ProfileKeyCiphertext {
         self.encrypt_profile_key_bytes(profile_key.bytes, uid_bytes)
@@ -138,9 +367,9 @@ impl GroupSecretParams {
     ) -> api::groups::ProfileKeyCiphertext {
         let profile_key =
             crypto::profile_key_struct::ProfileKeyStruct::new(profile_key_bytes, uid_bytes);
-        let ciphertext = self.profile_key_enc_key_pair.encrypt(profile_key);
+        let ciphertext = self.profile_key_enc_key_pair.encrypt(&profile_key);
         api::groups::ProfileKeyCiphertext {
-            reserved: Default::default(),
+            version: api::groups::ProfileKeyCiphertext::VERSION,
             ciphertext,
         }
     }
@@ -162,31 +391,141 @@ impl GroupSecretParams {
         &self,
         randomness: RandomnessBytes,
         plaintext: &[u8],
+    ) -> Result<Vec<u8>, ZkGroupError> {
+        self.encrypt_blob_with_ad(randomness, plaintext, &[])
+    }
+
+    // The associated data (e.g. field name and group revision) isn't stored
+    // in the blob, and the same associated data must be given to
+    // decrypt_blob_with_ad
+    pub fn encrypt_blob_with_ad(
+        &self,
+        randomness: RandomnessBytes,
+        plaintext: &[u8],
+        associated_data: &[u8],
     ) -> Result<Vec<u8>, ZkGroupError> {
         let mut sho = Sho::new(
             b"Signal_ZKGroup_20200424_Random_GroupSecretParams_EncryptBlob",
             &randomness,
         );
         let nonce_vec = sho.squeeze(AESGCM_NONCE_LEN);
-        match self.encrypt_blob_aesgcmsiv(&self.blob_key, &nonce_vec[..], plaintext) {
+        match self.encrypt_blob_aesgcmsiv(
+            &self.blob_key,
+            &nonce_vec[..],
+            plaintext,
+            associated_data,
+        ) {
             Ok(mut ciphertext_vec) => {
                 ciphertext_vec.extend(nonce_vec);
-                ciphertext_vec.extend(&[0u8]); // reserved byte
+                ciphertext_vec.extend(&[BLOB_FORMAT_PLAIN]);
                 Ok(ciphertext_vec)
             }
             Err(e) => Err(e),
         }
     }
 
-    pub fn decrypt_blob(self, ciphertext: &[u8]) -> Result<Vec<u8>, ZkGroupError> {
+    // Pads the plaintext up to a size chosen by padding_policy, so the
+    // ciphertext length reveals only the bucket; decrypt_blob removes the
+    // padding.  Returns BadArgs if that size is over MAX_PADDED_BLOB_LEN.
+    pub fn encrypt_blob_with_padding(
+        &self,
+        randomness: RandomnessBytes,
+        plaintext: &[u8],
+        padding_policy: &api::groups::BlobPaddingPolicy,
+    ) -> Result<Vec<u8>, ZkGroupError> {
+        self.encrypt_blob_with_padding_and_ad(randomness, plaintext, padding_policy, &[])
+    }
+
+    // Like encrypt_blob_with_padding, and the same associated data must be
+    // given to decrypt_blob_with_ad
+    pub fn encrypt_blob_with_padding_and_ad(
+        &self,
+        randomness: RandomnessBytes,
+        plaintext: &[u8],
+        padding_policy: &api::groups::BlobPaddingPolicy,
+        associated_data: &[u8],
+    ) -> Result<Vec<u8>, ZkGroupError> {
+        let mut sho = Sho::new(
+            b"Signal_ZKGroup_20200424_Random_GroupSecretParams_EncryptBlobWithPadding",
+            &randomness,
+        );
+        let padded_plaintext = api::groups::blob_padding::pad(plaintext, padding_policy)?;
+        let nonce_vec = sho.squeeze(AESGCM_NONCE_LEN);
+        // The format byte is authenticated so it can't be changed to skip
+        // removing the padding
+        let mut padded_associated_data = vec![BLOB_FORMAT_PADDED];
+        padded_associated_data.extend_from_slice(associated_data);
+        let mut ciphertext_vec = self.encrypt_blob_aesgcmsiv(
+            &self.blob_key,
+            &nonce_vec[..],
+            &padded_plaintext,
+            &padded_associated_data,
+        )?;
+        ciphertext_vec.extend(nonce_vec);
+        ciphertext_vec.extend(&[BLOB_FORMAT_PADDED]);
+        Ok(ciphertext_vec)
+    }
+
+    pub fn decrypt_blob(&self, ciphertext: &[u8]) -> Result<Vec<u8>, ZkGroupError> {
+        self.decrypt_blob_with_ad(ciphertext, &[])
+    }
+
+    pub fn decrypt_blob_with_ad(
+        &self,
+        ciphertext: &[u8],
+        associated_data: &[u8],
+    ) -> Result<Vec<u8>, ZkGroupError> {
         if ciphertext.len() < AESGCM_NONCE_LEN + 1 {
             // AESGCM_NONCE_LEN = 12 bytes for IV
             return Err(ZkGroupError::DecryptionFailure);
         }
         let unreserved_len = ciphertext.len() - 1;
+        let format = ciphertext[unreserved_len];
         let nonce = &ciphertext[unreserved_len - AESGCM_NONCE_LEN..unreserved_len];
         let ciphertext = &ciphertext[..unreserved_len - AESGCM_NONCE_LEN];
-        self.decrypt_blob_aesgcmsiv(&self.blob_key, nonce, ciphertext)
+        match format {
+            BLOB_FORMAT_PLAIN => {
+                self.decrypt_blob_aesgcmsiv(&self.blob_key, nonce, ciphertext, associated_data)
+            }
+            BLOB_FORMAT_PADDED => {
+                let mut padded_associated_data = vec![format];
+                padded_associated_data.extend_from_slice(associated_data);
+                let padded_plaintext = self.decrypt_blob_aesgcmsiv(
+                    &self.blob_key,
+                    nonce,
+                    ciphertext,
+                    &padded_associated_data,
+                )?;
+                api::groups::blob_padding::unpad(padded_plaintext)
+            }
+            _ => Err(ZkGroupError::DecryptionFailure),
+        }
+    }
+
+    // Encrypts everything read from reader in BLOB_STREAM_CHUNK_LEN chunks,
+    // writing the result to writer as it goes
+    pub fn encrypt_blob_stream<R: Read, W: Write>(
+        &self,
+        randomness: RandomnessBytes,
+        reader: R,
+        writer: W,
+    ) -> Result<(), ZkGroupError> {
+        let mut sho = Sho::new(
+            b"Signal_ZKGroup_20200424_Random_GroupSecretParams_EncryptBlobStream",
+            &randomness,
+        );
+        let nonce_prefix = sho.squeeze(BLOB_STREAM_NONCE_PREFIX_LEN);
+        api::groups::blob_stream::encrypt(&self.blob_key, &nonce_prefix, reader, writer)
+    }
+
+    // Chunks are written out as soon as they authenticate, so the writer's
+    // contents must be discarded if this returns an error
+    pub fn decrypt_blob_stream<R: Read, W: Write>(
+        &self,
+        reader: R,
+        writer: W,
+    ) -> Result<(), ZkGroupError> {
+        api::groups::blob_stream::decrypt(&self.blob_key, reader, writer)
     }
 
     fn encrypt_blob_aesgcmsiv(
@@ -194,21 +533,27 @@ impl GroupSecretParams {
         key: &[u8],
         nonce: &[u8],
         plaintext: &[u8],
+        associated_data: &[u8],
     ) -> Result<Vec<u8>, ZkGroupError> {
         let key = GenericArray::from_slice(key);
         let aead_cipher = Aes256GcmSiv::new(*key);
         let nonce = GenericArray::from_slice(nonce);
-        match aead_cipher.encrypt(nonce, plaintext) {
+        let payload = Payload {
+            msg: plaintext,
+            aad: associated_data,
+        };
+        match aead_cipher.encrypt(nonce, payload) {
             Ok(ciphertext_vec) => Ok(ciphertext_vec),
             Err(_) => Err(ZkGroupError::BadArgs),
         }
     }
 
     fn decrypt_blob_aesgcmsiv(
-        self,
+        &self,
         key: &[u8],
         nonce: &[u8],
         ciphertext: &[u8],
+        associated_data: &[u8],
     ) -> Result<Vec<u8>, ZkGroupError> {
         if ciphertext.len() < AESGCM_TAG_LEN {
             // AESGCM_TAG_LEN = 16 bytes for tag
@@ -217,7 +562,11 @@ impl GroupSecretParams {
         let key = GenericArray::from_slice(key);
         let aead_cipher = Aes256GcmSiv::new(*key);
         let nonce = GenericArray::from_slice(nonce);
-        match aead_cipher.decrypt(nonce, ciphertext) {
+        let payload = Payload {
+            msg: ciphertext,
+            aad: associated_data,
+        };
+        match aead_cipher.decrypt(nonce, payload) {
             Ok(plaintext_vec) => Ok(plaintext_vec),
             Err(_) => Err(ZkGroupError::DecryptionFailure),
         }
@@ -228,6 +577,40 @@ impl GroupPublicParams {
     pub fn get_group_identifier(&self) -> GroupIdentifierBytes {
         self.group_id
     }
+
+    // Checks that ciphertext (under these params) and other_ciphertext (under
+    // other_params) hold the same UID; for two ciphertexts in the same group,
+    // other_params is these params
+    pub fn verify_uuid_ciphertext_equality(
+        &self,
+        ciphertext: api::groups::UuidCiphertext,
+        other_params: GroupPublicParams,
+        other_ciphertext: api::groups::UuidCiphertext,
+        proof: &api::groups::UuidCiphertextEqualityProof,
+    ) -> Result<(), ZkGroupError> {
+        proof.proof.verify(
+            self.uid_enc_public_key,
+            ciphertext.ciphertext,
+            other_params.uid_enc_public_key,
+            other_ciphertext.ciphertext,
+        )
+    }
+
+    // Checks that ciphertext and the randomized entry, both under these
+    // params, hold the same UID; the caller picks the entry, e.g. the one at
+    // the index the client names
+    pub fn verify_uuid_ciphertext_match(
+        &self,
+        ciphertext: api::groups::UuidCiphertext,
+        entry: api::groups::RandomizedUuidCiphertext,
+        proof: &api::groups::UuidCiphertextMatchProof,
+    ) -> Result<(), ZkGroupError> {
+        proof.proof.verify(
+            self.uid_enc_public_key,
+            ciphertext.ciphertext,
+            entry.ciphertext,
+        )
+    }
 }
 
 #[cfg(test)]
@@ -264,13 +647,13 @@ mod tests {
         ];
 
         let calc_ciphertext = group_secret_params
-            .encrypt_blob_aesgcmsiv(&key_vec, &nonce_vec, &plaintext_vec)
+            .encrypt_blob_aesgcmsiv(&key_vec, &nonce_vec, &plaintext_vec, &[])
             .unwrap();
 
         assert!(&calc_ciphertext[..ciphertext_vec.len()] == &ciphertext_vec[..]);
 
         let calc_plaintext = group_secret_params
-            .decrypt_blob_aesgcmsiv(&key_vec, &nonce_vec, &calc_ciphertext)
+            .decrypt_blob_aesgcmsiv(&key_vec, &nonce_vec, &calc_ciphertext, &[])
             .unwrap();
         assert!(&calc_plaintext[..] == &plaintext_vec[..]);
     }
@@ -305,13 +688,13 @@ mod tests {
         ];
 
         let calc_ciphertext = group_secret_params
-            .encrypt_blob_aesgcmsiv(&key_vec, &nonce_vec, &plaintext_vec)
+            .encrypt_blob_aesgcmsiv(&key_vec, &nonce_vec, &plaintext_vec, &[])
             .unwrap();
 
         assert!(&calc_ciphertext[..ciphertext_vec.len()] == &ciphertext_vec[..]);
 
         let calc_plaintext = group_secret_params
-            .decrypt_blob_aesgcmsiv(&key_vec, &nonce_vec, &calc_ciphertext)
+            .decrypt_blob_aesgcmsiv(&key_vec, &nonce_vec, &calc_ciphertext, &[])
             .unwrap();
         assert!(&calc_plaintext[..] == &plaintext_vec[..]);
     }
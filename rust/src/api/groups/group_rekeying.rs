@@ -0,0 +1,134 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+
+#![allow(non_snake_case)]
+
+use crate::api;
+use crate::common::errors::*;
+use crate::common::simple_types::*;
+
+// Moves a group's ciphertexts from one GroupMasterKey to another.  Each
+// ciphertext is decrypted under the old params and encrypted again under the
+// new ones, so a member who only learns the new master key can't read
+// anything encrypted under the old one unless it has been re-keyed.
+pub struct GroupRekeying {
+    old_params: api::groups::GroupSecretParams,
+    new_params: api::groups::GroupSecretParams,
+}
+
+impl GroupRekeying {
+    pub fn new(
+        old_params: api::groups::GroupSecretParams,
+        new_params: api::groups::GroupSecretParams,
+    ) -> Self {
+        Self {
+            old_params,
+            new_params,
+        }
+    }
+
+    pub fn get_old_params(&self) -> &api::groups::GroupSecretParams {
+        &self.old_params
+    }
+
+    pub fn get_new_params(&self) -> &api::groups::GroupSecretParams {
+        &self.new_params
+    }
+
+    // Might return DecryptionFailure if the ciphertext isn't under the old
+    // params
+    pub fn rekey_uuid(
+        &self,
+        ciphertext: api::groups::UuidCiphertext,
+    ) -> Result<api::groups::UuidCiphertext, ZkGroupError> {
+        let uid = self
+            .old_params
+            .uid_enc_key_pair
+            .decrypt(ciphertext.ciphertext)?;
+        Ok(self.new_params.encrypt_uid_struct(uid))
+    }
+
+    pub fn rekey_uuids(
+        &self,
+        ciphertexts: &[api::groups::UuidCiphertext],
+    ) -> Result<Vec<api::groups::UuidCiphertext>, ZkGroupError> {
+        ciphertexts
+            .iter()
+            .map(|ciphertext| self.rekey_uuid(*ciphertext))
+            .collect()
+    }
+
+    // Also returns a proof that the new ciphertext holds the same UID as the
+    // old one, which anyone with both groups' GroupPublicParams can check
+    // using GroupPublicParams::verify_uuid_ciphertext_equality
+    pub fn rekey_uuid_with_proof(
+        &self,
+        randomness: RandomnessBytes,
+        ciphertext: api::groups::UuidCiphertext,
+    ) -> Result<
+        (
+            api::groups::UuidCiphertext,
+            api::groups::UuidCiphertextEqualityProof,
+        ),
+        ZkGroupError,
+    > {
+        let new_ciphertext = self.rekey_uuid(ciphertext)?;
+        let proof = self
+            .old_params
+            .create_uuid_ciphertext_equality_proof_across_groups(
+                randomness,
+                ciphertext,
+                &self.new_params,
+                new_ciphertext,
+            )?;
+        Ok((new_ciphertext, proof))
+    }
+
+    // Profile key ciphertexts are bound to their member's UID, which must be
+    // supplied (e.g. from decrypting the member's UuidCiphertext)
+    pub fn rekey_profile_key(
+        &self,
+        ciphertext: api::groups::ProfileKeyCiphertext,
+        uid_bytes: UidBytes,
+    ) -> Result<api::groups::ProfileKeyCiphertext, ZkGroupError> {
+        let profile_key = self.old_params.decrypt_profile_key(ciphertext, uid_bytes)?;
+        Ok(self.new_params.encrypt_profile_key(&profile_key, uid_bytes))
+    }
+
+    // The blob is re-encrypted with encrypt_blob_with_ad, so any padding is
+    // dropped; use rekey_blob_with_padding to keep blobs padded
+    pub fn rekey_blob(
+        &self,
+        randomness: RandomnessBytes,
+        ciphertext: &[u8],
+        associated_data: &[u8],
+    ) -> Result<Vec<u8>, ZkGroupError> {
+        let plaintext = self
+            .old_params
+            .decrypt_blob_with_ad(ciphertext, associated_data)?;
+        self.new_params
+            .encrypt_blob_with_ad(randomness, &plaintext, associated_data)
+    }
+
+    pub fn rekey_blob_with_padding(
+        &self,
+        randomness: RandomnessBytes,
+        ciphertext: &[u8],
+        padding_policy: &api::groups::BlobPaddingPolicy,
+        associated_data: &[u8],
+    ) -> Result<Vec<u8>, ZkGroupError> {
+        let plaintext = self
+            .old_params
+            .decrypt_blob_with_ad(ciphertext, associated_data)?;
+        self.new_params.encrypt_blob_with_padding_and_ad(
+            randomness,
+            &plaintext,
+            padding_policy,
+            associated_data,
+        )
+    }
+}
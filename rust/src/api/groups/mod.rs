@@ -5,12 +5,31 @@
 // SPDX-License-Identifier: GPL-3.0-only
 //
 
+mod blob_padding;
+mod blob_stream;
+pub mod group_invite_link_password;
+pub mod group_invite_link_password_commitment;
+pub mod group_invite_link_presentation;
 pub mod group_params;
+pub mod group_rekeying;
+pub mod pending_member_invitation;
 pub mod profile_key_ciphertext;
+pub mod randomized_uuid_ciphertext;
 pub mod uuid_ciphertext;
+pub mod uuid_ciphertext_equality_proof;
+pub mod uuid_ciphertext_match_proof;
 
+pub use blob_padding::BlobPaddingPolicy;
+pub use group_invite_link_password::GroupInviteLinkPassword;
+pub use group_invite_link_password_commitment::GroupInviteLinkPasswordCommitment;
+pub use group_invite_link_presentation::GroupInviteLinkPresentation;
 pub use group_params::GroupMasterKey;
 pub use group_params::GroupPublicParams;
 pub use group_params::GroupSecretParams;
+pub use group_rekeying::GroupRekeying;
+pub use pending_member_invitation::PendingMemberInvitation;
 pub use profile_key_ciphertext::ProfileKeyCiphertext;
+pub use randomized_uuid_ciphertext::RandomizedUuidCiphertext;
 pub use uuid_ciphertext::UuidCiphertext;
+pub use uuid_ciphertext_equality_proof::UuidCiphertextEqualityProof;
+pub use uuid_ciphertext_match_proof::UuidCiphertextMatchProof;
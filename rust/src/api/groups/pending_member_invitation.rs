@@ -0,0 +1,58 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+
+#![allow(non_snake_case)]
+
+use crate::api;
+use crate::common::errors::*;
+use crate::common::serialization::{Validate, Versioned};
+use crate::common::simple_types::*;
+use crate::crypto;
+use serde::{Deserialize, Serialize};
+
+#[derive(Copy, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serde_human_readable", serde(remote = "Self"))]
+pub struct PendingMemberInvitation {
+    pub(crate) version: VersionByte,
+    pub(crate) uid_enc_ciphertext: crypto::uid_encryption::Ciphertext,
+    pub(crate) inviter_uid_enc_ciphertext: crypto::uid_encryption::Ciphertext,
+}
+
+human_readable_serde!(PendingMemberInvitation);
+
+impl Versioned for PendingMemberInvitation {
+    const VERSION: VersionByte = 0;
+}
+
+impl Validate for PendingMemberInvitation {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        self.uid_enc_ciphertext.validate()?;
+        self.inviter_uid_enc_ciphertext.validate()
+    }
+}
+
+impl PendingMemberInvitation {
+    pub fn get_uuid_ciphertext(&self) -> api::groups::UuidCiphertext {
+        api::groups::UuidCiphertext {
+            version: api::groups::UuidCiphertext::VERSION,
+            ciphertext: self.uid_enc_ciphertext,
+        }
+    }
+
+    pub fn get_inviter_uuid_ciphertext(&self) -> api::groups::UuidCiphertext {
+        api::groups::UuidCiphertext {
+            version: api::groups::UuidCiphertext::VERSION,
+            ciphertext: self.inviter_uid_enc_ciphertext,
+        }
+    }
+
+    // UUID encryption is deterministic under a given GroupSecretParams, so
+    // the invitee's presentation matches iff the ciphertexts are equal
+    pub fn matches(&self, presentation: &api::profiles::ProfileKeyCredentialPresentation) -> bool {
+        self.uid_enc_ciphertext == presentation.uid_enc_ciphertext
+    }
+}
@@ -0,0 +1,35 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+
+#![allow(non_snake_case)]
+
+use crate::common::errors::*;
+use crate::common::serialization::{Validate, Versioned};
+use crate::common::simple_types::*;
+use crate::crypto;
+use serde::{Deserialize, Serialize};
+
+// Stored in place of a UuidCiphertext where entries mustn't be linkable across
+// group revisions; see GroupSecretParams::encrypt_uuid_randomized
+#[derive(Copy, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serde_human_readable", serde(remote = "Self"))]
+pub struct RandomizedUuidCiphertext {
+    pub(crate) version: VersionByte,
+    pub(crate) ciphertext: crypto::uid_encryption::RandomizedCiphertext,
+}
+
+human_readable_serde!(RandomizedUuidCiphertext);
+
+impl Versioned for RandomizedUuidCiphertext {
+    const VERSION: VersionByte = 0;
+}
+
+impl Validate for RandomizedUuidCiphertext {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        self.ciphertext.validate()
+    }
+}
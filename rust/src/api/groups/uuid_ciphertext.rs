@@ -7,12 +7,27 @@
 
 #![allow(non_snake_case)]
 
+use crate::common::errors::*;
+use crate::common::serialization::{Validate, Versioned};
 use crate::common::simple_types::*;
 use crate::crypto;
 use serde::{Deserialize, Serialize};
 
 #[derive(Copy, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serde_human_readable", serde(remote = "Self"))]
 pub struct UuidCiphertext {
-    pub(crate) reserved: ReservedBytes,
+    pub(crate) version: VersionByte,
     pub(crate) ciphertext: crypto::uid_encryption::Ciphertext,
 }
+
+human_readable_serde!(UuidCiphertext);
+
+impl Versioned for UuidCiphertext {
+    const VERSION: VersionByte = 0;
+}
+
+impl Validate for UuidCiphertext {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        self.ciphertext.validate()
+    }
+}
@@ -0,0 +1,33 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+
+#![allow(non_snake_case)]
+
+use crate::common::errors::*;
+use crate::common::serialization::{Validate, Versioned};
+use crate::common::simple_types::*;
+use crate::crypto;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde_human_readable", serde(remote = "Self"))]
+pub struct UuidCiphertextMatchProof {
+    pub(crate) version: VersionByte,
+    pub(crate) proof: crypto::proofs::UidEncryptionMatchProof,
+}
+
+human_readable_serde!(UuidCiphertextMatchProof);
+
+impl Versioned for UuidCiphertextMatchProof {
+    const VERSION: VersionByte = 0;
+}
+
+impl Validate for UuidCiphertextMatchProof {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        self.proof.validate()
+    }
+}
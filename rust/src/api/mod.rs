@@ -6,10 +6,14 @@
 //
 
 pub mod auth;
+pub mod challenges;
 pub mod groups;
 pub mod profiles;
+pub mod receipts;
 
+pub mod server_keyring;
 pub mod server_params;
 
+pub use server_keyring::ServerSecretParamsKeyring;
 pub use server_params::ServerPublicParams;
 pub use server_params::ServerSecretParams;
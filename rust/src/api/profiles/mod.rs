@@ -12,6 +12,7 @@ pub mod profile_key_credential_presentation;
 pub mod profile_key_credential_request;
 pub mod profile_key_credential_request_context;
 pub mod profile_key_credential_response;
+pub mod profile_key_credential_selective_presentation;
 pub mod profile_key_version;
 
 pub use profile_key::ProfileKey;
@@ -21,4 +22,5 @@ pub use profile_key_credential_presentation::ProfileKeyCredentialPresentation;
 pub use profile_key_credential_request::ProfileKeyCredentialRequest;
 pub use profile_key_credential_request_context::ProfileKeyCredentialRequestContext;
 pub use profile_key_credential_response::ProfileKeyCredentialResponse;
+pub use profile_key_credential_selective_presentation::ProfileKeyCredentialSelectivePresentation;
 pub use profile_key_version::ProfileKeyVersion;
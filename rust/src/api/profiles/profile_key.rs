@@ -9,16 +9,27 @@
 
 use crate::api;
 use crate::common::constants::*;
+use crate::common::serialization::Versioned;
 use crate::common::sho::*;
 use crate::common::simple_types::*;
 use crate::crypto;
 use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
 
-#[derive(Copy, Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde_human_readable", serde(remote = "Self"))]
 pub struct ProfileKey {
     pub bytes: ProfileKeyBytes,
 }
 
+human_readable_serde!(unversioned ProfileKey);
+
+impl Drop for ProfileKey {
+    fn drop(&mut self) {
+        self.bytes.zeroize();
+    }
+}
+
 impl ProfileKey {
     pub fn generate(randomness: RandomnessBytes) -> Self {
         let mut sho = Sho::new(
@@ -41,9 +52,9 @@ impl ProfileKey {
     pub fn get_commitment(&self, uid_bytes: UidBytes) -> api::profiles::ProfileKeyCommitment {
         let profile_key = crypto::profile_key_struct::ProfileKeyStruct::new(self.bytes, uid_bytes);
         let commitment =
-            crypto::profile_key_commitment::CommitmentWithSecretNonce::new(profile_key, uid_bytes);
+            crypto::profile_key_commitment::CommitmentWithSecretNonce::new(&profile_key, uid_bytes);
         api::profiles::ProfileKeyCommitment {
-            reserved: Default::default(),
+            version: api::profiles::ProfileKeyCommitment::VERSION,
             commitment: commitment.get_profile_key_commitment(),
         }
     }
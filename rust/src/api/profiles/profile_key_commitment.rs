@@ -7,12 +7,27 @@
 
 #![allow(non_snake_case)]
 
+use crate::common::errors::*;
+use crate::common::serialization::{Validate, Versioned};
 use crate::common::simple_types::*;
 use crate::crypto;
 use serde::{Deserialize, Serialize};
 
 #[derive(Copy, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde_human_readable", serde(remote = "Self"))]
 pub struct ProfileKeyCommitment {
-    pub(crate) reserved: ReservedBytes,
+    pub(crate) version: VersionByte,
     pub(crate) commitment: crypto::profile_key_commitment::Commitment,
 }
+
+human_readable_serde!(ProfileKeyCommitment);
+
+impl Versioned for ProfileKeyCommitment {
+    const VERSION: VersionByte = 0;
+}
+
+impl Validate for ProfileKeyCommitment {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        self.commitment.validate()
+    }
+}
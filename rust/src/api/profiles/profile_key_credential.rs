@@ -7,14 +7,29 @@
 
 #![allow(non_snake_case)]
 
+use crate::common::errors::*;
+use crate::common::serialization::{Validate, Versioned};
 use crate::common::simple_types::*;
 use crate::crypto;
 use serde::{Deserialize, Serialize};
 
 #[derive(Copy, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde_human_readable", serde(remote = "Self"))]
 pub struct ProfileKeyCredential {
-    pub(crate) reserved: ReservedBytes,
+    pub(crate) version: VersionByte,
     pub(crate) credential: crypto::credentials::ProfileKeyCredential,
     pub(crate) uid_bytes: UidBytes,
     pub(crate) profile_key_bytes: ProfileKeyBytes,
 }
+
+human_readable_serde!(ProfileKeyCredential);
+
+impl Versioned for ProfileKeyCredential {
+    const VERSION: VersionByte = 0;
+}
+
+impl Validate for ProfileKeyCredential {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        self.credential.validate()
+    }
+}
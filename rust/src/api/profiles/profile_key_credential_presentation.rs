@@ -8,30 +8,60 @@
 #![allow(non_snake_case)]
 
 use crate::api;
+use crate::common::errors::*;
+use crate::common::serialization::{deserialize_unversioned, KeyIdVersion, Validate, Versioned};
 use crate::common::simple_types::*;
 use crate::crypto;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "serde_human_readable", serde(remote = "Self"))]
 pub struct ProfileKeyCredentialPresentation {
-    pub(crate) reserved: ReservedBytes,
+    pub(crate) version: KeyIdVersion,
     pub(crate) proof: crypto::proofs::ProfileKeyCredentialPresentationProof,
     pub(crate) uid_enc_ciphertext: crypto::uid_encryption::Ciphertext,
     pub(crate) profile_key_enc_ciphertext: crypto::profile_key_encryption::Ciphertext,
 }
 
+human_readable_serde!(ProfileKeyCredentialPresentation);
+
+impl Versioned for ProfileKeyCredentialPresentation {
+    const VERSION: VersionByte = KeyIdVersion::NEWEST;
+
+    fn is_supported_version(version: VersionByte) -> bool {
+        version <= Self::VERSION
+    }
+
+    // KeyIdVersion reads the baseline layout too
+    fn deserialize_old_version(_version: VersionByte, bytes: &[u8]) -> Result<Self, ZkGroupError> {
+        deserialize_unversioned(bytes)
+    }
+}
+
+impl Validate for ProfileKeyCredentialPresentation {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        self.proof.validate()?;
+        self.uid_enc_ciphertext.validate()?;
+        self.profile_key_enc_ciphertext.validate()
+    }
+}
+
 impl ProfileKeyCredentialPresentation {
     pub fn get_uuid_ciphertext(&self) -> api::groups::UuidCiphertext {
         api::groups::UuidCiphertext {
-            reserved: Default::default(),
+            version: api::groups::UuidCiphertext::VERSION,
             ciphertext: self.uid_enc_ciphertext,
         }
     }
 
     pub fn get_profile_key_ciphertext(&self) -> api::groups::ProfileKeyCiphertext {
         api::groups::ProfileKeyCiphertext {
-            reserved: Default::default(),
+            version: api::groups::ProfileKeyCiphertext::VERSION,
             ciphertext: self.profile_key_enc_ciphertext,
         }
     }
+
+    pub fn get_key_id(&self) -> ServerKeyId {
+        self.version.key_id
+    }
 }
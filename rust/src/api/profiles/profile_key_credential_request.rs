@@ -7,14 +7,31 @@
 
 #![allow(non_snake_case)]
 
+use crate::common::errors::*;
+use crate::common::serialization::{Validate, Versioned};
 use crate::common::simple_types::*;
 use crate::crypto;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "serde_human_readable", serde(remote = "Self"))]
 pub struct ProfileKeyCredentialRequest {
-    pub(crate) reserved: ReservedBytes,
+    pub(crate) version: VersionByte,
     pub(crate) public_key: crypto::profile_key_credential_request::PublicKey,
     pub(crate) ciphertext: crypto::profile_key_credential_request::Ciphertext,
     pub(crate) proof: crypto::proofs::ProfileKeyCredentialRequestProof,
 }
+
+human_readable_serde!(ProfileKeyCredentialRequest);
+
+impl Versioned for ProfileKeyCredentialRequest {
+    const VERSION: VersionByte = 0;
+}
+
+impl Validate for ProfileKeyCredentialRequest {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        self.public_key.validate()?;
+        self.ciphertext.validate()?;
+        self.proof.validate()
+    }
+}
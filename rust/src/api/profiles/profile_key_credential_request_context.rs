@@ -8,13 +8,16 @@
 #![allow(non_snake_case)]
 
 use crate::api;
+use crate::common::errors::*;
+use crate::common::serialization::{Validate, Versioned};
 use crate::common::simple_types::*;
 use crate::crypto;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "serde_human_readable", serde(remote = "Self"))]
 pub struct ProfileKeyCredentialRequestContext {
-    pub(crate) reserved: ReservedBytes,
+    pub(crate) version: VersionByte,
     pub(crate) uid_bytes: UidBytes,
     pub(crate) profile_key_bytes: ProfileKeyBytes,
     pub(crate) key_pair: crypto::profile_key_credential_request::KeyPair,
@@ -23,12 +26,26 @@ pub struct ProfileKeyCredentialRequestContext {
     pub(crate) proof: crypto::proofs::ProfileKeyCredentialRequestProof,
 }
 
+human_readable_serde!(ProfileKeyCredentialRequestContext);
+
+impl Versioned for ProfileKeyCredentialRequestContext {
+    const VERSION: VersionByte = 0;
+}
+
+impl Validate for ProfileKeyCredentialRequestContext {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        self.key_pair.validate()?;
+        self.ciphertext_with_secret_nonce.validate()?;
+        self.proof.validate()
+    }
+}
+
 impl ProfileKeyCredentialRequestContext {
     pub fn get_request(&self) -> api::profiles::ProfileKeyCredentialRequest {
         let ciphertext = self.ciphertext_with_secret_nonce.get_ciphertext();
         let public_key = self.key_pair.get_public_key();
         api::profiles::ProfileKeyCredentialRequest {
-            reserved: Default::default(),
+            version: api::profiles::ProfileKeyCredentialRequest::VERSION,
             public_key,
             ciphertext,
             proof: self.proof.clone(),
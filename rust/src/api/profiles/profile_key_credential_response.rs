@@ -7,13 +7,38 @@
 
 #![allow(non_snake_case)]
 
+use crate::common::errors::*;
+use crate::common::serialization::{deserialize_unversioned, KeyIdVersion, Validate, Versioned};
 use crate::common::simple_types::*;
 use crate::crypto;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "serde_human_readable", serde(remote = "Self"))]
 pub struct ProfileKeyCredentialResponse {
-    pub(crate) reserved: ReservedBytes,
+    pub(crate) version: KeyIdVersion,
     pub(crate) blinded_credential: crypto::credentials::BlindedProfileKeyCredential,
     pub(crate) proof: crypto::proofs::ProfileKeyCredentialIssuanceProof,
 }
+
+human_readable_serde!(ProfileKeyCredentialResponse);
+
+impl Versioned for ProfileKeyCredentialResponse {
+    const VERSION: VersionByte = KeyIdVersion::NEWEST;
+
+    fn is_supported_version(version: VersionByte) -> bool {
+        version <= Self::VERSION
+    }
+
+    // KeyIdVersion reads the baseline layout too
+    fn deserialize_old_version(_version: VersionByte, bytes: &[u8]) -> Result<Self, ZkGroupError> {
+        deserialize_unversioned(bytes)
+    }
+}
+
+impl Validate for ProfileKeyCredentialResponse {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        self.blinded_credential.validate()?;
+        self.proof.validate()
+    }
+}
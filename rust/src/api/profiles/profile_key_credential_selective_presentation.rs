@@ -0,0 +1,52 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+
+#![allow(non_snake_case)]
+
+use crate::api;
+use crate::common::errors::*;
+use crate::common::serialization::{Validate, Versioned};
+use crate::common::simple_types::*;
+use crate::crypto;
+use serde::{Deserialize, Serialize};
+
+// Presents a profile key credential revealing only the UUID ciphertext; the
+// holder proves they have a profile key, without encrypting it for the verifier
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "serde_human_readable", serde(remote = "Self"))]
+pub struct ProfileKeyCredentialSelectivePresentation {
+    pub(crate) version: VersionByte,
+    pub(crate) key_id: ServerKeyId,
+    pub(crate) proof: crypto::proofs::ProfileKeyCredentialSelectivePresentationProof,
+    pub(crate) uid_enc_ciphertext: crypto::uid_encryption::Ciphertext,
+}
+
+human_readable_serde!(ProfileKeyCredentialSelectivePresentation);
+
+impl Versioned for ProfileKeyCredentialSelectivePresentation {
+    const VERSION: VersionByte = 0;
+}
+
+impl Validate for ProfileKeyCredentialSelectivePresentation {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        self.proof.validate()?;
+        self.uid_enc_ciphertext.validate()
+    }
+}
+
+impl ProfileKeyCredentialSelectivePresentation {
+    pub fn get_uuid_ciphertext(&self) -> api::groups::UuidCiphertext {
+        api::groups::UuidCiphertext {
+            version: api::groups::UuidCiphertext::VERSION,
+            ciphertext: self.uid_enc_ciphertext,
+        }
+    }
+
+    pub fn get_key_id(&self) -> ServerKeyId {
+        self.key_id
+    }
+}
@@ -7,9 +7,12 @@
 
 #![allow(non_snake_case)]
 
+use crate::common::constants::*;
 use crate::common::simple_types::*;
+use serde::de::{self, SeqAccess, Visitor};
 use serde::ser::SerializeTuple;
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
 
 #[derive(Copy, Clone)]
 pub struct ProfileKeyVersion {
@@ -21,10 +24,85 @@ impl Serialize for ProfileKeyVersion {
     where
         S: Serializer,
     {
-        let mut seq = serializer.serialize_tuple(self.bytes.len()).unwrap();
+        // The bytes are already a hex string
+        #[cfg(feature = "serde_human_readable")]
+        {
+            if serializer.is_human_readable() {
+                // Always ASCII, see ProfileKey::get_profile_key_version
+                let hex_string =
+                    std::str::from_utf8(&self.bytes).map_err(serde::ser::Error::custom)?;
+                return serializer.serialize_str(hex_string);
+            }
+        }
+        let mut seq = serializer.serialize_tuple(self.bytes.len())?;
         for b in self.bytes.iter() {
             seq.serialize_element(b)?;
         }
         seq.end()
     }
 }
+
+impl<'de> Deserialize<'de> for ProfileKeyVersion {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[cfg(feature = "serde_human_readable")]
+        {
+            if deserializer.is_human_readable() {
+                let hex_string = String::deserialize(deserializer)?;
+                return ProfileKeyVersion::from_encoded(hex_string.as_bytes()).ok_or_else(|| {
+                    de::Error::invalid_value(
+                        de::Unexpected::Str(&hex_string),
+                        &ProfileKeyVersionVisitor,
+                    )
+                });
+            }
+        }
+        deserializer.deserialize_tuple(PROFILE_KEY_VERSION_ENCODED_LEN, ProfileKeyVersionVisitor)
+    }
+}
+
+struct ProfileKeyVersionVisitor;
+
+impl<'de> Visitor<'de> for ProfileKeyVersionVisitor {
+    type Value = ProfileKeyVersion;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "{} lowercase hex digits",
+            PROFILE_KEY_VERSION_ENCODED_LEN
+        )
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut bytes = [0u8; PROFILE_KEY_VERSION_ENCODED_LEN];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+        ProfileKeyVersion::from_encoded(&bytes)
+            .ok_or_else(|| de::Error::invalid_value(de::Unexpected::Bytes(&bytes), &self))
+    }
+}
+
+impl ProfileKeyVersion {
+    // None unless the bytes are what get_profile_key_version produces
+    fn from_encoded(encoded: &[u8]) -> Option<Self> {
+        if encoded.len() != PROFILE_KEY_VERSION_ENCODED_LEN
+            || !encoded
+                .iter()
+                .all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(b))
+        {
+            return None;
+        }
+        let mut bytes = [0u8; PROFILE_KEY_VERSION_ENCODED_LEN];
+        bytes.copy_from_slice(encoded);
+        Some(ProfileKeyVersion { bytes })
+    }
+}
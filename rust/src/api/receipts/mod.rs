@@ -0,0 +1,18 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+
+pub mod receipt_credential;
+pub mod receipt_credential_presentation;
+pub mod receipt_credential_request;
+pub mod receipt_credential_request_context;
+pub mod receipt_credential_response;
+
+pub use receipt_credential::ReceiptCredential;
+pub use receipt_credential_presentation::ReceiptCredentialPresentation;
+pub use receipt_credential_request::ReceiptCredentialRequest;
+pub use receipt_credential_request_context::ReceiptCredentialRequestContext;
+pub use receipt_credential_response::ReceiptCredentialResponse;
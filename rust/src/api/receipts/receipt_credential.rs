@@ -0,0 +1,46 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+
+#![allow(non_snake_case)]
+
+use crate::common::errors::*;
+use crate::common::serialization::{Validate, Versioned};
+use crate::common::simple_types::*;
+use crate::crypto;
+use serde::{Deserialize, Serialize};
+
+#[derive(Copy, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde_human_readable", serde(remote = "Self"))]
+pub struct ReceiptCredential {
+    pub(crate) version: VersionByte,
+    pub(crate) credential: crypto::credentials::ReceiptCredential,
+    pub(crate) receipt_serial_bytes: ReceiptSerialBytes,
+    pub(crate) receipt_expiration_time: ReceiptExpirationTime,
+    pub(crate) receipt_level: ReceiptLevel,
+}
+
+human_readable_serde!(ReceiptCredential);
+
+impl Versioned for ReceiptCredential {
+    const VERSION: VersionByte = 0;
+}
+
+impl Validate for ReceiptCredential {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        self.credential.validate()
+    }
+}
+
+impl ReceiptCredential {
+    pub fn get_receipt_expiration_time(&self) -> ReceiptExpirationTime {
+        self.receipt_expiration_time
+    }
+
+    pub fn get_receipt_level(&self) -> ReceiptLevel {
+        self.receipt_level
+    }
+}
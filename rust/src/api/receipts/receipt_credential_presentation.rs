@@ -0,0 +1,56 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+
+#![allow(non_snake_case)]
+
+use crate::common::errors::*;
+use crate::common::serialization::{Validate, Versioned};
+use crate::common::simple_types::*;
+use crate::crypto;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "serde_human_readable", serde(remote = "Self"))]
+pub struct ReceiptCredentialPresentation {
+    pub(crate) version: VersionByte,
+    pub(crate) key_id: ServerKeyId,
+    pub(crate) proof: crypto::proofs::ReceiptCredentialPresentationProof,
+    pub(crate) receipt_expiration_time: ReceiptExpirationTime,
+    pub(crate) receipt_level: ReceiptLevel,
+    pub(crate) receipt_serial_bytes: ReceiptSerialBytes,
+}
+
+human_readable_serde!(ReceiptCredentialPresentation);
+
+impl Versioned for ReceiptCredentialPresentation {
+    const VERSION: VersionByte = 0;
+}
+
+impl Validate for ReceiptCredentialPresentation {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        self.proof.validate()
+    }
+}
+
+impl ReceiptCredentialPresentation {
+    pub fn get_receipt_expiration_time(&self) -> ReceiptExpirationTime {
+        self.receipt_expiration_time
+    }
+
+    pub fn get_receipt_level(&self) -> ReceiptLevel {
+        self.receipt_level
+    }
+
+    // The server records this to stop a receipt being redeemed twice
+    pub fn get_receipt_serial_bytes(&self) -> ReceiptSerialBytes {
+        self.receipt_serial_bytes
+    }
+
+    pub fn get_key_id(&self) -> ServerKeyId {
+        self.key_id
+    }
+}
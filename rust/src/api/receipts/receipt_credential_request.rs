@@ -0,0 +1,37 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+
+#![allow(non_snake_case)]
+
+use crate::common::errors::*;
+use crate::common::serialization::{Validate, Versioned};
+use crate::common::simple_types::*;
+use crate::crypto;
+use serde::{Deserialize, Serialize};
+
+// Carries no proof: the serial is only ever revealed at presentation, so
+// there is nothing for the server to check it against at issuance
+#[derive(Copy, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde_human_readable", serde(remote = "Self"))]
+pub struct ReceiptCredentialRequest {
+    pub(crate) version: VersionByte,
+    pub(crate) public_key: crypto::receipt_credential_request::PublicKey,
+    pub(crate) ciphertext: crypto::receipt_credential_request::Ciphertext,
+}
+
+human_readable_serde!(ReceiptCredentialRequest);
+
+impl Versioned for ReceiptCredentialRequest {
+    const VERSION: VersionByte = 0;
+}
+
+impl Validate for ReceiptCredentialRequest {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        self.public_key.validate()?;
+        self.ciphertext.validate()
+    }
+}
@@ -0,0 +1,50 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+
+#![allow(non_snake_case)]
+
+use crate::api;
+use crate::common::errors::*;
+use crate::common::serialization::{Validate, Versioned};
+use crate::common::simple_types::*;
+use crate::crypto;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "serde_human_readable", serde(remote = "Self"))]
+pub struct ReceiptCredentialRequestContext {
+    pub(crate) version: VersionByte,
+    pub(crate) receipt_serial_bytes: ReceiptSerialBytes,
+    pub(crate) key_pair: crypto::receipt_credential_request::KeyPair,
+    pub(crate) ciphertext_with_secret_nonce:
+        crypto::receipt_credential_request::CiphertextWithSecretNonce,
+}
+
+human_readable_serde!(ReceiptCredentialRequestContext);
+
+impl Versioned for ReceiptCredentialRequestContext {
+    const VERSION: VersionByte = 0;
+}
+
+impl Validate for ReceiptCredentialRequestContext {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        self.key_pair.validate()?;
+        self.ciphertext_with_secret_nonce.validate()
+    }
+}
+
+impl ReceiptCredentialRequestContext {
+    pub fn get_request(&self) -> api::receipts::ReceiptCredentialRequest {
+        let ciphertext = self.ciphertext_with_secret_nonce.get_ciphertext();
+        let public_key = self.key_pair.get_public_key();
+        api::receipts::ReceiptCredentialRequest {
+            version: api::receipts::ReceiptCredentialRequest::VERSION,
+            public_key,
+            ciphertext,
+        }
+    }
+}
@@ -0,0 +1,38 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+
+#![allow(non_snake_case)]
+
+use crate::common::errors::*;
+use crate::common::serialization::{Validate, Versioned};
+use crate::common::simple_types::*;
+use crate::crypto;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "serde_human_readable", serde(remote = "Self"))]
+pub struct ReceiptCredentialResponse {
+    pub(crate) version: VersionByte,
+    pub(crate) key_id: ServerKeyId,
+    pub(crate) receipt_expiration_time: ReceiptExpirationTime,
+    pub(crate) receipt_level: ReceiptLevel,
+    pub(crate) blinded_credential: crypto::credentials::BlindedReceiptCredential,
+    pub(crate) proof: crypto::proofs::ReceiptCredentialIssuanceProof,
+}
+
+human_readable_serde!(ReceiptCredentialResponse);
+
+impl Versioned for ReceiptCredentialResponse {
+    const VERSION: VersionByte = 0;
+}
+
+impl Validate for ReceiptCredentialResponse {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        self.blinded_credential.validate()?;
+        self.proof.validate()
+    }
+}
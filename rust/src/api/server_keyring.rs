@@ -0,0 +1,256 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+
+#![allow(non_snake_case)]
+
+use crate::api;
+use crate::common::errors::*;
+use crate::common::simple_types::*;
+
+// Holds the current ServerSecretParams plus a bounded number of previous
+// ones.  New credentials are always issued under the current params, while
+// presentations are verified under whichever params their key id names, so
+// clients holding credentials from before a rotation keep working until the
+// old params are evicted.
+pub struct ServerSecretParamsKeyring {
+    current: api::ServerSecretParams,
+    previous: Vec<api::ServerSecretParams>,
+    max_previous_keys: usize,
+}
+
+impl ServerSecretParamsKeyring {
+    pub fn new(current: api::ServerSecretParams, max_previous_keys: usize) -> Self {
+        Self {
+            current,
+            previous: Vec::new(),
+            max_previous_keys,
+        }
+    }
+
+    // Might return BadArgs if the key id is already in use by the keyring
+    pub fn rotate(&mut self, new_current: api::ServerSecretParams) -> Result<(), ZkGroupError> {
+        if self.get(new_current.key_id).is_some() {
+            return Err(ZkGroupError::BadArgs);
+        }
+        let old_current = std::mem::replace(&mut self.current, new_current);
+        self.previous.insert(0, old_current);
+        self.previous.truncate(self.max_previous_keys);
+        Ok(())
+    }
+
+    pub fn get_current(&self) -> &api::ServerSecretParams {
+        &self.current
+    }
+
+    pub fn get_public_params(&self) -> api::ServerPublicParams {
+        self.current.get_public_params()
+    }
+
+    pub fn get(&self, key_id: ServerKeyId) -> Option<&api::ServerSecretParams> {
+        if self.current.key_id == key_id {
+            return Some(&self.current);
+        }
+        self.previous.iter().find(|params| params.key_id == key_id)
+    }
+
+    pub fn issue_auth_credential(
+        &self,
+        randomness: RandomnessBytes,
+        uid_bytes: UidBytes,
+        redemption_time: RedemptionTime,
+    ) -> Result<api::auth::AuthCredentialResponse, ZkGroupError> {
+        self.current
+            .issue_auth_credential(randomness, uid_bytes, redemption_time)
+    }
+
+    pub fn issue_auth_credentials(
+        &self,
+        randomness: RandomnessBytes,
+        uid_bytes: UidBytes,
+        redemption_times: &[RedemptionTime],
+    ) -> Result<api::auth::AuthCredentialBatchResponse, ZkGroupError> {
+        self.current
+            .issue_auth_credentials(randomness, uid_bytes, redemption_times)
+    }
+
+    // The one credential per user per day must be enforced across the whole
+    // keyring, since each key issues credentials with unrelated tags
+    pub fn issue_rate_limit_credential(
+        &self,
+        randomness: RandomnessBytes,
+        request: &api::auth::RateLimitCredentialRequest,
+        redemption_time: RedemptionTime,
+    ) -> Result<api::auth::RateLimitCredentialResponse, ZkGroupError> {
+        self.current
+            .issue_rate_limit_credential(randomness, request, redemption_time)
+    }
+
+    pub fn issue_profile_key_credential(
+        &self,
+        randomness: RandomnessBytes,
+        request: &api::profiles::ProfileKeyCredentialRequest,
+        uid_bytes: UidBytes,
+        commitment: api::profiles::ProfileKeyCommitment,
+    ) -> Result<api::profiles::ProfileKeyCredentialResponse, ZkGroupError> {
+        self.current
+            .issue_profile_key_credential(randomness, request, uid_bytes, commitment)
+    }
+
+    pub fn issue_receipt_credential(
+        &self,
+        randomness: RandomnessBytes,
+        request: &api::receipts::ReceiptCredentialRequest,
+        receipt_expiration_time: ReceiptExpirationTime,
+        receipt_level: ReceiptLevel,
+    ) -> Result<api::receipts::ReceiptCredentialResponse, ZkGroupError> {
+        self.current.issue_receipt_credential(
+            randomness,
+            request,
+            receipt_expiration_time,
+            receipt_level,
+        )
+    }
+
+    // Might return KeyIdMismatch if the presentation's key is not (or no
+    // longer) in the keyring
+    pub fn verify_auth_credential_presentation(
+        &self,
+        group_public_params: api::groups::GroupPublicParams,
+        presentation: &api::auth::AuthCredentialPresentation,
+    ) -> Result<(), ZkGroupError> {
+        self.lookup(presentation.get_key_id())?
+            .verify_auth_credential_presentation(group_public_params, presentation)
+    }
+
+    pub fn verify_auth_credential_presentation_with_context(
+        &self,
+        group_public_params: api::groups::GroupPublicParams,
+        presentation: &api::auth::AuthCredentialPresentation,
+        context: &[u8],
+    ) -> Result<(), ZkGroupError> {
+        self.lookup(presentation.get_key_id())?
+            .verify_auth_credential_presentation_with_context(
+                group_public_params,
+                presentation,
+                context,
+            )
+    }
+
+    pub fn verify_auth_credential_presentation_with_time(
+        &self,
+        group_public_params: api::groups::GroupPublicParams,
+        presentation: &api::auth::AuthCredentialPresentation,
+        current_time_in_seconds: u64,
+        redemption_window: api::auth::RedemptionWindow,
+    ) -> Result<(), ZkGroupError> {
+        self.lookup(presentation.get_key_id())?
+            .verify_auth_credential_presentation_with_time(
+                group_public_params,
+                presentation,
+                current_time_in_seconds,
+                redemption_window,
+            )
+    }
+
+    pub fn verify_auth_credential_presentation_with_time_and_context(
+        &self,
+        group_public_params: api::groups::GroupPublicParams,
+        presentation: &api::auth::AuthCredentialPresentation,
+        current_time_in_seconds: u64,
+        redemption_window: api::auth::RedemptionWindow,
+        context: &[u8],
+    ) -> Result<(), ZkGroupError> {
+        self.lookup(presentation.get_key_id())?
+            .verify_auth_credential_presentation_with_time_and_context(
+                group_public_params,
+                presentation,
+                current_time_in_seconds,
+                redemption_window,
+                context,
+            )
+    }
+
+    // The tag depends only on the client's secret, so tokens from a
+    // credential issued before a rotation are still counted together
+    pub fn verify_rate_limit_token(
+        &self,
+        token: &api::auth::RateLimitToken,
+    ) -> Result<(), ZkGroupError> {
+        self.lookup(token.key_id)?.verify_rate_limit_token(token)
+    }
+
+    pub fn verify_rate_limit_token_with_time(
+        &self,
+        token: &api::auth::RateLimitToken,
+        current_time_in_seconds: u64,
+        redemption_window: api::auth::RedemptionWindow,
+    ) -> Result<(), ZkGroupError> {
+        self.lookup(token.key_id)?
+            .verify_rate_limit_token_with_time(token, current_time_in_seconds, redemption_window)
+    }
+
+    pub fn verify_profile_key_credential_presentation(
+        &self,
+        group_public_params: api::groups::GroupPublicParams,
+        presentation: &api::profiles::ProfileKeyCredentialPresentation,
+    ) -> Result<(), ZkGroupError> {
+        self.lookup(presentation.get_key_id())?
+            .verify_profile_key_credential_presentation(group_public_params, presentation)
+    }
+
+    pub fn verify_profile_key_credential_presentation_with_context(
+        &self,
+        group_public_params: api::groups::GroupPublicParams,
+        presentation: &api::profiles::ProfileKeyCredentialPresentation,
+        context: &[u8],
+    ) -> Result<(), ZkGroupError> {
+        self.lookup(presentation.get_key_id())?
+            .verify_profile_key_credential_presentation_with_context(
+                group_public_params,
+                presentation,
+                context,
+            )
+    }
+
+    pub fn verify_profile_key_credential_selective_presentation(
+        &self,
+        group_public_params: api::groups::GroupPublicParams,
+        presentation: &api::profiles::ProfileKeyCredentialSelectivePresentation,
+    ) -> Result<(), ZkGroupError> {
+        self.lookup(presentation.key_id)?
+            .verify_profile_key_credential_selective_presentation(group_public_params, presentation)
+    }
+
+    pub fn verify_profile_key_credential_selective_presentation_with_context(
+        &self,
+        group_public_params: api::groups::GroupPublicParams,
+        presentation: &api::profiles::ProfileKeyCredentialSelectivePresentation,
+        context: &[u8],
+    ) -> Result<(), ZkGroupError> {
+        self.lookup(presentation.key_id)?
+            .verify_profile_key_credential_selective_presentation_with_context(
+                group_public_params,
+                presentation,
+                context,
+            )
+    }
+
+    pub fn verify_receipt_credential_presentation(
+        &self,
+        presentation: &api::receipts::ReceiptCredentialPresentation,
+    ) -> Result<(), ZkGroupError> {
+        self.lookup(presentation.key_id)?
+            .verify_receipt_credential_presentation(presentation)
+    }
+
+    fn lookup(&self, key_id: ServerKeyId) -> Result<&api::ServerSecretParams, ZkGroupError> {
+        match self.get(key_id) {
+            Some(params) => Ok(params),
+            None => Err(ZkGroupError::KeyIdMismatch),
+        }
+    }
+}
@@ -10,56 +10,432 @@
 use crate::api;
 use crate::common::constants::*;
 use crate::common::errors::*;
+use crate::common::serialization::{
+    deserialize_layout, deserialize_unversioned, next_layout_element, unknown_layout, KeyIdVersion,
+    Validate, Versioned, VersionedLayout,
+};
 use crate::common::sho::*;
 use crate::common::simple_types::*;
 use crate::crypto;
-use serde::{Deserialize, Serialize};
+use serde::de::{self, SeqAccess};
+use serde::ser;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-#[derive(Copy, Clone, Serialize, Deserialize)]
+// Not Copy: the key pairs are wiped when dropped, so there should be only one
+#[derive(Clone)]
 pub struct ServerSecretParams {
-    pub(crate) reserved: ReservedBytes,
+    pub(crate) key_id: ServerKeyId,
     pub(crate) auth_credentials_key_pair: crypto::credentials::KeyPair,
     pub(crate) profile_key_credentials_key_pair: crypto::credentials::KeyPair,
     sig_key_pair: crypto::signature::KeyPair,
+    // Missing from version 0 params until upgrade() adds them
+    pub(crate) receipt_credentials_key_pair: Option<crypto::credentials::KeyPair>,
+    pub(crate) rate_limit_credentials_key_pair: Option<crypto::credentials::KeyPair>,
 }
 
-#[derive(Copy, Clone, Serialize, Deserialize)]
+human_readable_serde!(binary ServerSecretParams);
+
+// Written with the oldest version that holds the params: version 0 until
+// upgrade() or a key id other than the default needs version 1
+impl Versioned for ServerSecretParams {
+    const VERSION: VersionByte = 1;
+
+    fn is_supported_version(version: VersionByte) -> bool {
+        version <= Self::VERSION
+    }
+
+    // read_layout reads version 0 too
+    fn deserialize_old_version(_version: VersionByte, bytes: &[u8]) -> Result<Self, ZkGroupError> {
+        deserialize_unversioned(bytes)
+    }
+}
+
+// The baseline layout, whose credential key pairs all had four y
+#[derive(Serialize, Deserialize)]
+struct ServerSecretParamsV0 {
+    auth_credentials_key_pair: crypto::credentials::KeyPairV0,
+    profile_key_credentials_key_pair: crypto::credentials::KeyPairV0,
+    sig_key_pair: crypto::signature::KeyPair,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ServerSecretParamsV1 {
+    key_id: ServerKeyId,
+    auth_credentials_key_pair: crypto::credentials::KeyPair,
+    profile_key_credentials_key_pair: crypto::credentials::KeyPair,
+    sig_key_pair: crypto::signature::KeyPair,
+    receipt_credentials_key_pair: crypto::credentials::KeyPair,
+    rate_limit_credentials_key_pair: crypto::credentials::KeyPair,
+}
+
+impl VersionedLayout for ServerSecretParams {
+    const EXPECTING: &'static str = "ServerSecretParams version 0 or 1";
+
+    fn read_layout<'de, A>(version: VersionByte, mut seq: A) -> Result<Self, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        match version {
+            0 => {
+                let v0: ServerSecretParamsV0 = next_layout_element::<Self, _, _>(&mut seq, 1)?;
+                Ok(ServerSecretParams {
+                    key_id: DEFAULT_SERVER_KEY_ID,
+                    auth_credentials_key_pair: v0
+                        .auth_credentials_key_pair
+                        .to_key_pair(NUM_AUTH_CRED_ATTRIBUTES)
+                        .map_err(de::Error::custom)?,
+                    profile_key_credentials_key_pair: v0
+                        .profile_key_credentials_key_pair
+                        .to_key_pair(NUM_PROFILE_KEY_CRED_ATTRIBUTES)
+                        .map_err(de::Error::custom)?,
+                    sig_key_pair: v0.sig_key_pair,
+                    receipt_credentials_key_pair: None,
+                    rate_limit_credentials_key_pair: None,
+                })
+            }
+            1 => {
+                let v1: ServerSecretParamsV1 = next_layout_element::<Self, _, _>(&mut seq, 1)?;
+                Ok(ServerSecretParams {
+                    key_id: v1.key_id,
+                    auth_credentials_key_pair: v1.auth_credentials_key_pair,
+                    profile_key_credentials_key_pair: v1.profile_key_credentials_key_pair,
+                    sig_key_pair: v1.sig_key_pair,
+                    receipt_credentials_key_pair: Some(v1.receipt_credentials_key_pair),
+                    rate_limit_credentials_key_pair: Some(v1.rate_limit_credentials_key_pair),
+                })
+            }
+            _ => Err(unknown_layout::<Self, _>(version)),
+        }
+    }
+}
+
+impl ServerSecretParams {
+    pub(crate) fn get_version(&self) -> VersionByte {
+        if self.key_id == DEFAULT_SERVER_KEY_ID
+            && self.receipt_credentials_key_pair.is_none()
+            && self.rate_limit_credentials_key_pair.is_none()
+        {
+            0
+        } else {
+            1
+        }
+    }
+
+    // Borrows the key pairs rather than copying them into a
+    // ServerSecretParamsV1, but writes the same bytes
+    fn serialize_binary<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let version = self.get_version();
+        if version == 0 {
+            let v0 = ServerSecretParamsV0 {
+                auth_credentials_key_pair: crypto::credentials::KeyPairV0::from_key_pair(
+                    &self.auth_credentials_key_pair,
+                )
+                .map_err(ser::Error::custom)?,
+                profile_key_credentials_key_pair: crypto::credentials::KeyPairV0::from_key_pair(
+                    &self.profile_key_credentials_key_pair,
+                )
+                .map_err(ser::Error::custom)?,
+                sig_key_pair: self.sig_key_pair.clone(),
+            };
+            return (version, v0).serialize(serializer);
+        }
+        let v1 = (
+            self.key_id,
+            &self.auth_credentials_key_pair,
+            &self.profile_key_credentials_key_pair,
+            &self.sig_key_pair,
+            self.get_receipt_credentials_key_pair()
+                .map_err(ser::Error::custom)?,
+            self.get_rate_limit_credentials_key_pair()
+                .map_err(ser::Error::custom)?,
+        );
+        (version, v1).serialize(serializer)
+    }
+
+    fn deserialize_binary<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_layout(deserializer)
+    }
+
+    pub(crate) fn get_receipt_credentials_key_pair(
+        &self,
+    ) -> Result<&crypto::credentials::KeyPair, ZkGroupError> {
+        self.receipt_credentials_key_pair
+            .as_ref()
+            .ok_or(ZkGroupError::KeyPairMissing)
+    }
+
+    pub(crate) fn get_rate_limit_credentials_key_pair(
+        &self,
+    ) -> Result<&crypto::credentials::KeyPair, ZkGroupError> {
+        self.rate_limit_credentials_key_pair
+            .as_ref()
+            .ok_or(ZkGroupError::KeyPairMissing)
+    }
+}
+
+impl Validate for ServerSecretParams {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        self.auth_credentials_key_pair
+            .validate_with_attributes(NUM_AUTH_CRED_ATTRIBUTES)?;
+        self.profile_key_credentials_key_pair
+            .validate_with_attributes(NUM_PROFILE_KEY_CRED_ATTRIBUTES)?;
+        self.sig_key_pair.validate()?;
+        if let Some(key_pair) = &self.receipt_credentials_key_pair {
+            key_pair.validate_with_attributes(NUM_RECEIPT_CRED_ATTRIBUTES)?;
+        }
+        if let Some(key_pair) = &self.rate_limit_credentials_key_pair {
+            key_pair.validate_with_attributes(NUM_RATE_LIMIT_CRED_ATTRIBUTES)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Copy, Clone)]
 pub struct ServerPublicParams {
-    pub(crate) reserved: ReservedBytes,
+    pub(crate) key_id: ServerKeyId,
     pub(crate) auth_credentials_public_key: crypto::credentials::PublicKey,
     pub(crate) profile_key_credentials_public_key: crypto::credentials::PublicKey,
     sig_public_key: crypto::signature::PublicKey,
+    // Missing from version 0 params, as from the secret params they came from
+    pub(crate) receipt_credentials_public_key: Option<crypto::credentials::PublicKey>,
+    pub(crate) rate_limit_credentials_public_key: Option<crypto::credentials::PublicKey>,
+}
+
+human_readable_serde!(binary ServerPublicParams);
+
+// The same version as the secret params they came from
+impl Versioned for ServerPublicParams {
+    const VERSION: VersionByte = 1;
+
+    fn is_supported_version(version: VersionByte) -> bool {
+        version <= Self::VERSION
+    }
+
+    // read_layout reads version 0 too
+    fn deserialize_old_version(_version: VersionByte, bytes: &[u8]) -> Result<Self, ZkGroupError> {
+        deserialize_unversioned(bytes)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct ServerPublicParamsV0 {
+    auth_credentials_public_key: crypto::credentials::PublicKey,
+    profile_key_credentials_public_key: crypto::credentials::PublicKey,
+    sig_public_key: crypto::signature::PublicKey,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ServerPublicParamsV1 {
+    key_id: ServerKeyId,
+    auth_credentials_public_key: crypto::credentials::PublicKey,
+    profile_key_credentials_public_key: crypto::credentials::PublicKey,
+    sig_public_key: crypto::signature::PublicKey,
+    receipt_credentials_public_key: crypto::credentials::PublicKey,
+    rate_limit_credentials_public_key: crypto::credentials::PublicKey,
+}
+
+impl VersionedLayout for ServerPublicParams {
+    const EXPECTING: &'static str = "ServerPublicParams version 0 or 1";
+
+    fn read_layout<'de, A>(version: VersionByte, mut seq: A) -> Result<Self, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        match version {
+            0 => {
+                let v0: ServerPublicParamsV0 = next_layout_element::<Self, _, _>(&mut seq, 1)?;
+                Ok(ServerPublicParams {
+                    key_id: DEFAULT_SERVER_KEY_ID,
+                    auth_credentials_public_key: v0.auth_credentials_public_key,
+                    profile_key_credentials_public_key: v0.profile_key_credentials_public_key,
+                    sig_public_key: v0.sig_public_key,
+                    receipt_credentials_public_key: None,
+                    rate_limit_credentials_public_key: None,
+                })
+            }
+            1 => {
+                let v1: ServerPublicParamsV1 = next_layout_element::<Self, _, _>(&mut seq, 1)?;
+                Ok(ServerPublicParams {
+                    key_id: v1.key_id,
+                    auth_credentials_public_key: v1.auth_credentials_public_key,
+                    profile_key_credentials_public_key: v1.profile_key_credentials_public_key,
+                    sig_public_key: v1.sig_public_key,
+                    receipt_credentials_public_key: Some(v1.receipt_credentials_public_key),
+                    rate_limit_credentials_public_key: Some(v1.rate_limit_credentials_public_key),
+                })
+            }
+            _ => Err(unknown_layout::<Self, _>(version)),
+        }
+    }
+}
+
+impl ServerPublicParams {
+    pub(crate) fn get_version(&self) -> VersionByte {
+        if self.key_id == DEFAULT_SERVER_KEY_ID
+            && self.receipt_credentials_public_key.is_none()
+            && self.rate_limit_credentials_public_key.is_none()
+        {
+            0
+        } else {
+            1
+        }
+    }
+
+    fn serialize_binary<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let version = self.get_version();
+        if version == 0 {
+            let v0 = ServerPublicParamsV0 {
+                auth_credentials_public_key: self.auth_credentials_public_key,
+                profile_key_credentials_public_key: self.profile_key_credentials_public_key,
+                sig_public_key: self.sig_public_key,
+            };
+            return (version, v0).serialize(serializer);
+        }
+        let v1 = ServerPublicParamsV1 {
+            key_id: self.key_id,
+            auth_credentials_public_key: self.auth_credentials_public_key,
+            profile_key_credentials_public_key: self.profile_key_credentials_public_key,
+            sig_public_key: self.sig_public_key,
+            receipt_credentials_public_key: self
+                .get_receipt_credentials_public_key()
+                .map_err(ser::Error::custom)?,
+            rate_limit_credentials_public_key: self
+                .get_rate_limit_credentials_public_key()
+                .map_err(ser::Error::custom)?,
+        };
+        (version, v1).serialize(serializer)
+    }
+
+    fn deserialize_binary<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_layout(deserializer)
+    }
+
+    pub(crate) fn get_receipt_credentials_public_key(
+        &self,
+    ) -> Result<crypto::credentials::PublicKey, ZkGroupError> {
+        self.receipt_credentials_public_key
+            .ok_or(ZkGroupError::KeyPairMissing)
+    }
+
+    pub(crate) fn get_rate_limit_credentials_public_key(
+        &self,
+    ) -> Result<crypto::credentials::PublicKey, ZkGroupError> {
+        self.rate_limit_credentials_public_key
+            .ok_or(ZkGroupError::KeyPairMissing)
+    }
+}
+
+impl Validate for ServerPublicParams {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        self.auth_credentials_public_key.validate()?;
+        self.profile_key_credentials_public_key.validate()?;
+        self.sig_public_key.validate()?;
+        if let Some(public_key) = &self.receipt_credentials_public_key {
+            public_key.validate()?;
+        }
+        if let Some(public_key) = &self.rate_limit_credentials_public_key {
+            public_key.validate()?;
+        }
+        Ok(())
+    }
 }
 
 impl ServerSecretParams {
-    pub fn generate(randomness: RandomnessBytes) -> Self {
+    pub fn generate(randomness: RandomnessBytes) -> Result<Self, ZkGroupError> {
+        Self::generate_with_key_id(randomness, DEFAULT_SERVER_KEY_ID)
+    }
+
+    // The key id is carried in every response and presentation made under
+    // these params, so a verifier can pick the right key after a rotation
+    pub fn generate_with_key_id(
+        randomness: RandomnessBytes,
+        key_id: ServerKeyId,
+    ) -> Result<Self, ZkGroupError> {
         let mut sho = Sho::new(
             b"Signal_ZKGroup_20200424_Random_ServerSecretParams_Generate",
             &randomness,
         );
 
         let auth_credentials_key_pair =
-            crypto::credentials::KeyPair::generate(&mut sho, NUM_AUTH_CRED_ATTRIBUTES);
+            crypto::credentials::KeyPair::generate(&mut sho, NUM_AUTH_CRED_ATTRIBUTES)?;
         let profile_key_credentials_key_pair =
-            crypto::credentials::KeyPair::generate(&mut sho, NUM_PROFILE_KEY_CRED_ATTRIBUTES);
+            crypto::credentials::KeyPair::generate(&mut sho, NUM_PROFILE_KEY_CRED_ATTRIBUTES)?;
         let sig_key_pair = crypto::signature::KeyPair::generate(&mut sho);
+        let receipt_credentials_key_pair =
+            crypto::credentials::KeyPair::generate(&mut sho, NUM_RECEIPT_CRED_ATTRIBUTES)?;
+        let rate_limit_credentials_key_pair =
+            crypto::credentials::KeyPair::generate(&mut sho, NUM_RATE_LIMIT_CRED_ATTRIBUTES)?;
 
-        Self {
-            reserved: Default::default(),
+        Ok(Self {
+            key_id,
             auth_credentials_key_pair,
             profile_key_credentials_key_pair,
             sig_key_pair,
+            receipt_credentials_key_pair: Some(receipt_credentials_key_pair),
+            rate_limit_credentials_key_pair: Some(rate_limit_credentials_key_pair),
+        })
+    }
+
+    // Adds the key pairs that version 0 params lack, and returns whether it
+    // added any. Until then, issuing or verifying receipt and rate limit
+    // credentials fails with KeyPairMissing. Upgraded params are written as
+    // version 1, and their public params need to reach clients before they
+    // can use the new credentials.
+    pub fn upgrade(&mut self, randomness: RandomnessBytes) -> Result<bool, ZkGroupError> {
+        let mut sho = Sho::new(
+            b"Signal_ZKGroup_20200424_Random_ServerSecretParams_Upgrade",
+            &randomness,
+        );
+        let mut upgraded = false;
+        if self.receipt_credentials_key_pair.is_none() {
+            self.receipt_credentials_key_pair = Some(crypto::credentials::KeyPair::generate(
+                &mut sho,
+                NUM_RECEIPT_CRED_ATTRIBUTES,
+            )?);
+            upgraded = true;
+        }
+        if self.rate_limit_credentials_key_pair.is_none() {
+            self.rate_limit_credentials_key_pair = Some(crypto::credentials::KeyPair::generate(
+                &mut sho,
+                NUM_RATE_LIMIT_CRED_ATTRIBUTES,
+            )?);
+            upgraded = true;
         }
+        Ok(upgraded)
+    }
+
+    pub fn get_key_id(&self) -> ServerKeyId {
+        self.key_id
     }
 
     pub fn get_public_params(&self) -> ServerPublicParams {
         ServerPublicParams {
-            reserved: Default::default(),
+            key_id: self.key_id,
             auth_credentials_public_key: self.auth_credentials_key_pair.get_public_key(),
             profile_key_credentials_public_key: self
                 .profile_key_credentials_key_pair
                 .get_public_key(),
             sig_public_key: self.sig_key_pair.get_public_key(),
+            receipt_credentials_public_key: self
+                .receipt_credentials_key_pair
+                .as_ref()
+                .map(|key_pair| key_pair.get_public_key()),
+            rate_limit_credentials_public_key: self
+                .rate_limit_credentials_key_pair
+                .as_ref()
+                .map(|key_pair| key_pair.get_public_key()),
         }
     }
 
@@ -75,33 +451,153 @@ impl ServerSecretParams {
         self.sig_key_pair.sign(message, &mut sho)
     }
 
+    // Challenges aren't signed with sig_key_pair, so sign() can't be used to
+    // forge one, and a challenge's signature doesn't verify with
+    // ServerPublicParams::verify_signature.  Only these params check
+    // challenges, so the derived public key is never published.
+    fn get_challenge_key_pair(&self) -> crypto::signature::KeyPair {
+        self.sig_key_pair
+            .derive_key_pair(b"Signal_ZKGroup_20200424_ServerSecretParams_ChallengeKeyPair")
+    }
+
+    // The expiration time is in seconds since the epoch
+    pub fn issue_presentation_challenge(
+        &self,
+        randomness: RandomnessBytes,
+        expiration_time: ChallengeExpirationTime,
+    ) -> Result<api::challenges::PresentationChallenge, ZkGroupError> {
+        let mut sho = Sho::new(
+            b"Signal_ZKGroup_20200424_Random_ServerSecretParams_IssuePresentationChallenge",
+            &randomness,
+        );
+        let mut nonce = [0u8; CHALLENGE_NONCE_LEN];
+        nonce.copy_from_slice(&sho.squeeze(CHALLENGE_NONCE_LEN)[..]);
+
+        let mut challenge =
+            api::challenges::PresentationChallenge::new(self.key_id, nonce, expiration_time);
+        let signature = self
+            .get_challenge_key_pair()
+            .sign(&challenge.get_context(), &mut sho)?;
+        challenge.set_signature(signature);
+        Ok(challenge)
+    }
+
+    // Checks the challenge was issued under these params and hasn't expired;
+    // whether it was already redeemed is up to the caller's ReplayCache
+    pub fn verify_presentation_challenge(
+        &self,
+        challenge: &api::challenges::PresentationChallenge,
+        current_time_in_seconds: u64,
+    ) -> Result<(), ZkGroupError> {
+        if challenge.key_id != self.key_id {
+            return Err(ZkGroupError::KeyIdMismatch);
+        }
+        self.get_challenge_key_pair()
+            .get_public_key()
+            .verify(&challenge.get_context(), challenge.get_signature())?;
+        if current_time_in_seconds > challenge.expiration_time {
+            return Err(ZkGroupError::ChallengeExpired);
+        }
+        Ok(())
+    }
+
+    // Verifies the challenge, then the presentation with the challenge as its
+    // context.  The nonce is recorded only once both verify, so a garbled
+    // presentation doesn't use up the challenge; a second presentation for the
+    // same challenge fails with ChallengeReplayed.
+    fn redeem_presentation_challenge<C, F>(
+        &self,
+        challenge: &api::challenges::PresentationChallenge,
+        current_time_in_seconds: u64,
+        replay_cache: &mut C,
+        verify_presentation: F,
+    ) -> Result<(), ZkGroupError>
+    where
+        C: api::challenges::ReplayCache + ?Sized,
+        F: FnOnce(&[u8]) -> Result<(), ZkGroupError>,
+    {
+        self.verify_presentation_challenge(challenge, current_time_in_seconds)?;
+        verify_presentation(&challenge.get_context())?;
+        replay_cache.insert(
+            challenge.nonce,
+            challenge.expiration_time,
+            current_time_in_seconds,
+        )
+    }
+
     pub fn issue_auth_credential(
         &self,
         randomness: RandomnessBytes,
         uid_bytes: UidBytes,
         redemption_time: RedemptionTime,
-    ) -> api::auth::AuthCredentialResponse {
+    ) -> Result<api::auth::AuthCredentialResponse, ZkGroupError> {
         let mut sho = Sho::new(
             b"Signal_ZKGroup_20200424_Random_ServerSecretParams_IssueAuthCredential",
             &randomness,
         );
 
         let uid = crypto::uid_struct::UidStruct::new(uid_bytes);
-        let credential =
-            self.auth_credentials_key_pair
-                .create_auth_credential(uid, redemption_time, &mut sho);
+        let credential = self.auth_credentials_key_pair.create_auth_credential(
+            uid,
+            redemption_time,
+            &mut sho,
+        )?;
         let proof = crypto::proofs::AuthCredentialIssuanceProof::new(
-            self.auth_credentials_key_pair,
+            &self.auth_credentials_key_pair,
             credential,
             uid,
             redemption_time,
             &mut sho,
-        );
-        api::auth::AuthCredentialResponse {
-            reserved: Default::default(),
+        )?;
+        Ok(api::auth::AuthCredentialResponse {
+            version: KeyIdVersion {
+                key_id: self.key_id,
+            },
             credential,
             proof,
+        })
+    }
+
+    // Might return BadArgs if redemption_times is empty or longer than
+    // MAX_AUTH_CREDENTIAL_BATCH_SIZE
+    pub fn issue_auth_credentials(
+        &self,
+        randomness: RandomnessBytes,
+        uid_bytes: UidBytes,
+        redemption_times: &[RedemptionTime],
+    ) -> Result<api::auth::AuthCredentialBatchResponse, ZkGroupError> {
+        if redemption_times.is_empty() || redemption_times.len() > MAX_AUTH_CREDENTIAL_BATCH_SIZE {
+            return Err(ZkGroupError::BadArgs);
         }
+        let mut sho = Sho::new(
+            b"Signal_ZKGroup_20200424_Random_ServerSecretParams_IssueAuthCredentials",
+            &randomness,
+        );
+
+        let uid = crypto::uid_struct::UidStruct::new(uid_bytes);
+        let credentials = redemption_times
+            .iter()
+            .map(|redemption_time| {
+                self.auth_credentials_key_pair.create_auth_credential(
+                    uid,
+                    *redemption_time,
+                    &mut sho,
+                )
+            })
+            .collect::<Result<Vec<crypto::credentials::AuthCredential>, ZkGroupError>>()?;
+        let proof = crypto::proofs::AuthCredentialBatchIssuanceProof::new(
+            &self.auth_credentials_key_pair,
+            &credentials,
+            uid,
+            redemption_times,
+            &mut sho,
+        )?;
+        Ok(api::auth::AuthCredentialBatchResponse {
+            version: api::auth::AuthCredentialBatchResponse::VERSION,
+            key_id: self.key_id,
+            credentials,
+            proof,
+        })
     }
 
     pub fn verify_auth_credential_presentation(
@@ -109,20 +605,156 @@ impl ServerSecretParams {
         group_public_params: api::groups::GroupPublicParams,
         presentation: &api::auth::AuthCredentialPresentation,
     ) -> Result<(), ZkGroupError> {
+        self.verify_auth_credential_presentation_with_context(
+            group_public_params,
+            presentation,
+            &[],
+        )
+    }
+
+    // The context must be the one the presentation was created with, e.g. the
+    // request path or a challenge the server issued
+    pub fn verify_auth_credential_presentation_with_context(
+        &self,
+        group_public_params: api::groups::GroupPublicParams,
+        presentation: &api::auth::AuthCredentialPresentation,
+        context: &[u8],
+    ) -> Result<(), ZkGroupError> {
+        if presentation.version.key_id != self.key_id {
+            return Err(ZkGroupError::KeyIdMismatch);
+        }
         presentation.proof.verify(
-            self.auth_credentials_key_pair,
+            &self.auth_credentials_key_pair,
             group_public_params.uid_enc_public_key,
             presentation.ciphertext,
             presentation.redemption_time,
+            context,
+        )
+    }
+
+    pub fn verify_auth_credential_presentation_with_time(
+        &self,
+        group_public_params: api::groups::GroupPublicParams,
+        presentation: &api::auth::AuthCredentialPresentation,
+        current_time_in_seconds: u64,
+        redemption_window: api::auth::RedemptionWindow,
+    ) -> Result<(), ZkGroupError> {
+        self.verify_auth_credential_presentation_with_time_and_context(
+            group_public_params,
+            presentation,
+            current_time_in_seconds,
+            redemption_window,
+            &[],
+        )
+    }
+
+    pub fn verify_auth_credential_presentation_with_time_and_context(
+        &self,
+        group_public_params: api::groups::GroupPublicParams,
+        presentation: &api::auth::AuthCredentialPresentation,
+        current_time_in_seconds: u64,
+        redemption_window: api::auth::RedemptionWindow,
+        context: &[u8],
+    ) -> Result<(), ZkGroupError> {
+        redemption_window.check(presentation.redemption_time, current_time_in_seconds)?;
+        self.verify_auth_credential_presentation_with_context(
+            group_public_params,
+            presentation,
+            context,
+        )
+    }
+
+    // Nothing here ties the credential to a user: the caller must
+    // authenticate the user and issue them at most one credential per day,
+    // since each credential gives an unrelated tag.  The day is chosen here,
+    // so the client can't ask for one it already used.
+    pub fn issue_rate_limit_credential(
+        &self,
+        randomness: RandomnessBytes,
+        request: &api::auth::RateLimitCredentialRequest,
+        redemption_time: RedemptionTime,
+    ) -> Result<api::auth::RateLimitCredentialResponse, ZkGroupError> {
+        let key_pair = self.get_rate_limit_credentials_key_pair()?;
+        let mut sho = Sho::new(
+            b"Signal_ZKGroup_20200424_Random_ServerSecretParams_IssueRateLimitCredential",
+            &randomness,
+        );
+
+        let blinded_credential_with_secret_nonce = key_pair.create_blinded_rate_limit_credential(
+            request.public_key,
+            request.ciphertext,
+            redemption_time,
+            &mut sho,
+        )?;
+
+        let proof = crypto::proofs::RateLimitCredentialIssuanceProof::new(
+            key_pair,
+            request.public_key,
+            request.ciphertext,
+            blinded_credential_with_secret_nonce,
+            redemption_time,
+            &mut sho,
+        )?;
+
+        Ok(api::auth::RateLimitCredentialResponse {
+            version: api::auth::RateLimitCredentialResponse::VERSION,
+            key_id: self.key_id,
+            redemption_time,
+            blinded_credential: blinded_credential_with_secret_nonce
+                .get_blinded_rate_limit_credential(),
+            proof,
+        })
+    }
+
+    // On success the token's tag can be counted against the token's
+    // redemption time; the caller should also check that time is current,
+    // e.g. with verify_rate_limit_token_with_time
+    pub fn verify_rate_limit_token(
+        &self,
+        token: &api::auth::RateLimitToken,
+    ) -> Result<(), ZkGroupError> {
+        if token.key_id != self.key_id {
+            return Err(ZkGroupError::KeyIdMismatch);
+        }
+        token.proof.verify(
+            self.get_rate_limit_credentials_key_pair()?,
+            token.tag,
+            token.redemption_time,
         )
     }
 
+    pub fn verify_rate_limit_token_with_time(
+        &self,
+        token: &api::auth::RateLimitToken,
+        current_time_in_seconds: u64,
+        redemption_window: api::auth::RedemptionWindow,
+    ) -> Result<(), ZkGroupError> {
+        redemption_window.check(token.redemption_time, current_time_in_seconds)?;
+        self.verify_rate_limit_token(token)
+    }
+
     pub fn verify_profile_key_credential_presentation(
         &self,
         group_public_params: api::groups::GroupPublicParams,
         presentation: &api::profiles::ProfileKeyCredentialPresentation,
     ) -> Result<(), ZkGroupError> {
-        let credentials_key_pair = self.profile_key_credentials_key_pair;
+        self.verify_profile_key_credential_presentation_with_context(
+            group_public_params,
+            presentation,
+            &[],
+        )
+    }
+
+    pub fn verify_profile_key_credential_presentation_with_context(
+        &self,
+        group_public_params: api::groups::GroupPublicParams,
+        presentation: &api::profiles::ProfileKeyCredentialPresentation,
+        context: &[u8],
+    ) -> Result<(), ZkGroupError> {
+        if presentation.version.key_id != self.key_id {
+            return Err(ZkGroupError::KeyIdMismatch);
+        }
+        let credentials_key_pair = &self.profile_key_credentials_key_pair;
         let uid_enc_public_key = group_public_params.uid_enc_public_key;
         let profile_key_enc_public_key = group_public_params.profile_key_enc_public_key;
 
@@ -132,9 +764,162 @@ impl ServerSecretParams {
             uid_enc_public_key,
             presentation.profile_key_enc_ciphertext,
             profile_key_enc_public_key,
+            context,
+        )
+    }
+
+    pub fn verify_profile_key_credential_selective_presentation(
+        &self,
+        group_public_params: api::groups::GroupPublicParams,
+        presentation: &api::profiles::ProfileKeyCredentialSelectivePresentation,
+    ) -> Result<(), ZkGroupError> {
+        self.verify_profile_key_credential_selective_presentation_with_context(
+            group_public_params,
+            presentation,
+            &[],
+        )
+    }
+
+    pub fn verify_profile_key_credential_selective_presentation_with_context(
+        &self,
+        group_public_params: api::groups::GroupPublicParams,
+        presentation: &api::profiles::ProfileKeyCredentialSelectivePresentation,
+        context: &[u8],
+    ) -> Result<(), ZkGroupError> {
+        if presentation.key_id != self.key_id {
+            return Err(ZkGroupError::KeyIdMismatch);
+        }
+        presentation.proof.verify(
+            &self.profile_key_credentials_key_pair,
+            presentation.uid_enc_ciphertext,
+            group_public_params.uid_enc_public_key,
+            context,
+        )
+    }
+
+    pub fn verify_auth_credential_presentation_with_challenge<C>(
+        &self,
+        group_public_params: api::groups::GroupPublicParams,
+        presentation: &api::auth::AuthCredentialPresentation,
+        challenge: &api::challenges::PresentationChallenge,
+        current_time_in_seconds: u64,
+        replay_cache: &mut C,
+    ) -> Result<(), ZkGroupError>
+    where
+        C: api::challenges::ReplayCache + ?Sized,
+    {
+        self.redeem_presentation_challenge(
+            challenge,
+            current_time_in_seconds,
+            replay_cache,
+            |context| {
+                self.verify_auth_credential_presentation_with_context(
+                    group_public_params,
+                    presentation,
+                    context,
+                )
+            },
         )
     }
 
+    pub fn verify_profile_key_credential_presentation_with_challenge<C>(
+        &self,
+        group_public_params: api::groups::GroupPublicParams,
+        presentation: &api::profiles::ProfileKeyCredentialPresentation,
+        challenge: &api::challenges::PresentationChallenge,
+        current_time_in_seconds: u64,
+        replay_cache: &mut C,
+    ) -> Result<(), ZkGroupError>
+    where
+        C: api::challenges::ReplayCache + ?Sized,
+    {
+        self.redeem_presentation_challenge(
+            challenge,
+            current_time_in_seconds,
+            replay_cache,
+            |context| {
+                self.verify_profile_key_credential_presentation_with_context(
+                    group_public_params,
+                    presentation,
+                    context,
+                )
+            },
+        )
+    }
+
+    pub fn verify_profile_key_credential_selective_presentation_with_challenge<C>(
+        &self,
+        group_public_params: api::groups::GroupPublicParams,
+        presentation: &api::profiles::ProfileKeyCredentialSelectivePresentation,
+        challenge: &api::challenges::PresentationChallenge,
+        current_time_in_seconds: u64,
+        replay_cache: &mut C,
+    ) -> Result<(), ZkGroupError>
+    where
+        C: api::challenges::ReplayCache + ?Sized,
+    {
+        self.redeem_presentation_challenge(
+            challenge,
+            current_time_in_seconds,
+            replay_cache,
+            |context| {
+                self.verify_profile_key_credential_selective_presentation_with_context(
+                    group_public_params,
+                    presentation,
+                    context,
+                )
+            },
+        )
+    }
+
+    // The commitment is the one the group's admins registered for the group's
+    // current invite link
+    pub fn verify_group_invite_link_presentation(
+        &self,
+        group_public_params: api::groups::GroupPublicParams,
+        commitment: api::groups::GroupInviteLinkPasswordCommitment,
+        presentation: &api::groups::GroupInviteLinkPresentation,
+    ) -> Result<(), ZkGroupError> {
+        let message = api::groups::GroupInviteLinkPresentation::get_poksho_message(
+            group_public_params.get_group_identifier(),
+            presentation.ciphertext,
+        )?;
+        presentation.proof.verify(commitment.public_key, &message)
+    }
+
+    // Checks that the member adding the invitation is its inviter
+    pub fn verify_pending_member_invitation(
+        &self,
+        group_public_params: api::groups::GroupPublicParams,
+        inviter_presentation: &api::auth::AuthCredentialPresentation,
+        invitation: api::groups::PendingMemberInvitation,
+    ) -> Result<(), ZkGroupError> {
+        self.verify_auth_credential_presentation(group_public_params, inviter_presentation)?;
+        if inviter_presentation.ciphertext != invitation.inviter_uid_enc_ciphertext {
+            return Err(ZkGroupError::ProofVerificationFailure(
+                "PendingMemberInvitation",
+            ));
+        }
+        Ok(())
+    }
+
+    // Checks that the invitee accepting the invitation is the one it was
+    // issued to, and that their profile key ciphertext is valid
+    pub fn verify_pending_member_invitation_acceptance(
+        &self,
+        group_public_params: api::groups::GroupPublicParams,
+        invitation: api::groups::PendingMemberInvitation,
+        presentation: &api::profiles::ProfileKeyCredentialPresentation,
+    ) -> Result<(), ZkGroupError> {
+        self.verify_profile_key_credential_presentation(group_public_params, presentation)?;
+        if !invitation.matches(presentation) {
+            return Err(ZkGroupError::ProofVerificationFailure(
+                "PendingMemberInvitation",
+            ));
+        }
+        Ok(())
+    }
+
     pub fn issue_profile_key_credential(
         &self,
         randomness: RandomnessBytes,
@@ -161,27 +946,95 @@ impl ServerSecretParams {
                 request.public_key,
                 request.ciphertext,
                 &mut sho,
-            );
+            )?;
 
         let proof = crypto::proofs::ProfileKeyCredentialIssuanceProof::new(
-            self.profile_key_credentials_key_pair,
+            &self.profile_key_credentials_key_pair,
             request.public_key,
             request.ciphertext,
             blinded_credential_with_secret_nonce,
             uid,
             &mut sho,
-        );
+        )?;
 
         Ok(api::profiles::ProfileKeyCredentialResponse {
-            reserved: Default::default(),
+            version: KeyIdVersion {
+                key_id: self.key_id,
+            },
             blinded_credential: blinded_credential_with_secret_nonce
                 .get_blinded_profile_key_credential(),
             proof,
         })
     }
+    // The expiration and level are set by the server from the payment it
+    // checked; the serial stays blinded so redemption can't be linked back
+    pub fn issue_receipt_credential(
+        &self,
+        randomness: RandomnessBytes,
+        request: &api::receipts::ReceiptCredentialRequest,
+        receipt_expiration_time: ReceiptExpirationTime,
+        receipt_level: ReceiptLevel,
+    ) -> Result<api::receipts::ReceiptCredentialResponse, ZkGroupError> {
+        let key_pair = self.get_receipt_credentials_key_pair()?;
+        let mut sho = Sho::new(
+            b"Signal_ZKGroup_20200424_Random_ServerSecretParams_IssueReceiptCredential",
+            &randomness,
+        );
+
+        let blinded_credential_with_secret_nonce = key_pair.create_blinded_receipt_credential(
+            request.public_key,
+            request.ciphertext,
+            receipt_expiration_time,
+            receipt_level,
+            &mut sho,
+        )?;
+
+        let proof = crypto::proofs::ReceiptCredentialIssuanceProof::new(
+            key_pair,
+            request.public_key,
+            request.ciphertext,
+            blinded_credential_with_secret_nonce,
+            receipt_expiration_time,
+            receipt_level,
+            &mut sho,
+        )?;
+
+        Ok(api::receipts::ReceiptCredentialResponse {
+            version: api::receipts::ReceiptCredentialResponse::VERSION,
+            key_id: self.key_id,
+            receipt_expiration_time,
+            receipt_level,
+            blinded_credential: blinded_credential_with_secret_nonce
+                .get_blinded_receipt_credential(),
+            proof,
+        })
+    }
+
+    // The caller is responsible for checking the expiration and for
+    // rejecting serials it has already seen
+    pub fn verify_receipt_credential_presentation(
+        &self,
+        presentation: &api::receipts::ReceiptCredentialPresentation,
+    ) -> Result<(), ZkGroupError> {
+        if presentation.key_id != self.key_id {
+            return Err(ZkGroupError::KeyIdMismatch);
+        }
+        let receipt = crypto::receipt_struct::ReceiptStruct::new(
+            presentation.receipt_serial_bytes,
+            presentation.receipt_expiration_time,
+            presentation.receipt_level,
+        );
+        presentation
+            .proof
+            .verify(self.get_receipt_credentials_key_pair()?, receipt)
+    }
 }
 
 impl ServerPublicParams {
+    pub fn get_key_id(&self) -> ServerKeyId {
+        self.key_id
+    }
+
     pub fn verify_signature(
         &self,
         message: &[u8],
@@ -196,6 +1049,9 @@ impl ServerPublicParams {
         redemption_time: RedemptionTime,
         response: &api::auth::AuthCredentialResponse,
     ) -> Result<api::auth::AuthCredential, ZkGroupError> {
+        if response.version.key_id != self.key_id {
+            return Err(ZkGroupError::KeyIdMismatch);
+        }
         let uid = crypto::uid_struct::UidStruct::new(uid_bytes);
         response.proof.verify(
             self.auth_credentials_public_key,
@@ -205,7 +1061,7 @@ impl ServerPublicParams {
         )?;
 
         Ok(api::auth::AuthCredential {
-            reserved: Default::default(),
+            version: self.get_version(),
             credential: response.credential,
             server_public_params: *self,
             uid,
@@ -213,12 +1069,61 @@ impl ServerPublicParams {
         })
     }
 
+    // Returns credentials in the same order as redemption_times
+    pub fn receive_auth_credentials(
+        &self,
+        uid_bytes: UidBytes,
+        redemption_times: &[RedemptionTime],
+        response: &api::auth::AuthCredentialBatchResponse,
+    ) -> Result<Vec<api::auth::AuthCredential>, ZkGroupError> {
+        if response.key_id != self.key_id {
+            return Err(ZkGroupError::KeyIdMismatch);
+        }
+        let uid = crypto::uid_struct::UidStruct::new(uid_bytes);
+        response.proof.verify(
+            self.auth_credentials_public_key,
+            &response.credentials,
+            uid,
+            redemption_times,
+        )?;
+
+        Ok(response
+            .credentials
+            .iter()
+            .zip(redemption_times)
+            .map(|(credential, redemption_time)| api::auth::AuthCredential {
+                version: self.get_version(),
+                credential: *credential,
+                server_public_params: *self,
+                uid,
+                redemption_time: *redemption_time,
+            })
+            .collect())
+    }
+
     pub fn create_auth_credential_presentation(
         &self,
         randomness: RandomnessBytes,
-        group_secret_params: api::groups::GroupSecretParams,
+        group_secret_params: &api::groups::GroupSecretParams,
         auth_credential: api::auth::AuthCredential,
-    ) -> api::auth::AuthCredentialPresentation {
+    ) -> Result<api::auth::AuthCredentialPresentation, ZkGroupError> {
+        self.create_auth_credential_presentation_with_context(
+            randomness,
+            group_secret_params,
+            auth_credential,
+            &[],
+        )
+    }
+
+    // Binds the presentation to the context (a request path, a challenge the
+    // server issued, ...), so it verifies only where that context is expected
+    pub fn create_auth_credential_presentation_with_context(
+        &self,
+        randomness: RandomnessBytes,
+        group_secret_params: &api::groups::GroupSecretParams,
+        auth_credential: api::auth::AuthCredential,
+        context: &[u8],
+    ) -> Result<api::auth::AuthCredentialPresentation, ZkGroupError> {
         let mut sho = Sho::new(
             b"Signal_ZKGroup_20200424_Random_ServerPublicParams_CreateAuthCredentialPresentation",
             &randomness,
@@ -228,28 +1133,111 @@ impl ServerPublicParams {
 
         let proof = crypto::proofs::AuthCredentialPresentationProof::new(
             self.auth_credentials_public_key,
-            group_secret_params.uid_enc_key_pair,
+            &group_secret_params.uid_enc_key_pair,
             auth_credential.credential,
             auth_credential.uid,
             uuid_ciphertext.ciphertext,
             auth_credential.redemption_time,
+            context,
             &mut sho,
-        );
+        )?;
 
-        api::auth::AuthCredentialPresentation {
-            reserved: Default::default(),
+        Ok(api::auth::AuthCredentialPresentation {
+            version: KeyIdVersion {
+                key_id: self.key_id,
+            },
             proof,
             ciphertext: uuid_ciphertext.ciphertext,
             redemption_time: auth_credential.redemption_time,
+        })
+    }
+
+    // Picks the secret the credential's tokens derive their tag from; it's
+    // sent to the server only blinded
+    pub fn create_rate_limit_credential_request_context(
+        &self,
+        randomness: RandomnessBytes,
+    ) -> api::auth::RateLimitCredentialRequestContext {
+        let mut sho = Sho::new(
+            b"Signal_ZKGroup_20200424_Random_ServerPublicParams_CreateRateLimitCredentialRequestContext",
+            &randomness,
+        );
+
+        let rate_limit = crypto::rate_limit_struct::RateLimitStruct::generate(&mut sho);
+        let key_pair = crypto::rate_limit_credential_request::KeyPair::generate(&mut sho);
+        let ciphertext_with_secret_nonce = key_pair.encrypt(&rate_limit, &mut sho);
+
+        api::auth::RateLimitCredentialRequestContext {
+            version: api::auth::RateLimitCredentialRequestContext::VERSION,
+            rate_limit,
+            key_pair,
+            ciphertext_with_secret_nonce,
+        }
+    }
+
+    pub fn receive_rate_limit_credential(
+        &self,
+        context: &api::auth::RateLimitCredentialRequestContext,
+        response: &api::auth::RateLimitCredentialResponse,
+    ) -> Result<api::auth::RateLimitCredential, ZkGroupError> {
+        if response.key_id != self.key_id {
+            return Err(ZkGroupError::KeyIdMismatch);
         }
+        response.proof.verify(
+            self.get_rate_limit_credentials_public_key()?,
+            context.key_pair.get_public_key(),
+            context.ciphertext_with_secret_nonce.get_ciphertext(),
+            response.blinded_credential,
+            response.redemption_time,
+        )?;
+
+        let credential = context
+            .key_pair
+            .decrypt_blinded_rate_limit_credential(response.blinded_credential);
+
+        Ok(api::auth::RateLimitCredential {
+            version: api::auth::RateLimitCredential::VERSION,
+            credential,
+            rate_limit: context.rate_limit.clone(),
+            redemption_time: response.redemption_time,
+        })
+    }
+
+    // Every token made from the same credential carries the same tag
+    pub fn create_rate_limit_token(
+        &self,
+        randomness: RandomnessBytes,
+        rate_limit_credential: &api::auth::RateLimitCredential,
+    ) -> Result<api::auth::RateLimitToken, ZkGroupError> {
+        let mut sho = Sho::new(
+            b"Signal_ZKGroup_20200424_Random_ServerPublicParams_CreateRateLimitToken",
+            &randomness,
+        );
+
+        let redemption_time = rate_limit_credential.redemption_time;
+        let proof = crypto::proofs::RateLimitTokenProof::new(
+            self.get_rate_limit_credentials_public_key()?,
+            rate_limit_credential.credential,
+            &rate_limit_credential.rate_limit,
+            redemption_time,
+            &mut sho,
+        )?;
+
+        Ok(api::auth::RateLimitToken {
+            version: api::auth::RateLimitToken::VERSION,
+            key_id: self.key_id,
+            proof,
+            tag: rate_limit_credential.rate_limit.get_tag(redemption_time),
+            redemption_time,
+        })
     }
 
     pub fn create_profile_key_credential_request_context(
         &self,
         randomness: RandomnessBytes,
         uid_bytes: UidBytes,
-        profile_key: api::profiles::ProfileKey,
-    ) -> api::profiles::ProfileKeyCredentialRequestContext {
+        profile_key: &api::profiles::ProfileKey,
+    ) -> Result<api::profiles::ProfileKeyCredentialRequestContext, ZkGroupError> {
         let mut sho = Sho::new(
             b"Signal_ZKGroup_20200424_Random_ServerPublicParams_CreateProfileKeyCredentialRequestContext",
             &randomness,
@@ -259,28 +1247,28 @@ impl ServerPublicParams {
 
         let commitment_with_secret_nonce =
             crypto::profile_key_commitment::CommitmentWithSecretNonce::new(
-                profile_key_struct,
+                &profile_key_struct,
                 uid_bytes,
             );
 
         let key_pair = crypto::profile_key_credential_request::KeyPair::generate(&mut sho);
-        let ciphertext_with_secret_nonce = key_pair.encrypt(profile_key_struct, &mut sho);
+        let ciphertext_with_secret_nonce = key_pair.encrypt(&profile_key_struct, &mut sho);
 
         let proof = crypto::proofs::ProfileKeyCredentialRequestProof::new(
-            key_pair,
-            ciphertext_with_secret_nonce,
+            &key_pair,
+            &ciphertext_with_secret_nonce,
             commitment_with_secret_nonce,
             &mut sho,
-        );
+        )?;
 
-        api::profiles::ProfileKeyCredentialRequestContext {
-            reserved: Default::default(),
+        Ok(api::profiles::ProfileKeyCredentialRequestContext {
+            version: api::profiles::ProfileKeyCredentialRequestContext::VERSION,
             uid_bytes,
             profile_key_bytes: profile_key_struct.bytes,
             key_pair,
             ciphertext_with_secret_nonce,
             proof,
-        }
+        })
     }
 
     pub fn receive_profile_key_credential(
@@ -288,6 +1276,9 @@ impl ServerPublicParams {
         context: &api::profiles::ProfileKeyCredentialRequestContext,
         response: &api::profiles::ProfileKeyCredentialResponse,
     ) -> Result<api::profiles::ProfileKeyCredential, ZkGroupError> {
+        if response.version.key_id != self.key_id {
+            return Err(ZkGroupError::KeyIdMismatch);
+        }
         response.proof.verify(
             self.profile_key_credentials_public_key,
             context.key_pair.get_public_key(),
@@ -301,7 +1292,7 @@ impl ServerPublicParams {
             .decrypt_blinded_profile_key_credential(response.blinded_credential);
 
         Ok(api::profiles::ProfileKeyCredential {
-            reserved: Default::default(),
+            version: api::profiles::ProfileKeyCredential::VERSION,
             credential,
             uid_bytes: context.uid_bytes,
             profile_key_bytes: context.profile_key_bytes,
@@ -311,16 +1302,31 @@ impl ServerPublicParams {
     pub fn create_profile_key_credential_presentation(
         &self,
         randomness: RandomnessBytes,
-        group_secret_params: api::groups::GroupSecretParams,
+        group_secret_params: &api::groups::GroupSecretParams,
         profile_key_credential: api::profiles::ProfileKeyCredential,
-    ) -> api::profiles::ProfileKeyCredentialPresentation {
+    ) -> Result<api::profiles::ProfileKeyCredentialPresentation, ZkGroupError> {
+        self.create_profile_key_credential_presentation_with_context(
+            randomness,
+            group_secret_params,
+            profile_key_credential,
+            &[],
+        )
+    }
+
+    pub fn create_profile_key_credential_presentation_with_context(
+        &self,
+        randomness: RandomnessBytes,
+        group_secret_params: &api::groups::GroupSecretParams,
+        profile_key_credential: api::profiles::ProfileKeyCredential,
+        context: &[u8],
+    ) -> Result<api::profiles::ProfileKeyCredentialPresentation, ZkGroupError> {
         let mut sho = Sho::new(
             b"Signal_ZKGroup_20200424_Random_ServerPublicParams_CreateProfileKeyCredentialPresentation",
             &randomness,
         );
 
-        let uid_enc_key_pair = group_secret_params.uid_enc_key_pair;
-        let profile_key_enc_key_pair = group_secret_params.profile_key_enc_key_pair;
+        let uid_enc_key_pair = &group_secret_params.uid_enc_key_pair;
+        let profile_key_enc_key_pair = &group_secret_params.profile_key_enc_key_pair;
         let credentials_public_key = self.profile_key_credentials_public_key;
 
         let uuid_ciphertext = group_secret_params.encrypt_uuid(profile_key_credential.uid_bytes);
@@ -338,14 +1344,273 @@ impl ServerPublicParams {
             profile_key_ciphertext.ciphertext,
             profile_key_credential.uid_bytes,
             profile_key_credential.profile_key_bytes,
+            context,
             &mut sho,
-        );
+        )?;
 
-        api::profiles::ProfileKeyCredentialPresentation {
-            reserved: Default::default(),
+        Ok(api::profiles::ProfileKeyCredentialPresentation {
+            version: KeyIdVersion {
+                key_id: self.key_id,
+            },
             proof,
             uid_enc_ciphertext: uuid_ciphertext.ciphertext,
             profile_key_enc_ciphertext: profile_key_ciphertext.ciphertext,
+        })
+    }
+
+    pub fn create_profile_key_credential_selective_presentation(
+        &self,
+        randomness: RandomnessBytes,
+        group_secret_params: &api::groups::GroupSecretParams,
+        profile_key_credential: api::profiles::ProfileKeyCredential,
+    ) -> Result<api::profiles::ProfileKeyCredentialSelectivePresentation, ZkGroupError> {
+        self.create_profile_key_credential_selective_presentation_with_context(
+            randomness,
+            group_secret_params,
+            profile_key_credential,
+            &[],
+        )
+    }
+
+    pub fn create_profile_key_credential_selective_presentation_with_context(
+        &self,
+        randomness: RandomnessBytes,
+        group_secret_params: &api::groups::GroupSecretParams,
+        profile_key_credential: api::profiles::ProfileKeyCredential,
+        context: &[u8],
+    ) -> Result<api::profiles::ProfileKeyCredentialSelectivePresentation, ZkGroupError> {
+        let mut sho = Sho::new(
+            b"Signal_ZKGroup_20200424_Random_ServerPublicParams_CreateProfileKeyCredentialSelectivePresentation",
+            &randomness,
+        );
+
+        let uuid_ciphertext = group_secret_params.encrypt_uuid(profile_key_credential.uid_bytes);
+
+        let proof = crypto::proofs::ProfileKeyCredentialSelectivePresentationProof::new(
+            &group_secret_params.uid_enc_key_pair,
+            self.profile_key_credentials_public_key,
+            profile_key_credential.credential,
+            uuid_ciphertext.ciphertext,
+            profile_key_credential.uid_bytes,
+            profile_key_credential.profile_key_bytes,
+            context,
+            &mut sho,
+        )?;
+
+        Ok(api::profiles::ProfileKeyCredentialSelectivePresentation {
+            version: api::profiles::ProfileKeyCredentialSelectivePresentation::VERSION,
+            key_id: self.key_id,
+            proof,
+            uid_enc_ciphertext: uuid_ciphertext.ciphertext,
+        })
+    }
+
+    pub fn create_receipt_credential_request_context(
+        &self,
+        randomness: RandomnessBytes,
+        receipt_serial_bytes: ReceiptSerialBytes,
+    ) -> api::receipts::ReceiptCredentialRequestContext {
+        let mut sho = Sho::new(
+            b"Signal_ZKGroup_20200424_Random_ServerPublicParams_CreateReceiptCredentialRequestContext",
+            &randomness,
+        );
+
+        let key_pair = crypto::receipt_credential_request::KeyPair::generate(&mut sho);
+        let ciphertext_with_secret_nonce = key_pair.encrypt(receipt_serial_bytes, &mut sho);
+
+        api::receipts::ReceiptCredentialRequestContext {
+            version: api::receipts::ReceiptCredentialRequestContext::VERSION,
+            receipt_serial_bytes,
+            key_pair,
+            ciphertext_with_secret_nonce,
+        }
+    }
+
+    pub fn receive_receipt_credential(
+        &self,
+        context: &api::receipts::ReceiptCredentialRequestContext,
+        response: &api::receipts::ReceiptCredentialResponse,
+    ) -> Result<api::receipts::ReceiptCredential, ZkGroupError> {
+        if response.key_id != self.key_id {
+            return Err(ZkGroupError::KeyIdMismatch);
+        }
+        response.proof.verify(
+            self.get_receipt_credentials_public_key()?,
+            context.key_pair.get_public_key(),
+            context.ciphertext_with_secret_nonce.get_ciphertext(),
+            response.blinded_credential,
+            response.receipt_expiration_time,
+            response.receipt_level,
+        )?;
+
+        let credential = context
+            .key_pair
+            .decrypt_blinded_receipt_credential(response.blinded_credential);
+
+        Ok(api::receipts::ReceiptCredential {
+            version: api::receipts::ReceiptCredential::VERSION,
+            credential,
+            receipt_serial_bytes: context.receipt_serial_bytes,
+            receipt_expiration_time: response.receipt_expiration_time,
+            receipt_level: response.receipt_level,
+        })
+    }
+
+    pub fn create_receipt_credential_presentation(
+        &self,
+        randomness: RandomnessBytes,
+        receipt_credential: &api::receipts::ReceiptCredential,
+    ) -> Result<api::receipts::ReceiptCredentialPresentation, ZkGroupError> {
+        let mut sho = Sho::new(
+            b"Signal_ZKGroup_20200424_Random_ServerPublicParams_CreateReceiptCredentialPresentation",
+            &randomness,
+        );
+
+        let proof = crypto::proofs::ReceiptCredentialPresentationProof::new(
+            self.get_receipt_credentials_public_key()?,
+            receipt_credential.credential,
+            &mut sho,
+        )?;
+
+        Ok(api::receipts::ReceiptCredentialPresentation {
+            version: api::receipts::ReceiptCredentialPresentation::VERSION,
+            key_id: self.key_id,
+            proof,
+            receipt_expiration_time: receipt_credential.receipt_expiration_time,
+            receipt_level: receipt_credential.receipt_level,
+            receipt_serial_bytes: receipt_credential.receipt_serial_bytes,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::serialization::{deserialize, serialize};
+    use crate::crypto::credentials::{KeyPair, KeyPairV0};
+
+    // Params as the baseline wrote them, including the fourth y it drew for
+    // the auth key pair but never used
+    fn baseline_bytes(randomness: RandomnessBytes) -> Vec<u8> {
+        let params = ServerSecretParams::generate(randomness).unwrap();
+        let mut sho = Sho::new(
+            b"Signal_ZKGroup_20200424_Random_ServerSecretParams_Generate",
+            &randomness,
+        );
+        let unused_y = KeyPair::generate(&mut sho, NUM_HARDCODED_CRED_ATTRIBUTES)
+            .unwrap()
+            .y[NUM_AUTH_CRED_ATTRIBUTES];
+        let mut auth_credentials_key_pair =
+            KeyPairV0::from_key_pair(&params.auth_credentials_key_pair).unwrap();
+        auth_credentials_key_pair.y[NUM_AUTH_CRED_ATTRIBUTES] = unused_y;
+        let v0 = ServerSecretParamsV0 {
+            auth_credentials_key_pair,
+            profile_key_credentials_key_pair: KeyPairV0::from_key_pair(
+                &params.profile_key_credentials_key_pair,
+            )
+            .unwrap(),
+            sig_key_pair: params.sig_key_pair.clone(),
+        };
+        serialize(&(0u8, v0)).unwrap()
+    }
+
+    #[test]
+    fn test_server_secret_params_versions() {
+        let params = ServerSecretParams::generate(TEST_ARRAY_32).unwrap();
+        let bytes = serialize(&params).unwrap();
+        assert!(bytes.len() == SERVER_SECRET_PARAMS_LEN && bytes[0] == 1);
+        let params2: ServerSecretParams = deserialize(&bytes).unwrap();
+        assert!(serialize(&params2).unwrap() == bytes);
+
+        // The baseline layout reads as version 0, with the default key id and
+        // without the key pairs it predates
+        let v0_bytes = baseline_bytes(TEST_ARRAY_32);
+        assert!(v0_bytes.len() == 769);
+        let mut v0_params: ServerSecretParams = deserialize(&v0_bytes).unwrap();
+        assert!(v0_params.get_key_id() == DEFAULT_SERVER_KEY_ID);
+        assert!(v0_params.get_version() == 0);
+
+        // and is written back the same way, but for the unused y
+        let mut expected = v0_bytes.clone();
+        for b in expected[1 + 8 * 32..1 + 9 * 32].iter_mut() {
+            *b = 0;
+        }
+        assert!(serialize(&v0_params).unwrap() == expected);
+
+        // Its public params have the baseline layout too
+        let v0_public_params = v0_params.get_public_params();
+        let public_bytes = serialize(&v0_public_params).unwrap();
+        let public_params = params.get_public_params();
+        let mut baseline_public_bytes = vec![0u8];
+        baseline_public_bytes
+            .extend(serialize(&public_params.auth_credentials_public_key).unwrap());
+        baseline_public_bytes
+            .extend(serialize(&public_params.profile_key_credentials_public_key).unwrap());
+        baseline_public_bytes.extend(serialize(&public_params.sig_public_key).unwrap());
+        assert!(public_bytes.len() == 161);
+        assert!(public_bytes == baseline_public_bytes);
+        let v0_public_params2: ServerPublicParams = deserialize(&public_bytes).unwrap();
+        assert!(serialize(&v0_public_params2).unwrap() == public_bytes);
+
+        // So do the auth credentials received with them
+        let uid_bytes = TEST_ARRAY_16;
+        let redemption_time = 123456;
+        let response = v0_params
+            .issue_auth_credential(TEST_ARRAY_32_1, uid_bytes, redemption_time)
+            .unwrap();
+        let credential = v0_public_params
+            .receive_auth_credential(uid_bytes, redemption_time, &response)
+            .unwrap();
+        let credential_bytes = serialize(&credential).unwrap();
+        assert!(credential_bytes.len() == 342 && credential_bytes[0] == 0);
+        let credential2: api::auth::AuthCredential = deserialize(&credential_bytes).unwrap();
+        assert!(serialize(&credential2).unwrap() == credential_bytes);
+        let mut mismatched = credential_bytes;
+        mismatched[0] = 1;
+        match deserialize::<api::auth::AuthCredential>(&mismatched) {
+            Err(ZkGroupError::InvalidContents { .. }) => (),
+            _ => panic!(),
+        }
+
+        // The key pairs both versions hold are the same
+        let response = params
+            .issue_auth_credential(TEST_ARRAY_32_1, uid_bytes, redemption_time)
+            .unwrap();
+        assert!(v0_public_params
+            .receive_auth_credential(uid_bytes, redemption_time, &response)
+            .is_ok());
+
+        // Receipt credentials need a key pair version 0 lacks, until upgraded
+        let receipt_context = v0_public_params
+            .create_receipt_credential_request_context(TEST_ARRAY_32_2, TEST_ARRAY_16);
+        let receipt_request = receipt_context.get_request();
+        match v0_params.issue_receipt_credential(TEST_ARRAY_32_3, &receipt_request, 86400, 3) {
+            Err(ZkGroupError::KeyPairMissing) => (),
+            _ => panic!(),
+        }
+        assert!(v0_params.upgrade(TEST_ARRAY_32_4).unwrap());
+        assert!(!v0_params.upgrade(TEST_ARRAY_32_4).unwrap());
+        let upgraded_bytes = serialize(&v0_params).unwrap();
+        assert!(upgraded_bytes.len() == SERVER_SECRET_PARAMS_LEN && upgraded_bytes[0] == 1);
+        let upgraded_public_params = v0_params.get_public_params();
+        assert!(serialize(&upgraded_public_params).unwrap().len() == SERVER_PUBLIC_PARAMS_LEN);
+        let receipt_response = v0_params
+            .issue_receipt_credential(TEST_ARRAY_32_3, &receipt_request, 86400, 3)
+            .unwrap();
+        assert!(upgraded_public_params
+            .receive_receipt_credential(&receipt_context, &receipt_response)
+            .is_ok());
+        match v0_public_params.receive_receipt_credential(&receipt_context, &receipt_response) {
+            Err(ZkGroupError::KeyPairMissing) => (),
+            _ => panic!(),
+        }
+
+        // A version 0 key pair is still validated
+        let mut bad = v0_bytes;
+        bad[1 + 3 * 32] ^= 1;
+        match deserialize::<ServerSecretParams>(&bad) {
+            Err(ZkGroupError::InvalidContents { .. }) => (),
+            _ => panic!(),
         }
     }
 }
@@ -9,15 +9,34 @@
 
 pub const NUM_AUTH_CRED_ATTRIBUTES: usize = 3;
 pub const NUM_PROFILE_KEY_CRED_ATTRIBUTES: usize = 4;
+pub const NUM_RECEIPT_CRED_ATTRIBUTES: usize = 2;
+pub const NUM_RATE_LIMIT_CRED_ATTRIBUTES: usize = 2;
+pub const MIN_CRED_ATTRIBUTES: usize = 2;
+// How many attributes the hardcoded credentials system params have generators for
+pub const NUM_HARDCODED_CRED_ATTRIBUTES: usize = 4;
+pub const MAX_AUTH_CREDENTIAL_BATCH_SIZE: usize = 32;
 
 pub const AES_KEY_LEN: usize = 32;
 pub const AESGCM_NONCE_LEN: usize = 12;
 pub const AESGCM_TAG_LEN: usize = 16;
+pub const BLOB_STREAM_CHUNK_LEN: usize = 65536;
+pub const BLOB_STREAM_NONCE_PREFIX_LEN: usize = 7;
+pub const CHALLENGE_NONCE_LEN: usize = 32;
+pub const BLOB_FORMAT_PLAIN: u8 = 0;
+pub const BLOB_FORMAT_STREAM: u8 = 1;
+pub const BLOB_FORMAT_PADDED: u8 = 2;
+// A padded blob is built in memory whole, so a policy can't ask for more
+pub const MAX_PADDED_BLOB_LEN: usize = 1 << 24;
 pub const GROUP_MASTER_KEY_LEN: usize = 32;
 pub const GROUP_SECRET_PARAMS_LEN: usize = 289;
 pub const GROUP_PUBLIC_PARAMS_LEN: usize = 97;
 pub const GROUP_IDENTIFIER_LEN: usize = 32;
-pub const AUTH_CREDENTIAL_LEN: usize = 342;
+pub const GROUP_INVITE_LINK_PASSWORD_LEN: usize = 16;
+pub const GROUP_INVITE_LINK_PASSWORD_COMMITMENT_LEN: usize = 33;
+pub const GROUP_INVITE_LINK_PRESENTATION_LEN: usize = 137;
+pub const PENDING_MEMBER_INVITATION_LEN: usize = 129;
+pub const PRESENTATION_CHALLENGE_LEN: usize = 106;
+pub const AUTH_CREDENTIAL_LEN: usize = 471;
 pub const AUTH_CREDENTIAL_PRESENTATION_LEN: usize = 493;
 pub const AUTH_CREDENTIAL_RESPONSE_LEN: usize = 361;
 pub const PROFILE_KEY_LEN: usize = 32;
@@ -28,16 +47,37 @@ pub const PROFILE_KEY_CREDENTIAL_PRESENTATION_LEN: usize = 713;
 pub const PROFILE_KEY_CREDENTIAL_REQUEST_LEN: usize = 329;
 pub const PROFILE_KEY_CREDENTIAL_REQUEST_CONTEXT_LEN: usize = 473;
 pub const PROFILE_KEY_CREDENTIAL_RESPONSE_LEN: usize = 457;
+pub const PROFILE_KEY_CREDENTIAL_SELECTIVE_PRESENTATION_LEN: usize = 522;
 pub const PROFILE_KEY_VERSION_LEN: usize = 32;
 pub const PROFILE_KEY_VERSION_ENCODED_LEN: usize = 64;
-pub const RESERVED_LEN: usize = 1;
-pub const SERVER_SECRET_PARAMS_LEN: usize = 769;
-pub const SERVER_PUBLIC_PARAMS_LEN: usize = 161;
+pub const RANDOMIZED_UUID_CIPHERTEXT_LEN: usize = 65;
+pub const RATE_LIMIT_CREDENTIAL_LEN: usize = 165;
+pub const RATE_LIMIT_CREDENTIAL_REQUEST_LEN: usize = 97;
+pub const RATE_LIMIT_CREDENTIAL_REQUEST_CONTEXT_LEN: usize = 225;
+pub const RATE_LIMIT_CREDENTIAL_RESPONSE_LEN: usize = 398;
+pub const RATE_LIMIT_TAG_LEN: usize = 32;
+pub const RATE_LIMIT_TOKEN_LEN: usize = 366;
+pub const RECEIPT_CREDENTIAL_LEN: usize = 129;
+pub const RECEIPT_CREDENTIAL_PRESENTATION_LEN: usize = 330;
+pub const RECEIPT_CREDENTIAL_REQUEST_LEN: usize = 97;
+pub const RECEIPT_CREDENTIAL_REQUEST_CONTEXT_LEN: usize = 177;
+pub const RECEIPT_CREDENTIAL_RESPONSE_LEN: usize = 410;
+pub const RECEIPT_SERIAL_LEN: usize = 16;
+pub const SERVER_SECRET_PARAMS_LEN: usize = 1346;
+pub const SERVER_PUBLIC_PARAMS_LEN: usize = 290;
 pub const UUID_CIPHERTEXT_LEN: usize = 65;
+pub const UUID_CIPHERTEXT_MATCH_PROOF_LEN: usize = 105;
 pub const RANDOMNESS_LEN: usize = 32;
+pub const SCALAR_LEN: usize = 32;
 pub const SIGNATURE_LEN: usize = 64;
 pub const UUID_LEN: usize = 16;
 
+pub const SECONDS_PER_DAY: u64 = 86400;
+pub const DEFAULT_REDEMPTION_DAYS_BEFORE: u32 = 1;
+pub const DEFAULT_REDEMPTION_DAYS_AFTER: u32 = 2;
+
+pub const DEFAULT_SERVER_KEY_ID: u8 = 0;
+
 pub const TEST_ARRAY_16: [u8; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
 
 pub const TEST_ARRAY_16_1: [u8; 16] = [
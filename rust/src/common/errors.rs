@@ -5,12 +5,187 @@
 // SPDX-License-Identifier: GPL-3.0-only
 //
 
+use crate::ffi::constants::*;
+use std::cell::Cell;
+use std::error::Error;
+use std::fmt;
+
 #[derive(Debug)]
 pub enum ZkGroupError {
-    BadArgs,                      // Bad arguments were passed to the function
-    DecryptionFailure,            // Decryption failed
-    MacVerificationFailure,       // MAC verification failed
-    ProofVerificationFailure,     // Proof verification failed
-    SignatureVerificationFailure, // Signature verification failed
-    PointDecodeFailure,           // Lizard failed to decode; CAN HAPPEN
+    BadArgs,                                // Bad arguments were passed to the function
+    DecryptionFailure,                      // Decryption failed
+    MacVerificationFailure,                 // MAC verification failed
+    ProofVerificationFailure(&'static str), // The named proof or presentation didn't verify
+    SignatureVerificationFailure,           // Signature verification failed
+    PointDecodeFailure,                     // Lizard failed to decode; CAN HAPPEN
+    InvalidRedemptionTime,                  // Redemption time is outside the acceptable window
+    KeyIdMismatch,                          // Object was produced under a different server key
+    IoFailure(std::io::Error),              // Reading from or writing to a stream failed
+    InvalidAttributeCount,                  // A credential has too many or too few attributes
+    ProofGenerationFailure(&'static str),   // poksho couldn't produce the named proof
+    ChallengeExpired,                       // A presentation challenge is past its expiration time
+    ChallengeReplayed,                      // A presentation challenge was already redeemed
+    ReplayCacheFull,                        // A replay cache has no room for another nonce
+    KeyPairMissing,                         // Server params need upgrade() for this credential
+    // The named type couldn't be serialized
+    SerializationFailure {
+        type_name: &'static str,
+        source: bincode::Error,
+    },
+    // The bytes couldn't be decoded as the named type
+    DeserializationFailure {
+        type_name: &'static str,
+        source: bincode::Error,
+    },
+    // The named type was decoded from, or encodes to, the wrong number of bytes
+    LengthMismatch {
+        type_name: &'static str,
+        expected: usize,
+        actual: usize,
+    },
+    // The bytes carry a version of the named type this build can't read
+    UnsupportedVersion {
+        type_name: &'static str,
+        version: u8,
+    },
+    // The named type decoded, but its contents are structurally invalid
+    InvalidContents {
+        type_name: &'static str,
+        reason: &'static str,
+    },
+}
+
+impl ZkGroupError {
+    // Stable across releases, so hosts and logs can rely on them: new
+    // variants get new codes, and codes are never reused
+    pub fn get_code(&self) -> i32 {
+        match self {
+            ZkGroupError::BadArgs => 100,
+            ZkGroupError::DecryptionFailure => 101,
+            ZkGroupError::MacVerificationFailure => 102,
+            ZkGroupError::ProofVerificationFailure(_) => 103,
+            ZkGroupError::SignatureVerificationFailure => 104,
+            ZkGroupError::PointDecodeFailure => 105,
+            ZkGroupError::InvalidRedemptionTime => 106,
+            ZkGroupError::KeyIdMismatch => 107,
+            ZkGroupError::IoFailure(_) => 108,
+            ZkGroupError::InvalidAttributeCount => 109,
+            ZkGroupError::ProofGenerationFailure(_) => 110,
+            ZkGroupError::SerializationFailure { .. } => 111,
+            ZkGroupError::DeserializationFailure { .. } => 112,
+            ZkGroupError::LengthMismatch { .. } => 113,
+            ZkGroupError::UnsupportedVersion { .. } => 114,
+            ZkGroupError::InvalidContents { .. } => 115,
+            ZkGroupError::ChallengeExpired => 116,
+            ZkGroupError::ChallengeReplayed => 117,
+            ZkGroupError::ReplayCacheFull => 118,
+            ZkGroupError::KeyPairMissing => 119,
+        }
+    }
+
+    // Errors the caller could have caused map to FFI_RETURN_INPUT_ERROR; the
+    // rest are bugs or environment failures on our side. Either way, get_code()
+    // is kept for get_last_error_code.
+    pub fn get_ffi_return_code(&self) -> i32 {
+        let ffi_return = match self {
+            ZkGroupError::IoFailure(_)
+            | ZkGroupError::InvalidAttributeCount
+            | ZkGroupError::ProofGenerationFailure(_)
+            | ZkGroupError::ReplayCacheFull
+            | ZkGroupError::SerializationFailure { .. } => FFI_RETURN_INTERNAL_ERROR,
+            _ => FFI_RETURN_INPUT_ERROR,
+        };
+        self.record_last_error(ffi_return)
+    }
+
+    // For FFI paths that pick their own return code
+    pub(crate) fn record_last_error(&self, ffi_return: i32) -> i32 {
+        LAST_ERROR_CODE.with(|code| code.set(self.get_code()));
+        ffi_return
+    }
+}
+
+thread_local! {
+    static LAST_ERROR_CODE: Cell<i32> = Cell::new(0);
+}
+
+// The get_code() of the error behind this thread's last failed FFI call, or 0
+// if the last call succeeded or failed without a ZkGroupError (a panic, or an
+// unversioned input that didn't decode). Every FFI entry point clears it.
+pub fn get_last_error_code() -> i32 {
+    LAST_ERROR_CODE.with(|code| code.get())
+}
+
+pub(crate) fn clear_last_error_code() {
+    LAST_ERROR_CODE.with(|code| code.set(0));
+}
+
+impl fmt::Display for ZkGroupError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ZkGroupError::BadArgs => write!(f, "bad arguments"),
+            ZkGroupError::DecryptionFailure => write!(f, "decryption failed"),
+            ZkGroupError::MacVerificationFailure => write!(f, "MAC verification failed"),
+            ZkGroupError::ProofVerificationFailure(name) => {
+                write!(f, "verification of {} failed", name)
+            }
+            ZkGroupError::SignatureVerificationFailure => {
+                write!(f, "signature verification failed")
+            }
+            ZkGroupError::PointDecodeFailure => write!(f, "point decoding failed"),
+            ZkGroupError::InvalidRedemptionTime => {
+                write!(f, "redemption time is outside the acceptable window")
+            }
+            ZkGroupError::KeyIdMismatch => {
+                write!(f, "object was produced under a different server key")
+            }
+            ZkGroupError::IoFailure(_) => write!(f, "stream I/O failed"),
+            ZkGroupError::InvalidAttributeCount => {
+                write!(f, "credential has an invalid number of attributes")
+            }
+            ZkGroupError::ProofGenerationFailure(name) => {
+                write!(f, "generation of {} failed", name)
+            }
+            ZkGroupError::ChallengeExpired => write!(f, "presentation challenge has expired"),
+            ZkGroupError::ChallengeReplayed => {
+                write!(f, "presentation challenge was already redeemed")
+            }
+            ZkGroupError::ReplayCacheFull => write!(f, "replay cache is full"),
+            ZkGroupError::KeyPairMissing => {
+                write!(f, "server params lack the key pair for this credential")
+            }
+            ZkGroupError::SerializationFailure { type_name, .. } => {
+                write!(f, "serializing {} failed", type_name)
+            }
+            ZkGroupError::DeserializationFailure { type_name, .. } => {
+                write!(f, "deserializing {} failed", type_name)
+            }
+            ZkGroupError::LengthMismatch {
+                type_name,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "{} should be {} bytes, but was {}",
+                type_name, expected, actual
+            ),
+            ZkGroupError::UnsupportedVersion { type_name, version } => {
+                write!(f, "{} version {} is not supported", type_name, version)
+            }
+            ZkGroupError::InvalidContents { type_name, reason } => {
+                write!(f, "{} is invalid: {}", type_name, reason)
+            }
+        }
+    }
+}
+
+impl Error for ZkGroupError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ZkGroupError::IoFailure(source) => Some(source),
+            ZkGroupError::SerializationFailure { source, .. } => Some(source),
+            ZkGroupError::DeserializationFailure { source, .. } => Some(source),
+            _ => None,
+        }
+    }
 }
@@ -7,5 +7,7 @@
 
 pub mod constants;
 pub mod errors;
+#[macro_use]
+pub mod serialization;
 pub mod sho;
 pub mod simple_types;
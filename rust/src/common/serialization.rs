@@ -0,0 +1,801 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+
+use crate::common::constants::*;
+use crate::common::errors::*;
+use crate::common::simple_types::*;
+use curve25519_dalek::ristretto::RistrettoPoint;
+use curve25519_dalek::traits::IsIdentity;
+use serde::de::{self, SeqAccess, Unexpected, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::any::type_name;
+use std::fmt;
+use std::marker::PhantomData;
+
+// Implemented by every public type whose encoding starts with a version byte.
+//
+// To roll out a new format, bump VERSION, have is_supported_version also
+// accept the old one, and implement deserialize_old_version to read it.
+// deserialize() dispatches on the leading byte, so old and new objects can be
+// read by the same process. Objects are written with VERSION, except where a
+// type writes the oldest version that can hold the object (see KeyIdVersion).
+pub trait Versioned: Sized {
+    // The version new objects are written with
+    const VERSION: VersionByte;
+
+    fn is_supported_version(version: VersionByte) -> bool {
+        version == Self::VERSION
+    }
+
+    // Called by deserialize() for a supported version other than VERSION
+    fn deserialize_old_version(version: VersionByte, _bytes: &[u8]) -> Result<Self, ZkGroupError> {
+        Err(ZkGroupError::UnsupportedVersion {
+            type_name: type_name::<Self>(),
+            version,
+        })
+    }
+}
+
+// Checks what decoding alone doesn't. serde already rejects non-canonical
+// scalars and points, and deserialize() rejects the wrong length, so this is
+// about structure: points that must not be the identity, key pairs whose
+// public half matches the private half, and so on.
+pub trait Validate {
+    fn validate(&self) -> Result<(), ZkGroupError>;
+}
+
+// Fails with InvalidContents naming T unless the condition holds
+pub(crate) fn ensure<T: ?Sized>(condition: bool, reason: &'static str) -> Result<(), ZkGroupError> {
+    if condition {
+        Ok(())
+    } else {
+        Err(ZkGroupError::InvalidContents {
+            type_name: type_name::<T>(),
+            reason,
+        })
+    }
+}
+
+pub(crate) fn ensure_not_identity<T: ?Sized>(
+    point: &RistrettoPoint,
+    reason: &'static str,
+) -> Result<(), ZkGroupError> {
+    ensure::<T>(!point.is_identity(), reason)
+}
+
+// For the types whose layout after the version byte depends on the version.
+// They're encoded as a tuple of the version and the rest, which bincode writes
+// just as it would a struct with a version field.
+pub(crate) trait VersionedLayout: Sized {
+    // Names the versions read_layout accepts, for error messages
+    const EXPECTING: &'static str;
+
+    // Reads what follows the version byte
+    fn read_layout<'de, A>(version: VersionByte, seq: A) -> Result<Self, A::Error>
+    where
+        A: SeqAccess<'de>;
+}
+
+pub(crate) fn deserialize_layout<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: VersionedLayout,
+{
+    struct LayoutVisitor<T>(PhantomData<T>);
+
+    impl<'de, T: VersionedLayout> Visitor<'de> for LayoutVisitor<T> {
+        type Value = T;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str(T::EXPECTING)
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<T, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let version = next_layout_element::<T, _, _>(&mut seq, 0)?;
+            T::read_layout(version, seq)
+        }
+    }
+
+    // The version, then at most one more element
+    deserializer.deserialize_tuple(2, LayoutVisitor(PhantomData))
+}
+
+pub(crate) fn next_layout_element<'de, T, A, E>(seq: &mut A, index: usize) -> Result<E, A::Error>
+where
+    T: VersionedLayout,
+    A: SeqAccess<'de>,
+    E: Deserialize<'de>,
+{
+    seq.next_element()?
+        .ok_or_else(|| de::Error::invalid_length(index, &T::EXPECTING))
+}
+
+pub(crate) fn unknown_layout<T: VersionedLayout, E: de::Error>(version: VersionByte) -> E {
+    E::invalid_value(Unexpected::Unsigned(version.into()), &T::EXPECTING)
+}
+
+// Leads the types that shipped before server key ids, in place of their version
+// byte. Version 0 is the baseline layout and implies the default key id;
+// version 1 is followed by the key id. The default key id is always written as
+// version 0, so those objects keep their baseline encoding.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub(crate) struct KeyIdVersion {
+    pub(crate) key_id: ServerKeyId,
+}
+
+impl KeyIdVersion {
+    pub(crate) const BASELINE: VersionByte = 0;
+    pub(crate) const NEWEST: VersionByte = 1;
+}
+
+impl Serialize for KeyIdVersion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if self.key_id == DEFAULT_SERVER_KEY_ID {
+            Self::BASELINE.serialize(serializer)
+        } else {
+            (Self::NEWEST, self.key_id).serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for KeyIdVersion {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_layout(deserializer)
+    }
+}
+
+impl VersionedLayout for KeyIdVersion {
+    const EXPECTING: &'static str = "version 0, or version 1 and a key id other than the default";
+
+    fn read_layout<'de, A>(version: VersionByte, mut seq: A) -> Result<Self, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let key_id = match version {
+            KeyIdVersion::BASELINE => DEFAULT_SERVER_KEY_ID,
+            KeyIdVersion::NEWEST => {
+                let key_id: ServerKeyId = next_layout_element::<Self, _, _>(&mut seq, 1)?;
+                // Otherwise the same object would have two encodings
+                if key_id == DEFAULT_SERVER_KEY_ID {
+                    return Err(de::Error::invalid_value(
+                        Unexpected::Unsigned(key_id.into()),
+                        &Self::EXPECTING,
+                    ));
+                }
+                key_id
+            }
+            _ => return Err(unknown_layout::<Self, _>(version)),
+        };
+        Ok(KeyIdVersion { key_id })
+    }
+}
+
+// With the serde_human_readable feature, each api type moves its derived serde
+// impls aside with #[serde(remote = "Self")] and gets these instead: formats
+// like JSON see the type's bincode encoding as a hex string, and binary formats
+// see the derived encoding, so bincode output is the same either way. The hex
+// is read back through deserialize(), so JSON gets the same version dispatch
+// and validation as bytes from the FFI.
+#[cfg(feature = "serde_human_readable")]
+macro_rules! human_readable_serde {
+    ($type:ident) => {
+        human_readable_serde!(
+            @impl $type,
+            crate::common::serialization::deserialize,
+            serialize,
+            deserialize
+        );
+    };
+    // For the types that are plain bytes, with no version byte
+    (unversioned $type:ident) => {
+        human_readable_serde!(
+            @impl $type,
+            crate::common::serialization::deserialize_unversioned,
+            serialize,
+            deserialize
+        );
+    };
+    // For the types whose binary encoding is written by hand, in their
+    // serialize_binary and deserialize_binary
+    (binary $type:ident) => {
+        human_readable_serde!(
+            @impl $type,
+            crate::common::serialization::deserialize,
+            serialize_binary,
+            deserialize_binary
+        );
+    };
+    (@impl $type:ident, $deserialize:path, $serialize_binary:ident, $deserialize_binary:ident) => {
+        impl serde::Serialize for $type {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                if serializer.is_human_readable() {
+                    let bytes = crate::common::serialization::serialize(self)
+                        .map_err(serde::ser::Error::custom)?;
+                    serializer.serialize_str(&hex::encode(bytes))
+                } else {
+                    $type::$serialize_binary(self, serializer)
+                }
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $type {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                if deserializer.is_human_readable() {
+                    let hex_string = <String as serde::Deserialize>::deserialize(deserializer)?;
+                    let bytes = hex::decode(hex_string).map_err(serde::de::Error::custom)?;
+                    $deserialize(&bytes).map_err(serde::de::Error::custom)
+                } else {
+                    $type::$deserialize_binary(deserializer)
+                }
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "serde_human_readable"))]
+macro_rules! human_readable_serde {
+    (binary $type:ident) => {
+        impl serde::Serialize for $type {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                $type::serialize_binary(self, serializer)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $type {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                $type::deserialize_binary(deserializer)
+            }
+        }
+    };
+    ($($tokens:tt)*) => {};
+}
+
+pub fn serialize<T: Serialize>(value: &T) -> Result<Vec<u8>, ZkGroupError> {
+    bincode::serialize(value).map_err(|source| ZkGroupError::SerializationFailure {
+        type_name: type_name::<T>(),
+        source,
+    })
+}
+
+// Unlike bincode::deserialize, checks the version byte, rejects trailing
+// bytes, and validates the result
+pub fn deserialize<'a, T>(bytes: &'a [u8]) -> Result<T, ZkGroupError>
+where
+    T: Deserialize<'a> + Serialize + Versioned + Validate,
+{
+    let value: T = match bytes.first() {
+        Some(&version) if !T::is_supported_version(version) => {
+            return Err(ZkGroupError::UnsupportedVersion {
+                type_name: type_name::<T>(),
+                version,
+            });
+        }
+        Some(&version) if version != T::VERSION => T::deserialize_old_version(version, bytes)?,
+        _ => deserialize_unversioned(bytes)?,
+    };
+    value.validate()?;
+    Ok(value)
+}
+
+// For the types that are plain bytes or scalars, with no version byte
+pub fn deserialize_unversioned<'a, T>(bytes: &'a [u8]) -> Result<T, ZkGroupError>
+where
+    T: Deserialize<'a> + Serialize,
+{
+    let value: T =
+        bincode::deserialize(bytes).map_err(|source| ZkGroupError::DeserializationFailure {
+            type_name: type_name::<T>(),
+            source,
+        })?;
+    let expected =
+        bincode::serialized_size(&value).map_err(|source| ZkGroupError::SerializationFailure {
+            type_name: type_name::<T>(),
+            source,
+        })? as usize;
+    if expected != bytes.len() {
+        return Err(ZkGroupError::LengthMismatch {
+            type_name: type_name::<T>(),
+            expected,
+            actual: bytes.len(),
+        });
+    }
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api;
+    use crate::common::constants::*;
+    use curve25519_dalek::constants::RISTRETTO_BASEPOINT_POINT;
+    use curve25519_dalek::scalar::Scalar;
+    use curve25519_dalek::traits::Identity;
+    use serde::de::DeserializeOwned;
+    use std::error::Error;
+
+    #[test]
+    fn test_deserialize_errors() {
+        let master_key = api::groups::GroupMasterKey::new(TEST_ARRAY_32);
+        let mut bytes = serialize(&master_key).unwrap();
+        assert!(deserialize_unversioned::<api::groups::GroupMasterKey>(&bytes).is_ok());
+
+        bytes.push(0);
+        match deserialize_unversioned::<api::groups::GroupMasterKey>(&bytes) {
+            Err(ZkGroupError::LengthMismatch {
+                expected, actual, ..
+            }) => assert!(expected == GROUP_MASTER_KEY_LEN && actual == GROUP_MASTER_KEY_LEN + 1),
+            _ => panic!(),
+        }
+
+        let error = deserialize_unversioned::<api::groups::GroupMasterKey>(&bytes[..8])
+            .err()
+            .unwrap();
+        assert!(error.source().is_some());
+        assert!(error.to_string().contains("GroupMasterKey"));
+    }
+
+    #[test]
+    fn test_unsupported_version() {
+        let group_secret_params = api::groups::GroupSecretParams::generate(TEST_ARRAY_32);
+        let ciphertext = group_secret_params.encrypt_uuid(TEST_ARRAY_16);
+        let mut bytes = serialize(&ciphertext).unwrap();
+        assert!(bytes[0] == api::groups::UuidCiphertext::VERSION);
+        assert!(deserialize::<api::groups::UuidCiphertext>(&bytes).is_ok());
+
+        bytes[0] = 1;
+        match deserialize::<api::groups::UuidCiphertext>(&bytes) {
+            Err(ZkGroupError::UnsupportedVersion { version: 1, .. }) => (),
+            _ => panic!(),
+        }
+    }
+
+    // Checks the vectors every type must reject (trailing and missing bytes,
+    // an unknown version) plus a type-specific structurally invalid encoding
+    fn check_vectors<T>(value: &T, invalid_bytes: Vec<u8>)
+    where
+        T: DeserializeOwned + Serialize + Versioned + Validate,
+    {
+        let bytes = serialize(value).unwrap();
+        assert!(deserialize::<T>(&bytes).is_ok());
+
+        let mut longer = bytes.clone();
+        longer.push(0);
+        match deserialize::<T>(&longer) {
+            Err(ZkGroupError::LengthMismatch { .. }) => (),
+            _ => panic!("{} accepted a trailing byte", type_name::<T>()),
+        }
+
+        match deserialize::<T>(&bytes[..bytes.len() - 1]) {
+            Err(ZkGroupError::DeserializationFailure { .. }) => (),
+            _ => panic!("{} accepted a missing byte", type_name::<T>()),
+        }
+
+        let mut bumped = bytes.clone();
+        bumped[0] = T::VERSION + 1;
+        match deserialize::<T>(&bumped) {
+            Err(ZkGroupError::UnsupportedVersion { .. }) => (),
+            _ => panic!("{} accepted an unknown version", type_name::<T>()),
+        }
+
+        match deserialize::<T>(&invalid_bytes) {
+            Err(ZkGroupError::InvalidContents { .. }) => (),
+            _ => panic!("{} accepted invalid contents", type_name::<T>()),
+        }
+    }
+
+    // For the types that aren't Clone
+    fn copy<T: DeserializeOwned + Serialize>(value: &T) -> T {
+        bincode::deserialize(&serialize(value).unwrap()).unwrap()
+    }
+
+    // Appends a byte to the poksho proof whose length prefix is at the given
+    // offset, so it's no longer a whole number of scalars
+    fn lengthen_proof(bytes: &[u8], len_offset: usize) -> Vec<u8> {
+        let mut len_bytes = [0u8; 8];
+        len_bytes.copy_from_slice(&bytes[len_offset..len_offset + 8]);
+        let len = u64::from_le_bytes(len_bytes);
+        let mut result = bytes.to_vec();
+        result[len_offset..len_offset + 8].copy_from_slice(&(len + 1).to_le_bytes());
+        result.insert(len_offset + 8 + len as usize, 0);
+        result
+    }
+
+    #[test]
+    fn test_validate_negative_vectors() {
+        let identity = RistrettoPoint::identity();
+        let uid_bytes = TEST_ARRAY_16;
+        let redemption_time = 123456;
+
+        let server_secret_params = api::ServerSecretParams::generate(TEST_ARRAY_32).unwrap();
+        let mut bad = copy(&server_secret_params);
+        bad.auth_credentials_key_pair.x0 += Scalar::one();
+        check_vectors(&server_secret_params, serialize(&bad).unwrap());
+
+        let server_public_params = server_secret_params.get_public_params();
+        let mut bad = server_public_params;
+        bad.auth_credentials_public_key.I = identity;
+        check_vectors(&server_public_params, serialize(&bad).unwrap());
+
+        let group_secret_params = api::groups::GroupSecretParams::generate(TEST_ARRAY_32_1);
+        let mut bad = group_secret_params.clone();
+        bad.uid_enc_key_pair.a2 += Scalar::one();
+        check_vectors(&group_secret_params, serialize(&bad).unwrap());
+
+        let group_public_params = group_secret_params.get_public_params();
+        let mut bad = group_public_params;
+        bad.uid_enc_public_key.A = identity;
+        check_vectors(&group_public_params, serialize(&bad).unwrap());
+
+        let uuid_ciphertext = group_secret_params.encrypt_uuid(uid_bytes);
+        let mut bad = uuid_ciphertext;
+        bad.ciphertext.E_A1 = RISTRETTO_BASEPOINT_POINT;
+        check_vectors(&uuid_ciphertext, serialize(&bad).unwrap());
+
+        // Non-canonical points are already rejected while decoding
+        let mut non_canonical = serialize(&uuid_ciphertext).unwrap();
+        for b in non_canonical[1..33].iter_mut() {
+            *b = 0xff;
+        }
+        match deserialize::<api::groups::UuidCiphertext>(&non_canonical) {
+            Err(ZkGroupError::DeserializationFailure { .. }) => (),
+            _ => panic!(),
+        }
+
+        let profile_key = api::profiles::ProfileKey::create(TEST_ARRAY_32_2);
+        let profile_key_ciphertext =
+            group_secret_params.encrypt_profile_key(&profile_key, uid_bytes);
+        let mut bad = profile_key_ciphertext;
+        bad.ciphertext.E_B1 = identity;
+        check_vectors(&profile_key_ciphertext, serialize(&bad).unwrap());
+
+        let invitation = group_secret_params.create_pending_member_invitation(uid_bytes, uid_bytes);
+        let mut bad = invitation;
+        bad.inviter_uid_enc_ciphertext.E_A1 = identity;
+        check_vectors(&invitation, serialize(&bad).unwrap());
+
+        let password = api::groups::GroupInviteLinkPassword::create(TEST_ARRAY_16_1);
+        let password_commitment = password.get_commitment(group_public_params);
+        let mut bad = password_commitment;
+        bad.public_key.P = identity;
+        check_vectors(&password_commitment, serialize(&bad).unwrap());
+
+        let invite_link_presentation = group_secret_params
+            .create_group_invite_link_presentation(TEST_ARRAY_32_3, &password, uid_bytes)
+            .unwrap();
+        let mut bad = copy(&invite_link_presentation);
+        bad.ciphertext.E_A1 = identity;
+        check_vectors(&invite_link_presentation, serialize(&bad).unwrap());
+
+        let equality_proof = group_secret_params
+            .create_uuid_ciphertext_equality_proof(
+                TEST_ARRAY_32_3,
+                uuid_ciphertext,
+                uuid_ciphertext,
+            )
+            .unwrap();
+        let bytes = serialize(&equality_proof).unwrap();
+        check_vectors(&equality_proof, lengthen_proof(&bytes, 1));
+
+        let randomized_ciphertext =
+            group_secret_params.encrypt_uuid_randomized(TEST_ARRAY_32_3, uid_bytes);
+        let mut bad = randomized_ciphertext;
+        bad.ciphertext.E_A1 = identity;
+        check_vectors(&randomized_ciphertext, serialize(&bad).unwrap());
+
+        let match_proof = group_secret_params
+            .create_uuid_ciphertext_match_proof(
+                TEST_ARRAY_32_3,
+                uuid_ciphertext,
+                randomized_ciphertext,
+            )
+            .unwrap();
+        let bytes = serialize(&match_proof).unwrap();
+        check_vectors(&match_proof, lengthen_proof(&bytes, 1));
+
+        let auth_credential_response = server_secret_params
+            .issue_auth_credential(TEST_ARRAY_32_4, uid_bytes, redemption_time)
+            .unwrap();
+        let mut bad = copy(&auth_credential_response);
+        bad.credential.U = identity;
+        check_vectors(&auth_credential_response, serialize(&bad).unwrap());
+
+        let auth_credential_batch_response = server_secret_params
+            .issue_auth_credentials(TEST_ARRAY_32_4, uid_bytes, &[redemption_time])
+            .unwrap();
+        let mut bad = copy(&auth_credential_batch_response);
+        bad.credentials.clear();
+        check_vectors(&auth_credential_batch_response, serialize(&bad).unwrap());
+
+        let auth_credential = server_public_params
+            .receive_auth_credential(uid_bytes, redemption_time, &auth_credential_response)
+            .unwrap();
+        let mut bad = auth_credential;
+        bad.uid.M1 = identity;
+        check_vectors(&auth_credential, serialize(&bad).unwrap());
+
+        let auth_credential_presentation = server_public_params
+            .create_auth_credential_presentation(
+                TEST_ARRAY_32_5,
+                &group_secret_params,
+                auth_credential,
+            )
+            .unwrap();
+        let mut bad = copy(&auth_credential_presentation);
+        bad.ciphertext.E_A1 = RISTRETTO_BASEPOINT_POINT;
+        check_vectors(&auth_credential_presentation, serialize(&bad).unwrap());
+
+        let rate_limit_context =
+            server_public_params.create_rate_limit_credential_request_context(TEST_ARRAY_32_3);
+        let mut bad = copy(&rate_limit_context);
+        bad.rate_limit.s += Scalar::one();
+        check_vectors(&rate_limit_context, serialize(&bad).unwrap());
+
+        let rate_limit_request = rate_limit_context.get_request();
+        let mut bad = rate_limit_request;
+        bad.ciphertext.D1 = identity;
+        check_vectors(&rate_limit_request, serialize(&bad).unwrap());
+
+        let rate_limit_response = server_secret_params
+            .issue_rate_limit_credential(TEST_ARRAY_32_4, &rate_limit_request, redemption_time)
+            .unwrap();
+        let mut bad = copy(&rate_limit_response);
+        bad.blinded_credential.U = identity;
+        check_vectors(&rate_limit_response, serialize(&bad).unwrap());
+
+        let rate_limit_credential = server_public_params
+            .receive_rate_limit_credential(&rate_limit_context, &rate_limit_response)
+            .unwrap();
+        let mut bad = rate_limit_credential.clone();
+        bad.rate_limit.M2 = identity;
+        check_vectors(&rate_limit_credential, serialize(&bad).unwrap());
+
+        let rate_limit_token = server_public_params
+            .create_rate_limit_token(TEST_ARRAY_32_5, &rate_limit_credential)
+            .unwrap();
+        let mut bad = copy(&rate_limit_token);
+        bad.tag = identity;
+        check_vectors(&rate_limit_token, serialize(&bad).unwrap());
+
+        let profile_key_commitment = profile_key.get_commitment(uid_bytes);
+        let mut bad = profile_key_commitment;
+        bad.commitment.J3 = identity;
+        check_vectors(&profile_key_commitment, serialize(&bad).unwrap());
+
+        let context = server_public_params
+            .create_profile_key_credential_request_context(TEST_ARRAY_32_3, uid_bytes, &profile_key)
+            .unwrap();
+        let mut bad = copy(&context);
+        bad.key_pair.y += Scalar::one();
+        check_vectors(&context, serialize(&bad).unwrap());
+
+        let request = context.get_request();
+        let mut bad = copy(&request);
+        bad.ciphertext.E1 = identity;
+        check_vectors(&request, serialize(&bad).unwrap());
+
+        let profile_key_credential_response = server_secret_params
+            .issue_profile_key_credential(
+                TEST_ARRAY_32_4,
+                &request,
+                uid_bytes,
+                profile_key_commitment,
+            )
+            .unwrap();
+        let mut bad = copy(&profile_key_credential_response);
+        bad.blinded_credential.U = identity;
+        check_vectors(&profile_key_credential_response, serialize(&bad).unwrap());
+
+        let profile_key_credential = server_public_params
+            .receive_profile_key_credential(&context, &profile_key_credential_response)
+            .unwrap();
+        let mut bad = profile_key_credential;
+        bad.credential.U = identity;
+        check_vectors(&profile_key_credential, serialize(&bad).unwrap());
+
+        let profile_key_credential_presentation = server_public_params
+            .create_profile_key_credential_presentation(
+                TEST_ARRAY_32_5,
+                &group_secret_params,
+                profile_key_credential,
+            )
+            .unwrap();
+        let mut bad = copy(&profile_key_credential_presentation);
+        bad.profile_key_enc_ciphertext.E_B1 = RISTRETTO_BASEPOINT_POINT;
+        check_vectors(
+            &profile_key_credential_presentation,
+            serialize(&bad).unwrap(),
+        );
+
+        let selective_presentation = server_public_params
+            .create_profile_key_credential_selective_presentation(
+                TEST_ARRAY_32_5,
+                &group_secret_params,
+                profile_key_credential,
+            )
+            .unwrap();
+        let mut bad = copy(&selective_presentation);
+        bad.uid_enc_ciphertext.E_A1 = RISTRETTO_BASEPOINT_POINT;
+        check_vectors(&selective_presentation, serialize(&bad).unwrap());
+
+        let receipt_context = server_public_params
+            .create_receipt_credential_request_context(TEST_ARRAY_32_3, TEST_ARRAY_16_1);
+        let mut bad = copy(&receipt_context);
+        bad.ciphertext_with_secret_nonce.r1 += Scalar::one();
+        check_vectors(&receipt_context, serialize(&bad).unwrap());
+
+        let receipt_request = receipt_context.get_request();
+        let mut bad = receipt_request;
+        bad.public_key.Y = identity;
+        check_vectors(&receipt_request, serialize(&bad).unwrap());
+
+        let receipt_response = server_secret_params
+            .issue_receipt_credential(TEST_ARRAY_32_4, &receipt_request, 86400, 3)
+            .unwrap();
+        let mut bad = copy(&receipt_response);
+        bad.blinded_credential.U = identity;
+        check_vectors(&receipt_response, serialize(&bad).unwrap());
+
+        let receipt_credential = server_public_params
+            .receive_receipt_credential(&receipt_context, &receipt_response)
+            .unwrap();
+        let mut bad = receipt_credential;
+        bad.credential.U = identity;
+        check_vectors(&receipt_credential, serialize(&bad).unwrap());
+
+        let receipt_presentation = server_public_params
+            .create_receipt_credential_presentation(TEST_ARRAY_32_5, &receipt_credential)
+            .unwrap();
+        let bytes = serialize(&receipt_presentation).unwrap();
+        // version, key id, and five commitment points precede the proof
+        check_vectors(&receipt_presentation, lengthen_proof(&bytes, 2 + 5 * 32));
+    }
+
+    #[test]
+    fn test_profile_key_version_serde() {
+        let profile_key = api::profiles::ProfileKey::create(TEST_ARRAY_32);
+        let version = profile_key.get_profile_key_version(TEST_ARRAY_16);
+        let bytes = serialize(&version).unwrap();
+        assert!(bytes.len() == PROFILE_KEY_VERSION_ENCODED_LEN);
+        let version2: api::profiles::ProfileKeyVersion = deserialize_unversioned(&bytes).unwrap();
+        assert!(version2.bytes[..] == version.bytes[..]);
+
+        let mut bad = bytes;
+        bad[0] = b'g';
+        match deserialize_unversioned::<api::profiles::ProfileKeyVersion>(&bad) {
+            Err(ZkGroupError::DeserializationFailure { .. }) => (),
+            _ => panic!(),
+        }
+    }
+
+    // JSON gets the bincode encoding as a hex string, and reads it back
+    #[cfg(feature = "serde_human_readable")]
+    fn check_json_unversioned<T: DeserializeOwned + Serialize>(value: &T) {
+        let bytes = serialize(value).unwrap();
+        let json = serde_json::to_string(value).unwrap();
+        assert!(json == format!("\"{}\"", hex::encode(&bytes)));
+        let value2: T = serde_json::from_str(&json).unwrap();
+        assert!(serialize(&value2).unwrap() == bytes);
+
+        let truncated = format!("\"{}\"", hex::encode(&bytes[..bytes.len() - 1]));
+        assert!(serde_json::from_str::<T>(&truncated).is_err());
+        assert!(serde_json::from_str::<T>("\"not hex\"").is_err());
+    }
+
+    // Versioned types are read back through deserialize(), so JSON rejects an
+    // unknown version and invalid contents just as bytes from the FFI are
+    #[cfg(feature = "serde_human_readable")]
+    fn check_json<T>(value: &T, invalid_bytes: Vec<u8>)
+    where
+        T: DeserializeOwned + Serialize + Versioned + Validate,
+    {
+        check_json_unversioned(value);
+
+        let mut bumped = serialize(value).unwrap();
+        bumped[0] = T::VERSION + 1;
+        let json = format!("\"{}\"", hex::encode(&bumped));
+        let error = serde_json::from_str::<T>(&json).err().unwrap();
+        assert!(error.to_string().contains("is not supported"));
+
+        let json = format!("\"{}\"", hex::encode(&invalid_bytes));
+        let error = serde_json::from_str::<T>(&json).err().unwrap();
+        assert!(error.to_string().contains("is invalid"));
+    }
+
+    #[cfg(feature = "serde_human_readable")]
+    #[test]
+    fn test_human_readable_serde() {
+        let identity = RistrettoPoint::identity();
+        let uid_bytes = TEST_ARRAY_16;
+        let server_secret_params = api::ServerSecretParams::generate(TEST_ARRAY_32).unwrap();
+        let mut bad = copy(&server_secret_params);
+        bad.auth_credentials_key_pair.x0 += Scalar::one();
+        check_json(&server_secret_params, serialize(&bad).unwrap());
+
+        let server_public_params = server_secret_params.get_public_params();
+        let mut bad = server_public_params;
+        bad.auth_credentials_public_key.I = identity;
+        check_json(&server_public_params, serialize(&bad).unwrap());
+
+        let group_secret_params = api::groups::GroupSecretParams::generate(TEST_ARRAY_32_1);
+        let mut bad = group_secret_params.clone();
+        bad.uid_enc_key_pair.a2 += Scalar::one();
+        check_json(&group_secret_params, serialize(&bad).unwrap());
+
+        let group_public_params = group_secret_params.get_public_params();
+        let mut bad = group_public_params;
+        bad.uid_enc_public_key.A = identity;
+        check_json(&group_public_params, serialize(&bad).unwrap());
+
+        let uuid_ciphertext = group_secret_params.encrypt_uuid(uid_bytes);
+        let mut bad = uuid_ciphertext;
+        bad.ciphertext.E_A1 = RISTRETTO_BASEPOINT_POINT;
+        check_json(&uuid_ciphertext, serialize(&bad).unwrap());
+
+        let profile_key = api::profiles::ProfileKey::create(TEST_ARRAY_32_2);
+        check_json_unversioned(&profile_key);
+        let profile_key_commitment = profile_key.get_commitment(uid_bytes);
+        let context = server_public_params
+            .create_profile_key_credential_request_context(TEST_ARRAY_32_3, uid_bytes, &profile_key)
+            .unwrap();
+        let response = server_secret_params
+            .issue_profile_key_credential(
+                TEST_ARRAY_32_4,
+                &context.get_request(),
+                uid_bytes,
+                profile_key_commitment,
+            )
+            .unwrap();
+        let profile_key_credential = server_public_params
+            .receive_profile_key_credential(&context, &response)
+            .unwrap();
+        let presentation = server_public_params
+            .create_profile_key_credential_presentation(
+                TEST_ARRAY_32_5,
+                &group_secret_params,
+                profile_key_credential,
+            )
+            .unwrap();
+        let mut bad = copy(&presentation);
+        bad.profile_key_enc_ciphertext.E_B1 = RISTRETTO_BASEPOINT_POINT;
+        check_json(&presentation, serialize(&bad).unwrap());
+
+        // Binary formats are unaffected
+        let bytes = serialize(&presentation).unwrap();
+        let presentation2: api::profiles::ProfileKeyCredentialPresentation =
+            deserialize(&bytes).unwrap();
+        assert!(serialize(&presentation2).unwrap() == bytes);
+
+        // Already a hex string, so it's written as is
+        let version = profile_key.get_profile_key_version(uid_bytes);
+        let json = serde_json::to_string(&version).unwrap();
+        assert!(json.as_bytes()[1..json.len() - 1] == version.bytes[..]);
+        let version2: api::profiles::ProfileKeyVersion = serde_json::from_str(&json).unwrap();
+        assert!(version2.bytes[..] == version.bytes[..]);
+        assert!(serde_json::from_str::<api::profiles::ProfileKeyVersion>("\"abc\"").is_err());
+    }
+}
@@ -9,17 +9,25 @@ use crate::common::constants::*;
 use curve25519_dalek::scalar::Scalar;
 
 pub type AesKeyBytes = [u8; AES_KEY_LEN];
+pub type ChallengeExpirationTime = u64;
+pub type ChallengeNonceBytes = [u8; CHALLENGE_NONCE_LEN];
 pub type GroupMasterKeyBytes = [u8; GROUP_MASTER_KEY_LEN];
 pub type UidBytes = [u8; UUID_LEN];
 pub type ProfileKeyBytes = [u8; PROFILE_KEY_LEN];
 pub type RandomnessBytes = [u8; RANDOMNESS_LEN];
-pub type ReservedBytes = [u8; RESERVED_LEN];
+pub type VersionByte = u8;
 pub type SignatureBytes = [u8; SIGNATURE_LEN];
 pub type NotarySignatureBytes = [u8; SIGNATURE_LEN];
 pub type GroupIdentifierBytes = [u8; GROUP_IDENTIFIER_LEN];
+pub type GroupInviteLinkPasswordBytes = [u8; GROUP_INVITE_LINK_PASSWORD_LEN];
 pub type ProfileKeyVersionBytes = [u8; PROFILE_KEY_VERSION_LEN];
 pub type ProfileKeyVersionEncodedBytes = [u8; PROFILE_KEY_VERSION_ENCODED_LEN];
+pub type RateLimitTagBytes = [u8; RATE_LIMIT_TAG_LEN];
+pub type ReceiptSerialBytes = [u8; RECEIPT_SERIAL_LEN];
+pub type ReceiptExpirationTime = u64;
+pub type ReceiptLevel = u64;
 pub type RedemptionTime = u32;
+pub type ServerKeyId = u8;
 
 pub fn encode_redemption_time(redemption_time: u32) -> Scalar {
     let mut scalar_bytes: [u8; 32] = Default::default();
@@ -0,0 +1,624 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+
+#![allow(non_snake_case)]
+
+use crate::common::constants::*;
+use crate::common::errors::*;
+use crate::crypto::credentials;
+use curve25519_dalek::ristretto::RistrettoPoint;
+use curve25519_dalek::scalar::Scalar;
+use curve25519_dalek::traits::MultiscalarMul;
+
+// How the issuer and the verifier of a presentation see an attribute
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum AttributeKind {
+    // m * G_m for a scalar m known to both the issuer and the verifier
+    PublicScalar,
+    // A point known to the issuer, but shown to the verifier only encrypted
+    HiddenPoint,
+    // A point the issuer sees only blinded, but which the verifier sees
+    Blinded,
+    // A point the issuer sees only blinded, and the verifier only encrypted
+    Encrypted,
+}
+
+impl AttributeKind {
+    pub fn is_blinded(self) -> bool {
+        self == AttributeKind::Blinded || self == AttributeKind::Encrypted
+    }
+
+    pub fn is_revealed(self) -> bool {
+        self == AttributeKind::PublicScalar || self == AttributeKind::Blinded
+    }
+}
+
+// The issuer's MAC as the client receives it
+pub enum IssuedMac {
+    Clear {
+        V: RistrettoPoint,
+    },
+    // One (D1, D2) ciphertext under Y for each blinded attribute, in order
+    Blinded {
+        Y: RistrettoPoint,
+        S1: RistrettoPoint,
+        S2: RistrettoPoint,
+        ciphertexts: Vec<(RistrettoPoint, RistrettoPoint)>,
+    },
+}
+
+// The public side of the encryption of one pair of hidden attributes
+#[derive(Copy, Clone)]
+pub struct EncryptedPair {
+    pub(crate) public_key: RistrettoPoint,
+    pub(crate) G_1: RistrettoPoint,
+    pub(crate) G_2: RistrettoPoint,
+    pub(crate) E_1: RistrettoPoint,
+    pub(crate) E_2: RistrettoPoint,
+}
+
+pub struct Commitments {
+    pub(crate) C_x0: RistrettoPoint,
+    pub(crate) C_x1: RistrettoPoint,
+    pub(crate) C_y: Vec<RistrettoPoint>,
+    pub(crate) C_V: RistrettoPoint,
+    pub(crate) C_z: RistrettoPoint,
+}
+
+// Describes an algebraic MAC credential by the kinds of its attributes, and
+// emits the poksho statements and arguments for issuing and presenting it.
+//
+// Attributes hidden from the verifier are presented in consecutive pairs,
+// each pair encrypted under its own key (as a UID or a profile key is), and
+// named A, B, ... in order.  Blinded attributes are named D, E, ... in order
+// in the issuance request.
+//
+// A presentation may also withhold hidden attributes: they are committed to
+// like the others, but not encrypted, so the verifier learns nothing about
+// them beyond their being certified by the issuer.
+#[derive(Clone)]
+pub struct CredentialSchema {
+    kinds: Vec<AttributeKind>,
+    withheld: Vec<usize>,
+}
+
+impl CredentialSchema {
+    pub fn new(kinds: &[AttributeKind]) -> Result<Self, ZkGroupError> {
+        Self::new_withholding(kinds, &[])
+    }
+
+    // For credentials whose presentations always withhold some attributes,
+    // which then needn't make up whole pairs
+    pub fn new_withholding(
+        kinds: &[AttributeKind],
+        withheld: &[usize],
+    ) -> Result<Self, ZkGroupError> {
+        if kinds.len() < MIN_CRED_ATTRIBUTES {
+            return Err(ZkGroupError::InvalidAttributeCount);
+        }
+        for &i in withheld {
+            if i >= kinds.len() || kinds[i].is_revealed() {
+                return Err(ZkGroupError::BadArgs);
+            }
+        }
+        let schema = CredentialSchema {
+            kinds: kinds.to_vec(),
+            withheld: withheld.to_vec(),
+        };
+        schema.check_encrypted_pairs()?;
+        Ok(schema)
+    }
+
+    // The same schema, for a presentation that leaves the hidden attributes
+    // at the given indices unencrypted
+    pub fn withhold(&self, indices: &[usize]) -> Result<Self, ZkGroupError> {
+        Self::new_withholding(&self.kinds, indices)
+    }
+
+    fn check_encrypted_pairs(&self) -> Result<(), ZkGroupError> {
+        let encrypted = self.get_indices(|kind| !kind.is_revealed()).len() - self.withheld.len();
+        if encrypted % 2 != 0 {
+            return Err(ZkGroupError::InvalidAttributeCount);
+        }
+        for (i, j) in self.get_encrypted_pairs() {
+            if j != i + 1 {
+                return Err(ZkGroupError::InvalidAttributeCount);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn get_num_attributes(&self) -> usize {
+        self.kinds.len()
+    }
+
+    pub fn get_kinds(&self) -> &[AttributeKind] {
+        &self.kinds
+    }
+
+    fn get_indices(&self, filter: impl Fn(AttributeKind) -> bool) -> Vec<usize> {
+        (0..self.kinds.len())
+            .filter(|&i| filter(self.kinds[i]))
+            .collect()
+    }
+
+    fn get_encrypted_pairs(&self) -> Vec<(usize, usize)> {
+        let encrypted: Vec<usize> = self
+            .get_indices(|kind| !kind.is_revealed())
+            .into_iter()
+            .filter(|i| !self.withheld.contains(i))
+            .collect();
+        encrypted
+            .chunks_exact(2)
+            .map(|pair| (pair[0], pair[1]))
+            .collect()
+    }
+
+    pub fn get_issuance_statement(&self) -> poksho::Statement {
+        let mut st = poksho::Statement::new();
+        st.add("C_W", &[("w", "G_w"), ("wprime", "G_wprime")]);
+
+        let mut terms = vec![(s("x0"), s("G_x0")), (s("x1"), s("G_x1"))];
+        for i in 0..self.kinds.len() {
+            terms.push((y(i), G_y(i)));
+        }
+        add(&mut st, "G_V-I", &terms);
+
+        let mut mac_terms = vec![(s("w"), s("G_w")), (s("x0"), s("U")), (s("x1"), s("tU"))];
+        for i in self.get_indices(|kind| !kind.is_blinded()) {
+            mac_terms.push((y(i), M(i)));
+        }
+
+        let blinded = self.get_indices(AttributeKind::is_blinded);
+        if blinded.is_empty() {
+            add(&mut st, "V", &mac_terms);
+        } else {
+            let mut S1_terms = Vec::new();
+            let mut S2_terms = Vec::new();
+            for (k, &i) in blinded.iter().enumerate() {
+                S1_terms.push((y(i), format!("{}1", blinded_name(k))));
+                S2_terms.push((y(i), format!("{}2", blinded_name(k))));
+            }
+            S1_terms.push((s("rprime"), s("G")));
+            S2_terms.push((s("rprime"), s("Y")));
+            S2_terms.extend(mac_terms);
+            add(&mut st, "S1", &S1_terms);
+            add(&mut st, "S2", &S2_terms);
+        }
+        st
+    }
+
+    // rprime is the issuer's blinding nonce, if any attributes are blinded
+    pub fn get_issuance_scalar_args(
+        &self,
+        key_pair: &credentials::KeyPair,
+        rprime: Option<Scalar>,
+    ) -> poksho::ScalarArgs {
+        let mut scalar_args = poksho::ScalarArgs::new();
+        scalar_args.add("w", key_pair.w);
+        scalar_args.add("wprime", key_pair.wprime);
+        scalar_args.add("x0", key_pair.x0);
+        scalar_args.add("x1", key_pair.x1);
+        for (i, y_i) in key_pair.y.iter().take(self.kinds.len()).enumerate() {
+            scalar_args.add(y(i), *y_i);
+        }
+        if let Some(rprime) = rprime {
+            scalar_args.add("rprime", rprime);
+        }
+        scalar_args
+    }
+
+    // clear_points holds the points of the attributes that aren't blinded,
+    // in order
+    pub fn get_issuance_point_args(
+        &self,
+        public_key: credentials::PublicKey,
+        t: Scalar,
+        U: RistrettoPoint,
+        clear_points: &[RistrettoPoint],
+        mac: &IssuedMac,
+    ) -> poksho::PointArgs {
+        let system = credentials::SystemParams::get_hardcoded();
+        let system_G_y = system.get_G_y(self.kinds.len());
+
+        let mut point_args = poksho::PointArgs::new();
+        point_args.add("C_W", public_key.C_W);
+        point_args.add("G_w", system.G_w);
+        point_args.add("G_wprime", system.G_wprime);
+        point_args.add("G_V-I", system.G_V - public_key.I);
+        point_args.add("G_x0", system.G_x0);
+        point_args.add("G_x1", system.G_x1);
+        for i in 0..self.kinds.len() {
+            point_args.add(G_y(i), system_G_y[i]);
+        }
+        match mac {
+            IssuedMac::Clear { V } => {
+                point_args.add("V", *V);
+            }
+            IssuedMac::Blinded {
+                Y,
+                S1,
+                S2,
+                ciphertexts,
+            } => {
+                point_args.add("S1", *S1);
+                point_args.add("S2", *S2);
+                point_args.add("Y", *Y);
+                for (k, (D1, D2)) in ciphertexts.iter().enumerate() {
+                    point_args.add(format!("{}1", blinded_name(k)), *D1);
+                    point_args.add(format!("{}2", blinded_name(k)), *D2);
+                }
+            }
+        }
+        point_args.add("U", U);
+        point_args.add("tU", t * U);
+        for (&i, M_i) in self
+            .get_indices(|kind| !kind.is_blinded())
+            .iter()
+            .zip(clear_points)
+        {
+            point_args.add(M(i), *M_i);
+        }
+        point_args
+    }
+
+    // hidden_points holds the points of the attributes the verifier doesn't
+    // see, in order
+    pub fn commit(
+        &self,
+        z: Scalar,
+        t: Scalar,
+        U: RistrettoPoint,
+        V: RistrettoPoint,
+        hidden_points: &[RistrettoPoint],
+    ) -> Result<Commitments, ZkGroupError> {
+        let system = credentials::SystemParams::get_hardcoded();
+        let system_G_y = system.get_G_y(self.kinds.len());
+
+        let mut hidden_points = hidden_points.iter();
+        let mut C_y = Vec::with_capacity(self.kinds.len());
+        for i in 0..self.kinds.len() {
+            let mut C_y_i = z * system_G_y[i];
+            if !self.kinds[i].is_revealed() {
+                match hidden_points.next() {
+                    Some(M_i) => C_y_i += M_i,
+                    None => return Err(ZkGroupError::BadArgs),
+                }
+            }
+            C_y.push(C_y_i);
+        }
+
+        if hidden_points.next().is_some() {
+            return Err(ZkGroupError::BadArgs);
+        }
+
+        Ok(Commitments {
+            C_x0: z * system.G_x0 + U,
+            C_x1: z * system.G_x1 + t * U,
+            C_y,
+            C_V: z * system.G_V + V,
+            C_z: z * system.G_z,
+        })
+    }
+
+    // commit_to_z adds a C_z = z * G_z statement, which the profile key
+    // credential presentation has always carried
+    pub fn get_presentation_statement(&self, commit_to_z: bool) -> poksho::Statement {
+        let mut st = poksho::Statement::new();
+        if commit_to_z {
+            st.add("C_z", &[("z", "G_z")]);
+        }
+        st.add("Z", &[("z", "I")]);
+        st.add("C_x1", &[("t", "C_x0"), ("z0", "G_x0"), ("z", "G_x1")]);
+
+        let pairs = self.get_encrypted_pairs();
+        for n in 0..pairs.len() {
+            let (K, k) = pair_names(n);
+            add(
+                &mut st,
+                &K,
+                &[
+                    (format!("{}1", k), format!("G_{}1", k)),
+                    (format!("{}2", k), format!("G_{}2", k)),
+                ],
+            );
+        }
+        for (n, &(i, j)) in pairs.iter().enumerate() {
+            let (K, k) = pair_names(n);
+            add(
+                &mut st,
+                &format!("{}-E_{}2", C_y(j), K),
+                &[(s("z"), G_y(j)), (format!("{}2", k), format!("-E_{}1", K))],
+            );
+            add(
+                &mut st,
+                &format!("E_{}1", K),
+                &[(format!("{}1", k), C_y(i)), (format!("z{}", n + 1), G_y(i))],
+            );
+        }
+        for i in self.get_indices(AttributeKind::is_revealed) {
+            add(&mut st, &C_y(i), &[(s("z"), G_y(i))]);
+        }
+        st
+    }
+
+    // pair_keys holds the two private scalars of each pair's encryption key
+    pub fn get_presentation_scalar_args(
+        &self,
+        z: Scalar,
+        t: Scalar,
+        pair_keys: &[(Scalar, Scalar)],
+    ) -> poksho::ScalarArgs {
+        let mut scalar_args = poksho::ScalarArgs::new();
+        scalar_args.add("z", z);
+        scalar_args.add("t", t);
+        scalar_args.add("z0", -z * t);
+        for (n, (k1, k2)) in pair_keys.iter().enumerate() {
+            let (_, k) = pair_names(n);
+            scalar_args.add(format!("{}1", k), *k1);
+            scalar_args.add(format!("{}2", k), *k2);
+            scalar_args.add(format!("z{}", n + 1), -z * k1);
+        }
+        scalar_args
+    }
+
+    pub fn get_presentation_point_args(
+        &self,
+        commitments: &Commitments,
+        I: RistrettoPoint,
+        Z: RistrettoPoint,
+        pairs: &[EncryptedPair],
+    ) -> poksho::PointArgs {
+        let system = credentials::SystemParams::get_hardcoded();
+        let system_G_y = system.get_G_y(self.kinds.len());
+
+        let mut point_args = poksho::PointArgs::new();
+        point_args.add("C_z", commitments.C_z);
+        point_args.add("G_z", system.G_z);
+        point_args.add("Z", Z);
+        point_args.add("I", I);
+        point_args.add("C_x1", commitments.C_x1);
+        point_args.add("C_x0", commitments.C_x0);
+        point_args.add("G_x0", system.G_x0);
+        point_args.add("G_x1", system.G_x1);
+
+        for (n, pair) in pairs.iter().enumerate() {
+            let (K, k) = pair_names(n);
+            point_args.add(K, pair.public_key);
+            point_args.add(format!("G_{}1", k), pair.G_1);
+            point_args.add(format!("G_{}2", k), pair.G_2);
+        }
+        for (n, (pair, (i, j))) in pairs.iter().zip(self.get_encrypted_pairs()).enumerate() {
+            let (K, _) = pair_names(n);
+            point_args.add(
+                format!("{}-E_{}2", C_y(j), K),
+                commitments.C_y[j] - pair.E_2,
+            );
+            point_args.add(G_y(j), system_G_y[j]);
+            point_args.add(format!("-E_{}1", K), -pair.E_1);
+            point_args.add(format!("E_{}1", K), pair.E_1);
+            point_args.add(C_y(i), commitments.C_y[i]);
+            point_args.add(G_y(i), system_G_y[i]);
+        }
+        for i in self.get_indices(AttributeKind::is_revealed) {
+            point_args.add(C_y(i), commitments.C_y[i]);
+            point_args.add(G_y(i), system_G_y[i]);
+        }
+        point_args
+    }
+
+    // Recomputes z * I from the issuer's key; revealed_points holds the
+    // points of the attributes the verifier sees, in order
+    pub fn get_presentation_Z(
+        &self,
+        key_pair: &credentials::KeyPair,
+        commitments: &Commitments,
+        revealed_points: &[RistrettoPoint],
+    ) -> RistrettoPoint {
+        let mut scalars = vec![key_pair.x0, key_pair.x1];
+        let mut points = vec![commitments.C_x0, commitments.C_x1];
+        for (y_i, C_y_i) in key_pair.y.iter().zip(&commitments.C_y) {
+            scalars.push(*y_i);
+            points.push(*C_y_i);
+        }
+        for (&i, M_i) in self
+            .get_indices(AttributeKind::is_revealed)
+            .iter()
+            .zip(revealed_points)
+        {
+            if let Some(y_i) = key_pair.y.get(i) {
+                scalars.push(*y_i);
+                points.push(*M_i);
+            }
+        }
+        commitments.C_V - key_pair.W - RistrettoPoint::multiscalar_mul(&scalars, &points)
+    }
+}
+
+fn add(st: &mut poksho::Statement, lhs: &str, terms: &[(String, String)]) {
+    let terms: Vec<(&str, &str)> = terms
+        .iter()
+        .map(|(scalar, point)| (scalar.as_str(), point.as_str()))
+        .collect();
+    st.add(lhs, &terms);
+}
+
+fn s(name: &str) -> String {
+    name.to_string()
+}
+
+fn y(index: usize) -> String {
+    format!("y{}", index + 1)
+}
+
+fn G_y(index: usize) -> String {
+    format!("G_y{}", index + 1)
+}
+
+fn C_y(index: usize) -> String {
+    format!("C_y{}", index + 1)
+}
+
+fn M(index: usize) -> String {
+    format!("M{}", index + 1)
+}
+
+// Blinded attributes and encrypted pairs get single letters while those
+// can't collide with the statements' other names (M1, S1, I, Z, ...), then
+// numbered names
+fn blinded_name(index: usize) -> String {
+    if index < 9 {
+        ((b'D' + index as u8) as char).to_string()
+    } else {
+        format!("D{}_", index)
+    }
+}
+
+fn pair_names(index: usize) -> (String, String) {
+    if index < 8 {
+        let K = (b'A' + index as u8) as char;
+        (K.to_string(), K.to_ascii_lowercase().to_string())
+    } else {
+        (format!("P{}_", index), format!("p{}_", index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::sho::*;
+
+    #[test]
+    fn test_mac_round_trip() {
+        let mut sho = Sho::new(b"Test_Credential_Schema", b"");
+        let schema = CredentialSchema::new(&[
+            AttributeKind::HiddenPoint,
+            AttributeKind::HiddenPoint,
+            AttributeKind::PublicScalar,
+        ])
+        .unwrap();
+        let key_pair =
+            credentials::KeyPair::generate(&mut sho, schema.get_num_attributes()).unwrap();
+
+        let M = vec![sho.get_point(), sho.get_point(), sho.get_point()];
+        let (t, U, V) = key_pair.credential_core(M.clone(), &mut sho).unwrap();
+
+        let z = sho.get_scalar();
+        assert!(schema.commit(z, t, U, V, &M[..1]).is_err());
+        let commitments = schema.commit(z, t, U, V, &M[..2]).unwrap();
+        let Z = schema.get_presentation_Z(&key_pair, &commitments, &M[2..]);
+        assert!(Z == z * key_pair.I);
+    }
+
+    #[test]
+    fn test_invalid_schema() {
+        assert!(CredentialSchema::new(&[
+            AttributeKind::HiddenPoint,
+            AttributeKind::PublicScalar,
+            AttributeKind::HiddenPoint,
+        ])
+        .is_err());
+        assert!(CredentialSchema::new(&[AttributeKind::PublicScalar]).is_err());
+        assert!(CredentialSchema::new(&[AttributeKind::PublicScalar; 5]).is_ok());
+    }
+
+    #[test]
+    fn test_more_than_four_attributes() {
+        let mut sho = Sho::new(b"Test_Credential_Schema_Six_Attributes", b"");
+        let schema = CredentialSchema::new(&[
+            AttributeKind::HiddenPoint,
+            AttributeKind::HiddenPoint,
+            AttributeKind::HiddenPoint,
+            AttributeKind::HiddenPoint,
+            AttributeKind::PublicScalar,
+            AttributeKind::PublicScalar,
+        ])
+        .unwrap();
+        let key_pair = credentials::KeyPair::generate(&mut sho, 6).unwrap();
+        assert!(key_pair.validate_with_attributes(6).is_ok());
+        assert!(key_pair.validate_with_attributes(4).is_err());
+
+        let M: Vec<RistrettoPoint> = (0..6).map(|_| sho.get_point()).collect();
+        let (t, U, V) = key_pair.credential_core(M.clone(), &mut sho).unwrap();
+
+        let statement = schema.get_issuance_statement();
+        let point_args = schema.get_issuance_point_args(
+            key_pair.get_public_key(),
+            t,
+            U,
+            &M,
+            &IssuedMac::Clear { V },
+        );
+        let proof = statement
+            .prove(
+                &schema.get_issuance_scalar_args(&key_pair, None),
+                &point_args,
+                &[],
+                &sho.squeeze(RANDOMNESS_LEN)[..],
+            )
+            .unwrap();
+        assert!(statement.verify_proof(&proof, &point_args, &[]).is_ok());
+
+        // The four hidden attributes are presented as two encrypted pairs
+        let z = sho.get_scalar();
+        let commitments = schema.commit(z, t, U, V, &M[..4]).unwrap();
+        let Z = schema.get_presentation_Z(&key_pair, &commitments, &M[4..]);
+        assert!(Z == z * key_pair.I);
+
+        let mut pair_keys = Vec::new();
+        let mut pairs = Vec::new();
+        for n in 0..2 {
+            let (k1, k2) = (sho.get_scalar(), sho.get_scalar());
+            let (G_1, G_2) = (sho.get_point(), sho.get_point());
+            let E_1 = k1 * M[2 * n];
+            pair_keys.push((k1, k2));
+            pairs.push(EncryptedPair {
+                public_key: k1 * G_1 + k2 * G_2,
+                G_1,
+                G_2,
+                E_1,
+                E_2: k2 * E_1 + M[2 * n + 1],
+            });
+        }
+
+        let statement = schema.get_presentation_statement(false);
+        let point_args = schema.get_presentation_point_args(&commitments, key_pair.I, Z, &pairs);
+        let proof = statement
+            .prove(
+                &schema.get_presentation_scalar_args(z, t, &pair_keys),
+                &point_args,
+                &[],
+                &sho.squeeze(RANDOMNESS_LEN)[..],
+            )
+            .unwrap();
+        assert!(statement.verify_proof(&proof, &point_args, &[]).is_ok());
+    }
+
+    #[test]
+    fn test_withhold() {
+        let schema = credentials::get_profile_key_credential_schema().unwrap();
+        assert_eq!(schema.get_encrypted_pairs(), vec![(0, 1), (2, 3)]);
+
+        let withheld = schema.withhold(&[2, 3]).unwrap();
+        assert_eq!(withheld.get_encrypted_pairs(), vec![(0, 1)]);
+        assert_eq!(withheld.get_num_attributes(), 4);
+
+        // Withheld attributes must be hidden ones, and leave whole pairs
+        assert!(schema.withhold(&[2]).is_err());
+        assert!(schema.withhold(&[1, 2]).is_err());
+        assert!(schema.withhold(&[4]).is_err());
+        let auth_schema = credentials::get_auth_credential_schema().unwrap();
+        assert!(auth_schema.withhold(&[2]).is_err());
+
+        // A lone hidden attribute can't be presented unless it's withheld
+        let kinds = [AttributeKind::PublicScalar, AttributeKind::Encrypted];
+        assert!(CredentialSchema::new(&kinds).is_err());
+        let withheld = CredentialSchema::new_withholding(&kinds, &[1]).unwrap();
+        assert!(withheld.get_encrypted_pairs().is_empty());
+        assert!(CredentialSchema::new_withholding(&kinds, &[0]).is_err());
+    }
+}
@@ -7,14 +7,23 @@
 
 #![allow(non_snake_case)]
 
+use crate::common::constants::*;
+use crate::common::errors::*;
+use crate::common::serialization::{ensure, ensure_not_identity, Validate};
 use crate::common::sho::*;
 use crate::common::simple_types::*;
+use crate::crypto::credential_schema::{AttributeKind, CredentialSchema};
 use crate::crypto::profile_key_credential_request;
+use crate::crypto::rate_limit_credential_request;
+use crate::crypto::rate_limit_struct;
+use crate::crypto::receipt_credential_request;
+use crate::crypto::receipt_struct;
 use crate::crypto::uid_struct;
 use curve25519_dalek::constants::RISTRETTO_BASEPOINT_POINT;
 use curve25519_dalek::ristretto::RistrettoPoint;
 use curve25519_dalek::scalar::Scalar;
 use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
 
 #[derive(Copy, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct SystemParams {
@@ -34,7 +43,7 @@ pub struct SystemParams {
     pub(crate) G_z: RistrettoPoint,
 }
 
-#[derive(Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
 pub struct KeyPair {
     // private
     pub(crate) w: Scalar,
@@ -42,16 +51,90 @@ pub struct KeyPair {
     pub(crate) W: RistrettoPoint,
     pub(crate) x0: Scalar,
     pub(crate) x1: Scalar,
-    pub(crate) y1: Scalar,
-    pub(crate) y2: Scalar,
-    pub(crate) y3: Scalar,
-    pub(crate) y4: Scalar,
+    // One per attribute
+    pub(crate) y: Vec<Scalar>,
 
     // public
     pub(crate) C_W: RistrettoPoint,
     pub(crate) I: RistrettoPoint,
 }
 
+impl Drop for KeyPair {
+    fn drop(&mut self) {
+        self.w.zeroize();
+        self.wprime.zeroize();
+        self.x0.zeroize();
+        self.x1.zeroize();
+        for y_i in self.y.iter_mut() {
+            y_i.zeroize();
+        }
+    }
+}
+
+// The layout before key pairs were sized per schema, used by version 0
+// ServerSecretParams. Every key pair had four y, and I only covered the
+// leading ones the schema used.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct KeyPairV0 {
+    pub(crate) w: Scalar,
+    pub(crate) wprime: Scalar,
+    pub(crate) W: RistrettoPoint,
+    pub(crate) x0: Scalar,
+    pub(crate) x1: Scalar,
+    pub(crate) y: [Scalar; NUM_HARDCODED_CRED_ATTRIBUTES],
+    pub(crate) C_W: RistrettoPoint,
+    pub(crate) I: RistrettoPoint,
+}
+
+impl Drop for KeyPairV0 {
+    fn drop(&mut self) {
+        self.w.zeroize();
+        self.wprime.zeroize();
+        self.x0.zeroize();
+        self.x1.zeroize();
+        for y_i in self.y.iter_mut() {
+            y_i.zeroize();
+        }
+    }
+}
+
+impl KeyPairV0 {
+    // The unused y are written as zero
+    pub(crate) fn from_key_pair(key_pair: &KeyPair) -> Result<Self, ZkGroupError> {
+        if key_pair.y.len() > NUM_HARDCODED_CRED_ATTRIBUTES {
+            return Err(ZkGroupError::InvalidAttributeCount);
+        }
+        let mut y = [Scalar::zero(); NUM_HARDCODED_CRED_ATTRIBUTES];
+        y[..key_pair.y.len()].copy_from_slice(&key_pair.y);
+        Ok(KeyPairV0 {
+            w: key_pair.w,
+            wprime: key_pair.wprime,
+            W: key_pair.W,
+            x0: key_pair.x0,
+            x1: key_pair.x1,
+            y,
+            C_W: key_pair.C_W,
+            I: key_pair.I,
+        })
+    }
+
+    pub(crate) fn to_key_pair(&self, num_attributes: usize) -> Result<KeyPair, ZkGroupError> {
+        if num_attributes > self.y.len() {
+            return Err(ZkGroupError::InvalidAttributeCount);
+        }
+        Ok(KeyPair {
+            w: self.w,
+            wprime: self.wprime,
+            W: self.W,
+            x0: self.x0,
+            x1: self.x1,
+            y: self.y[..num_attributes].to_vec(),
+            C_W: self.C_W,
+            I: self.I,
+        })
+    }
+}
+
 #[derive(Copy, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PublicKey {
     pub(crate) C_W: RistrettoPoint,
@@ -89,6 +172,90 @@ pub struct BlindedProfileKeyCredential {
     pub(crate) S2: RistrettoPoint,
 }
 
+#[derive(Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReceiptCredential {
+    pub(crate) t: Scalar,
+    pub(crate) U: RistrettoPoint,
+    pub(crate) V: RistrettoPoint,
+}
+
+#[derive(Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BlindedReceiptCredentialWithSecretNonce {
+    pub(crate) rprime: Scalar,
+    pub(crate) t: Scalar,
+    pub(crate) U: RistrettoPoint,
+    pub(crate) S1: RistrettoPoint,
+    pub(crate) S2: RistrettoPoint,
+}
+
+#[derive(Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BlindedReceiptCredential {
+    pub(crate) t: Scalar,
+    pub(crate) U: RistrettoPoint,
+    pub(crate) S1: RistrettoPoint,
+    pub(crate) S2: RistrettoPoint,
+}
+
+#[derive(Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RateLimitCredential {
+    pub(crate) t: Scalar,
+    pub(crate) U: RistrettoPoint,
+    pub(crate) V: RistrettoPoint,
+}
+
+#[derive(Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BlindedRateLimitCredentialWithSecretNonce {
+    pub(crate) rprime: Scalar,
+    pub(crate) t: Scalar,
+    pub(crate) U: RistrettoPoint,
+    pub(crate) S1: RistrettoPoint,
+    pub(crate) S2: RistrettoPoint,
+}
+
+#[derive(Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BlindedRateLimitCredential {
+    pub(crate) t: Scalar,
+    pub(crate) U: RistrettoPoint,
+    pub(crate) S1: RistrettoPoint,
+    pub(crate) S2: RistrettoPoint,
+}
+
+pub fn get_auth_credential_schema() -> Result<CredentialSchema, ZkGroupError> {
+    CredentialSchema::new(&[
+        AttributeKind::HiddenPoint,
+        AttributeKind::HiddenPoint,
+        AttributeKind::PublicScalar,
+    ])
+}
+
+pub fn get_profile_key_credential_schema() -> Result<CredentialSchema, ZkGroupError> {
+    CredentialSchema::new(&[
+        AttributeKind::HiddenPoint,
+        AttributeKind::HiddenPoint,
+        AttributeKind::Encrypted,
+        AttributeKind::Encrypted,
+    ])
+}
+
+// For presentations that show only the UID; the profile key stays bound to
+// it by the MAC, but isn't encrypted for the verifier
+pub fn get_profile_key_credential_uid_only_schema() -> Result<CredentialSchema, ZkGroupError> {
+    get_profile_key_credential_schema()?.withhold(&[2, 3])
+}
+
+pub fn get_receipt_credential_schema() -> Result<CredentialSchema, ZkGroupError> {
+    CredentialSchema::new(&[AttributeKind::PublicScalar, AttributeKind::Blinded])
+}
+
+// The day, then the client's secret point, which is never shown: a rate limit
+// token's tag is proven against the commitment to it instead
+pub fn get_rate_limit_credential_schema() -> Result<CredentialSchema, ZkGroupError> {
+    CredentialSchema::new_withholding(
+        &[AttributeKind::PublicScalar, AttributeKind::Encrypted],
+        &[1],
+    )
+}
+
 pub(crate) fn convert_to_points_uid_struct(
     uid: uid_struct::UidStruct,
     redemption_time: RedemptionTime,
@@ -141,8 +308,26 @@ impl SystemParams {
         }
     }
 
+    // test_system checks the hardcoded bytes against generate(), so falling
+    // back to it only recomputes the same params
     pub fn get_hardcoded() -> SystemParams {
-        bincode::deserialize::<SystemParams>(&SystemParams::SYSTEM_HARDCODED).unwrap()
+        bincode::deserialize::<SystemParams>(&SystemParams::SYSTEM_HARDCODED)
+            .unwrap_or_else(|_| SystemParams::generate())
+    }
+
+    // The first four are hardcoded above; schemas with more attributes get
+    // the rest derived one per index
+    pub fn get_G_y(&self, num_attributes: usize) -> Vec<RistrettoPoint> {
+        let mut G_y = vec![self.G_y1, self.G_y2, self.G_y3, self.G_y4];
+        G_y.truncate(num_attributes);
+        for i in G_y.len()..num_attributes {
+            let mut sho = Sho::new(
+                b"Signal_ZKGroup_20200424_Constant_Credentials_SystemParams_G_y",
+                &(i as u64).to_be_bytes(),
+            );
+            G_y.push(sho.get_point());
+        }
+        G_y
     }
 
     const SYSTEM_HARDCODED: [u8; 448] = [
@@ -180,9 +365,9 @@ impl SystemParams {
 }
 
 impl KeyPair {
-    pub fn generate(sho: &mut Sho, num_attributes: usize) -> Self {
-        if num_attributes > 4 || num_attributes < 3 {
-            panic!();
+    pub fn generate(sho: &mut Sho, num_attributes: usize) -> Result<Self, ZkGroupError> {
+        if num_attributes < MIN_CRED_ATTRIBUTES {
+            return Err(ZkGroupError::InvalidAttributeCount);
         }
 
         let system = SystemParams::get_hardcoded();
@@ -191,36 +376,34 @@ impl KeyPair {
         let wprime = sho.get_scalar();
         let x0 = sho.get_scalar();
         let x1 = sho.get_scalar();
-        let y1 = sho.get_scalar();
-        let y2 = sho.get_scalar();
-        let y3 = sho.get_scalar();
-        let y4 = sho.get_scalar();
+        // At least four are drawn, as when every key pair had four, so the
+        // same sho still derives the same keys after this one
+        let mut y: Vec<Scalar> = (0..std::cmp::max(num_attributes, NUM_HARDCODED_CRED_ATTRIBUTES))
+            .map(|_| sho.get_scalar())
+            .collect();
+        y.truncate(num_attributes);
 
         let C_W = (w * system.G_w) + (wprime * system.G_wprime);
-        let mut I = system.G_V
-            - (x0 * system.G_x0)
-            - (x1 * system.G_x1)
-            - (y1 * system.G_y1)
-            - (y2 * system.G_y2)
-            - (y3 * system.G_y3);
-
-        if num_attributes > 3 {
-            I -= y4 * system.G_y4;
-        }
+        let I = Self::calc_I(&system, x0, x1, &y);
 
-        KeyPair {
+        Ok(KeyPair {
             w,
             wprime,
             W,
             x0,
             x1,
-            y1,
-            y2,
-            y3,
-            y4,
+            y,
             C_W,
             I,
+        })
+    }
+
+    fn calc_I(system: &SystemParams, x0: Scalar, x1: Scalar, y: &[Scalar]) -> RistrettoPoint {
+        let mut I = system.G_V - (x0 * system.G_x0) - (x1 * system.G_x1);
+        for (y_i, G_y_i) in y.iter().zip(system.get_G_y(y.len())) {
+            I -= y_i * G_y_i;
         }
+        I
     }
 
     pub fn get_public_key(&self) -> PublicKey {
@@ -230,38 +413,92 @@ impl KeyPair {
         }
     }
 
+    // Not a Validate impl, since I depends on the attribute count, which the
+    // key pair doesn't record
+    pub fn validate_with_attributes(&self, num_attributes: usize) -> Result<(), ZkGroupError> {
+        ensure::<Self>(
+            num_attributes >= MIN_CRED_ATTRIBUTES && self.y.len() == num_attributes,
+            "attribute count doesn't match",
+        )?;
+        let system = SystemParams::get_hardcoded();
+        ensure::<Self>(self.W == self.w * system.G_w, "W doesn't match w")?;
+        ensure::<Self>(
+            self.C_W == self.W + (self.wprime * system.G_wprime),
+            "C_W doesn't match w and w'",
+        )?;
+        ensure::<Self>(
+            self.I == Self::calc_I(&system, self.x0, self.x1, &self.y),
+            "I doesn't match x0, x1 and y",
+        )?;
+        self.get_public_key().validate()
+    }
+
+    pub fn get_num_attributes(&self) -> usize {
+        self.y.len()
+    }
+
     pub fn create_auth_credential(
         &self,
         uid: uid_struct::UidStruct,
         redemption_time: RedemptionTime,
         sho: &mut Sho,
-    ) -> AuthCredential {
+    ) -> Result<AuthCredential, ZkGroupError> {
         let M = convert_to_points_uid_struct(uid, redemption_time);
-        let (t, U, V) = self.credential_core(M, sho);
-        AuthCredential { t, U, V }
+        let (t, U, V) = self.credential_core(M, sho)?;
+        Ok(AuthCredential { t, U, V })
     }
 
-    fn credential_core(
+    // MACs the points M of the leading attributes
+    pub(crate) fn credential_core(
         &self,
         M: Vec<RistrettoPoint>,
         sho: &mut Sho,
-    ) -> (Scalar, RistrettoPoint, RistrettoPoint) {
-        if M.len() > 4 {
-            panic!();
+    ) -> Result<(Scalar, RistrettoPoint, RistrettoPoint), ZkGroupError> {
+        if M.len() > self.y.len() {
+            return Err(ZkGroupError::InvalidAttributeCount);
         }
         let t = sho.get_scalar();
         let U = sho.get_point();
 
         let mut V = self.W + (self.x0 + self.x1 * t) * U;
-        V += self.y1 * M[0];
-        V += self.y2 * M[1];
-        if M.len() > 2 {
-            V += self.y3 * M[2];
+        for (y_i, M_i) in self.y.iter().zip(M.iter()) {
+            V += y_i * M_i;
+        }
+        Ok((t, U, V))
+    }
+
+    // MACs the points M of the leading attributes in the clear, and the
+    // remaining attributes under their (D1, D2) ciphertexts for public_key_Y
+    fn blinded_credential_core(
+        &self,
+        M: Vec<RistrettoPoint>,
+        public_key_Y: RistrettoPoint,
+        ciphertexts: &[(RistrettoPoint, RistrettoPoint)],
+        sho: &mut Sho,
+    ) -> Result<
+        (
+            Scalar,
+            Scalar,
+            RistrettoPoint,
+            RistrettoPoint,
+            RistrettoPoint,
+        ),
+        ZkGroupError,
+    > {
+        if M.len() + ciphertexts.len() > self.y.len() {
+            return Err(ZkGroupError::InvalidAttributeCount);
         }
-        if M.len() > 3 {
-            V += self.y4 * M[3];
+        let num_clear_attributes = M.len();
+
+        let (t, U, Vprime) = self.credential_core(M, sho)?;
+        let rprime = sho.get_scalar();
+        let mut S1 = rprime * RISTRETTO_BASEPOINT_POINT;
+        let mut S2 = rprime * public_key_Y + Vprime;
+        for (y_i, (D1, D2)) in self.y[num_clear_attributes..].iter().zip(ciphertexts) {
+            S1 += y_i * D1;
+            S2 += y_i * D2;
         }
-        (t, U, V)
+        Ok((rprime, t, U, S1, S2))
     }
 
     pub fn create_blinded_profile_key_credential(
@@ -270,22 +507,72 @@ impl KeyPair {
         public_key: profile_key_credential_request::PublicKey,
         ciphertext: profile_key_credential_request::Ciphertext,
         sho: &mut Sho,
-    ) -> BlindedProfileKeyCredentialWithSecretNonce {
+    ) -> Result<BlindedProfileKeyCredentialWithSecretNonce, ZkGroupError> {
         let M = vec![uid.M1, uid.M2];
+        let ciphertexts = [
+            (ciphertext.D1, ciphertext.D2),
+            (ciphertext.E1, ciphertext.E2),
+        ];
+
+        let (rprime, t, U, S1, S2) =
+            self.blinded_credential_core(M, public_key.Y, &ciphertexts, sho)?;
+        Ok(BlindedProfileKeyCredentialWithSecretNonce {
+            rprime,
+            t,
+            U,
+            S1,
+            S2,
+        })
+    }
 
-        let (t, U, Vprime) = self.credential_core(M, sho);
-        let rprime = sho.get_scalar();
-        let R1 = rprime * RISTRETTO_BASEPOINT_POINT;
-        let R2 = rprime * public_key.Y + Vprime;
-        let S1 = R1 + (self.y3 * ciphertext.D1) + (self.y4 * ciphertext.E1);
-        let S2 = R2 + (self.y3 * ciphertext.D2) + (self.y4 * ciphertext.E2);
-        BlindedProfileKeyCredentialWithSecretNonce {
+    // Only the serial is blinded; the expiration and level are chosen by the
+    // server and revealed again at presentation
+    pub fn create_blinded_receipt_credential(
+        &self,
+        public_key: receipt_credential_request::PublicKey,
+        ciphertext: receipt_credential_request::Ciphertext,
+        receipt_expiration_time: ReceiptExpirationTime,
+        receipt_level: ReceiptLevel,
+        sho: &mut Sho,
+    ) -> Result<BlindedReceiptCredentialWithSecretNonce, ZkGroupError> {
+        let M = vec![receipt_struct::ReceiptStruct::calc_M1(
+            receipt_expiration_time,
+            receipt_level,
+        )];
+        let ciphertexts = [(ciphertext.D1, ciphertext.D2)];
+
+        let (rprime, t, U, S1, S2) =
+            self.blinded_credential_core(M, public_key.Y, &ciphertexts, sho)?;
+        Ok(BlindedReceiptCredentialWithSecretNonce {
             rprime,
             t,
             U,
             S1,
             S2,
-        }
+        })
+    }
+
+    // Only the client's secret point is blinded; the day is chosen by the
+    // server and revealed again in every token
+    pub fn create_blinded_rate_limit_credential(
+        &self,
+        public_key: rate_limit_credential_request::PublicKey,
+        ciphertext: rate_limit_credential_request::Ciphertext,
+        redemption_time: RedemptionTime,
+        sho: &mut Sho,
+    ) -> Result<BlindedRateLimitCredentialWithSecretNonce, ZkGroupError> {
+        let M = vec![rate_limit_struct::RateLimitStruct::calc_M1(redemption_time)];
+        let ciphertexts = [(ciphertext.D1, ciphertext.D2)];
+
+        let (rprime, t, U, S1, S2) =
+            self.blinded_credential_core(M, public_key.Y, &ciphertexts, sho)?;
+        Ok(BlindedRateLimitCredentialWithSecretNonce {
+            rprime,
+            t,
+            U,
+            S1,
+            S2,
+        })
     }
 }
 
@@ -300,10 +587,80 @@ impl BlindedProfileKeyCredentialWithSecretNonce {
     }
 }
 
+impl BlindedReceiptCredentialWithSecretNonce {
+    pub fn get_blinded_receipt_credential(&self) -> BlindedReceiptCredential {
+        BlindedReceiptCredential {
+            t: self.t,
+            U: self.U,
+            S1: self.S1,
+            S2: self.S2,
+        }
+    }
+}
+
+impl BlindedRateLimitCredentialWithSecretNonce {
+    pub fn get_blinded_rate_limit_credential(&self) -> BlindedRateLimitCredential {
+        BlindedRateLimitCredential {
+            t: self.t,
+            U: self.U,
+            S1: self.S1,
+            S2: self.S2,
+        }
+    }
+}
+
+impl Validate for PublicKey {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        ensure_not_identity::<Self>(&self.C_W, "C_W is the identity")?;
+        ensure_not_identity::<Self>(&self.I, "I is the identity")
+    }
+}
+
+impl Validate for AuthCredential {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        ensure_not_identity::<Self>(&self.U, "U is the identity")
+    }
+}
+
+impl Validate for ProfileKeyCredential {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        ensure_not_identity::<Self>(&self.U, "U is the identity")
+    }
+}
+
+impl Validate for ReceiptCredential {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        ensure_not_identity::<Self>(&self.U, "U is the identity")
+    }
+}
+
+impl Validate for RateLimitCredential {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        ensure_not_identity::<Self>(&self.U, "U is the identity")
+    }
+}
+
+impl Validate for BlindedProfileKeyCredential {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        ensure_not_identity::<Self>(&self.U, "U is the identity")
+    }
+}
+
+impl Validate for BlindedReceiptCredential {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        ensure_not_identity::<Self>(&self.U, "U is the identity")
+    }
+}
+
+impl Validate for BlindedRateLimitCredential {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        ensure_not_identity::<Self>(&self.U, "U is the identity")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::common::constants::*;
     use crate::crypto::proofs;
 
     #[test]
@@ -316,19 +673,23 @@ mod tests {
     #[test]
     fn test_mac() {
         let mut sho = Sho::new(b"Test_Credentials", b"");
-        let keypair = KeyPair::generate(&mut sho, NUM_AUTH_CRED_ATTRIBUTES);
+        assert!(KeyPair::generate(&mut sho, MIN_CRED_ATTRIBUTES - 1).is_err());
+        let keypair = KeyPair::generate(&mut sho, NUM_AUTH_CRED_ATTRIBUTES).unwrap();
 
         let uid_bytes = TEST_ARRAY_16;
         let redemption_time = 37;
         let uid = uid_struct::UidStruct::new(uid_bytes);
-        let credential = keypair.create_auth_credential(uid, redemption_time, &mut sho);
+        let credential = keypair
+            .create_auth_credential(uid, redemption_time, &mut sho)
+            .unwrap();
         let proof = proofs::AuthCredentialIssuanceProof::new(
-            keypair,
+            &keypair,
             credential,
             uid,
             redemption_time,
             &mut sho,
-        );
+        )
+        .unwrap();
 
         let public_key = keypair.get_public_key();
         proof
@@ -0,0 +1,66 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+
+#![allow(non_snake_case)]
+
+use crate::common::constants::*;
+use crate::common::errors::*;
+use crate::common::serialization::{ensure_not_identity, Validate};
+use crate::common::sho::*;
+use crate::common::simple_types::*;
+use curve25519_dalek::constants::RISTRETTO_BASEPOINT_POINT;
+use curve25519_dalek::ristretto::RistrettoPoint;
+use curve25519_dalek::scalar::Scalar;
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
+
+// The password is hashed together with the group id, so the same password
+// used for two groups gives unrelated public keys
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct KeyPair {
+    pub(crate) p: Scalar,
+    pub(crate) P: RistrettoPoint,
+}
+
+impl Drop for KeyPair {
+    fn drop(&mut self) {
+        self.p.zeroize();
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PublicKey {
+    pub(crate) P: RistrettoPoint,
+}
+
+impl KeyPair {
+    pub fn derive_from(
+        password_bytes: GroupInviteLinkPasswordBytes,
+        group_id: GroupIdentifierBytes,
+    ) -> Self {
+        let mut combined_array = [0u8; GROUP_IDENTIFIER_LEN + GROUP_INVITE_LINK_PASSWORD_LEN];
+        combined_array[..GROUP_IDENTIFIER_LEN].copy_from_slice(&group_id);
+        combined_array[GROUP_IDENTIFIER_LEN..].copy_from_slice(&password_bytes);
+        let mut sho = Sho::new(
+            b"Signal_ZKGroup_20200424_GroupInviteLinkPasswordAndGroupId_InviteLinkPassword_DeriveFrom",
+            &combined_array,
+        );
+        let p = sho.get_scalar();
+        let P = p * RISTRETTO_BASEPOINT_POINT;
+        KeyPair { p, P }
+    }
+
+    pub fn get_public_key(&self) -> PublicKey {
+        PublicKey { P: self.P }
+    }
+}
+
+impl Validate for PublicKey {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        ensure_not_identity::<Self>(&self.P, "P is the identity")
+    }
+}
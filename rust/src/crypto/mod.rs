@@ -5,12 +5,18 @@
 // SPDX-License-Identifier: GPL-3.0-only
 //
 
+pub mod credential_schema;
 pub mod credentials;
+pub mod invite_link_password;
 pub mod profile_key_commitment;
 pub mod profile_key_credential_request;
 pub mod profile_key_encryption;
 pub mod profile_key_struct;
 pub mod proofs;
+pub mod rate_limit_credential_request;
+pub mod rate_limit_struct;
+pub mod receipt_credential_request;
+pub mod receipt_struct;
 pub mod signature;
 pub mod uid_encryption;
 pub mod uid_struct;
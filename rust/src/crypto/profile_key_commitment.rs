@@ -8,6 +8,8 @@
 #![allow(non_snake_case)]
 
 use crate::common::constants::*;
+use crate::common::errors::*;
+use crate::common::serialization::{ensure_not_identity, Validate};
 use crate::common::sho::*;
 use crate::common::simple_types::*;
 use crate::crypto::profile_key_struct;
@@ -50,7 +52,8 @@ impl SystemParams {
     }
 
     pub fn get_hardcoded() -> SystemParams {
-        bincode::deserialize::<SystemParams>(&SystemParams::SYSTEM_HARDCODED).unwrap()
+        bincode::deserialize::<SystemParams>(&SystemParams::SYSTEM_HARDCODED)
+            .unwrap_or_else(|_| SystemParams::generate())
     }
 
     const SYSTEM_HARDCODED: [u8; 96] = [
@@ -66,15 +69,14 @@ impl SystemParams {
 
 impl CommitmentWithSecretNonce {
     pub fn new(
-        profile_key: profile_key_struct::ProfileKeyStruct,
+        profile_key: &profile_key_struct::ProfileKeyStruct,
         uid_bytes: UidBytes,
     ) -> CommitmentWithSecretNonce {
         let commitment_system = SystemParams::get_hardcoded();
 
-        let profile_key_struct::ProfileKeyStruct { M3, M4, .. } = profile_key;
         let j3 = Self::calc_j3(profile_key.bytes, uid_bytes);
-        let J1 = (j3 * commitment_system.G_j1) + M3;
-        let J2 = (j3 * commitment_system.G_j2) + M4;
+        let J1 = (j3 * commitment_system.G_j1) + profile_key.M3;
+        let J2 = (j3 * commitment_system.G_j2) + profile_key.M4;
         let J3 = j3 * commitment_system.G_j3;
         CommitmentWithSecretNonce { J1, J2, J3, j3 }
     }
@@ -99,6 +101,12 @@ impl CommitmentWithSecretNonce {
     }
 }
 
+impl Validate for Commitment {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        ensure_not_identity::<Self>(&self.J3, "J3 is the identity")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -113,8 +121,8 @@ mod tests {
     #[test]
     fn test_commitment() {
         let profile_key = profile_key_struct::ProfileKeyStruct::new(TEST_ARRAY_32, TEST_ARRAY_16);
-        let c1 = CommitmentWithSecretNonce::new(profile_key, TEST_ARRAY_16);
-        let c2 = CommitmentWithSecretNonce::new(profile_key, TEST_ARRAY_16);
+        let c1 = CommitmentWithSecretNonce::new(&profile_key, TEST_ARRAY_16);
+        let c2 = CommitmentWithSecretNonce::new(&profile_key, TEST_ARRAY_16);
         assert!(c1 == c2);
     }
 }
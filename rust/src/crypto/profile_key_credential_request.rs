@@ -7,6 +7,8 @@
 
 #![allow(non_snake_case)]
 
+use crate::common::errors::*;
+use crate::common::serialization::{ensure, ensure_not_identity, Validate};
 use crate::common::sho::*;
 use crate::crypto::credentials::{BlindedProfileKeyCredential, ProfileKeyCredential};
 use crate::crypto::profile_key_struct;
@@ -14,8 +16,9 @@ use curve25519_dalek::constants::RISTRETTO_BASEPOINT_POINT;
 use curve25519_dalek::ristretto::RistrettoPoint;
 use curve25519_dalek::scalar::Scalar;
 use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
 
-#[derive(Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
 pub struct KeyPair {
     // private
     pub(crate) y: Scalar,
@@ -24,12 +27,18 @@ pub struct KeyPair {
     pub(crate) Y: RistrettoPoint,
 }
 
+impl Drop for KeyPair {
+    fn drop(&mut self) {
+        self.y.zeroize();
+    }
+}
+
 #[derive(Copy, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PublicKey {
     pub(crate) Y: RistrettoPoint,
 }
 
-#[derive(Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
 pub struct CiphertextWithSecretNonce {
     pub(crate) r1: Scalar,
     pub(crate) r2: Scalar,
@@ -39,6 +48,13 @@ pub struct CiphertextWithSecretNonce {
     pub(crate) E2: RistrettoPoint,
 }
 
+impl Drop for CiphertextWithSecretNonce {
+    fn drop(&mut self) {
+        self.r1.zeroize();
+        self.r2.zeroize();
+    }
+}
+
 #[derive(Copy, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Ciphertext {
     pub(crate) D1: RistrettoPoint,
@@ -60,7 +76,7 @@ impl KeyPair {
 
     pub fn encrypt(
         &self,
-        profile_key_struct: profile_key_struct::ProfileKeyStruct,
+        profile_key_struct: &profile_key_struct::ProfileKeyStruct,
         sho: &mut Sho,
     ) -> CiphertextWithSecretNonce {
         let r1 = sho.get_scalar();
@@ -105,6 +121,43 @@ impl CiphertextWithSecretNonce {
     }
 }
 
+impl Validate for KeyPair {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        ensure_not_identity::<Self>(&self.Y, "Y is the identity")?;
+        ensure::<Self>(
+            self.Y == self.y * RISTRETTO_BASEPOINT_POINT,
+            "Y doesn't match y",
+        )
+    }
+}
+
+impl Validate for PublicKey {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        ensure_not_identity::<Self>(&self.Y, "Y is the identity")
+    }
+}
+
+impl Validate for CiphertextWithSecretNonce {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        ensure::<Self>(
+            self.D1 == self.r1 * RISTRETTO_BASEPOINT_POINT,
+            "D1 doesn't match r1",
+        )?;
+        ensure::<Self>(
+            self.E1 == self.r2 * RISTRETTO_BASEPOINT_POINT,
+            "E1 doesn't match r2",
+        )?;
+        self.get_ciphertext().validate()
+    }
+}
+
+impl Validate for Ciphertext {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        ensure_not_identity::<Self>(&self.D1, "D1 is the identity")?;
+        ensure_not_identity::<Self>(&self.E1, "E1 is the identity")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -122,12 +175,12 @@ mod tests {
         let profile_key_struct =
             profile_key_struct::ProfileKeyStruct::new(TEST_ARRAY_32, TEST_ARRAY_16);
         let _ = profile_key_commitment::CommitmentWithSecretNonce::new(
-            profile_key_struct,
+            &profile_key_struct,
             TEST_ARRAY_16,
         );
 
         // client
-        let _ = blind_key_pair.encrypt(profile_key_struct, &mut sho);
+        let _ = blind_key_pair.encrypt(&profile_key_struct, &mut sho);
 
         // server
         /*TODO request_ciphertext.verify(c).unwrap();
@@ -8,6 +8,7 @@
 #![allow(non_snake_case)]
 
 use crate::common::errors::*;
+use crate::common::serialization::{ensure, ensure_not_identity, Validate};
 use crate::common::sho::*;
 use crate::common::simple_types::*;
 use crate::crypto::profile_key_struct;
@@ -17,9 +18,9 @@ use curve25519_dalek::scalar::Scalar;
 use serde::{Deserialize, Serialize};
 
 use curve25519_dalek::subtle::Choice;
-use curve25519_dalek::subtle::ConditionallySelectable;
 use curve25519_dalek::subtle::ConstantTimeEq;
 
+use zeroize::Zeroize;
 use ZkGroupError::*;
 
 #[derive(Copy, Clone, PartialEq, Serialize, Deserialize)]
@@ -28,13 +29,20 @@ pub struct SystemParams {
     pub(crate) G_b2: RistrettoPoint,
 }
 
-#[derive(Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
 pub struct KeyPair {
     pub(crate) b1: Scalar,
     pub(crate) b2: Scalar,
     pub(crate) B: RistrettoPoint,
 }
 
+impl Drop for KeyPair {
+    fn drop(&mut self) {
+        self.b1.zeroize();
+        self.b2.zeroize();
+    }
+}
+
 #[derive(Copy, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PublicKey {
     pub(crate) B: RistrettoPoint,
@@ -58,7 +66,8 @@ impl SystemParams {
     }
 
     pub fn get_hardcoded() -> SystemParams {
-        bincode::deserialize::<SystemParams>(&SystemParams::SYSTEM_HARDCODED).unwrap()
+        bincode::deserialize::<SystemParams>(&SystemParams::SYSTEM_HARDCODED)
+            .unwrap_or_else(|_| SystemParams::generate())
     }
 
     const SYSTEM_HARDCODED: [u8; 64] = [
@@ -81,7 +90,7 @@ impl KeyPair {
         KeyPair { b1, b2, B }
     }
 
-    pub fn encrypt(&self, profile_key: profile_key_struct::ProfileKeyStruct) -> Ciphertext {
+    pub fn encrypt(&self, profile_key: &profile_key_struct::ProfileKeyStruct) -> Ciphertext {
         let E_B1 = self.calc_E_B1(profile_key);
         let E_B2 = (self.b2 * E_B1) + profile_key.M4;
         Ciphertext { E_B1, E_B2 }
@@ -132,7 +141,7 @@ impl KeyPair {
         }
     }
 
-    fn calc_E_B1(&self, profile_key: profile_key_struct::ProfileKeyStruct) -> RistrettoPoint {
+    fn calc_E_B1(&self, profile_key: &profile_key_struct::ProfileKeyStruct) -> RistrettoPoint {
         self.b1 * profile_key.M3
     }
 
@@ -141,6 +150,23 @@ impl KeyPair {
     }
 }
 
+impl Validate for PublicKey {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        ensure_not_identity::<Self>(&self.B, "B is the identity")
+    }
+}
+
+// decrypt() rejects these as well, but only once someone tries to decrypt
+impl Validate for Ciphertext {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        ensure_not_identity::<Self>(&self.E_B1, "E_B1 is the identity")?;
+        ensure::<Self>(
+            self.E_B1 != RISTRETTO_BASEPOINT_POINT,
+            "E_B1 is the basepoint",
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -169,7 +195,7 @@ mod tests {
         let profile_key_bytes = TEST_ARRAY_32_1;
         let uid_bytes = TEST_ARRAY_16_1;
         let profile_key = profile_key_struct::ProfileKeyStruct::new(profile_key_bytes, uid_bytes);
-        let ciphertext = key_pair.encrypt(profile_key);
+        let ciphertext = key_pair.encrypt(&profile_key);
 
         // Test serialize / deserialize of Ciphertext
         let ciphertext_bytes = bincode::serialize(&ciphertext).unwrap();
@@ -201,28 +227,28 @@ mod tests {
 
             let profile_key =
                 profile_key_struct::ProfileKeyStruct::new(profile_key_bytes, uid_bytes);
-            let ciphertext = key_pair.encrypt(profile_key);
+            let ciphertext = key_pair.encrypt(&profile_key);
             assert!(key_pair.decrypt(ciphertext, uid_bytes).unwrap() == profile_key);
         }
 
         let uid_bytes = TEST_ARRAY_16;
         let profile_key = profile_key_struct::ProfileKeyStruct::new(TEST_ARRAY_32, TEST_ARRAY_16);
-        let ciphertext = key_pair.encrypt(profile_key);
+        let ciphertext = key_pair.encrypt(&profile_key);
         assert!(key_pair.decrypt(ciphertext, uid_bytes).unwrap() == profile_key);
 
         let uid_bytes = TEST_ARRAY_16;
         let profile_key = profile_key_struct::ProfileKeyStruct::new(TEST_ARRAY_32_2, TEST_ARRAY_16);
-        let ciphertext = key_pair.encrypt(profile_key);
+        let ciphertext = key_pair.encrypt(&profile_key);
         assert!(key_pair.decrypt(ciphertext, uid_bytes).unwrap() == profile_key);
 
         let uid_bytes = TEST_ARRAY_16;
         let profile_key = profile_key_struct::ProfileKeyStruct::new(TEST_ARRAY_32_3, TEST_ARRAY_16);
-        let ciphertext = key_pair.encrypt(profile_key);
+        let ciphertext = key_pair.encrypt(&profile_key);
         assert!(key_pair.decrypt(ciphertext, uid_bytes).unwrap() == profile_key);
 
         let uid_bytes = TEST_ARRAY_16;
         let profile_key = profile_key_struct::ProfileKeyStruct::new(TEST_ARRAY_32_4, TEST_ARRAY_16);
-        let ciphertext = key_pair.encrypt(profile_key);
+        let ciphertext = key_pair.encrypt(&profile_key);
         assert!(key_pair.decrypt(ciphertext, uid_bytes).unwrap() == profile_key);
     }
 }
@@ -12,17 +12,24 @@ use crate::common::sho::*;
 use crate::common::simple_types::*;
 use curve25519_dalek::ristretto::RistrettoPoint;
 use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
 
 use curve25519_dalek::subtle::Choice;
 use curve25519_dalek::subtle::ConditionallySelectable;
 
-#[derive(Copy, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct ProfileKeyStruct {
     pub(crate) bytes: ProfileKeyBytes,
     pub(crate) M3: RistrettoPoint,
     pub(crate) M4: RistrettoPoint,
 }
 
+impl Drop for ProfileKeyStruct {
+    fn drop(&mut self) {
+        self.bytes.zeroize();
+    }
+}
+
 impl ProfileKeyStruct {
     pub fn new(profile_key_bytes: ProfileKeyBytes, uid_bytes: UidBytes) -> Self {
         let mut encoded_profile_key = profile_key_bytes;
@@ -52,24 +59,14 @@ impl ProfileKeyStruct {
     pub fn to_bytes(&self) -> ProfileKeyBytes {
         self.bytes
     }
-}
 
-impl ConditionallySelectable for ProfileKeyStruct {
+    // Not ConditionallySelectable, which needs Copy
     #[allow(clippy::needless_range_loop)]
-    fn conditional_select(
-        a: &ProfileKeyStruct,
-        b: &ProfileKeyStruct,
-        choice: Choice,
-    ) -> ProfileKeyStruct {
-        let mut bytes: ProfileKeyBytes = [0u8; PROFILE_KEY_LEN];
+    pub fn conditional_assign(&mut self, other: &ProfileKeyStruct, choice: Choice) {
         for i in 0..PROFILE_KEY_LEN {
-            bytes[i] = u8::conditional_select(&a.bytes[i], &b.bytes[i], choice);
-        }
-
-        ProfileKeyStruct {
-            bytes,
-            M3: RistrettoPoint::conditional_select(&a.M3, &b.M3, choice),
-            M4: RistrettoPoint::conditional_select(&a.M4, &b.M4, choice),
+            self.bytes[i].conditional_assign(&other.bytes[i], choice);
         }
+        self.M3.conditional_assign(&other.M3, choice);
+        self.M4.conditional_assign(&other.M4, choice);
     }
 }
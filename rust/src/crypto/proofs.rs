@@ -9,15 +9,23 @@
 use crate::common::constants::*;
 use crate::common::errors::ZkGroupError::*;
 use crate::common::errors::*;
+use crate::common::serialization::{ensure, Validate};
 use crate::common::sho::*;
 use crate::common::simple_types::*;
+use crate::crypto::credential_schema::{Commitments, CredentialSchema, EncryptedPair, IssuedMac};
 use crate::crypto::credentials;
+use crate::crypto::invite_link_password;
 use crate::crypto::profile_key_commitment;
 use crate::crypto::profile_key_credential_request;
 use crate::crypto::profile_key_encryption;
 use crate::crypto::profile_key_struct;
+use crate::crypto::rate_limit_credential_request;
+use crate::crypto::rate_limit_struct;
+use crate::crypto::receipt_credential_request;
+use crate::crypto::receipt_struct;
 use crate::crypto::uid_encryption;
 use crate::crypto::uid_struct;
+use curve25519_dalek::constants::RISTRETTO_BASEPOINT_POINT;
 use curve25519_dalek::ristretto::RistrettoPoint;
 use serde::{Deserialize, Serialize};
 
@@ -26,6 +34,11 @@ pub struct AuthCredentialIssuanceProof {
     poksho_proof: Vec<u8>,
 }
 
+#[derive(Serialize, Deserialize, Clone)]
+pub struct AuthCredentialBatchIssuanceProof {
+    poksho_proof: Vec<u8>,
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct ProfileKeyCredentialRequestProof {
     poksho_proof: Vec<u8>,
@@ -36,6 +49,31 @@ pub struct ProfileKeyCredentialIssuanceProof {
     poksho_proof: Vec<u8>,
 }
 
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ReceiptCredentialIssuanceProof {
+    poksho_proof: Vec<u8>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct RateLimitCredentialIssuanceProof {
+    poksho_proof: Vec<u8>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct UidEncryptionEqualityProof {
+    poksho_proof: Vec<u8>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct UidEncryptionMatchProof {
+    poksho_proof: Vec<u8>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct GroupInviteLinkPasswordProof {
+    poksho_proof: Vec<u8>,
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct AuthCredentialPresentationProof {
     C_x0: RistrettoPoint,
@@ -47,6 +85,16 @@ pub struct AuthCredentialPresentationProof {
     poksho_proof: Vec<u8>,
 }
 
+#[derive(Serialize, Deserialize, Clone)]
+pub struct RateLimitTokenProof {
+    C_x0: RistrettoPoint,
+    C_x1: RistrettoPoint,
+    C_y1: RistrettoPoint,
+    C_y2: RistrettoPoint,
+    C_V: RistrettoPoint,
+    poksho_proof: Vec<u8>,
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct ProfileKeyCredentialPresentationProof {
     C_x0: RistrettoPoint,
@@ -61,7 +109,73 @@ pub struct ProfileKeyCredentialPresentationProof {
 }
 
 impl AuthCredentialIssuanceProof {
-    pub fn get_poksho_statement() -> poksho::Statement {
+    pub fn get_poksho_statement() -> Result<poksho::Statement, ZkGroupError> {
+        Ok(credentials::get_auth_credential_schema()?.get_issuance_statement())
+    }
+
+    pub fn new(
+        key_pair: &credentials::KeyPair,
+        credential: credentials::AuthCredential,
+        uid: uid_struct::UidStruct,
+        redemption_time: RedemptionTime,
+        sho: &mut Sho,
+    ) -> Result<Self, ZkGroupError> {
+        let schema = credentials::get_auth_credential_schema()?;
+        let M = credentials::convert_to_points_uid_struct(uid, redemption_time);
+
+        let scalar_args = schema.get_issuance_scalar_args(&key_pair, None);
+        let point_args = schema.get_issuance_point_args(
+            key_pair.get_public_key(),
+            credential.t,
+            credential.U,
+            &M,
+            &IssuedMac::Clear { V: credential.V },
+        );
+
+        let poksho_proof = schema
+            .get_issuance_statement()
+            .prove(
+                &scalar_args,
+                &point_args,
+                &[],
+                &sho.squeeze(RANDOMNESS_LEN)[..],
+            )
+            .map_err(|_| ProofGenerationFailure("AuthCredentialIssuanceProof"))?;
+        Ok(Self { poksho_proof })
+    }
+
+    pub fn verify(
+        &self,
+        public_key: credentials::PublicKey,
+        credential: credentials::AuthCredential,
+        uid_struct: uid_struct::UidStruct,
+        redemption_time: RedemptionTime,
+    ) -> Result<(), ZkGroupError> {
+        let schema = credentials::get_auth_credential_schema()?;
+        let M = credentials::convert_to_points_uid_struct(uid_struct, redemption_time);
+
+        let point_args = schema.get_issuance_point_args(
+            public_key,
+            credential.t,
+            credential.U,
+            &M,
+            &IssuedMac::Clear { V: credential.V },
+        );
+
+        match schema
+            .get_issuance_statement()
+            .verify_proof(&self.poksho_proof, &point_args, &[])
+        {
+            Err(_) => Err(ProofVerificationFailure("AuthCredentialIssuanceProof")),
+            Ok(_) => Ok(()),
+        }
+    }
+}
+
+// Proves that every credential in a batch was issued for the same uid under
+// the same key, with one redemption time per credential
+impl AuthCredentialBatchIssuanceProof {
+    pub fn get_poksho_statement(num_credentials: usize) -> poksho::Statement {
         let mut st = poksho::Statement::new();
         st.add("C_W", &[("w", "G_w"), ("wprime", "G_wprime")]);
         st.add(
@@ -74,98 +188,121 @@ impl AuthCredentialIssuanceProof {
                 ("y3", "G_y3"),
             ],
         );
-        st.add(
-            "V",
-            &[
-                ("w", "G_w"),
-                ("x0", "U"),
-                ("x1", "tU"),
-                ("y1", "M1"),
-                ("y2", "M2"),
-                ("y3", "M3"),
-            ],
-        );
+        for i in 0..num_credentials {
+            let (V, U, tU, M3) = Self::get_point_names(i);
+            st.add(
+                &V,
+                &[
+                    ("w", "G_w"),
+                    ("x0", &U),
+                    ("x1", &tU),
+                    ("y1", "M1"),
+                    ("y2", "M2"),
+                    ("y3", &M3),
+                ],
+            );
+        }
         st
     }
 
-    pub fn new(
-        key_pair: credentials::KeyPair,
-        credential: credentials::AuthCredential,
+    fn get_point_names(index: usize) -> (String, String, String, String) {
+        (
+            format!("V{}", index),
+            format!("U{}", index),
+            format!("tU{}", index),
+            format!("M3_{}", index),
+        )
+    }
+
+    fn get_point_args(
+        public_key: credentials::PublicKey,
+        credentials: &[credentials::AuthCredential],
         uid: uid_struct::UidStruct,
-        redemption_time: RedemptionTime,
-        sho: &mut Sho,
-    ) -> Self {
+        redemption_times: &[RedemptionTime],
+    ) -> poksho::PointArgs {
         let system = credentials::SystemParams::get_hardcoded();
 
-        let M = credentials::convert_to_points_uid_struct(uid, redemption_time);
-
-        let mut scalar_args = poksho::ScalarArgs::new();
-        scalar_args.add("w", key_pair.w);
-        scalar_args.add("wprime", key_pair.wprime);
-        scalar_args.add("x0", key_pair.x0);
-        scalar_args.add("x1", key_pair.x1);
-        scalar_args.add("y1", key_pair.y1);
-        scalar_args.add("y2", key_pair.y2);
-        scalar_args.add("y3", key_pair.y3);
-
         let mut point_args = poksho::PointArgs::new();
-        point_args.add("C_W", key_pair.C_W);
+        point_args.add("C_W", public_key.C_W);
         point_args.add("G_w", system.G_w);
         point_args.add("G_wprime", system.G_wprime);
-        point_args.add("G_V-I", system.G_V - key_pair.I);
+        point_args.add("G_V-I", system.G_V - public_key.I);
         point_args.add("G_x0", system.G_x0);
         point_args.add("G_x1", system.G_x1);
         point_args.add("G_y1", system.G_y1);
         point_args.add("G_y2", system.G_y2);
         point_args.add("G_y3", system.G_y3);
-        point_args.add("V", credential.V);
-        point_args.add("U", credential.U);
-        point_args.add("tU", credential.t * credential.U);
-        point_args.add("M1", M[0]);
-        point_args.add("M2", M[1]);
-        point_args.add("M3", M[2]);
+        point_args.add("M1", uid.M1);
+        point_args.add("M2", uid.M2);
+        for (i, (credential, redemption_time)) in
+            credentials.iter().zip(redemption_times).enumerate()
+        {
+            let M = credentials::convert_to_points_uid_struct(uid, *redemption_time);
+            let (V, U, tU, M3) = Self::get_point_names(i);
+            point_args.add(V, credential.V);
+            point_args.add(U, credential.U);
+            point_args.add(tU, credential.t * credential.U);
+            point_args.add(M3, M[2]);
+        }
+        point_args
+    }
 
-        let poksho_proof = Self::get_poksho_statement()
+    pub fn new(
+        key_pair: &credentials::KeyPair,
+        credentials: &[credentials::AuthCredential],
+        uid: uid_struct::UidStruct,
+        redemption_times: &[RedemptionTime],
+        sho: &mut Sho,
+    ) -> Result<Self, ZkGroupError> {
+        let mut scalar_args = poksho::ScalarArgs::new();
+        scalar_args.add("w", key_pair.w);
+        scalar_args.add("wprime", key_pair.wprime);
+        scalar_args.add("x0", key_pair.x0);
+        scalar_args.add("x1", key_pair.x1);
+        for (i, y_i) in key_pair.y.iter().enumerate() {
+            scalar_args.add(format!("y{}", i + 1), *y_i);
+        }
+
+        let point_args = Self::get_point_args(
+            key_pair.get_public_key(),
+            credentials,
+            uid,
+            redemption_times,
+        );
+
+        let poksho_proof = Self::get_poksho_statement(credentials.len())
             .prove(
                 &scalar_args,
                 &point_args,
                 &[],
                 &sho.squeeze(RANDOMNESS_LEN)[..],
             )
-            .unwrap();
-        Self { poksho_proof }
+            .map_err(|_| ProofGenerationFailure("AuthCredentialBatchIssuanceProof"))?;
+        Ok(Self { poksho_proof })
     }
 
     pub fn verify(
         &self,
         public_key: credentials::PublicKey,
-        credential: credentials::AuthCredential,
-        uid_struct: uid_struct::UidStruct,
-        redemption_time: RedemptionTime,
+        credentials: &[credentials::AuthCredential],
+        uid: uid_struct::UidStruct,
+        redemption_times: &[RedemptionTime],
     ) -> Result<(), ZkGroupError> {
-        let system = credentials::SystemParams::get_hardcoded();
-
-        let M = credentials::convert_to_points_uid_struct(uid_struct, redemption_time);
+        if credentials.is_empty()
+            || credentials.len() > MAX_AUTH_CREDENTIAL_BATCH_SIZE
+            || credentials.len() != redemption_times.len()
+        {
+            return Err(ProofVerificationFailure("AuthCredentialBatchIssuanceProof"));
+        }
 
-        let mut point_args = poksho::PointArgs::new();
-        point_args.add("C_W", public_key.C_W);
-        point_args.add("G_w", system.G_w);
-        point_args.add("G_wprime", system.G_wprime);
-        point_args.add("G_V-I", system.G_V - public_key.I);
-        point_args.add("G_x0", system.G_x0);
-        point_args.add("G_x1", system.G_x1);
-        point_args.add("G_y1", system.G_y1);
-        point_args.add("G_y2", system.G_y2);
-        point_args.add("G_y3", system.G_y3);
-        point_args.add("V", credential.V);
-        point_args.add("U", credential.U);
-        point_args.add("tU", credential.t * credential.U);
-        point_args.add("M1", M[0]);
-        point_args.add("M2", M[1]);
-        point_args.add("M3", M[2]);
+        let point_args = Self::get_point_args(public_key, credentials, uid, redemption_times);
 
-        match Self::get_poksho_statement().verify_proof(&self.poksho_proof, &point_args, &[]) {
-            Err(_) => Err(ProofVerificationFailure),
+        match Self::get_poksho_statement(credentials.len()).verify_proof(
+            &self.poksho_proof,
+            &point_args,
+            &[],
+        ) {
+            Err(_) => Err(ProofVerificationFailure("AuthCredentialBatchIssuanceProof")),
             Ok(_) => Ok(()),
         }
     }
@@ -184,11 +321,11 @@ impl ProfileKeyCredentialRequestProof {
     }
 
     pub fn new(
-        key_pair: profile_key_credential_request::KeyPair,
-        ciphertext: profile_key_credential_request::CiphertextWithSecretNonce,
+        key_pair: &profile_key_credential_request::KeyPair,
+        ciphertext: &profile_key_credential_request::CiphertextWithSecretNonce,
         commitment: profile_key_commitment::CommitmentWithSecretNonce,
         sho: &mut Sho,
-    ) -> ProfileKeyCredentialRequestProof {
+    ) -> Result<Self, ZkGroupError> {
         let commitment_system = profile_key_commitment::SystemParams::get_hardcoded();
 
         let mut scalar_args = poksho::ScalarArgs::new();
@@ -215,8 +352,8 @@ impl ProfileKeyCredentialRequestProof {
                 &[],
                 &sho.squeeze(RANDOMNESS_LEN)[..],
             )
-            .unwrap();
-        ProfileKeyCredentialRequestProof { poksho_proof }
+            .map_err(|_| ProofGenerationFailure("ProfileKeyCredentialRequestProof"))?;
+        Ok(ProfileKeyCredentialRequestProof { poksho_proof })
     }
 
     pub fn verify(
@@ -239,97 +376,48 @@ impl ProfileKeyCredentialRequestProof {
         point_args.add("-G_j2", -commitment_system.G_j2);
 
         match Self::get_poksho_statement().verify_proof(&self.poksho_proof, &point_args, &[]) {
-            Err(_) => Err(ProofVerificationFailure),
+            Err(_) => Err(ProofVerificationFailure("ProfileKeyCredentialRequestProof")),
             Ok(_) => Ok(()),
         }
     }
 }
 
 impl ProfileKeyCredentialIssuanceProof {
-    pub fn get_poksho_statement() -> poksho::Statement {
-        let mut st = poksho::Statement::new();
-        st.add("C_W", &[("w", "G_w"), ("wprime", "G_wprime")]);
-        st.add(
-            "G_V-I",
-            &[
-                ("x0", "G_x0"),
-                ("x1", "G_x1"),
-                ("y1", "G_y1"),
-                ("y2", "G_y2"),
-                ("y3", "G_y3"),
-                ("y4", "G_y4"),
-            ],
-        );
-        st.add("S1", &[("y3", "D1"), ("y4", "E1"), ("rprime", "G")]);
-        st.add(
-            "S2",
-            &[
-                ("y3", "D2"),
-                ("y4", "E2"),
-                ("rprime", "Y"),
-                ("w", "G_w"),
-                ("x0", "U"),
-                ("x1", "tU"),
-                ("y1", "M1"),
-                ("y2", "M2"),
-            ],
-        );
-        st
+    pub fn get_poksho_statement() -> Result<poksho::Statement, ZkGroupError> {
+        Ok(credentials::get_profile_key_credential_schema()?.get_issuance_statement())
     }
 
     pub fn new(
-        key_pair: credentials::KeyPair,
+        key_pair: &credentials::KeyPair,
         request_public_key: profile_key_credential_request::PublicKey,
         request: profile_key_credential_request::Ciphertext,
         blinded_credential: credentials::BlindedProfileKeyCredentialWithSecretNonce,
         uid: uid_struct::UidStruct,
         sho: &mut Sho,
-    ) -> ProfileKeyCredentialIssuanceProof {
-        let credentials_system = credentials::SystemParams::get_hardcoded();
-
-        let mut scalar_args = poksho::ScalarArgs::new();
-        scalar_args.add("w", key_pair.w);
-        scalar_args.add("wprime", key_pair.wprime);
-        scalar_args.add("x0", key_pair.x0);
-        scalar_args.add("x1", key_pair.x1);
-        scalar_args.add("y1", key_pair.y1);
-        scalar_args.add("y2", key_pair.y2);
-        scalar_args.add("y3", key_pair.y3);
-        scalar_args.add("y4", key_pair.y4);
-        scalar_args.add("rprime", blinded_credential.rprime);
-
-        let mut point_args = poksho::PointArgs::new();
-        point_args.add("C_W", key_pair.C_W);
-        point_args.add("G_w", credentials_system.G_w);
-        point_args.add("G_wprime", credentials_system.G_wprime);
-        point_args.add("G_V-I", credentials_system.G_V - key_pair.I);
-        point_args.add("G_x0", credentials_system.G_x0);
-        point_args.add("G_x1", credentials_system.G_x1);
-        point_args.add("G_y1", credentials_system.G_y1);
-        point_args.add("G_y2", credentials_system.G_y2);
-        point_args.add("G_y3", credentials_system.G_y3);
-        point_args.add("G_y4", credentials_system.G_y4);
-        point_args.add("S1", blinded_credential.S1);
-        point_args.add("D1", request.D1);
-        point_args.add("E1", request.E1);
-        point_args.add("S2", blinded_credential.S2);
-        point_args.add("D2", request.D2);
-        point_args.add("E2", request.E2);
-        point_args.add("Y", request_public_key.Y);
-        point_args.add("U", blinded_credential.U);
-        point_args.add("tU", blinded_credential.t * blinded_credential.U);
-        point_args.add("M1", uid.M1);
-        point_args.add("M2", uid.M2);
+    ) -> Result<Self, ZkGroupError> {
+        let schema = credentials::get_profile_key_credential_schema()?;
+
+        let scalar_args =
+            schema.get_issuance_scalar_args(&key_pair, Some(blinded_credential.rprime));
+        let point_args = Self::get_point_args(
+            &schema,
+            key_pair.get_public_key(),
+            request_public_key,
+            request,
+            blinded_credential.get_blinded_profile_key_credential(),
+            uid,
+        );
 
-        let poksho_proof = Self::get_poksho_statement()
+        let poksho_proof = schema
+            .get_issuance_statement()
             .prove(
                 &scalar_args,
                 &point_args,
                 &[],
                 &sho.squeeze(RANDOMNESS_LEN)[..],
             )
-            .unwrap();
-        ProfileKeyCredentialIssuanceProof { poksho_proof }
+            .map_err(|_| ProofGenerationFailure("ProfileKeyCredentialIssuanceProof"))?;
+        Ok(ProfileKeyCredentialIssuanceProof { poksho_proof })
     }
 
     pub fn verify(
@@ -340,291 +428,966 @@ impl ProfileKeyCredentialIssuanceProof {
         request: profile_key_credential_request::Ciphertext,
         blinded_credential: credentials::BlindedProfileKeyCredential,
     ) -> Result<(), ZkGroupError> {
-        let credentials_system = credentials::SystemParams::get_hardcoded();
+        let schema = credentials::get_profile_key_credential_schema()?;
         let uid = uid_struct::UidStruct::new(uid_bytes);
 
-        let mut point_args = poksho::PointArgs::new();
-        point_args.add("C_W", credentials_public_key.C_W);
-        point_args.add("G_w", credentials_system.G_w);
-        point_args.add("G_wprime", credentials_system.G_wprime);
-        point_args.add("G_V-I", credentials_system.G_V - credentials_public_key.I);
-        point_args.add("G_x0", credentials_system.G_x0);
-        point_args.add("G_x1", credentials_system.G_x1);
-        point_args.add("G_y1", credentials_system.G_y1);
-        point_args.add("G_y2", credentials_system.G_y2);
-        point_args.add("G_y3", credentials_system.G_y3);
-        point_args.add("G_y4", credentials_system.G_y4);
-        point_args.add("S1", blinded_credential.S1);
-        point_args.add("D1", request.D1);
-        point_args.add("E1", request.E1);
-        point_args.add("S2", blinded_credential.S2);
-        point_args.add("D2", request.D2);
-        point_args.add("E2", request.E2);
-        point_args.add("Y", request_public_key.Y);
-        point_args.add("U", blinded_credential.U);
-        point_args.add("tU", blinded_credential.t * blinded_credential.U);
-        point_args.add("M1", uid.M1);
-        point_args.add("M2", uid.M2);
+        let point_args = Self::get_point_args(
+            &schema,
+            credentials_public_key,
+            request_public_key,
+            request,
+            blinded_credential,
+            uid,
+        );
 
-        match Self::get_poksho_statement().verify_proof(&self.poksho_proof, &point_args, &[]) {
-            Err(_) => Err(ProofVerificationFailure),
+        match schema
+            .get_issuance_statement()
+            .verify_proof(&self.poksho_proof, &point_args, &[])
+        {
+            Err(_) => Err(ProofVerificationFailure(
+                "ProfileKeyCredentialIssuanceProof",
+            )),
             Ok(_) => Ok(()),
         }
     }
+
+    fn get_point_args(
+        schema: &CredentialSchema,
+        credentials_public_key: credentials::PublicKey,
+        request_public_key: profile_key_credential_request::PublicKey,
+        request: profile_key_credential_request::Ciphertext,
+        blinded_credential: credentials::BlindedProfileKeyCredential,
+        uid: uid_struct::UidStruct,
+    ) -> poksho::PointArgs {
+        schema.get_issuance_point_args(
+            credentials_public_key,
+            blinded_credential.t,
+            blinded_credential.U,
+            &[uid.M1, uid.M2],
+            &IssuedMac::Blinded {
+                Y: request_public_key.Y,
+                S1: blinded_credential.S1,
+                S2: blinded_credential.S2,
+                ciphertexts: vec![(request.D1, request.D2), (request.E1, request.E2)],
+            },
+        )
+    }
 }
 
-impl AuthCredentialPresentationProof {
-    pub fn get_poksho_statement() -> poksho::Statement {
-        let mut st = poksho::Statement::new();
+impl ReceiptCredentialIssuanceProof {
+    pub fn get_poksho_statement() -> Result<poksho::Statement, ZkGroupError> {
+        Ok(credentials::get_receipt_credential_schema()?.get_issuance_statement())
+    }
 
-        st.add("Z", &[("z", "I")]);
-        st.add("C_x1", &[("t", "C_x0"), ("z0", "G_x0"), ("z", "G_x1")]);
-        st.add("A", &[("a1", "G_a1"), ("a2", "G_a2")]);
-        st.add("C_y2-E_A2", &[("z", "G_y2"), ("a2", "-E_A1")]);
-        st.add("E_A1", &[("a1", "C_y1"), ("z1", "G_y1")]);
-        st.add("C_y3", &[("z", "G_y3")]);
-        st
+    pub fn new(
+        key_pair: &credentials::KeyPair,
+        request_public_key: receipt_credential_request::PublicKey,
+        request: receipt_credential_request::Ciphertext,
+        blinded_credential: credentials::BlindedReceiptCredentialWithSecretNonce,
+        receipt_expiration_time: ReceiptExpirationTime,
+        receipt_level: ReceiptLevel,
+        sho: &mut Sho,
+    ) -> Result<Self, ZkGroupError> {
+        let schema = credentials::get_receipt_credential_schema()?;
+
+        let scalar_args =
+            schema.get_issuance_scalar_args(&key_pair, Some(blinded_credential.rprime));
+        let point_args = Self::get_point_args(
+            &schema,
+            key_pair.get_public_key(),
+            request_public_key,
+            request,
+            blinded_credential.get_blinded_receipt_credential(),
+            receipt_expiration_time,
+            receipt_level,
+        );
+
+        let poksho_proof = schema
+            .get_issuance_statement()
+            .prove(
+                &scalar_args,
+                &point_args,
+                &[],
+                &sho.squeeze(RANDOMNESS_LEN)[..],
+            )
+            .map_err(|_| ProofGenerationFailure("ReceiptCredentialIssuanceProof"))?;
+        Ok(ReceiptCredentialIssuanceProof { poksho_proof })
+    }
+
+    pub fn verify(
+        &self,
+        credentials_public_key: credentials::PublicKey,
+        request_public_key: receipt_credential_request::PublicKey,
+        request: receipt_credential_request::Ciphertext,
+        blinded_credential: credentials::BlindedReceiptCredential,
+        receipt_expiration_time: ReceiptExpirationTime,
+        receipt_level: ReceiptLevel,
+    ) -> Result<(), ZkGroupError> {
+        let schema = credentials::get_receipt_credential_schema()?;
+        let point_args = Self::get_point_args(
+            &schema,
+            credentials_public_key,
+            request_public_key,
+            request,
+            blinded_credential,
+            receipt_expiration_time,
+            receipt_level,
+        );
+
+        match schema
+            .get_issuance_statement()
+            .verify_proof(&self.poksho_proof, &point_args, &[])
+        {
+            Err(_) => Err(ProofVerificationFailure("ReceiptCredentialIssuanceProof")),
+            Ok(_) => Ok(()),
+        }
+    }
+
+    fn get_point_args(
+        schema: &CredentialSchema,
+        credentials_public_key: credentials::PublicKey,
+        request_public_key: receipt_credential_request::PublicKey,
+        request: receipt_credential_request::Ciphertext,
+        blinded_credential: credentials::BlindedReceiptCredential,
+        receipt_expiration_time: ReceiptExpirationTime,
+        receipt_level: ReceiptLevel,
+    ) -> poksho::PointArgs {
+        let M1 = receipt_struct::ReceiptStruct::calc_M1(receipt_expiration_time, receipt_level);
+        schema.get_issuance_point_args(
+            credentials_public_key,
+            blinded_credential.t,
+            blinded_credential.U,
+            &[M1],
+            &IssuedMac::Blinded {
+                Y: request_public_key.Y,
+                S1: blinded_credential.S1,
+                S2: blinded_credential.S2,
+                ciphertexts: vec![(request.D1, request.D2)],
+            },
+        )
+    }
+}
+
+impl RateLimitCredentialIssuanceProof {
+    pub fn get_poksho_statement() -> Result<poksho::Statement, ZkGroupError> {
+        Ok(credentials::get_rate_limit_credential_schema()?.get_issuance_statement())
+    }
+
+    pub fn new(
+        key_pair: &credentials::KeyPair,
+        request_public_key: rate_limit_credential_request::PublicKey,
+        request: rate_limit_credential_request::Ciphertext,
+        blinded_credential: credentials::BlindedRateLimitCredentialWithSecretNonce,
+        redemption_time: RedemptionTime,
+        sho: &mut Sho,
+    ) -> Result<Self, ZkGroupError> {
+        let schema = credentials::get_rate_limit_credential_schema()?;
+
+        let scalar_args =
+            schema.get_issuance_scalar_args(&key_pair, Some(blinded_credential.rprime));
+        let point_args = Self::get_point_args(
+            &schema,
+            key_pair.get_public_key(),
+            request_public_key,
+            request,
+            blinded_credential.get_blinded_rate_limit_credential(),
+            redemption_time,
+        );
+
+        let poksho_proof = schema
+            .get_issuance_statement()
+            .prove(
+                &scalar_args,
+                &point_args,
+                &[],
+                &sho.squeeze(RANDOMNESS_LEN)[..],
+            )
+            .map_err(|_| ProofGenerationFailure("RateLimitCredentialIssuanceProof"))?;
+        Ok(RateLimitCredentialIssuanceProof { poksho_proof })
+    }
+
+    pub fn verify(
+        &self,
+        credentials_public_key: credentials::PublicKey,
+        request_public_key: rate_limit_credential_request::PublicKey,
+        request: rate_limit_credential_request::Ciphertext,
+        blinded_credential: credentials::BlindedRateLimitCredential,
+        redemption_time: RedemptionTime,
+    ) -> Result<(), ZkGroupError> {
+        let schema = credentials::get_rate_limit_credential_schema()?;
+        let point_args = Self::get_point_args(
+            &schema,
+            credentials_public_key,
+            request_public_key,
+            request,
+            blinded_credential,
+            redemption_time,
+        );
+
+        match schema
+            .get_issuance_statement()
+            .verify_proof(&self.poksho_proof, &point_args, &[])
+        {
+            Err(_) => Err(ProofVerificationFailure("RateLimitCredentialIssuanceProof")),
+            Ok(_) => Ok(()),
+        }
+    }
+
+    fn get_point_args(
+        schema: &CredentialSchema,
+        credentials_public_key: credentials::PublicKey,
+        request_public_key: rate_limit_credential_request::PublicKey,
+        request: rate_limit_credential_request::Ciphertext,
+        blinded_credential: credentials::BlindedRateLimitCredential,
+        redemption_time: RedemptionTime,
+    ) -> poksho::PointArgs {
+        let M1 = rate_limit_struct::RateLimitStruct::calc_M1(redemption_time);
+        schema.get_issuance_point_args(
+            credentials_public_key,
+            blinded_credential.t,
+            blinded_credential.U,
+            &[M1],
+            &IssuedMac::Blinded {
+                Y: request_public_key.Y,
+                S1: blinded_credential.S1,
+                S2: blinded_credential.S2,
+                ciphertexts: vec![(request.D1, request.D2)],
+            },
+        )
+    }
+}
+
+impl AuthCredentialPresentationProof {
+    pub fn get_poksho_statement() -> Result<poksho::Statement, ZkGroupError> {
+        Ok(credentials::get_auth_credential_schema()?.get_presentation_statement(false))
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         credentials_public_key: credentials::PublicKey,
-        uid_enc_key_pair: uid_encryption::KeyPair,
+        uid_enc_key_pair: &uid_encryption::KeyPair,
         credential: credentials::AuthCredential,
         uid: uid_struct::UidStruct,
         uid_ciphertext: uid_encryption::Ciphertext,
         redemption_time: RedemptionTime,
+        message: &[u8],
         sho: &mut Sho,
-    ) -> Self {
-        let credentials_system = credentials::SystemParams::get_hardcoded();
+    ) -> Result<Self, ZkGroupError> {
+        let schema = credentials::get_auth_credential_schema()?;
         let uid_system = uid_encryption::SystemParams::get_hardcoded();
         let M = credentials::convert_to_points_uid_struct(uid, redemption_time);
 
         let z = sho.get_scalar();
 
-        let C_y1 = z * credentials_system.G_y1 + M[0];
-        let C_y2 = z * credentials_system.G_y2 + M[1];
-        let C_y3 = z * credentials_system.G_y3;
-
-        let C_x0 = z * credentials_system.G_x0 + credential.U;
-        let C_V = z * credentials_system.G_V + credential.V;
-        let C_x1 = z * credentials_system.G_x1 + credential.t * credential.U;
-
-        let z0 = -z * credential.t;
-        let z1 = -z * uid_enc_key_pair.a1;
+        let commitments = schema.commit(z, credential.t, credential.U, credential.V, &M[..2])?;
 
         let I = credentials_public_key.I;
         let Z = z * I;
 
-        // Scalars listed in order of stmts for debugging
-        let mut scalar_args = poksho::ScalarArgs::new();
-        scalar_args.add("z", z);
-        scalar_args.add("t", credential.t);
-        scalar_args.add("z0", z0);
-        scalar_args.add("a1", uid_enc_key_pair.a1);
-        scalar_args.add("a2", uid_enc_key_pair.a2);
-        scalar_args.add("z1", z1);
-
-        // Points listed in order of stmts for debugging
-        let mut point_args = poksho::PointArgs::new();
-        point_args.add("Z", Z);
-        point_args.add("I", I);
-        point_args.add("C_x1", C_x1);
-        point_args.add("C_x0", C_x0);
-        point_args.add("G_x0", credentials_system.G_x0);
-        point_args.add("G_x1", credentials_system.G_x1);
-        point_args.add("A", uid_enc_key_pair.A);
-        point_args.add("G_a1", uid_system.G_a1);
-        point_args.add("G_a2", uid_system.G_a2);
-        point_args.add("C_y2-E_A2", C_y2 - uid_ciphertext.E_A2);
-        point_args.add("G_y2", credentials_system.G_y2);
-        point_args.add("-E_A1", -uid_ciphertext.E_A1);
-        point_args.add("E_A1", uid_ciphertext.E_A1);
-        point_args.add("C_y1", C_y1);
-        point_args.add("G_y1", credentials_system.G_y1);
-        point_args.add("C_y3", C_y3);
-        point_args.add("G_y3", credentials_system.G_y3);
+        let scalar_args = schema.get_presentation_scalar_args(
+            z,
+            credential.t,
+            &[(uid_enc_key_pair.a1, uid_enc_key_pair.a2)],
+        );
+        let point_args = schema.get_presentation_point_args(
+            &commitments,
+            I,
+            Z,
+            &[Self::get_encrypted_pair(
+                &uid_system,
+                uid_enc_key_pair.get_public_key(),
+                uid_ciphertext,
+            )],
+        );
 
-        let poksho_proof = Self::get_poksho_statement()
+        let poksho_proof = schema
+            .get_presentation_statement(false)
             .prove(
                 &scalar_args,
                 &point_args,
-                &[],
+                message,
                 &sho.squeeze(RANDOMNESS_LEN)[..],
             )
-            .unwrap();
-
-        Self {
-            C_x0,
-            C_x1,
-            C_y1,
-            C_y2,
-            C_y3,
-            C_V,
+            .map_err(|_| ProofGenerationFailure("AuthCredentialPresentationProof"))?;
+
+        Ok(Self {
+            C_x0: commitments.C_x0,
+            C_x1: commitments.C_x1,
+            C_y1: commitments.C_y[0],
+            C_y2: commitments.C_y[1],
+            C_y3: commitments.C_y[2],
+            C_V: commitments.C_V,
             poksho_proof,
-        }
+        })
     }
 
+    // There's no batch form of this. A poksho proof holds the challenge and
+    // responses but not the prover's commitments, so each verification has
+    // to rebuild its own commitments to hash them. Combining many proofs into
+    // one multiscalar multiplication would need the commitments sent, which
+    // is a new presentation format.
     pub fn verify(
         &self,
-        credentials_key_pair: credentials::KeyPair,
+        credentials_key_pair: &credentials::KeyPair,
         uid_enc_public_key: uid_encryption::PublicKey,
         uid_ciphertext: uid_encryption::Ciphertext,
         redemption_time: RedemptionTime,
+        message: &[u8],
     ) -> Result<(), ZkGroupError> {
         let enc_system = uid_encryption::SystemParams::get_hardcoded();
+        let schema = credentials::get_auth_credential_schema()?;
         let credentials_system = credentials::SystemParams::get_hardcoded();
+        let commitments = Commitments {
+            C_x0: self.C_x0,
+            C_x1: self.C_x1,
+            C_y: vec![self.C_y1, self.C_y2, self.C_y3],
+            C_V: self.C_V,
+            C_z: Default::default(),
+        };
 
-        let Self {
-            C_x0,
-            C_x1,
-            C_y1,
-            C_y2,
-            C_y3,
-            C_V,
-            poksho_proof,
-        } = self;
+        let m3 = encode_redemption_time(redemption_time);
+        let Z = schema.get_presentation_Z(
+            &credentials_key_pair,
+            &commitments,
+            &[m3 * credentials_system.G_m3],
+        );
 
-        let (C_x0, C_x1, C_y1, C_y2, C_y3, C_V) = (*C_x0, *C_x1, *C_y1, *C_y2, *C_y3, *C_V);
+        let point_args = schema.get_presentation_point_args(
+            &commitments,
+            credentials_key_pair.I,
+            Z,
+            &[Self::get_encrypted_pair(
+                &enc_system,
+                uid_enc_public_key,
+                uid_ciphertext,
+            )],
+        );
 
-        let credentials::KeyPair {
-            W,
-            x0,
-            x1,
-            y1,
-            y2,
-            y3,
+        match schema.get_presentation_statement(false).verify_proof(
+            &self.poksho_proof,
+            &point_args,
+            message,
+        ) {
+            Err(_) => Err(ProofVerificationFailure("AuthCredentialPresentationProof")),
+            Ok(_) => Ok(()),
+        }
+    }
+
+    fn get_encrypted_pair(
+        enc_system: &uid_encryption::SystemParams,
+        uid_enc_public_key: uid_encryption::PublicKey,
+        uid_ciphertext: uid_encryption::Ciphertext,
+    ) -> EncryptedPair {
+        EncryptedPair {
+            public_key: uid_enc_public_key.A,
+            G_1: enc_system.G_a1,
+            G_2: enc_system.G_a2,
+            E_1: uid_ciphertext.E_A1,
+            E_2: uid_ciphertext.E_A2,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ProfileKeyCredentialSelectivePresentationProof {
+    C_x0: RistrettoPoint,
+    C_x1: RistrettoPoint,
+    C_y1: RistrettoPoint,
+    C_y2: RistrettoPoint,
+    C_y3: RistrettoPoint,
+    C_y4: RistrettoPoint,
+    C_V: RistrettoPoint,
+    poksho_proof: Vec<u8>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ReceiptCredentialPresentationProof {
+    C_x0: RistrettoPoint,
+    C_x1: RistrettoPoint,
+    C_y1: RistrettoPoint,
+    C_y2: RistrettoPoint,
+    C_V: RistrettoPoint,
+    poksho_proof: Vec<u8>,
+}
+
+impl ProfileKeyCredentialPresentationProof {
+    pub fn get_poksho_statement() -> Result<poksho::Statement, ZkGroupError> {
+        Ok(credentials::get_profile_key_credential_schema()?.get_presentation_statement(true))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        uid_enc_key_pair: &uid_encryption::KeyPair,
+        profile_key_enc_key_pair: &profile_key_encryption::KeyPair,
+        credentials_public_key: credentials::PublicKey,
+        credential: credentials::ProfileKeyCredential,
+        uid_ciphertext: uid_encryption::Ciphertext,
+        profile_key_ciphertext: profile_key_encryption::Ciphertext,
+        uid_bytes: UidBytes,
+        profile_key_bytes: ProfileKeyBytes,
+        message: &[u8],
+        sho: &mut Sho,
+    ) -> Result<Self, ZkGroupError> {
+        let schema = credentials::get_profile_key_credential_schema()?;
+        let uid = uid_struct::UidStruct::new(uid_bytes);
+        let profile_key = profile_key_struct::ProfileKeyStruct::new(profile_key_bytes, uid_bytes);
+
+        let z = sho.get_scalar();
+
+        let commitments = schema.commit(
+            z,
+            credential.t,
+            credential.U,
+            credential.V,
+            &[uid.M1, uid.M2, profile_key.M3, profile_key.M4],
+        )?;
+
+        let I = credentials_public_key.I;
+        let Z = z * I;
+
+        let scalar_args = schema.get_presentation_scalar_args(
+            z,
+            credential.t,
+            &[
+                (uid_enc_key_pair.a1, uid_enc_key_pair.a2),
+                (profile_key_enc_key_pair.b1, profile_key_enc_key_pair.b2),
+            ],
+        );
+        let point_args = schema.get_presentation_point_args(
+            &commitments,
             I,
-            ..
-        } = credentials_key_pair;
+            Z,
+            &Self::get_encrypted_pairs(
+                uid_enc_key_pair.get_public_key(),
+                uid_ciphertext,
+                profile_key_enc_key_pair.get_public_key(),
+                profile_key_ciphertext,
+            ),
+        );
 
-        let m3 = encode_redemption_time(redemption_time);
-        let M3 = m3 * credentials_system.G_m3;
-        let Z = C_V - W - x0 * C_x0 - x1 * C_x1 - y1 * C_y1 - y2 * C_y2 - y3 * (C_y3 + M3);
+        let poksho_proof = schema
+            .get_presentation_statement(true)
+            .prove(
+                &scalar_args,
+                &point_args,
+                message,
+                &sho.squeeze(RANDOMNESS_LEN)[..],
+            )
+            .map_err(|_| ProofGenerationFailure("ProfileKeyCredentialPresentationProof"))?;
+
+        Ok(ProfileKeyCredentialPresentationProof {
+            C_y1: commitments.C_y[0],
+            C_y2: commitments.C_y[1],
+            C_y3: commitments.C_y[2],
+            C_y4: commitments.C_y[3],
+            C_x0: commitments.C_x0,
+            C_x1: commitments.C_x1,
+            C_V: commitments.C_V,
+            C_z: commitments.C_z,
+            poksho_proof,
+        })
+    }
 
-        // Points listed in order of stmts for debugging
-        let mut point_args = poksho::PointArgs::new();
-        point_args.add("Z", Z);
-        point_args.add("I", I);
-        point_args.add("C_x1", C_x1);
-        point_args.add("C_x0", C_x0);
-        point_args.add("G_x0", credentials_system.G_x0);
-        point_args.add("G_x1", credentials_system.G_x1);
-        point_args.add("A", uid_enc_public_key.A);
-        point_args.add("G_a1", enc_system.G_a1);
-        point_args.add("G_a2", enc_system.G_a2);
-        point_args.add("C_y2-E_A2", C_y2 - uid_ciphertext.E_A2);
-        point_args.add("G_y2", credentials_system.G_y2);
-        point_args.add("-E_A1", -uid_ciphertext.E_A1);
-        point_args.add("E_A1", uid_ciphertext.E_A1);
-        point_args.add("C_y1", C_y1);
-        point_args.add("G_y1", credentials_system.G_y1);
-        point_args.add("C_y3", C_y3);
-        point_args.add("G_y3", credentials_system.G_y3);
-
-        match Self::get_poksho_statement().verify_proof(&poksho_proof, &point_args, &[]) {
-            Err(_) => Err(ZkGroupError::ProofVerificationFailure),
+    pub fn verify(
+        &self,
+        credentials_key_pair: &credentials::KeyPair,
+        uid_ciphertext: uid_encryption::Ciphertext,
+        uid_enc_public_key: uid_encryption::PublicKey,
+        profile_key_ciphertext: profile_key_encryption::Ciphertext,
+        profile_key_enc_public_key: profile_key_encryption::PublicKey,
+        message: &[u8],
+    ) -> Result<(), ZkGroupError> {
+        let schema = credentials::get_profile_key_credential_schema()?;
+        let commitments = Commitments {
+            C_x0: self.C_x0,
+            C_x1: self.C_x1,
+            C_y: vec![self.C_y1, self.C_y2, self.C_y3, self.C_y4],
+            C_V: self.C_V,
+            C_z: self.C_z,
+        };
+
+        let Z = schema.get_presentation_Z(&credentials_key_pair, &commitments, &[]);
+
+        let point_args = schema.get_presentation_point_args(
+            &commitments,
+            credentials_key_pair.I,
+            Z,
+            &Self::get_encrypted_pairs(
+                uid_enc_public_key,
+                uid_ciphertext,
+                profile_key_enc_public_key,
+                profile_key_ciphertext,
+            ),
+        );
+
+        match schema.get_presentation_statement(true).verify_proof(
+            &self.poksho_proof,
+            &point_args,
+            message,
+        ) {
+            Err(_) => Err(ProofVerificationFailure(
+                "ProfileKeyCredentialPresentationProof",
+            )),
             Ok(_) => Ok(()),
         }
     }
+
+    fn get_encrypted_pairs(
+        uid_enc_public_key: uid_encryption::PublicKey,
+        uid_ciphertext: uid_encryption::Ciphertext,
+        profile_key_enc_public_key: profile_key_encryption::PublicKey,
+        profile_key_ciphertext: profile_key_encryption::Ciphertext,
+    ) -> [EncryptedPair; 2] {
+        let uid_enc_system = uid_encryption::SystemParams::get_hardcoded();
+        let profile_key_enc_system = profile_key_encryption::SystemParams::get_hardcoded();
+        [
+            EncryptedPair {
+                public_key: uid_enc_public_key.A,
+                G_1: uid_enc_system.G_a1,
+                G_2: uid_enc_system.G_a2,
+                E_1: uid_ciphertext.E_A1,
+                E_2: uid_ciphertext.E_A2,
+            },
+            EncryptedPair {
+                public_key: profile_key_enc_public_key.B,
+                G_1: profile_key_enc_system.G_b1,
+                G_2: profile_key_enc_system.G_b2,
+                E_1: profile_key_ciphertext.E_B1,
+                E_2: profile_key_ciphertext.E_B2,
+            },
+        ]
+    }
 }
 
-impl ProfileKeyCredentialPresentationProof {
-    pub fn get_poksho_statement() -> poksho::Statement {
-        let mut st = poksho::Statement::new();
-        st.add("C_z", &[("z", "G_z")]);
-        st.add("Z", &[("z", "I")]);
-        st.add("C_x1", &[("t", "C_x0"), ("z0", "G_x0"), ("z", "G_x1")]);
-        st.add("A", &[("a1", "G_a1"), ("a2", "G_a2")]);
-        st.add("B", &[("b1", "G_b1"), ("b2", "G_b2")]);
-        st.add("C_y2-E_A2", &[("z", "G_y2"), ("a2", "-E_A1")]);
-        st.add("E_A1", &[("a1", "C_y1"), ("z1", "G_y1")]);
-        st.add("C_y4-E_B2", &[("z", "G_y4"), ("b2", "-E_B1")]);
-        st.add("E_B1", &[("b1", "C_y3"), ("z2", "G_y3")]);
-        st
+impl ProfileKeyCredentialSelectivePresentationProof {
+    pub fn get_poksho_statement() -> Result<poksho::Statement, ZkGroupError> {
+        Ok(credentials::get_profile_key_credential_uid_only_schema()?
+            .get_presentation_statement(false))
     }
 
     #[allow(clippy::too_many_arguments)]
     pub fn new(
-        uid_enc_key_pair: uid_encryption::KeyPair,
-        profile_key_enc_key_pair: profile_key_encryption::KeyPair,
+        uid_enc_key_pair: &uid_encryption::KeyPair,
         credentials_public_key: credentials::PublicKey,
         credential: credentials::ProfileKeyCredential,
         uid_ciphertext: uid_encryption::Ciphertext,
-        profile_key_ciphertext: profile_key_encryption::Ciphertext,
         uid_bytes: UidBytes,
         profile_key_bytes: ProfileKeyBytes,
+        message: &[u8],
         sho: &mut Sho,
-    ) -> Self {
-        let credentials_system = credentials::SystemParams::get_hardcoded();
+    ) -> Result<Self, ZkGroupError> {
+        let schema = credentials::get_profile_key_credential_uid_only_schema()?;
         let uid_system = uid_encryption::SystemParams::get_hardcoded();
-        let profile_key_system = profile_key_encryption::SystemParams::get_hardcoded();
         let uid = uid_struct::UidStruct::new(uid_bytes);
         let profile_key = profile_key_struct::ProfileKeyStruct::new(profile_key_bytes, uid_bytes);
 
         let z = sho.get_scalar();
 
-        let C_y1 = z * credentials_system.G_y1 + uid.M1;
-        let C_y2 = z * credentials_system.G_y2 + uid.M2;
-        let C_y3 = z * credentials_system.G_y3 + profile_key.M3;
-        let C_y4 = z * credentials_system.G_y4 + profile_key.M4;
+        let commitments = schema.commit(
+            z,
+            credential.t,
+            credential.U,
+            credential.V,
+            &[uid.M1, uid.M2, profile_key.M3, profile_key.M4],
+        )?;
 
-        let C_x0 = z * credentials_system.G_x0 + credential.U;
-        let C_V = z * credentials_system.G_V + credential.V;
-        let C_x1 = z * credentials_system.G_x1 + credential.t * credential.U;
-        let C_z = z * credentials_system.G_z;
+        let I = credentials_public_key.I;
+        let Z = z * I;
 
-        let z0 = -z * credential.t;
-        let z1 = -z * uid_enc_key_pair.a1;
-        let z2 = -z * profile_key_enc_key_pair.b1;
+        let scalar_args = schema.get_presentation_scalar_args(
+            z,
+            credential.t,
+            &[(uid_enc_key_pair.a1, uid_enc_key_pair.a2)],
+        );
+        let point_args = schema.get_presentation_point_args(
+            &commitments,
+            I,
+            Z,
+            &[AuthCredentialPresentationProof::get_encrypted_pair(
+                &uid_system,
+                uid_enc_key_pair.get_public_key(),
+                uid_ciphertext,
+            )],
+        );
+
+        let poksho_proof = schema
+            .get_presentation_statement(false)
+            .prove(
+                &scalar_args,
+                &point_args,
+                message,
+                &sho.squeeze(RANDOMNESS_LEN)[..],
+            )
+            .map_err(|_| {
+                ProofGenerationFailure("ProfileKeyCredentialSelectivePresentationProof")
+            })?;
+
+        Ok(Self {
+            C_x0: commitments.C_x0,
+            C_x1: commitments.C_x1,
+            C_y1: commitments.C_y[0],
+            C_y2: commitments.C_y[1],
+            C_y3: commitments.C_y[2],
+            C_y4: commitments.C_y[3],
+            C_V: commitments.C_V,
+            poksho_proof,
+        })
+    }
+
+    pub fn verify(
+        &self,
+        credentials_key_pair: &credentials::KeyPair,
+        uid_ciphertext: uid_encryption::Ciphertext,
+        uid_enc_public_key: uid_encryption::PublicKey,
+        message: &[u8],
+    ) -> Result<(), ZkGroupError> {
+        let schema = credentials::get_profile_key_credential_uid_only_schema()?;
+        let uid_system = uid_encryption::SystemParams::get_hardcoded();
+        let commitments = Commitments {
+            C_x0: self.C_x0,
+            C_x1: self.C_x1,
+            C_y: vec![self.C_y1, self.C_y2, self.C_y3, self.C_y4],
+            C_V: self.C_V,
+            C_z: Default::default(),
+        };
+
+        let Z = schema.get_presentation_Z(credentials_key_pair, &commitments, &[]);
+
+        let point_args = schema.get_presentation_point_args(
+            &commitments,
+            credentials_key_pair.I,
+            Z,
+            &[AuthCredentialPresentationProof::get_encrypted_pair(
+                &uid_system,
+                uid_enc_public_key,
+                uid_ciphertext,
+            )],
+        );
+
+        match schema.get_presentation_statement(false).verify_proof(
+            &self.poksho_proof,
+            &point_args,
+            message,
+        ) {
+            Err(_) => Err(ProofVerificationFailure(
+                "ProfileKeyCredentialSelectivePresentationProof",
+            )),
+            Ok(_) => Ok(()),
+        }
+    }
+}
+
+impl ReceiptCredentialPresentationProof {
+    pub fn get_poksho_statement() -> Result<poksho::Statement, ZkGroupError> {
+        Ok(credentials::get_receipt_credential_schema()?.get_presentation_statement(false))
+    }
+
+    // All the attributes are revealed, so the commitments to them only hide
+    // the credential itself, which keeps presentations unlinkable to issuance
+    pub fn new(
+        credentials_public_key: credentials::PublicKey,
+        credential: credentials::ReceiptCredential,
+        sho: &mut Sho,
+    ) -> Result<Self, ZkGroupError> {
+        let schema = credentials::get_receipt_credential_schema()?;
+
+        let z = sho.get_scalar();
+
+        let commitments = schema.commit(z, credential.t, credential.U, credential.V, &[])?;
+
+        let I = credentials_public_key.I;
+        let Z = z * I;
+
+        let scalar_args = schema.get_presentation_scalar_args(z, credential.t, &[]);
+        let point_args = schema.get_presentation_point_args(&commitments, I, Z, &[]);
+
+        let poksho_proof = schema
+            .get_presentation_statement(false)
+            .prove(
+                &scalar_args,
+                &point_args,
+                &[],
+                &sho.squeeze(RANDOMNESS_LEN)[..],
+            )
+            .map_err(|_| ProofGenerationFailure("ReceiptCredentialPresentationProof"))?;
+
+        Ok(Self {
+            C_x0: commitments.C_x0,
+            C_x1: commitments.C_x1,
+            C_y1: commitments.C_y[0],
+            C_y2: commitments.C_y[1],
+            C_V: commitments.C_V,
+            poksho_proof,
+        })
+    }
+
+    pub fn verify(
+        &self,
+        credentials_key_pair: &credentials::KeyPair,
+        receipt: receipt_struct::ReceiptStruct,
+    ) -> Result<(), ZkGroupError> {
+        let schema = credentials::get_receipt_credential_schema()?;
+        let commitments = Commitments {
+            C_x0: self.C_x0,
+            C_x1: self.C_x1,
+            C_y: vec![self.C_y1, self.C_y2],
+            C_V: self.C_V,
+            C_z: Default::default(),
+        };
+
+        let Z = schema.get_presentation_Z(
+            &credentials_key_pair,
+            &commitments,
+            &[receipt.M1, receipt.M2],
+        );
+
+        let point_args =
+            schema.get_presentation_point_args(&commitments, credentials_key_pair.I, Z, &[]);
+
+        match schema.get_presentation_statement(false).verify_proof(
+            &self.poksho_proof,
+            &point_args,
+            &[],
+        ) {
+            Err(_) => Err(ProofVerificationFailure(
+                "ReceiptCredentialPresentationProof",
+            )),
+            Ok(_) => Ok(()),
+        }
+    }
+}
+
+impl RateLimitTokenProof {
+    // The rate limit credential presentation statement with the secret point
+    // withheld, plus C_y2 = z * G_y2 + s * G_s, which opens the commitment to
+    // it as M2 = s * G_s, and tag = s * H(day) for the same s
+    pub fn get_poksho_statement() -> Result<poksho::Statement, ZkGroupError> {
+        let mut st =
+            credentials::get_rate_limit_credential_schema()?.get_presentation_statement(false);
+        st.add("C_y2", &[("z", "G_y2"), ("s", "G_s")]);
+        st.add("tag", &[("s", "H")]);
+        Ok(st)
+    }
+
+    pub fn new(
+        credentials_public_key: credentials::PublicKey,
+        credential: credentials::RateLimitCredential,
+        rate_limit: &rate_limit_struct::RateLimitStruct,
+        redemption_time: RedemptionTime,
+        sho: &mut Sho,
+    ) -> Result<Self, ZkGroupError> {
+        let schema = credentials::get_rate_limit_credential_schema()?;
+
+        let z = sho.get_scalar();
+
+        let commitments = schema.commit(
+            z,
+            credential.t,
+            credential.U,
+            credential.V,
+            &[rate_limit.M2],
+        )?;
 
         let I = credentials_public_key.I;
         let Z = z * I;
 
-        // Scalars listed in order of stmts for debugging
+        let mut scalar_args = schema.get_presentation_scalar_args(z, credential.t, &[]);
+        scalar_args.add("s", rate_limit.s);
+        let point_args = Self::get_point_args(
+            &schema,
+            &commitments,
+            I,
+            Z,
+            rate_limit.get_tag(redemption_time),
+            redemption_time,
+        );
+
+        let poksho_proof = Self::get_poksho_statement()?
+            .prove(
+                &scalar_args,
+                &point_args,
+                &[],
+                &sho.squeeze(RANDOMNESS_LEN)[..],
+            )
+            .map_err(|_| ProofGenerationFailure("RateLimitTokenProof"))?;
+
+        Ok(Self {
+            C_x0: commitments.C_x0,
+            C_x1: commitments.C_x1,
+            C_y1: commitments.C_y[0],
+            C_y2: commitments.C_y[1],
+            C_V: commitments.C_V,
+            poksho_proof,
+        })
+    }
+
+    pub fn verify(
+        &self,
+        credentials_key_pair: &credentials::KeyPair,
+        tag: RistrettoPoint,
+        redemption_time: RedemptionTime,
+    ) -> Result<(), ZkGroupError> {
+        let schema = credentials::get_rate_limit_credential_schema()?;
+        let commitments = Commitments {
+            C_x0: self.C_x0,
+            C_x1: self.C_x1,
+            C_y: vec![self.C_y1, self.C_y2],
+            C_V: self.C_V,
+            C_z: Default::default(),
+        };
+
+        let Z = schema.get_presentation_Z(
+            credentials_key_pair,
+            &commitments,
+            &[rate_limit_struct::RateLimitStruct::calc_M1(redemption_time)],
+        );
+
+        let point_args = Self::get_point_args(
+            &schema,
+            &commitments,
+            credentials_key_pair.I,
+            Z,
+            tag,
+            redemption_time,
+        );
+
+        match Self::get_poksho_statement()?.verify_proof(&self.poksho_proof, &point_args, &[]) {
+            Err(_) => Err(ProofVerificationFailure("RateLimitTokenProof")),
+            Ok(_) => Ok(()),
+        }
+    }
+
+    fn get_point_args(
+        schema: &CredentialSchema,
+        commitments: &Commitments,
+        I: RistrettoPoint,
+        Z: RistrettoPoint,
+        tag: RistrettoPoint,
+        redemption_time: RedemptionTime,
+    ) -> poksho::PointArgs {
+        let credentials_system = credentials::SystemParams::get_hardcoded();
+
+        let mut point_args = schema.get_presentation_point_args(commitments, I, Z, &[]);
+        point_args.add("C_y2", commitments.C_y[1]);
+        point_args.add("G_y2", credentials_system.G_y2);
+        point_args.add("G_s", rate_limit_struct::RateLimitStruct::get_G_s());
+        point_args.add("tag", tag);
+        point_args.add(
+            "H",
+            rate_limit_struct::RateLimitStruct::get_tag_base(redemption_time),
+        );
+        point_args
+    }
+}
+
+impl UidEncryptionEqualityProof {
+    // Shows that two uid ciphertexts, under uid encryption keys the prover
+    // holds, decrypt to the same M1 and M2 (and hence the same UID), without
+    // revealing them.  With c = a1'/a1, writing A' as c*A + d*G_a2 forces
+    // a1' = c*a1, so E'_A1 = c*E_A1 means E_A1 and E'_A1 encrypt the same M1.
+    pub fn get_poksho_statement() -> poksho::Statement {
+        let mut st = poksho::Statement::new();
+        st.add("A", &[("a1", "G_a1"), ("a2", "G_a2")]);
+        st.add("A'", &[("a1'", "G_a1"), ("a2'", "G_a2")]);
+        st.add("A'", &[("c", "A"), ("d", "G_a2")]);
+        st.add("E'_A1", &[("c", "E_A1")]);
+        st.add("E_A2-E'_A2", &[("a2", "E_A1"), ("a2'", "-E'_A1")]);
+        st
+    }
+
+    pub fn new(
+        key_pair: &uid_encryption::KeyPair,
+        ciphertext: uid_encryption::Ciphertext,
+        other_key_pair: &uid_encryption::KeyPair,
+        other_ciphertext: uid_encryption::Ciphertext,
+        sho: &mut Sho,
+    ) -> Result<Self, ZkGroupError> {
         let mut scalar_args = poksho::ScalarArgs::new();
-        scalar_args.add("z", z);
-        scalar_args.add("t", credential.t);
-        scalar_args.add("z0", z0);
-        scalar_args.add("a1", uid_enc_key_pair.a1);
-        scalar_args.add("a2", uid_enc_key_pair.a2);
-        scalar_args.add("b1", profile_key_enc_key_pair.b1);
-        scalar_args.add("b2", profile_key_enc_key_pair.b2);
-        scalar_args.add("z1", z1);
-        scalar_args.add("z2", z2);
-
-        // Points listed in order of stmts for debugging
-        let mut point_args = poksho::PointArgs::new();
-        point_args.add("C_z", C_z);
-        point_args.add("G_z", credentials_system.G_z);
-        point_args.add("Z", Z);
-        point_args.add("I", I);
+        scalar_args.add("a1", key_pair.a1);
+        scalar_args.add("a2", key_pair.a2);
+        scalar_args.add("a1'", other_key_pair.a1);
+        scalar_args.add("a2'", other_key_pair.a2);
+        let c = other_key_pair.a1 * key_pair.a1.invert();
+        scalar_args.add("c", c);
+        scalar_args.add("d", other_key_pair.a2 - c * key_pair.a2);
+
+        let point_args = Self::get_point_args(
+            key_pair.get_public_key(),
+            ciphertext,
+            other_key_pair.get_public_key(),
+            other_ciphertext,
+        );
 
-        point_args.add("C_x1", C_x1);
-        point_args.add("C_x0", C_x0);
-        point_args.add("G_x0", credentials_system.G_x0);
-        point_args.add("G_x1", credentials_system.G_x1);
+        let poksho_proof = Self::get_poksho_statement()
+            .prove(
+                &scalar_args,
+                &point_args,
+                &[],
+                &sho.squeeze(RANDOMNESS_LEN)[..],
+            )
+            .map_err(|_| ProofGenerationFailure("UidEncryptionEqualityProof"))?;
+        Ok(UidEncryptionEqualityProof { poksho_proof })
+    }
+
+    pub fn verify(
+        &self,
+        public_key: uid_encryption::PublicKey,
+        ciphertext: uid_encryption::Ciphertext,
+        other_public_key: uid_encryption::PublicKey,
+        other_ciphertext: uid_encryption::Ciphertext,
+    ) -> Result<(), ZkGroupError> {
+        let point_args =
+            Self::get_point_args(public_key, ciphertext, other_public_key, other_ciphertext);
+        match Self::get_poksho_statement().verify_proof(&self.poksho_proof, &point_args, &[]) {
+            Err(_) => Err(ProofVerificationFailure("UidEncryptionEqualityProof")),
+            Ok(_) => Ok(()),
+        }
+    }
 
-        point_args.add("A", uid_enc_key_pair.A);
+    fn get_point_args(
+        public_key: uid_encryption::PublicKey,
+        ciphertext: uid_encryption::Ciphertext,
+        other_public_key: uid_encryption::PublicKey,
+        other_ciphertext: uid_encryption::Ciphertext,
+    ) -> poksho::PointArgs {
+        let uid_system = uid_encryption::SystemParams::get_hardcoded();
+
+        let mut point_args = poksho::PointArgs::new();
+        point_args.add("A", public_key.A);
         point_args.add("G_a1", uid_system.G_a1);
         point_args.add("G_a2", uid_system.G_a2);
+        point_args.add("A'", other_public_key.A);
+        point_args.add("E'_A1", other_ciphertext.E_A1);
+        point_args.add("E_A1", ciphertext.E_A1);
+        point_args.add("E_A2-E'_A2", ciphertext.E_A2 - other_ciphertext.E_A2);
+        point_args.add("-E'_A1", -other_ciphertext.E_A1);
+        point_args
+    }
+}
+
+impl UidEncryptionMatchProof {
+    // Shows that a uid ciphertext and a randomized one, both under a uid
+    // encryption key the prover holds, decrypt to the same M2: subtracting
+    // them cancels M2, leaving a2 times the difference of the E_A1s
+    pub fn get_poksho_statement() -> poksho::Statement {
+        let mut st = poksho::Statement::new();
+        st.add("A", &[("a1", "G_a1"), ("a2", "G_a2")]);
+        st.add("E_A2-E'_A2", &[("a2", "E_A1-E'_A1")]);
+        st
+    }
 
-        point_args.add("B", profile_key_enc_key_pair.B);
-        point_args.add("G_b1", profile_key_system.G_b1);
-        point_args.add("G_b2", profile_key_system.G_b2);
+    pub fn new(
+        key_pair: &uid_encryption::KeyPair,
+        ciphertext: uid_encryption::Ciphertext,
+        randomized_ciphertext: uid_encryption::RandomizedCiphertext,
+        sho: &mut Sho,
+    ) -> Result<Self, ZkGroupError> {
+        let mut scalar_args = poksho::ScalarArgs::new();
+        scalar_args.add("a1", key_pair.a1);
+        scalar_args.add("a2", key_pair.a2);
 
-        point_args.add("C_y2-E_A2", C_y2 - uid_ciphertext.E_A2);
-        point_args.add("G_y2", credentials_system.G_y2);
-        point_args.add("-E_A1", -uid_ciphertext.E_A1);
-        point_args.add("E_A1", uid_ciphertext.E_A1);
-        point_args.add("C_y1", C_y1);
-        point_args.add("G_y1", credentials_system.G_y1);
-
-        point_args.add("C_y4-E_B2", C_y4 - profile_key_ciphertext.E_B2);
-        point_args.add("G_y4", credentials_system.G_y4);
-        point_args.add("-E_B1", -profile_key_ciphertext.E_B1);
-        point_args.add("E_B1", profile_key_ciphertext.E_B1);
-        point_args.add("C_y3", C_y3);
-        point_args.add("G_y3", credentials_system.G_y3);
+        let point_args =
+            Self::get_point_args(key_pair.get_public_key(), ciphertext, randomized_ciphertext);
 
         let poksho_proof = Self::get_poksho_statement()
             .prove(
@@ -633,99 +1396,234 @@ impl ProfileKeyCredentialPresentationProof {
                 &[],
                 &sho.squeeze(RANDOMNESS_LEN)[..],
             )
-            .unwrap();
-
-        ProfileKeyCredentialPresentationProof {
-            C_y1,
-            C_y2,
-            C_y3,
-            C_y4,
-            C_x0,
-            C_x1,
-            C_V,
-            C_z,
-            poksho_proof,
-        }
+            .map_err(|_| ProofGenerationFailure("UidEncryptionMatchProof"))?;
+        Ok(UidEncryptionMatchProof { poksho_proof })
     }
 
     pub fn verify(
         &self,
-        credentials_key_pair: credentials::KeyPair,
-        uid_ciphertext: uid_encryption::Ciphertext,
-        uid_enc_public_key: uid_encryption::PublicKey,
-        profile_key_ciphertext: profile_key_encryption::Ciphertext,
-        profile_key_enc_public_key: profile_key_encryption::PublicKey,
+        public_key: uid_encryption::PublicKey,
+        ciphertext: uid_encryption::Ciphertext,
+        randomized_ciphertext: uid_encryption::RandomizedCiphertext,
     ) -> Result<(), ZkGroupError> {
-        let uid_enc_system = uid_encryption::SystemParams::get_hardcoded();
-        let profile_key_enc_system = profile_key_encryption::SystemParams::get_hardcoded();
-        let credentials_system = credentials::SystemParams::get_hardcoded();
+        let point_args = Self::get_point_args(public_key, ciphertext, randomized_ciphertext);
+        match Self::get_poksho_statement().verify_proof(&self.poksho_proof, &point_args, &[]) {
+            Err(_) => Err(ProofVerificationFailure("UidEncryptionMatchProof")),
+            Ok(_) => Ok(()),
+        }
+    }
 
-        let Self {
-            C_x0,
-            C_x1,
-            C_y1,
-            C_y2,
-            C_y3,
-            C_y4,
-            C_V,
-            C_z,
-            poksho_proof,
-        } = self;
-
-        let (C_x0, C_x1, C_y1, C_y2, C_y3, C_y4, C_V, C_z) =
-            (*C_x0, *C_x1, *C_y1, *C_y2, *C_y3, *C_y4, *C_V, *C_z);
-
-        let credentials::KeyPair {
-            W,
-            x0,
-            x1,
-            y1,
-            y2,
-            y3,
-            y4,
-            I,
-            ..
-        } = credentials_key_pair;
+    fn get_point_args(
+        public_key: uid_encryption::PublicKey,
+        ciphertext: uid_encryption::Ciphertext,
+        randomized_ciphertext: uid_encryption::RandomizedCiphertext,
+    ) -> poksho::PointArgs {
+        let uid_system = uid_encryption::SystemParams::get_hardcoded();
 
-        let Z =
-            C_V - W - x0 * C_x0 - x1 * C_x1 - (y1 * C_y1) - (y2 * C_y2) - (y3 * C_y3) - (y4 * C_y4);
+        let mut point_args = poksho::PointArgs::new();
+        point_args.add("A", public_key.A);
+        point_args.add("G_a1", uid_system.G_a1);
+        point_args.add("G_a2", uid_system.G_a2);
+        point_args.add("E_A2-E'_A2", ciphertext.E_A2 - randomized_ciphertext.E_A2);
+        point_args.add("E_A1-E'_A1", ciphertext.E_A1 - randomized_ciphertext.E_A1);
+        point_args
+    }
+}
+
+impl GroupInviteLinkPasswordProof {
+    pub fn get_poksho_statement() -> poksho::Statement {
+        let mut st = poksho::Statement::new();
+        st.add("P", &[("p", "G")]);
+        st
+    }
+
+    // The message binds the proof to whatever the caller needs it tied to,
+    // since the statement itself only covers the password
+    pub fn new(
+        key_pair: &invite_link_password::KeyPair,
+        message: &[u8],
+        sho: &mut Sho,
+    ) -> Result<Self, ZkGroupError> {
+        let mut scalar_args = poksho::ScalarArgs::new();
+        scalar_args.add("p", key_pair.p);
 
-        // Points listed in order of stmts for debugging
         let mut point_args = poksho::PointArgs::new();
-        point_args.add("C_z", C_z);
-        point_args.add("G_z", credentials_system.G_z);
-        point_args.add("Z", Z);
-        point_args.add("I", I);
-        point_args.add("C_x1", C_x1);
-        point_args.add("C_x0", C_x0);
-        point_args.add("G_x0", credentials_system.G_x0);
-        point_args.add("G_x1", credentials_system.G_x1);
-
-        point_args.add("A", uid_enc_public_key.A);
-        point_args.add("G_a1", uid_enc_system.G_a1);
-        point_args.add("G_a2", uid_enc_system.G_a2);
-
-        point_args.add("B", profile_key_enc_public_key.B);
-        point_args.add("G_b1", profile_key_enc_system.G_b1);
-        point_args.add("G_b2", profile_key_enc_system.G_b2);
-
-        point_args.add("C_y2-E_A2", C_y2 - uid_ciphertext.E_A2);
-        point_args.add("G_y2", credentials_system.G_y2);
-        point_args.add("-E_A1", -uid_ciphertext.E_A1);
-        point_args.add("E_A1", uid_ciphertext.E_A1);
-        point_args.add("C_y1", C_y1);
-        point_args.add("G_y1", credentials_system.G_y1);
-
-        point_args.add("C_y4-E_B2", C_y4 - profile_key_ciphertext.E_B2);
-        point_args.add("G_y4", credentials_system.G_y4);
-        point_args.add("-E_B1", -profile_key_ciphertext.E_B1);
-        point_args.add("E_B1", profile_key_ciphertext.E_B1);
-        point_args.add("C_y3", C_y3);
-        point_args.add("G_y3", credentials_system.G_y3);
-
-        match Self::get_poksho_statement().verify_proof(&poksho_proof, &point_args, &[]) {
-            Err(_) => Err(ZkGroupError::ProofVerificationFailure),
+        point_args.add("P", key_pair.P);
+        point_args.add("G", RISTRETTO_BASEPOINT_POINT);
+
+        let poksho_proof = Self::get_poksho_statement()
+            .prove(
+                &scalar_args,
+                &point_args,
+                message,
+                &sho.squeeze(RANDOMNESS_LEN)[..],
+            )
+            .map_err(|_| ProofGenerationFailure("GroupInviteLinkPasswordProof"))?;
+        Ok(GroupInviteLinkPasswordProof { poksho_proof })
+    }
+
+    pub fn verify(
+        &self,
+        public_key: invite_link_password::PublicKey,
+        message: &[u8],
+    ) -> Result<(), ZkGroupError> {
+        let mut point_args = poksho::PointArgs::new();
+        point_args.add("P", public_key.P);
+        point_args.add("G", RISTRETTO_BASEPOINT_POINT);
+
+        match Self::get_poksho_statement().verify_proof(&self.poksho_proof, &point_args, message) {
+            Err(_) => Err(ProofVerificationFailure("GroupInviteLinkPasswordProof")),
             Ok(_) => Ok(()),
         }
     }
 }
+
+// A poksho proof is a challenge followed by one response per secret scalar;
+// anything else can't verify, so reject it before running the verifier
+fn validate_poksho_proof<T>(poksho_proof: &[u8]) -> Result<(), ZkGroupError> {
+    ensure::<T>(
+        poksho_proof.len() % SCALAR_LEN == 0,
+        "poksho proof isn't a whole number of scalars",
+    )
+}
+
+impl Validate for AuthCredentialIssuanceProof {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        validate_poksho_proof::<Self>(&self.poksho_proof)
+    }
+}
+
+impl Validate for AuthCredentialBatchIssuanceProof {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        validate_poksho_proof::<Self>(&self.poksho_proof)
+    }
+}
+
+impl Validate for ProfileKeyCredentialRequestProof {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        validate_poksho_proof::<Self>(&self.poksho_proof)
+    }
+}
+
+impl Validate for ProfileKeyCredentialIssuanceProof {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        validate_poksho_proof::<Self>(&self.poksho_proof)
+    }
+}
+
+impl Validate for ReceiptCredentialIssuanceProof {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        validate_poksho_proof::<Self>(&self.poksho_proof)
+    }
+}
+
+impl Validate for RateLimitCredentialIssuanceProof {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        validate_poksho_proof::<Self>(&self.poksho_proof)
+    }
+}
+
+impl Validate for AuthCredentialPresentationProof {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        validate_poksho_proof::<Self>(&self.poksho_proof)
+    }
+}
+
+impl Validate for ProfileKeyCredentialPresentationProof {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        validate_poksho_proof::<Self>(&self.poksho_proof)
+    }
+}
+
+impl Validate for ProfileKeyCredentialSelectivePresentationProof {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        validate_poksho_proof::<Self>(&self.poksho_proof)
+    }
+}
+
+impl Validate for RateLimitTokenProof {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        validate_poksho_proof::<Self>(&self.poksho_proof)
+    }
+}
+
+impl Validate for ReceiptCredentialPresentationProof {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        validate_poksho_proof::<Self>(&self.poksho_proof)
+    }
+}
+
+impl Validate for UidEncryptionEqualityProof {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        validate_poksho_proof::<Self>(&self.poksho_proof)
+    }
+}
+
+impl Validate for UidEncryptionMatchProof {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        validate_poksho_proof::<Self>(&self.poksho_proof)
+    }
+}
+
+impl Validate for GroupInviteLinkPasswordProof {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        validate_poksho_proof::<Self>(&self.poksho_proof)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::constants::*;
+
+    #[test]
+    fn test_uid_encryption_equality_proof() {
+        let mut sho = Sho::new(b"Test_Uid_Encryption_Equality_Proof", &TEST_ARRAY_32);
+        let key_pair = uid_encryption::KeyPair::derive_from(&mut sho);
+        let other_key_pair = uid_encryption::KeyPair::derive_from(&mut sho);
+        let uid = uid_struct::UidStruct::new(TEST_ARRAY_16);
+        let ciphertext = key_pair.encrypt(uid);
+        let other_ciphertext = other_key_pair.encrypt(uid);
+
+        let proof = UidEncryptionEqualityProof::new(
+            &key_pair,
+            ciphertext,
+            &other_key_pair,
+            other_ciphertext,
+            &mut sho,
+        )
+        .unwrap();
+        assert!(proof
+            .verify(
+                key_pair.get_public_key(),
+                ciphertext,
+                other_key_pair.get_public_key(),
+                other_ciphertext,
+            )
+            .is_ok());
+
+        // Same M2 but a different E'_A1, so the second ciphertext doesn't
+        // decrypt to the same UID even though the M2 equation still holds
+        let mut tampered_ciphertext = other_ciphertext;
+        tampered_ciphertext.E_A1 = sho.get_point();
+        tampered_ciphertext.E_A2 = other_key_pair.a2 * tampered_ciphertext.E_A1 + uid.M2;
+        assert!(other_key_pair.decrypt(tampered_ciphertext).is_err());
+
+        assert!(UidEncryptionEqualityProof::new(
+            &key_pair,
+            ciphertext,
+            &other_key_pair,
+            tampered_ciphertext,
+            &mut sho,
+        )
+        .is_err());
+        assert!(proof
+            .verify(
+                key_pair.get_public_key(),
+                ciphertext,
+                other_key_pair.get_public_key(),
+                tampered_ciphertext,
+            )
+            .is_err());
+    }
+}
@@ -0,0 +1,74 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+
+#![allow(non_snake_case)]
+
+use crate::common::errors::*;
+use crate::common::serialization::{ensure, ensure_not_identity, Validate};
+use crate::common::sho::*;
+use crate::common::simple_types::*;
+use crate::crypto::credentials;
+use curve25519_dalek::ristretto::RistrettoPoint;
+use curve25519_dalek::scalar::Scalar;
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
+
+// The client's secret s behind its rate limit tags.  The server certifies
+// M2 = s * G_s blindly, so it never learns s, and a token's tag s * H(day)
+// can only be computed by the client.
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct RateLimitStruct {
+    pub(crate) s: Scalar,
+    pub(crate) M2: RistrettoPoint,
+}
+
+impl Drop for RateLimitStruct {
+    fn drop(&mut self) {
+        self.s.zeroize();
+    }
+}
+
+impl RateLimitStruct {
+    pub fn generate(sho: &mut Sho) -> Self {
+        let s = sho.get_scalar();
+        RateLimitStruct {
+            s,
+            M2: s * Self::get_G_s(),
+        }
+    }
+
+    // The day is chosen by the server at issuance and revealed again in
+    // every token
+    pub fn calc_M1(redemption_time: RedemptionTime) -> RistrettoPoint {
+        let system = credentials::SystemParams::get_hardcoded();
+        encode_redemption_time(redemption_time) * system.G_m1
+    }
+
+    pub fn get_G_s() -> RistrettoPoint {
+        Sho::new(b"Signal_ZKGroup_20200424_Constant_RateLimitStruct_G_s", b"").get_point()
+    }
+
+    // H(day), which nobody knows a discrete log of relative to G_s
+    pub fn get_tag_base(redemption_time: RedemptionTime) -> RistrettoPoint {
+        Sho::new(
+            b"Signal_ZKGroup_20200424_RedemptionTime_CalcRateLimitTagBase",
+            &redemption_time.to_be_bytes(),
+        )
+        .get_point()
+    }
+
+    pub fn get_tag(&self, redemption_time: RedemptionTime) -> RistrettoPoint {
+        self.s * Self::get_tag_base(redemption_time)
+    }
+}
+
+impl Validate for RateLimitStruct {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        ensure_not_identity::<Self>(&self.M2, "M2 is the identity")?;
+        ensure::<Self>(self.M2 == self.s * Self::get_G_s(), "M2 doesn't match s")
+    }
+}
@@ -0,0 +1,137 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+
+#![allow(non_snake_case)]
+
+use crate::common::errors::*;
+use crate::common::serialization::{ensure, ensure_not_identity, Validate};
+use crate::common::sho::*;
+use crate::common::simple_types::*;
+use crate::crypto::credentials::{BlindedReceiptCredential, ReceiptCredential};
+use crate::crypto::receipt_struct;
+use curve25519_dalek::constants::RISTRETTO_BASEPOINT_POINT;
+use curve25519_dalek::ristretto::RistrettoPoint;
+use curve25519_dalek::scalar::Scalar;
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct KeyPair {
+    // private
+    pub(crate) y: Scalar,
+
+    // public
+    pub(crate) Y: RistrettoPoint,
+}
+
+impl Drop for KeyPair {
+    fn drop(&mut self) {
+        self.y.zeroize();
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PublicKey {
+    pub(crate) Y: RistrettoPoint,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct CiphertextWithSecretNonce {
+    pub(crate) r1: Scalar,
+    pub(crate) D1: RistrettoPoint,
+    pub(crate) D2: RistrettoPoint,
+}
+
+impl Drop for CiphertextWithSecretNonce {
+    fn drop(&mut self) {
+        self.r1.zeroize();
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Ciphertext {
+    pub(crate) D1: RistrettoPoint,
+    pub(crate) D2: RistrettoPoint,
+}
+
+impl KeyPair {
+    pub fn generate(sho: &mut Sho) -> Self {
+        let y = sho.get_scalar();
+        let Y = y * RISTRETTO_BASEPOINT_POINT;
+        KeyPair { y, Y }
+    }
+
+    pub fn get_public_key(&self) -> PublicKey {
+        PublicKey { Y: self.Y }
+    }
+
+    pub fn encrypt(
+        &self,
+        receipt_serial_bytes: ReceiptSerialBytes,
+        sho: &mut Sho,
+    ) -> CiphertextWithSecretNonce {
+        let M2 = receipt_struct::ReceiptStruct::calc_M2(receipt_serial_bytes);
+        let r1 = sho.get_scalar();
+        let D1 = r1 * RISTRETTO_BASEPOINT_POINT;
+        let D2 = r1 * (self.Y) + M2;
+
+        CiphertextWithSecretNonce { r1, D1, D2 }
+    }
+
+    pub fn decrypt_blinded_receipt_credential(
+        &self,
+        blinded_receipt_credential: BlindedReceiptCredential,
+    ) -> ReceiptCredential {
+        let V = blinded_receipt_credential.S2 - self.y * blinded_receipt_credential.S1;
+        ReceiptCredential {
+            t: blinded_receipt_credential.t,
+            U: blinded_receipt_credential.U,
+            V,
+        }
+    }
+}
+
+impl CiphertextWithSecretNonce {
+    pub fn get_ciphertext(&self) -> Ciphertext {
+        Ciphertext {
+            D1: self.D1,
+            D2: self.D2,
+        }
+    }
+}
+
+impl Validate for KeyPair {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        ensure_not_identity::<Self>(&self.Y, "Y is the identity")?;
+        ensure::<Self>(
+            self.Y == self.y * RISTRETTO_BASEPOINT_POINT,
+            "Y doesn't match y",
+        )
+    }
+}
+
+impl Validate for PublicKey {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        ensure_not_identity::<Self>(&self.Y, "Y is the identity")
+    }
+}
+
+impl Validate for CiphertextWithSecretNonce {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        ensure::<Self>(
+            self.D1 == self.r1 * RISTRETTO_BASEPOINT_POINT,
+            "D1 doesn't match r1",
+        )?;
+        self.get_ciphertext().validate()
+    }
+}
+
+impl Validate for Ciphertext {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        ensure_not_identity::<Self>(&self.D1, "D1 is the identity")
+    }
+}
@@ -0,0 +1,63 @@
+//
+// Copyright (C) 2020 Signal Messenger, LLC.
+// All rights reserved.
+//
+// SPDX-License-Identifier: GPL-3.0-only
+//
+
+#![allow(non_snake_case)]
+
+use crate::common::sho::*;
+use crate::common::simple_types::*;
+use crate::crypto::credentials;
+use curve25519_dalek::ristretto::RistrettoPoint;
+use curve25519_dalek::scalar::Scalar;
+use serde::{Deserialize, Serialize};
+
+#[derive(Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReceiptStruct {
+    pub(crate) receipt_serial_bytes: ReceiptSerialBytes,
+    pub(crate) receipt_expiration_time: ReceiptExpirationTime,
+    pub(crate) receipt_level: ReceiptLevel,
+    pub(crate) M1: RistrettoPoint,
+    pub(crate) M2: RistrettoPoint,
+}
+
+impl ReceiptStruct {
+    pub fn new(
+        receipt_serial_bytes: ReceiptSerialBytes,
+        receipt_expiration_time: ReceiptExpirationTime,
+        receipt_level: ReceiptLevel,
+    ) -> Self {
+        let M1 = Self::calc_M1(receipt_expiration_time, receipt_level);
+        let M2 = Self::calc_M2(receipt_serial_bytes);
+        ReceiptStruct {
+            receipt_serial_bytes,
+            receipt_expiration_time,
+            receipt_level,
+            M1,
+            M2,
+        }
+    }
+
+    // The expiration and level are both chosen by the server and revealed to
+    // it again at presentation, so they share one attribute
+    pub fn calc_M1(
+        receipt_expiration_time: ReceiptExpirationTime,
+        receipt_level: ReceiptLevel,
+    ) -> RistrettoPoint {
+        let system = credentials::SystemParams::get_hardcoded();
+        let mut scalar_bytes: [u8; 32] = Default::default();
+        scalar_bytes[0..8].copy_from_slice(&receipt_expiration_time.to_be_bytes());
+        scalar_bytes[8..16].copy_from_slice(&receipt_level.to_be_bytes());
+        Scalar::from_bytes_mod_order(scalar_bytes) * system.G_m1
+    }
+
+    pub fn calc_M2(receipt_serial_bytes: ReceiptSerialBytes) -> RistrettoPoint {
+        Sho::new(
+            b"Signal_ZKGroup_20200424_ReceiptSerial_CalcM2",
+            &receipt_serial_bytes,
+        )
+        .get_point()
+    }
+}
@@ -9,6 +9,7 @@
 
 use crate::common::constants::*;
 use crate::common::errors::*;
+use crate::common::serialization::{ensure, ensure_not_identity, Validate};
 use crate::common::sho::*;
 use crate::common::simple_types::*;
 use curve25519_dalek::constants::RISTRETTO_BASEPOINT_POINT;
@@ -16,14 +17,21 @@ use curve25519_dalek::ristretto::RistrettoPoint;
 use curve25519_dalek::scalar::Scalar;
 use serde::{Deserialize, Serialize};
 
+use zeroize::Zeroize;
 use ZkGroupError::*;
 
-#[derive(Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
 pub struct KeyPair {
     pub(crate) signing_key: Scalar,
     pub(crate) public_key: RistrettoPoint,
 }
 
+impl Drop for KeyPair {
+    fn drop(&mut self) {
+        self.signing_key.zeroize();
+    }
+}
+
 #[derive(Copy, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PublicKey {
     pub(crate) public_key: RistrettoPoint,
@@ -48,6 +56,13 @@ impl KeyPair {
             &sho.squeeze(RANDOMNESS_LEN)[..],
         ) {
             Ok(vec_bytes) => {
+                if vec_bytes.len() != SIGNATURE_LEN {
+                    return Err(LengthMismatch {
+                        type_name: "SignatureBytes",
+                        expected: SIGNATURE_LEN,
+                        actual: vec_bytes.len(),
+                    });
+                }
                 let mut s: SignatureBytes = [0u8; SIGNATURE_LEN];
                 s.copy_from_slice(&vec_bytes[..]);
                 Ok(s)
@@ -61,6 +76,13 @@ impl KeyPair {
             public_key: self.public_key,
         }
     }
+
+    // An unrelated-looking key pair that only the holder of this one can
+    // derive, so signatures under the two can't be swapped for each other
+    pub fn derive_key_pair(&self, label: &[u8]) -> Self {
+        let mut sho = Sho::new(label, self.signing_key.as_bytes());
+        Self::generate(&mut sho)
+    }
 }
 
 impl PublicKey {
@@ -73,6 +95,22 @@ impl PublicKey {
     }
 }
 
+impl Validate for KeyPair {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        ensure_not_identity::<Self>(&self.public_key, "public key is the identity")?;
+        ensure::<Self>(
+            self.public_key == self.signing_key * RISTRETTO_BASEPOINT_POINT,
+            "public key doesn't match the signing key",
+        )
+    }
+}
+
+impl Validate for PublicKey {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        ensure_not_identity::<Self>(&self.public_key, "public key is the identity")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
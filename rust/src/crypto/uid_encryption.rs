@@ -8,13 +8,17 @@
 #![allow(non_snake_case)]
 
 use crate::common::errors::*;
+use crate::common::serialization::{ensure, ensure_not_identity, Validate};
 use crate::common::sho::*;
 use crate::crypto::uid_struct;
 use curve25519_dalek::constants::RISTRETTO_BASEPOINT_POINT;
 use curve25519_dalek::ristretto::RistrettoPoint;
 use curve25519_dalek::scalar::Scalar;
+use curve25519_dalek::traits::{Identity, IsIdentity};
 use serde::{Deserialize, Serialize};
 
+use curve25519_dalek::subtle::ConstantTimeEq;
+use zeroize::Zeroize;
 use ZkGroupError::*;
 
 #[derive(Copy, Clone, PartialEq, Serialize, Deserialize)]
@@ -23,13 +27,21 @@ pub struct SystemParams {
     pub(crate) G_a2: RistrettoPoint,
 }
 
-#[derive(Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
 pub struct KeyPair {
     pub(crate) a1: Scalar,
     pub(crate) a2: Scalar,
     pub(crate) A: RistrettoPoint,
 }
 
+// Not Copy, so the secret scalars live in one place and are wiped when it's dropped
+impl Drop for KeyPair {
+    fn drop(&mut self) {
+        self.a1.zeroize();
+        self.a2.zeroize();
+    }
+}
+
 #[derive(Copy, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PublicKey {
     pub(crate) A: RistrettoPoint,
@@ -41,6 +53,14 @@ pub struct Ciphertext {
     pub(crate) E_A2: RistrettoPoint,
 }
 
+// Like Ciphertext, but E_A1 is a random multiple of the deterministic E_A1, so
+// two encryptions of the same UID can't be linked without the key
+#[derive(Copy, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RandomizedCiphertext {
+    pub(crate) E_A1: RistrettoPoint,
+    pub(crate) E_A2: RistrettoPoint,
+}
+
 impl SystemParams {
     pub fn generate() -> Self {
         let mut sho = Sho::new(
@@ -53,7 +73,8 @@ impl SystemParams {
     }
 
     pub fn get_hardcoded() -> SystemParams {
-        bincode::deserialize::<SystemParams>(&SystemParams::SYSTEM_HARDCODED).unwrap()
+        bincode::deserialize::<SystemParams>(&SystemParams::SYSTEM_HARDCODED)
+            .unwrap_or_else(|_| SystemParams::generate())
     }
 
     const SYSTEM_HARDCODED: [u8; 64] = [
@@ -83,22 +104,58 @@ impl KeyPair {
     }
 
     // Might return DecryptionFailure
+    //
+    // All three checks run on every ciphertext and are combined before the
+    // one branch, so the timing doesn't say which of them failed
     pub fn decrypt(&self, ciphertext: Ciphertext) -> Result<uid_struct::UidStruct, ZkGroupError> {
-        if ciphertext.E_A1 == RISTRETTO_BASEPOINT_POINT {
-            return Err(DecryptionFailure);
+        let is_not_basepoint = !ciphertext.E_A1.ct_eq(&RISTRETTO_BASEPOINT_POINT);
+        let (decrypted_uid, is_decoded) =
+            uid_struct::UidStruct::from_M2_unchecked(ciphertext.E_A2 - (self.a2 * ciphertext.E_A1));
+        let is_match = ciphertext.E_A1.ct_eq(&self.calc_E_A1(decrypted_uid));
+        if bool::from(is_not_basepoint & is_decoded & is_match) {
+            Ok(decrypted_uid)
+        } else {
+            Err(DecryptionFailure)
         }
-        match uid_struct::UidStruct::from_M2(ciphertext.E_A2 - (self.a2 * ciphertext.E_A1)) {
-            Err(_) => Err(DecryptionFailure),
-            Ok(decrypted_uid) => {
-                if ciphertext.E_A1 == self.calc_E_A1(decrypted_uid) {
-                    Ok(decrypted_uid)
-                } else {
-                    Err(DecryptionFailure)
-                }
-            }
+    }
+
+    pub fn encrypt_randomized(
+        &self,
+        uid: uid_struct::UidStruct,
+        z: Scalar,
+    ) -> RandomizedCiphertext {
+        let E_A1 = z * self.calc_E_A1(uid);
+        let E_A2 = (self.a2 * E_A1) + uid.M2;
+        RandomizedCiphertext { E_A1, E_A2 }
+    }
+
+    // Might return DecryptionFailure
+    //
+    // Without z there's nothing to check E_A1 against, so beyond ruling out the
+    // identity this only fails if M2 doesn't decode
+    pub fn decrypt_randomized(
+        &self,
+        ciphertext: RandomizedCiphertext,
+    ) -> Result<uid_struct::UidStruct, ZkGroupError> {
+        let is_not_identity = !ciphertext.E_A1.ct_eq(&RistrettoPoint::identity());
+        let (decrypted_uid, is_decoded) =
+            uid_struct::UidStruct::from_M2_unchecked(ciphertext.E_A2 - (self.a2 * ciphertext.E_A1));
+        if bool::from(is_not_identity & is_decoded) {
+            Ok(decrypted_uid)
+        } else {
+            Err(DecryptionFailure)
         }
     }
 
+    // Cheaper than decrypt_randomized, since it skips decoding M2
+    pub fn is_randomized_encryption_of(
+        &self,
+        ciphertext: RandomizedCiphertext,
+        uid: uid_struct::UidStruct,
+    ) -> bool {
+        !ciphertext.E_A1.is_identity() && ciphertext.E_A2 - (self.a2 * ciphertext.E_A1) == uid.M2
+    }
+
     fn calc_E_A1(&self, uid: uid_struct::UidStruct) -> RistrettoPoint {
         self.a1 * uid.M1
     }
@@ -108,6 +165,29 @@ impl KeyPair {
     }
 }
 
+impl Validate for PublicKey {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        ensure_not_identity::<Self>(&self.A, "A is the identity")
+    }
+}
+
+// decrypt() rejects these as well, but only once someone tries to decrypt
+impl Validate for Ciphertext {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        ensure_not_identity::<Self>(&self.E_A1, "E_A1 is the identity")?;
+        ensure::<Self>(
+            self.E_A1 != RISTRETTO_BASEPOINT_POINT,
+            "E_A1 is the basepoint",
+        )
+    }
+}
+
+impl Validate for RandomizedCiphertext {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        ensure_not_identity::<Self>(&self.E_A1, "E_A1 is the identity")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -157,4 +237,82 @@ mod tests {
 
         assert!(plaintext == uid);
     }
+
+    #[test]
+    fn test_uid_decryption_malformed_ciphertexts() {
+        let mut sho = Sho::new(b"Test_Uid_Decryption_Malformed", &TEST_ARRAY_32);
+        let key_pair = KeyPair::derive_from(&mut sho);
+        let other_key_pair = KeyPair::derive_from(&mut sho);
+
+        let uid = uid_struct::UidStruct::new(TEST_ARRAY_16);
+        let other_uid = uid_struct::UidStruct::new(TEST_ARRAY_16_1);
+        let ciphertext = key_pair.encrypt(uid);
+        let other_ciphertext = key_pair.encrypt(other_uid);
+
+        let malformed = vec![
+            // E_A1 is the basepoint, with an E_A2 that decodes
+            Ciphertext {
+                E_A1: RISTRETTO_BASEPOINT_POINT,
+                E_A2: key_pair.a2 * RISTRETTO_BASEPOINT_POINT + uid.M2,
+            },
+            // E_A1 is the identity, so E_A2 is the plaintext M2
+            Ciphertext {
+                E_A1: RistrettoPoint::default(),
+                E_A2: uid.M2,
+            },
+            // E_A2 no longer decodes to a UID
+            Ciphertext {
+                E_A1: ciphertext.E_A1,
+                E_A2: ciphertext.E_A2 + sho.get_point(),
+            },
+            // Both halves are valid, but from different encryptions
+            Ciphertext {
+                E_A1: ciphertext.E_A1,
+                E_A2: other_ciphertext.E_A2,
+            },
+            Ciphertext {
+                E_A1: other_ciphertext.E_A1,
+                E_A2: ciphertext.E_A2,
+            },
+            // The halves swapped
+            Ciphertext {
+                E_A1: ciphertext.E_A2,
+                E_A2: ciphertext.E_A1,
+            },
+            // Encrypted under another key
+            other_key_pair.encrypt(uid),
+            Ciphertext::default(),
+        ];
+
+        for ciphertext in malformed {
+            match key_pair.decrypt(ciphertext) {
+                Err(DecryptionFailure) => (),
+                _ => panic!("malformed ciphertext decrypted"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_uid_encryption_randomized() {
+        let mut sho = Sho::new(b"Test_Uid_Encryption_Randomized", &TEST_ARRAY_32);
+        let key_pair = KeyPair::derive_from(&mut sho);
+        let uid = uid_struct::UidStruct::new(TEST_ARRAY_16);
+        let other_uid = uid_struct::UidStruct::new(TEST_ARRAY_16_1);
+
+        let ciphertext = key_pair.encrypt_randomized(uid, sho.get_scalar());
+        let ciphertext2 = key_pair.encrypt_randomized(uid, sho.get_scalar());
+        assert!(ciphertext != ciphertext2);
+        assert!(ciphertext.E_A1 != key_pair.encrypt(uid).E_A1);
+
+        assert!(key_pair.is_randomized_encryption_of(ciphertext, uid));
+        assert!(key_pair.is_randomized_encryption_of(ciphertext2, uid));
+        assert!(!key_pair.is_randomized_encryption_of(ciphertext, other_uid));
+
+        let degenerate = key_pair.encrypt_randomized(uid, Scalar::zero());
+        assert!(!key_pair.is_randomized_encryption_of(degenerate, uid));
+        match key_pair.decrypt_randomized(degenerate) {
+            Err(DecryptionFailure) => (),
+            _ => panic!("identity E_A1 decrypted"),
+        }
+    }
 }
@@ -8,12 +8,15 @@
 #![allow(non_snake_case)]
 
 use crate::common::errors::*;
+use crate::common::serialization::{ensure, Validate};
 use crate::common::sho::*;
 use crate::common::simple_types::*;
 use curve25519_dalek::ristretto::RistrettoPoint;
 use serde::{Deserialize, Serialize};
 use sha2::Sha256;
 
+use curve25519_dalek::subtle::Choice;
+
 use ZkGroupError::*;
 
 #[derive(Copy, Clone, PartialEq, Serialize, Deserialize)]
@@ -37,13 +40,35 @@ impl UidStruct {
 
     // Might return PointDecodeFailure
     pub fn from_M2(M2: RistrettoPoint) -> Result<Self, ZkGroupError> {
-        match M2.lizard_decode::<Sha256>() {
-            None => Err(PointDecodeFailure),
-            Some(bytes) => Ok(Self::new(bytes)),
+        let (uid, decoded) = Self::from_M2_unchecked(M2);
+        if bool::from(decoded) {
+            Ok(uid)
+        } else {
+            Err(PointDecodeFailure)
         }
     }
 
+    // Does the same work whether or not M2 decodes, so the caller can fold
+    // the returned Choice into its own checks before branching on anything;
+    // on failure the UidStruct is built from zero bytes and must be ignored
+    pub(crate) fn from_M2_unchecked(M2: RistrettoPoint) -> (Self, Choice) {
+        let decoded = M2.lizard_decode::<Sha256>();
+        let is_decoded = Choice::from(decoded.is_some() as u8);
+        (Self::new(decoded.unwrap_or_default()), is_decoded)
+    }
+
     pub fn to_bytes(&self) -> UidBytes {
         self.bytes
     }
 }
+
+// M1 and M2 are derived from the bytes, so a stored UidStruct can't carry
+// points that disagree with its bytes
+impl Validate for UidStruct {
+    fn validate(&self) -> Result<(), ZkGroupError> {
+        ensure::<Self>(
+            *self == Self::new(self.bytes),
+            "M1 and M2 don't match the bytes",
+        )
+    }
+}
@@ -10,9 +10,17 @@
 #![allow(non_snake_case)]
 
 use super::simpleapi;
+use crate::common::errors::{clear_last_error_code, get_last_error_code};
 use crate::ffi::constants::FFI_RETURN_INTERNAL_ERROR;
 use std::{panic, slice};
 
+// The ZkGroupError code behind this thread's last failed call; see
+// errors::get_last_error_code
+#[no_mangle]
+pub extern "C" fn FFI_getLastErrorCode() -> i32 {
+    get_last_error_code()
+}
+
 #[no_mangle]
 pub extern "C" fn FFI_ProfileKey_getCommitment(
     profileKey: *const u8,
@@ -22,6 +30,7 @@ pub extern "C" fn FFI_ProfileKey_getCommitment(
     profileKeyCommitmentOut: *mut u8,
     profileKeyCommitmentLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let profile_key: &[u8] =
             unsafe { slice::from_raw_parts(profileKey, profileKeyLen as usize) };
@@ -48,6 +57,7 @@ pub extern "C" fn FFI_ProfileKey_getProfileKeyVersion(
     profileKeyVersionOut: *mut u8,
     profileKeyVersionLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let profile_key: &[u8] =
             unsafe { slice::from_raw_parts(profileKey, profileKeyLen as usize) };
@@ -70,6 +80,7 @@ pub extern "C" fn FFI_ProfileKeyCommitment_checkValidContents(
     profileKeyCommitment: *const u8,
     profileKeyCommitmentLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let profile_key_commitment: &[u8] = unsafe {
             slice::from_raw_parts(profileKeyCommitment, profileKeyCommitmentLen as usize)
@@ -91,6 +102,7 @@ pub extern "C" fn FFI_GroupSecretParams_generateDeterministic(
     groupSecretParamsOut: *mut u8,
     groupSecretParamsLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let randomness: &[u8] =
             unsafe { slice::from_raw_parts(randomness, randomnessLen as usize) };
@@ -114,6 +126,7 @@ pub extern "C" fn FFI_GroupSecretParams_deriveFromMasterKey(
     groupSecretParamsOut: *mut u8,
     groupSecretParamsLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let group_master_key: &[u8] =
             unsafe { slice::from_raw_parts(groupMasterKey, groupMasterKeyLen as usize) };
@@ -135,6 +148,7 @@ pub extern "C" fn FFI_GroupSecretParams_checkValidContents(
     groupSecretParams: *const u8,
     groupSecretParamsLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let group_secret_params: &[u8] =
             unsafe { slice::from_raw_parts(groupSecretParams, groupSecretParamsLen as usize) };
@@ -155,6 +169,7 @@ pub extern "C" fn FFI_GroupSecretParams_getMasterKey(
     groupMasterKeyOut: *mut u8,
     groupMasterKeyLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let group_secret_params: &[u8] =
             unsafe { slice::from_raw_parts(groupSecretParams, groupSecretParamsLen as usize) };
@@ -177,6 +192,7 @@ pub extern "C" fn FFI_GroupSecretParams_getPublicParams(
     groupPublicParamsOut: *mut u8,
     groupPublicParamsLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let group_secret_params: &[u8] =
             unsafe { slice::from_raw_parts(groupSecretParams, groupSecretParamsLen as usize) };
@@ -202,6 +218,7 @@ pub extern "C" fn FFI_GroupSecretParams_encryptUuid(
     uuidCiphertextOut: *mut u8,
     uuidCiphertextLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let group_secret_params: &[u8] =
             unsafe { slice::from_raw_parts(groupSecretParams, groupSecretParamsLen as usize) };
@@ -227,6 +244,7 @@ pub extern "C" fn FFI_GroupSecretParams_decryptUuid(
     uuidOut: *mut u8,
     uuidLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let group_secret_params: &[u8] =
             unsafe { slice::from_raw_parts(groupSecretParams, groupSecretParamsLen as usize) };
@@ -254,6 +272,7 @@ pub extern "C" fn FFI_GroupSecretParams_encryptProfileKey(
     profileKeyCiphertextOut: *mut u8,
     profileKeyCiphertextLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let group_secret_params: &[u8] =
             unsafe { slice::from_raw_parts(groupSecretParams, groupSecretParamsLen as usize) };
@@ -289,6 +308,7 @@ pub extern "C" fn FFI_GroupSecretParams_decryptProfileKey(
     profileKeyOut: *mut u8,
     profileKeyLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let group_secret_params: &[u8] =
             unsafe { slice::from_raw_parts(groupSecretParams, groupSecretParamsLen as usize) };
@@ -324,6 +344,7 @@ pub extern "C" fn FFI_GroupSecretParams_encryptBlobDeterministic(
     blobCiphertextOut: *mut u8,
     blobCiphertextLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let group_secret_params: &[u8] =
             unsafe { slice::from_raw_parts(groupSecretParams, groupSecretParamsLen as usize) };
@@ -356,6 +377,7 @@ pub extern "C" fn FFI_GroupSecretParams_decryptBlob(
     plaintextOut: *mut u8,
     plaintextLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let group_secret_params: &[u8] =
             unsafe { slice::from_raw_parts(groupSecretParams, groupSecretParamsLen as usize) };
@@ -373,6 +395,322 @@ pub extern "C" fn FFI_GroupSecretParams_decryptBlob(
     }
 }
 
+#[no_mangle]
+pub extern "C" fn FFI_GroupSecretParams_encryptBlobWithPaddingDeterministic(
+    groupSecretParams: *const u8,
+    groupSecretParamsLen: u32,
+    randomness: *const u8,
+    randomnessLen: u32,
+    plaintext: *const u8,
+    plaintextLen: u32,
+    paddedLength: u32,
+    blobCiphertextOut: *mut u8,
+    blobCiphertextLen: u32,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let group_secret_params: &[u8] =
+            unsafe { slice::from_raw_parts(groupSecretParams, groupSecretParamsLen as usize) };
+        let randomness: &[u8] =
+            unsafe { slice::from_raw_parts(randomness, randomnessLen as usize) };
+        let plaintext: &[u8] = unsafe { slice::from_raw_parts(plaintext, plaintextLen as usize) };
+        let padded_length = paddedLength as u32;
+        let blob_ciphertext: &mut [u8] =
+            unsafe { slice::from_raw_parts_mut(blobCiphertextOut, blobCiphertextLen as usize) };
+
+        simpleapi::GroupSecretParams_encryptBlobWithPaddingDeterministic(
+            group_secret_params,
+            &randomness,
+            &plaintext,
+            padded_length,
+            blob_ciphertext,
+        )
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn FFI_GroupSecretParams_decryptPaddedBlob(
+    groupSecretParams: *const u8,
+    groupSecretParamsLen: u32,
+    blobCiphertext: *const u8,
+    blobCiphertextLen: u32,
+    lengthPrefixedPlaintextOut: *mut u8,
+    lengthPrefixedPlaintextLen: u32,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let group_secret_params: &[u8] =
+            unsafe { slice::from_raw_parts(groupSecretParams, groupSecretParamsLen as usize) };
+        let blob_ciphertext: &[u8] =
+            unsafe { slice::from_raw_parts(blobCiphertext, blobCiphertextLen as usize) };
+        let length_prefixed_plaintext: &mut [u8] = unsafe {
+            slice::from_raw_parts_mut(
+                lengthPrefixedPlaintextOut,
+                lengthPrefixedPlaintextLen as usize,
+            )
+        };
+
+        simpleapi::GroupSecretParams_decryptPaddedBlob(
+            group_secret_params,
+            &blob_ciphertext,
+            length_prefixed_plaintext,
+        )
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn FFI_GroupSecretParams_createGroupInviteLinkPresentationDeterministic(
+    groupSecretParams: *const u8,
+    groupSecretParamsLen: u32,
+    randomness: *const u8,
+    randomnessLen: u32,
+    groupInviteLinkPassword: *const u8,
+    groupInviteLinkPasswordLen: u32,
+    uuid: *const u8,
+    uuidLen: u32,
+    groupInviteLinkPresentationOut: *mut u8,
+    groupInviteLinkPresentationLen: u32,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let group_secret_params: &[u8] =
+            unsafe { slice::from_raw_parts(groupSecretParams, groupSecretParamsLen as usize) };
+        let randomness: &[u8] =
+            unsafe { slice::from_raw_parts(randomness, randomnessLen as usize) };
+        let group_invite_link_password: &[u8] = unsafe {
+            slice::from_raw_parts(groupInviteLinkPassword, groupInviteLinkPasswordLen as usize)
+        };
+        let uuid: &[u8] = unsafe { slice::from_raw_parts(uuid, uuidLen as usize) };
+        let group_invite_link_presentation: &mut [u8] = unsafe {
+            slice::from_raw_parts_mut(
+                groupInviteLinkPresentationOut,
+                groupInviteLinkPresentationLen as usize,
+            )
+        };
+
+        simpleapi::GroupSecretParams_createGroupInviteLinkPresentationDeterministic(
+            group_secret_params,
+            &randomness,
+            &group_invite_link_password,
+            &uuid,
+            group_invite_link_presentation,
+        )
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn FFI_GroupSecretParams_encryptUuidRandomizedDeterministic(
+    groupSecretParams: *const u8,
+    groupSecretParamsLen: u32,
+    randomness: *const u8,
+    randomnessLen: u32,
+    uuid: *const u8,
+    uuidLen: u32,
+    randomizedUuidCiphertextOut: *mut u8,
+    randomizedUuidCiphertextLen: u32,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let group_secret_params: &[u8] =
+            unsafe { slice::from_raw_parts(groupSecretParams, groupSecretParamsLen as usize) };
+        let randomness: &[u8] =
+            unsafe { slice::from_raw_parts(randomness, randomnessLen as usize) };
+        let uuid: &[u8] = unsafe { slice::from_raw_parts(uuid, uuidLen as usize) };
+        let randomized_uuid_ciphertext: &mut [u8] = unsafe {
+            slice::from_raw_parts_mut(
+                randomizedUuidCiphertextOut,
+                randomizedUuidCiphertextLen as usize,
+            )
+        };
+
+        simpleapi::GroupSecretParams_encryptUuidRandomizedDeterministic(
+            group_secret_params,
+            &randomness,
+            &uuid,
+            randomized_uuid_ciphertext,
+        )
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn FFI_GroupSecretParams_decryptRandomizedUuid(
+    groupSecretParams: *const u8,
+    groupSecretParamsLen: u32,
+    randomizedUuidCiphertext: *const u8,
+    randomizedUuidCiphertextLen: u32,
+    uuidOut: *mut u8,
+    uuidLen: u32,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let group_secret_params: &[u8] =
+            unsafe { slice::from_raw_parts(groupSecretParams, groupSecretParamsLen as usize) };
+        let randomized_uuid_ciphertext: &[u8] = unsafe {
+            slice::from_raw_parts(
+                randomizedUuidCiphertext,
+                randomizedUuidCiphertextLen as usize,
+            )
+        };
+        let uuid: &mut [u8] = unsafe { slice::from_raw_parts_mut(uuidOut, uuidLen as usize) };
+
+        simpleapi::GroupSecretParams_decryptRandomizedUuid(
+            group_secret_params,
+            &randomized_uuid_ciphertext,
+            uuid,
+        )
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn FFI_GroupSecretParams_createUuidCiphertextMatchProofDeterministic(
+    groupSecretParams: *const u8,
+    groupSecretParamsLen: u32,
+    randomness: *const u8,
+    randomnessLen: u32,
+    uuidCiphertext: *const u8,
+    uuidCiphertextLen: u32,
+    randomizedUuidCiphertext: *const u8,
+    randomizedUuidCiphertextLen: u32,
+    uuidCiphertextMatchProofOut: *mut u8,
+    uuidCiphertextMatchProofLen: u32,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let group_secret_params: &[u8] =
+            unsafe { slice::from_raw_parts(groupSecretParams, groupSecretParamsLen as usize) };
+        let randomness: &[u8] =
+            unsafe { slice::from_raw_parts(randomness, randomnessLen as usize) };
+        let uuid_ciphertext: &[u8] =
+            unsafe { slice::from_raw_parts(uuidCiphertext, uuidCiphertextLen as usize) };
+        let randomized_uuid_ciphertext: &[u8] = unsafe {
+            slice::from_raw_parts(
+                randomizedUuidCiphertext,
+                randomizedUuidCiphertextLen as usize,
+            )
+        };
+        let uuid_ciphertext_match_proof: &mut [u8] = unsafe {
+            slice::from_raw_parts_mut(
+                uuidCiphertextMatchProofOut,
+                uuidCiphertextMatchProofLen as usize,
+            )
+        };
+
+        simpleapi::GroupSecretParams_createUuidCiphertextMatchProofDeterministic(
+            group_secret_params,
+            &randomness,
+            &uuid_ciphertext,
+            &randomized_uuid_ciphertext,
+            uuid_ciphertext_match_proof,
+        )
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn FFI_GroupSecretParams_encryptBlobWithAdDeterministic(
+    groupSecretParams: *const u8,
+    groupSecretParamsLen: u32,
+    randomness: *const u8,
+    randomnessLen: u32,
+    plaintext: *const u8,
+    plaintextLen: u32,
+    associatedData: *const u8,
+    associatedDataLen: u32,
+    blobCiphertextOut: *mut u8,
+    blobCiphertextLen: u32,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let group_secret_params: &[u8] =
+            unsafe { slice::from_raw_parts(groupSecretParams, groupSecretParamsLen as usize) };
+        let randomness: &[u8] =
+            unsafe { slice::from_raw_parts(randomness, randomnessLen as usize) };
+        let plaintext: &[u8] = unsafe { slice::from_raw_parts(plaintext, plaintextLen as usize) };
+        let associated_data: &[u8] =
+            unsafe { slice::from_raw_parts(associatedData, associatedDataLen as usize) };
+        let blob_ciphertext: &mut [u8] =
+            unsafe { slice::from_raw_parts_mut(blobCiphertextOut, blobCiphertextLen as usize) };
+
+        simpleapi::GroupSecretParams_encryptBlobWithAdDeterministic(
+            group_secret_params,
+            &randomness,
+            &plaintext,
+            &associated_data,
+            blob_ciphertext,
+        )
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn FFI_GroupSecretParams_decryptBlobWithAd(
+    groupSecretParams: *const u8,
+    groupSecretParamsLen: u32,
+    blobCiphertext: *const u8,
+    blobCiphertextLen: u32,
+    associatedData: *const u8,
+    associatedDataLen: u32,
+    plaintextOut: *mut u8,
+    plaintextLen: u32,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let group_secret_params: &[u8] =
+            unsafe { slice::from_raw_parts(groupSecretParams, groupSecretParamsLen as usize) };
+        let blob_ciphertext: &[u8] =
+            unsafe { slice::from_raw_parts(blobCiphertext, blobCiphertextLen as usize) };
+        let associated_data: &[u8] =
+            unsafe { slice::from_raw_parts(associatedData, associatedDataLen as usize) };
+        let plaintext: &mut [u8] =
+            unsafe { slice::from_raw_parts_mut(plaintextOut, plaintextLen as usize) };
+
+        simpleapi::GroupSecretParams_decryptBlobWithAd(
+            group_secret_params,
+            &blob_ciphertext,
+            &associated_data,
+            plaintext,
+        )
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn FFI_ServerSecretParams_generateDeterministic(
     randomness: *const u8,
@@ -380,6 +718,7 @@ pub extern "C" fn FFI_ServerSecretParams_generateDeterministic(
     serverSecretParamsOut: *mut u8,
     serverSecretParamsLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let randomness: &[u8] =
             unsafe { slice::from_raw_parts(randomness, randomnessLen as usize) };
@@ -401,6 +740,7 @@ pub extern "C" fn FFI_ServerSecretParams_checkValidContents(
     serverSecretParams: *const u8,
     serverSecretParamsLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let server_secret_params: &[u8] =
             unsafe { slice::from_raw_parts(serverSecretParams, serverSecretParamsLen as usize) };
@@ -421,6 +761,7 @@ pub extern "C" fn FFI_ServerSecretParams_getPublicParams(
     serverPublicParamsOut: *mut u8,
     serverPublicParamsLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let server_secret_params: &[u8] =
             unsafe { slice::from_raw_parts(serverSecretParams, serverSecretParamsLen as usize) };
@@ -448,6 +789,7 @@ pub extern "C" fn FFI_ServerSecretParams_signDeterministic(
     notarySignatureOut: *mut u8,
     notarySignatureLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let server_secret_params: &[u8] =
             unsafe { slice::from_raw_parts(serverSecretParams, serverSecretParamsLen as usize) };
@@ -483,6 +825,7 @@ pub extern "C" fn FFI_ServerPublicParams_receiveAuthCredential(
     authCredentialOut: *mut u8,
     authCredentialLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let server_public_params: &[u8] =
             unsafe { slice::from_raw_parts(serverPublicParams, serverPublicParamsLen as usize) };
@@ -522,6 +865,7 @@ pub extern "C" fn FFI_ServerPublicParams_createAuthCredentialPresentationDetermi
     authCredentialPresentationOut: *mut u8,
     authCredentialPresentationLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let server_public_params: &[u8] =
             unsafe { slice::from_raw_parts(serverPublicParams, serverPublicParamsLen as usize) };
@@ -554,30 +898,196 @@ pub extern "C" fn FFI_ServerPublicParams_createAuthCredentialPresentationDetermi
 }
 
 #[no_mangle]
-pub extern "C" fn FFI_ServerPublicParams_createProfileKeyCredentialRequestContextDeterministic(
+pub extern "C" fn FFI_ServerPublicParams_createAuthCredentialPresentationWithContextDeterministic(
     serverPublicParams: *const u8,
     serverPublicParamsLen: u32,
     randomness: *const u8,
     randomnessLen: u32,
-    uuid: *const u8,
-    uuidLen: u32,
-    profileKey: *const u8,
-    profileKeyLen: u32,
-    profileKeyCredentialRequestContextOut: *mut u8,
-    profileKeyCredentialRequestContextLen: u32,
+    groupSecretParams: *const u8,
+    groupSecretParamsLen: u32,
+    authCredential: *const u8,
+    authCredentialLen: u32,
+    context: *const u8,
+    contextLen: u32,
+    authCredentialPresentationOut: *mut u8,
+    authCredentialPresentationLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let server_public_params: &[u8] =
             unsafe { slice::from_raw_parts(serverPublicParams, serverPublicParamsLen as usize) };
         let randomness: &[u8] =
             unsafe { slice::from_raw_parts(randomness, randomnessLen as usize) };
-        let uuid: &[u8] = unsafe { slice::from_raw_parts(uuid, uuidLen as usize) };
-        let profile_key: &[u8] =
-            unsafe { slice::from_raw_parts(profileKey, profileKeyLen as usize) };
-        let profile_key_credential_request_context: &mut [u8] = unsafe {
-            slice::from_raw_parts_mut(
-                profileKeyCredentialRequestContextOut,
-                profileKeyCredentialRequestContextLen as usize,
+        let group_secret_params: &[u8] =
+            unsafe { slice::from_raw_parts(groupSecretParams, groupSecretParamsLen as usize) };
+        let auth_credential: &[u8] =
+            unsafe { slice::from_raw_parts(authCredential, authCredentialLen as usize) };
+        let context: &[u8] = unsafe { slice::from_raw_parts(context, contextLen as usize) };
+        let auth_credential_presentation: &mut [u8] = unsafe {
+            slice::from_raw_parts_mut(
+                authCredentialPresentationOut,
+                authCredentialPresentationLen as usize,
+            )
+        };
+
+        simpleapi::ServerPublicParams_createAuthCredentialPresentationWithContextDeterministic(
+            server_public_params,
+            &randomness,
+            &group_secret_params,
+            &auth_credential,
+            &context,
+            auth_credential_presentation,
+        )
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn FFI_ServerPublicParams_createRateLimitCredentialRequestContextDeterministic(
+    serverPublicParams: *const u8,
+    serverPublicParamsLen: u32,
+    randomness: *const u8,
+    randomnessLen: u32,
+    rateLimitCredentialRequestContextOut: *mut u8,
+    rateLimitCredentialRequestContextLen: u32,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let server_public_params: &[u8] =
+            unsafe { slice::from_raw_parts(serverPublicParams, serverPublicParamsLen as usize) };
+        let randomness: &[u8] =
+            unsafe { slice::from_raw_parts(randomness, randomnessLen as usize) };
+        let rate_limit_credential_request_context: &mut [u8] = unsafe {
+            slice::from_raw_parts_mut(
+                rateLimitCredentialRequestContextOut,
+                rateLimitCredentialRequestContextLen as usize,
+            )
+        };
+
+        simpleapi::ServerPublicParams_createRateLimitCredentialRequestContextDeterministic(
+            server_public_params,
+            &randomness,
+            rate_limit_credential_request_context,
+        )
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn FFI_ServerPublicParams_receiveRateLimitCredential(
+    serverPublicParams: *const u8,
+    serverPublicParamsLen: u32,
+    rateLimitCredentialRequestContext: *const u8,
+    rateLimitCredentialRequestContextLen: u32,
+    rateLimitCredentialResponse: *const u8,
+    rateLimitCredentialResponseLen: u32,
+    rateLimitCredentialOut: *mut u8,
+    rateLimitCredentialLen: u32,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let server_public_params: &[u8] =
+            unsafe { slice::from_raw_parts(serverPublicParams, serverPublicParamsLen as usize) };
+        let rate_limit_credential_request_context: &[u8] = unsafe {
+            slice::from_raw_parts(
+                rateLimitCredentialRequestContext,
+                rateLimitCredentialRequestContextLen as usize,
+            )
+        };
+        let rate_limit_credential_response: &[u8] = unsafe {
+            slice::from_raw_parts(
+                rateLimitCredentialResponse,
+                rateLimitCredentialResponseLen as usize,
+            )
+        };
+        let rate_limit_credential: &mut [u8] = unsafe {
+            slice::from_raw_parts_mut(rateLimitCredentialOut, rateLimitCredentialLen as usize)
+        };
+
+        simpleapi::ServerPublicParams_receiveRateLimitCredential(
+            server_public_params,
+            &rate_limit_credential_request_context,
+            &rate_limit_credential_response,
+            rate_limit_credential,
+        )
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn FFI_ServerPublicParams_createRateLimitTokenDeterministic(
+    serverPublicParams: *const u8,
+    serverPublicParamsLen: u32,
+    randomness: *const u8,
+    randomnessLen: u32,
+    rateLimitCredential: *const u8,
+    rateLimitCredentialLen: u32,
+    rateLimitTokenOut: *mut u8,
+    rateLimitTokenLen: u32,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let server_public_params: &[u8] =
+            unsafe { slice::from_raw_parts(serverPublicParams, serverPublicParamsLen as usize) };
+        let randomness: &[u8] =
+            unsafe { slice::from_raw_parts(randomness, randomnessLen as usize) };
+        let rate_limit_credential: &[u8] =
+            unsafe { slice::from_raw_parts(rateLimitCredential, rateLimitCredentialLen as usize) };
+        let rate_limit_token: &mut [u8] =
+            unsafe { slice::from_raw_parts_mut(rateLimitTokenOut, rateLimitTokenLen as usize) };
+
+        simpleapi::ServerPublicParams_createRateLimitTokenDeterministic(
+            server_public_params,
+            &randomness,
+            &rate_limit_credential,
+            rate_limit_token,
+        )
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn FFI_ServerPublicParams_createProfileKeyCredentialRequestContextDeterministic(
+    serverPublicParams: *const u8,
+    serverPublicParamsLen: u32,
+    randomness: *const u8,
+    randomnessLen: u32,
+    uuid: *const u8,
+    uuidLen: u32,
+    profileKey: *const u8,
+    profileKeyLen: u32,
+    profileKeyCredentialRequestContextOut: *mut u8,
+    profileKeyCredentialRequestContextLen: u32,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let server_public_params: &[u8] =
+            unsafe { slice::from_raw_parts(serverPublicParams, serverPublicParamsLen as usize) };
+        let randomness: &[u8] =
+            unsafe { slice::from_raw_parts(randomness, randomnessLen as usize) };
+        let uuid: &[u8] = unsafe { slice::from_raw_parts(uuid, uuidLen as usize) };
+        let profile_key: &[u8] =
+            unsafe { slice::from_raw_parts(profileKey, profileKeyLen as usize) };
+        let profile_key_credential_request_context: &mut [u8] = unsafe {
+            slice::from_raw_parts_mut(
+                profileKeyCredentialRequestContextOut,
+                profileKeyCredentialRequestContextLen as usize,
             )
         };
 
@@ -607,6 +1117,7 @@ pub extern "C" fn FFI_ServerPublicParams_receiveProfileKeyCredential(
     profileKeyCredentialOut: *mut u8,
     profileKeyCredentialLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let server_public_params: &[u8] =
             unsafe { slice::from_raw_parts(serverPublicParams, serverPublicParamsLen as usize) };
@@ -653,6 +1164,7 @@ pub extern "C" fn FFI_ServerPublicParams_createProfileKeyCredentialPresentationD
     profileKeyCredentialPresentationOut: *mut u8,
     profileKeyCredentialPresentationLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let server_public_params: &[u8] =
             unsafe { slice::from_raw_parts(serverPublicParams, serverPublicParamsLen as usize) };
@@ -685,6 +1197,145 @@ pub extern "C" fn FFI_ServerPublicParams_createProfileKeyCredentialPresentationD
     }
 }
 
+#[no_mangle]
+pub extern "C" fn FFI_ServerPublicParams_createProfileKeyCredentialPresentationWithContextDeterministic(
+    serverPublicParams: *const u8,
+    serverPublicParamsLen: u32,
+    randomness: *const u8,
+    randomnessLen: u32,
+    groupSecretParams: *const u8,
+    groupSecretParamsLen: u32,
+    profileKeyCredential: *const u8,
+    profileKeyCredentialLen: u32,
+    context: *const u8,
+    contextLen: u32,
+    profileKeyCredentialPresentationOut: *mut u8,
+    profileKeyCredentialPresentationLen: u32,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let server_public_params: &[u8] =
+            unsafe { slice::from_raw_parts(serverPublicParams, serverPublicParamsLen as usize) };
+        let randomness: &[u8] =
+            unsafe { slice::from_raw_parts(randomness, randomnessLen as usize) };
+        let group_secret_params: &[u8] =
+            unsafe { slice::from_raw_parts(groupSecretParams, groupSecretParamsLen as usize) };
+        let profile_key_credential: &[u8] = unsafe {
+            slice::from_raw_parts(profileKeyCredential, profileKeyCredentialLen as usize)
+        };
+        let context: &[u8] = unsafe { slice::from_raw_parts(context, contextLen as usize) };
+        let profile_key_credential_presentation: &mut [u8] = unsafe {
+            slice::from_raw_parts_mut(
+                profileKeyCredentialPresentationOut,
+                profileKeyCredentialPresentationLen as usize,
+            )
+        };
+
+        simpleapi::ServerPublicParams_createProfileKeyCredentialPresentationWithContextDeterministic(
+            server_public_params,
+            &randomness,
+            &group_secret_params,
+            &profile_key_credential,
+            &context,
+            profile_key_credential_presentation,
+        )
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn FFI_ServerPublicParams_createProfileKeyCredentialSelectivePresentationDeterministic(
+    serverPublicParams: *const u8,
+    serverPublicParamsLen: u32,
+    randomness: *const u8,
+    randomnessLen: u32,
+    groupSecretParams: *const u8,
+    groupSecretParamsLen: u32,
+    profileKeyCredential: *const u8,
+    profileKeyCredentialLen: u32,
+    profileKeyCredentialSelectivePresentationOut: *mut u8,
+    profileKeyCredentialSelectivePresentationLen: u32,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let server_public_params: &[u8] =
+            unsafe { slice::from_raw_parts(serverPublicParams, serverPublicParamsLen as usize) };
+        let randomness: &[u8] =
+            unsafe { slice::from_raw_parts(randomness, randomnessLen as usize) };
+        let group_secret_params: &[u8] =
+            unsafe { slice::from_raw_parts(groupSecretParams, groupSecretParamsLen as usize) };
+        let profile_key_credential: &[u8] = unsafe {
+            slice::from_raw_parts(profileKeyCredential, profileKeyCredentialLen as usize)
+        };
+        let profile_key_credential_selective_presentation: &mut [u8] = unsafe {
+            slice::from_raw_parts_mut(
+                profileKeyCredentialSelectivePresentationOut,
+                profileKeyCredentialSelectivePresentationLen as usize,
+            )
+        };
+
+        simpleapi::ServerPublicParams_createProfileKeyCredentialSelectivePresentationDeterministic(
+            server_public_params,
+            &randomness,
+            &group_secret_params,
+            &profile_key_credential,
+            profile_key_credential_selective_presentation,
+        )
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn FFI_ServerPublicParams_createProfileKeyCredentialSelectivePresentationWithContextDeterministic(
+    serverPublicParams: *const u8,
+    serverPublicParamsLen: u32,
+    randomness: *const u8,
+    randomnessLen: u32,
+    groupSecretParams: *const u8,
+    groupSecretParamsLen: u32,
+    profileKeyCredential: *const u8,
+    profileKeyCredentialLen: u32,
+    context: *const u8,
+    contextLen: u32,
+    profileKeyCredentialSelectivePresentationOut: *mut u8,
+    profileKeyCredentialSelectivePresentationLen: u32,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let server_public_params: &[u8] =
+            unsafe { slice::from_raw_parts(serverPublicParams, serverPublicParamsLen as usize) };
+        let randomness: &[u8] =
+            unsafe { slice::from_raw_parts(randomness, randomnessLen as usize) };
+        let group_secret_params: &[u8] =
+            unsafe { slice::from_raw_parts(groupSecretParams, groupSecretParamsLen as usize) };
+        let profile_key_credential: &[u8] = unsafe {
+            slice::from_raw_parts(profileKeyCredential, profileKeyCredentialLen as usize)
+        };
+        let context: &[u8] = unsafe { slice::from_raw_parts(context, contextLen as usize) };
+        let profile_key_credential_selective_presentation: &mut [u8] = unsafe {
+            slice::from_raw_parts_mut(
+                profileKeyCredentialSelectivePresentationOut,
+                profileKeyCredentialSelectivePresentationLen as usize,
+            )
+        };
+
+        simpleapi::ServerPublicParams_createProfileKeyCredentialSelectivePresentationWithContextDeterministic(server_public_params, &randomness, &group_secret_params, &profile_key_credential, &context,  profile_key_credential_selective_presentation)
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn FFI_ServerSecretParams_issueAuthCredentialDeterministic(
     serverSecretParams: *const u8,
@@ -697,6 +1348,7 @@ pub extern "C" fn FFI_ServerSecretParams_issueAuthCredentialDeterministic(
     authCredentialResponseOut: *mut u8,
     authCredentialResponseLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let server_secret_params: &[u8] =
             unsafe { slice::from_raw_parts(serverSecretParams, serverSecretParamsLen as usize) };
@@ -735,6 +1387,7 @@ pub extern "C" fn FFI_ServerSecretParams_verifyAuthCredentialPresentation(
     authCredentialPresentation: *const u8,
     authCredentialPresentationLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let server_secret_params: &[u8] =
             unsafe { slice::from_raw_parts(serverSecretParams, serverSecretParamsLen as usize) };
@@ -761,28 +1414,182 @@ pub extern "C" fn FFI_ServerSecretParams_verifyAuthCredentialPresentation(
 }
 
 #[no_mangle]
-pub extern "C" fn FFI_ServerSecretParams_issueProfileKeyCredentialDeterministic(
+pub extern "C" fn FFI_ServerSecretParams_verifyAuthCredentialPresentationWithContext(
     serverSecretParams: *const u8,
     serverSecretParamsLen: u32,
-    randomness: *const u8,
-    randomnessLen: u32,
-    profileKeyCredentialRequest: *const u8,
-    profileKeyCredentialRequestLen: u32,
-    uuid: *const u8,
-    uuidLen: u32,
-    profileKeyCommitment: *const u8,
-    profileKeyCommitmentLen: u32,
-    profileKeyCredentialResponseOut: *mut u8,
-    profileKeyCredentialResponseLen: u32,
+    groupPublicParams: *const u8,
+    groupPublicParamsLen: u32,
+    authCredentialPresentation: *const u8,
+    authCredentialPresentationLen: u32,
+    context: *const u8,
+    contextLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let server_secret_params: &[u8] =
             unsafe { slice::from_raw_parts(serverSecretParams, serverSecretParamsLen as usize) };
-        let randomness: &[u8] =
-            unsafe { slice::from_raw_parts(randomness, randomnessLen as usize) };
-        let profile_key_credential_request: &[u8] = unsafe {
-            slice::from_raw_parts(
-                profileKeyCredentialRequest,
+        let group_public_params: &[u8] =
+            unsafe { slice::from_raw_parts(groupPublicParams, groupPublicParamsLen as usize) };
+        let auth_credential_presentation: &[u8] = unsafe {
+            slice::from_raw_parts(
+                authCredentialPresentation,
+                authCredentialPresentationLen as usize,
+            )
+        };
+        let context: &[u8] = unsafe { slice::from_raw_parts(context, contextLen as usize) };
+
+        simpleapi::ServerSecretParams_verifyAuthCredentialPresentationWithContext(
+            server_secret_params,
+            &group_public_params,
+            &auth_credential_presentation,
+            &context,
+        )
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn FFI_ServerSecretParams_issueRateLimitCredentialDeterministic(
+    serverSecretParams: *const u8,
+    serverSecretParamsLen: u32,
+    randomness: *const u8,
+    randomnessLen: u32,
+    rateLimitCredentialRequest: *const u8,
+    rateLimitCredentialRequestLen: u32,
+    redemptionTime: u32,
+    rateLimitCredentialResponseOut: *mut u8,
+    rateLimitCredentialResponseLen: u32,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let server_secret_params: &[u8] =
+            unsafe { slice::from_raw_parts(serverSecretParams, serverSecretParamsLen as usize) };
+        let randomness: &[u8] =
+            unsafe { slice::from_raw_parts(randomness, randomnessLen as usize) };
+        let rate_limit_credential_request: &[u8] = unsafe {
+            slice::from_raw_parts(
+                rateLimitCredentialRequest,
+                rateLimitCredentialRequestLen as usize,
+            )
+        };
+        let redemption_time = redemptionTime as u32;
+        let rate_limit_credential_response: &mut [u8] = unsafe {
+            slice::from_raw_parts_mut(
+                rateLimitCredentialResponseOut,
+                rateLimitCredentialResponseLen as usize,
+            )
+        };
+
+        simpleapi::ServerSecretParams_issueRateLimitCredentialDeterministic(
+            server_secret_params,
+            &randomness,
+            &rate_limit_credential_request,
+            redemption_time,
+            rate_limit_credential_response,
+        )
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn FFI_ServerSecretParams_verifyRateLimitToken(
+    serverSecretParams: *const u8,
+    serverSecretParamsLen: u32,
+    rateLimitToken: *const u8,
+    rateLimitTokenLen: u32,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let server_secret_params: &[u8] =
+            unsafe { slice::from_raw_parts(serverSecretParams, serverSecretParamsLen as usize) };
+        let rate_limit_token: &[u8] =
+            unsafe { slice::from_raw_parts(rateLimitToken, rateLimitTokenLen as usize) };
+
+        simpleapi::ServerSecretParams_verifyRateLimitToken(server_secret_params, &rate_limit_token)
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn FFI_ServerSecretParams_verifyGroupInviteLinkPresentation(
+    serverSecretParams: *const u8,
+    serverSecretParamsLen: u32,
+    groupPublicParams: *const u8,
+    groupPublicParamsLen: u32,
+    groupInviteLinkPasswordCommitment: *const u8,
+    groupInviteLinkPasswordCommitmentLen: u32,
+    groupInviteLinkPresentation: *const u8,
+    groupInviteLinkPresentationLen: u32,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let server_secret_params: &[u8] =
+            unsafe { slice::from_raw_parts(serverSecretParams, serverSecretParamsLen as usize) };
+        let group_public_params: &[u8] =
+            unsafe { slice::from_raw_parts(groupPublicParams, groupPublicParamsLen as usize) };
+        let group_invite_link_password_commitment: &[u8] = unsafe {
+            slice::from_raw_parts(
+                groupInviteLinkPasswordCommitment,
+                groupInviteLinkPasswordCommitmentLen as usize,
+            )
+        };
+        let group_invite_link_presentation: &[u8] = unsafe {
+            slice::from_raw_parts(
+                groupInviteLinkPresentation,
+                groupInviteLinkPresentationLen as usize,
+            )
+        };
+
+        simpleapi::ServerSecretParams_verifyGroupInviteLinkPresentation(
+            server_secret_params,
+            &group_public_params,
+            &group_invite_link_password_commitment,
+            &group_invite_link_presentation,
+        )
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn FFI_ServerSecretParams_issueProfileKeyCredentialDeterministic(
+    serverSecretParams: *const u8,
+    serverSecretParamsLen: u32,
+    randomness: *const u8,
+    randomnessLen: u32,
+    profileKeyCredentialRequest: *const u8,
+    profileKeyCredentialRequestLen: u32,
+    uuid: *const u8,
+    uuidLen: u32,
+    profileKeyCommitment: *const u8,
+    profileKeyCommitmentLen: u32,
+    profileKeyCredentialResponseOut: *mut u8,
+    profileKeyCredentialResponseLen: u32,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let server_secret_params: &[u8] =
+            unsafe { slice::from_raw_parts(serverSecretParams, serverSecretParamsLen as usize) };
+        let randomness: &[u8] =
+            unsafe { slice::from_raw_parts(randomness, randomnessLen as usize) };
+        let profile_key_credential_request: &[u8] = unsafe {
+            slice::from_raw_parts(
+                profileKeyCredentialRequest,
                 profileKeyCredentialRequestLen as usize,
             )
         };
@@ -822,6 +1629,7 @@ pub extern "C" fn FFI_ServerSecretParams_verifyProfileKeyCredentialPresentation(
     profileKeyCredentialPresentation: *const u8,
     profileKeyCredentialPresentationLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let server_secret_params: &[u8] =
             unsafe { slice::from_raw_parts(serverSecretParams, serverSecretParamsLen as usize) };
@@ -847,16 +1655,368 @@ pub extern "C" fn FFI_ServerSecretParams_verifyProfileKeyCredentialPresentation(
     }
 }
 
+#[no_mangle]
+pub extern "C" fn FFI_ServerSecretParams_verifyProfileKeyCredentialPresentationWithContext(
+    serverSecretParams: *const u8,
+    serverSecretParamsLen: u32,
+    groupPublicParams: *const u8,
+    groupPublicParamsLen: u32,
+    profileKeyCredentialPresentation: *const u8,
+    profileKeyCredentialPresentationLen: u32,
+    context: *const u8,
+    contextLen: u32,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let server_secret_params: &[u8] =
+            unsafe { slice::from_raw_parts(serverSecretParams, serverSecretParamsLen as usize) };
+        let group_public_params: &[u8] =
+            unsafe { slice::from_raw_parts(groupPublicParams, groupPublicParamsLen as usize) };
+        let profile_key_credential_presentation: &[u8] = unsafe {
+            slice::from_raw_parts(
+                profileKeyCredentialPresentation,
+                profileKeyCredentialPresentationLen as usize,
+            )
+        };
+        let context: &[u8] = unsafe { slice::from_raw_parts(context, contextLen as usize) };
+
+        simpleapi::ServerSecretParams_verifyProfileKeyCredentialPresentationWithContext(
+            server_secret_params,
+            &group_public_params,
+            &profile_key_credential_presentation,
+            &context,
+        )
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn FFI_ServerSecretParams_verifyProfileKeyCredentialSelectivePresentation(
+    serverSecretParams: *const u8,
+    serverSecretParamsLen: u32,
+    groupPublicParams: *const u8,
+    groupPublicParamsLen: u32,
+    profileKeyCredentialSelectivePresentation: *const u8,
+    profileKeyCredentialSelectivePresentationLen: u32,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let server_secret_params: &[u8] =
+            unsafe { slice::from_raw_parts(serverSecretParams, serverSecretParamsLen as usize) };
+        let group_public_params: &[u8] =
+            unsafe { slice::from_raw_parts(groupPublicParams, groupPublicParamsLen as usize) };
+        let profile_key_credential_selective_presentation: &[u8] = unsafe {
+            slice::from_raw_parts(
+                profileKeyCredentialSelectivePresentation,
+                profileKeyCredentialSelectivePresentationLen as usize,
+            )
+        };
+
+        simpleapi::ServerSecretParams_verifyProfileKeyCredentialSelectivePresentation(
+            server_secret_params,
+            &group_public_params,
+            &profile_key_credential_selective_presentation,
+        )
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn FFI_ServerSecretParams_verifyProfileKeyCredentialSelectivePresentationWithContext(
+    serverSecretParams: *const u8,
+    serverSecretParamsLen: u32,
+    groupPublicParams: *const u8,
+    groupPublicParamsLen: u32,
+    profileKeyCredentialSelectivePresentation: *const u8,
+    profileKeyCredentialSelectivePresentationLen: u32,
+    context: *const u8,
+    contextLen: u32,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let server_secret_params: &[u8] =
+            unsafe { slice::from_raw_parts(serverSecretParams, serverSecretParamsLen as usize) };
+        let group_public_params: &[u8] =
+            unsafe { slice::from_raw_parts(groupPublicParams, groupPublicParamsLen as usize) };
+        let profile_key_credential_selective_presentation: &[u8] = unsafe {
+            slice::from_raw_parts(
+                profileKeyCredentialSelectivePresentation,
+                profileKeyCredentialSelectivePresentationLen as usize,
+            )
+        };
+        let context: &[u8] = unsafe { slice::from_raw_parts(context, contextLen as usize) };
+
+        simpleapi::ServerSecretParams_verifyProfileKeyCredentialSelectivePresentationWithContext(
+            server_secret_params,
+            &group_public_params,
+            &profile_key_credential_selective_presentation,
+            &context,
+        )
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn FFI_GroupPublicParams_checkValidContents(
     groupPublicParams: *const u8,
     groupPublicParamsLen: u32,
 ) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let group_public_params: &[u8] =
+            unsafe { slice::from_raw_parts(groupPublicParams, groupPublicParamsLen as usize) };
+
+        simpleapi::GroupPublicParams_checkValidContents(group_public_params)
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn FFI_GroupPublicParams_getGroupIdentifier(
+    groupPublicParams: *const u8,
+    groupPublicParamsLen: u32,
+    groupIdentifierOut: *mut u8,
+    groupIdentifierLen: u32,
+) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let group_public_params: &[u8] =
             unsafe { slice::from_raw_parts(groupPublicParams, groupPublicParamsLen as usize) };
+        let group_identifier: &mut [u8] =
+            unsafe { slice::from_raw_parts_mut(groupIdentifierOut, groupIdentifierLen as usize) };
+
+        simpleapi::GroupPublicParams_getGroupIdentifier(group_public_params, group_identifier)
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn FFI_GroupPublicParams_verifyUuidCiphertextMatch(
+    groupPublicParams: *const u8,
+    groupPublicParamsLen: u32,
+    uuidCiphertext: *const u8,
+    uuidCiphertextLen: u32,
+    randomizedUuidCiphertext: *const u8,
+    randomizedUuidCiphertextLen: u32,
+    uuidCiphertextMatchProof: *const u8,
+    uuidCiphertextMatchProofLen: u32,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let group_public_params: &[u8] =
+            unsafe { slice::from_raw_parts(groupPublicParams, groupPublicParamsLen as usize) };
+        let uuid_ciphertext: &[u8] =
+            unsafe { slice::from_raw_parts(uuidCiphertext, uuidCiphertextLen as usize) };
+        let randomized_uuid_ciphertext: &[u8] = unsafe {
+            slice::from_raw_parts(
+                randomizedUuidCiphertext,
+                randomizedUuidCiphertextLen as usize,
+            )
+        };
+        let uuid_ciphertext_match_proof: &[u8] = unsafe {
+            slice::from_raw_parts(
+                uuidCiphertextMatchProof,
+                uuidCiphertextMatchProofLen as usize,
+            )
+        };
+
+        simpleapi::GroupPublicParams_verifyUuidCiphertextMatch(
+            group_public_params,
+            &uuid_ciphertext,
+            &randomized_uuid_ciphertext,
+            &uuid_ciphertext_match_proof,
+        )
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn FFI_ServerPublicParams_checkValidContents(
+    serverPublicParams: *const u8,
+    serverPublicParamsLen: u32,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let server_public_params: &[u8] =
+            unsafe { slice::from_raw_parts(serverPublicParams, serverPublicParamsLen as usize) };
+
+        simpleapi::ServerPublicParams_checkValidContents(server_public_params)
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn FFI_ServerPublicParams_verifySignature(
+    serverPublicParams: *const u8,
+    serverPublicParamsLen: u32,
+    message: *const u8,
+    messageLen: u32,
+    notarySignature: *const u8,
+    notarySignatureLen: u32,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let server_public_params: &[u8] =
+            unsafe { slice::from_raw_parts(serverPublicParams, serverPublicParamsLen as usize) };
+        let message: &[u8] = unsafe { slice::from_raw_parts(message, messageLen as usize) };
+        let notary_signature: &[u8] =
+            unsafe { slice::from_raw_parts(notarySignature, notarySignatureLen as usize) };
+
+        simpleapi::ServerPublicParams_verifySignature(
+            server_public_params,
+            &message,
+            &notary_signature,
+        )
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn FFI_AuthCredentialResponse_checkValidContents(
+    authCredentialResponse: *const u8,
+    authCredentialResponseLen: u32,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let auth_credential_response: &[u8] = unsafe {
+            slice::from_raw_parts(authCredentialResponse, authCredentialResponseLen as usize)
+        };
+
+        simpleapi::AuthCredentialResponse_checkValidContents(auth_credential_response)
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn FFI_AuthCredential_checkValidContents(
+    authCredential: *const u8,
+    authCredentialLen: u32,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let auth_credential: &[u8] =
+            unsafe { slice::from_raw_parts(authCredential, authCredentialLen as usize) };
+
+        simpleapi::AuthCredential_checkValidContents(auth_credential)
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn FFI_AuthCredentialPresentation_checkValidContents(
+    authCredentialPresentation: *const u8,
+    authCredentialPresentationLen: u32,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let auth_credential_presentation: &[u8] = unsafe {
+            slice::from_raw_parts(
+                authCredentialPresentation,
+                authCredentialPresentationLen as usize,
+            )
+        };
+
+        simpleapi::AuthCredentialPresentation_checkValidContents(auth_credential_presentation)
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn FFI_AuthCredentialPresentation_getUuidCiphertext(
+    authCredentialPresentation: *const u8,
+    authCredentialPresentationLen: u32,
+    uuidCiphertextOut: *mut u8,
+    uuidCiphertextLen: u32,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let auth_credential_presentation: &[u8] = unsafe {
+            slice::from_raw_parts(
+                authCredentialPresentation,
+                authCredentialPresentationLen as usize,
+            )
+        };
+        let uuid_ciphertext: &mut [u8] =
+            unsafe { slice::from_raw_parts_mut(uuidCiphertextOut, uuidCiphertextLen as usize) };
+
+        simpleapi::AuthCredentialPresentation_getUuidCiphertext(
+            auth_credential_presentation,
+            uuid_ciphertext,
+        )
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn FFI_AuthCredentialPresentation_getRedemptionTime(
+    authCredentialPresentation: *const u8,
+    authCredentialPresentationLen: u32,
+    redemptionTimeOut: *mut u8,
+    redemptionTimeLen: u32,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let auth_credential_presentation: &[u8] = unsafe {
+            slice::from_raw_parts(
+                authCredentialPresentation,
+                authCredentialPresentationLen as usize,
+            )
+        };
+        let redemption_time: &mut [u8] =
+            unsafe { slice::from_raw_parts_mut(redemptionTimeOut, redemptionTimeLen as usize) };
 
-        simpleapi::GroupPublicParams_checkValidContents(group_public_params)
+        simpleapi::AuthCredentialPresentation_getRedemptionTime(
+            auth_credential_presentation,
+            redemption_time,
+        )
     });
 
     match result {
@@ -866,19 +2026,22 @@ pub extern "C" fn FFI_GroupPublicParams_checkValidContents(
 }
 
 #[no_mangle]
-pub extern "C" fn FFI_GroupPublicParams_getGroupIdentifier(
-    groupPublicParams: *const u8,
-    groupPublicParamsLen: u32,
-    groupIdentifierOut: *mut u8,
-    groupIdentifierLen: u32,
+pub extern "C" fn FFI_RateLimitCredentialRequestContext_checkValidContents(
+    rateLimitCredentialRequestContext: *const u8,
+    rateLimitCredentialRequestContextLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
-        let group_public_params: &[u8] =
-            unsafe { slice::from_raw_parts(groupPublicParams, groupPublicParamsLen as usize) };
-        let group_identifier: &mut [u8] =
-            unsafe { slice::from_raw_parts_mut(groupIdentifierOut, groupIdentifierLen as usize) };
+        let rate_limit_credential_request_context: &[u8] = unsafe {
+            slice::from_raw_parts(
+                rateLimitCredentialRequestContext,
+                rateLimitCredentialRequestContextLen as usize,
+            )
+        };
 
-        simpleapi::GroupPublicParams_getGroupIdentifier(group_public_params, group_identifier)
+        simpleapi::RateLimitCredentialRequestContext_checkValidContents(
+            rate_limit_credential_request_context,
+        )
     });
 
     match result {
@@ -888,15 +2051,31 @@ pub extern "C" fn FFI_GroupPublicParams_getGroupIdentifier(
 }
 
 #[no_mangle]
-pub extern "C" fn FFI_ServerPublicParams_checkValidContents(
-    serverPublicParams: *const u8,
-    serverPublicParamsLen: u32,
+pub extern "C" fn FFI_RateLimitCredentialRequestContext_getRequest(
+    rateLimitCredentialRequestContext: *const u8,
+    rateLimitCredentialRequestContextLen: u32,
+    rateLimitCredentialRequestOut: *mut u8,
+    rateLimitCredentialRequestLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
-        let server_public_params: &[u8] =
-            unsafe { slice::from_raw_parts(serverPublicParams, serverPublicParamsLen as usize) };
+        let rate_limit_credential_request_context: &[u8] = unsafe {
+            slice::from_raw_parts(
+                rateLimitCredentialRequestContext,
+                rateLimitCredentialRequestContextLen as usize,
+            )
+        };
+        let rate_limit_credential_request: &mut [u8] = unsafe {
+            slice::from_raw_parts_mut(
+                rateLimitCredentialRequestOut,
+                rateLimitCredentialRequestLen as usize,
+            )
+        };
 
-        simpleapi::ServerPublicParams_checkValidContents(server_public_params)
+        simpleapi::RateLimitCredentialRequestContext_getRequest(
+            rate_limit_credential_request_context,
+            rate_limit_credential_request,
+        )
     });
 
     match result {
@@ -906,26 +2085,20 @@ pub extern "C" fn FFI_ServerPublicParams_checkValidContents(
 }
 
 #[no_mangle]
-pub extern "C" fn FFI_ServerPublicParams_verifySignature(
-    serverPublicParams: *const u8,
-    serverPublicParamsLen: u32,
-    message: *const u8,
-    messageLen: u32,
-    notarySignature: *const u8,
-    notarySignatureLen: u32,
+pub extern "C" fn FFI_RateLimitCredentialRequest_checkValidContents(
+    rateLimitCredentialRequest: *const u8,
+    rateLimitCredentialRequestLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
-        let server_public_params: &[u8] =
-            unsafe { slice::from_raw_parts(serverPublicParams, serverPublicParamsLen as usize) };
-        let message: &[u8] = unsafe { slice::from_raw_parts(message, messageLen as usize) };
-        let notary_signature: &[u8] =
-            unsafe { slice::from_raw_parts(notarySignature, notarySignatureLen as usize) };
+        let rate_limit_credential_request: &[u8] = unsafe {
+            slice::from_raw_parts(
+                rateLimitCredentialRequest,
+                rateLimitCredentialRequestLen as usize,
+            )
+        };
 
-        simpleapi::ServerPublicParams_verifySignature(
-            server_public_params,
-            &message,
-            &notary_signature,
-        )
+        simpleapi::RateLimitCredentialRequest_checkValidContents(rate_limit_credential_request)
     });
 
     match result {
@@ -935,16 +2108,20 @@ pub extern "C" fn FFI_ServerPublicParams_verifySignature(
 }
 
 #[no_mangle]
-pub extern "C" fn FFI_AuthCredentialResponse_checkValidContents(
-    authCredentialResponse: *const u8,
-    authCredentialResponseLen: u32,
+pub extern "C" fn FFI_RateLimitCredentialResponse_checkValidContents(
+    rateLimitCredentialResponse: *const u8,
+    rateLimitCredentialResponseLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
-        let auth_credential_response: &[u8] = unsafe {
-            slice::from_raw_parts(authCredentialResponse, authCredentialResponseLen as usize)
+        let rate_limit_credential_response: &[u8] = unsafe {
+            slice::from_raw_parts(
+                rateLimitCredentialResponse,
+                rateLimitCredentialResponseLen as usize,
+            )
         };
 
-        simpleapi::AuthCredentialResponse_checkValidContents(auth_credential_response)
+        simpleapi::RateLimitCredentialResponse_checkValidContents(rate_limit_credential_response)
     });
 
     match result {
@@ -954,15 +2131,16 @@ pub extern "C" fn FFI_AuthCredentialResponse_checkValidContents(
 }
 
 #[no_mangle]
-pub extern "C" fn FFI_AuthCredential_checkValidContents(
-    authCredential: *const u8,
-    authCredentialLen: u32,
+pub extern "C" fn FFI_RateLimitCredential_checkValidContents(
+    rateLimitCredential: *const u8,
+    rateLimitCredentialLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
-        let auth_credential: &[u8] =
-            unsafe { slice::from_raw_parts(authCredential, authCredentialLen as usize) };
+        let rate_limit_credential: &[u8] =
+            unsafe { slice::from_raw_parts(rateLimitCredential, rateLimitCredentialLen as usize) };
 
-        simpleapi::AuthCredential_checkValidContents(auth_credential)
+        simpleapi::RateLimitCredential_checkValidContents(rate_limit_credential)
     });
 
     match result {
@@ -972,19 +2150,16 @@ pub extern "C" fn FFI_AuthCredential_checkValidContents(
 }
 
 #[no_mangle]
-pub extern "C" fn FFI_AuthCredentialPresentation_checkValidContents(
-    authCredentialPresentation: *const u8,
-    authCredentialPresentationLen: u32,
+pub extern "C" fn FFI_RateLimitToken_checkValidContents(
+    rateLimitToken: *const u8,
+    rateLimitTokenLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
-        let auth_credential_presentation: &[u8] = unsafe {
-            slice::from_raw_parts(
-                authCredentialPresentation,
-                authCredentialPresentationLen as usize,
-            )
-        };
+        let rate_limit_token: &[u8] =
+            unsafe { slice::from_raw_parts(rateLimitToken, rateLimitTokenLen as usize) };
 
-        simpleapi::AuthCredentialPresentation_checkValidContents(auth_credential_presentation)
+        simpleapi::RateLimitToken_checkValidContents(rate_limit_token)
     });
 
     match result {
@@ -994,26 +2169,20 @@ pub extern "C" fn FFI_AuthCredentialPresentation_checkValidContents(
 }
 
 #[no_mangle]
-pub extern "C" fn FFI_AuthCredentialPresentation_getUuidCiphertext(
-    authCredentialPresentation: *const u8,
-    authCredentialPresentationLen: u32,
-    uuidCiphertextOut: *mut u8,
-    uuidCiphertextLen: u32,
+pub extern "C" fn FFI_RateLimitToken_getTag(
+    rateLimitToken: *const u8,
+    rateLimitTokenLen: u32,
+    rateLimitTagOut: *mut u8,
+    rateLimitTagLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
-        let auth_credential_presentation: &[u8] = unsafe {
-            slice::from_raw_parts(
-                authCredentialPresentation,
-                authCredentialPresentationLen as usize,
-            )
-        };
-        let uuid_ciphertext: &mut [u8] =
-            unsafe { slice::from_raw_parts_mut(uuidCiphertextOut, uuidCiphertextLen as usize) };
+        let rate_limit_token: &[u8] =
+            unsafe { slice::from_raw_parts(rateLimitToken, rateLimitTokenLen as usize) };
+        let rate_limit_tag: &mut [u8] =
+            unsafe { slice::from_raw_parts_mut(rateLimitTagOut, rateLimitTagLen as usize) };
 
-        simpleapi::AuthCredentialPresentation_getUuidCiphertext(
-            auth_credential_presentation,
-            uuid_ciphertext,
-        )
+        simpleapi::RateLimitToken_getTag(rate_limit_token, rate_limit_tag)
     });
 
     match result {
@@ -1023,26 +2192,20 @@ pub extern "C" fn FFI_AuthCredentialPresentation_getUuidCiphertext(
 }
 
 #[no_mangle]
-pub extern "C" fn FFI_AuthCredentialPresentation_getRedemptionTime(
-    authCredentialPresentation: *const u8,
-    authCredentialPresentationLen: u32,
+pub extern "C" fn FFI_RateLimitToken_getRedemptionTime(
+    rateLimitToken: *const u8,
+    rateLimitTokenLen: u32,
     redemptionTimeOut: *mut u8,
     redemptionTimeLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
-        let auth_credential_presentation: &[u8] = unsafe {
-            slice::from_raw_parts(
-                authCredentialPresentation,
-                authCredentialPresentationLen as usize,
-            )
-        };
+        let rate_limit_token: &[u8] =
+            unsafe { slice::from_raw_parts(rateLimitToken, rateLimitTokenLen as usize) };
         let redemption_time: &mut [u8] =
             unsafe { slice::from_raw_parts_mut(redemptionTimeOut, redemptionTimeLen as usize) };
 
-        simpleapi::AuthCredentialPresentation_getRedemptionTime(
-            auth_credential_presentation,
-            redemption_time,
-        )
+        simpleapi::RateLimitToken_getRedemptionTime(rate_limit_token, redemption_time)
     });
 
     match result {
@@ -1056,6 +2219,7 @@ pub extern "C" fn FFI_ProfileKeyCredentialRequestContext_checkValidContents(
     profileKeyCredentialRequestContext: *const u8,
     profileKeyCredentialRequestContextLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let profile_key_credential_request_context: &[u8] = unsafe {
             slice::from_raw_parts(
@@ -1082,6 +2246,7 @@ pub extern "C" fn FFI_ProfileKeyCredentialRequestContext_getRequest(
     profileKeyCredentialRequestOut: *mut u8,
     profileKeyCredentialRequestLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let profile_key_credential_request_context: &[u8] = unsafe {
             slice::from_raw_parts(
@@ -1113,6 +2278,7 @@ pub extern "C" fn FFI_ProfileKeyCredentialRequest_checkValidContents(
     profileKeyCredentialRequest: *const u8,
     profileKeyCredentialRequestLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let profile_key_credential_request: &[u8] = unsafe {
             slice::from_raw_parts(
@@ -1135,6 +2301,7 @@ pub extern "C" fn FFI_ProfileKeyCredentialResponse_checkValidContents(
     profileKeyCredentialResponse: *const u8,
     profileKeyCredentialResponseLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let profile_key_credential_response: &[u8] = unsafe {
             slice::from_raw_parts(
@@ -1157,6 +2324,7 @@ pub extern "C" fn FFI_ProfileKeyCredential_checkValidContents(
     profileKeyCredential: *const u8,
     profileKeyCredentialLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let profile_key_credential: &[u8] = unsafe {
             slice::from_raw_parts(profileKeyCredential, profileKeyCredentialLen as usize)
@@ -1176,6 +2344,7 @@ pub extern "C" fn FFI_ProfileKeyCredentialPresentation_checkValidContents(
     profileKeyCredentialPresentation: *const u8,
     profileKeyCredentialPresentationLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let profile_key_credential_presentation: &[u8] = unsafe {
             slice::from_raw_parts(
@@ -1202,6 +2371,7 @@ pub extern "C" fn FFI_ProfileKeyCredentialPresentation_getUuidCiphertext(
     uuidCiphertextOut: *mut u8,
     uuidCiphertextLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let profile_key_credential_presentation: &[u8] = unsafe {
             slice::from_raw_parts(
@@ -1231,6 +2401,7 @@ pub extern "C" fn FFI_ProfileKeyCredentialPresentation_getProfileKeyCiphertext(
     profileKeyCiphertextOut: *mut u8,
     profileKeyCiphertextLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let profile_key_credential_presentation: &[u8] = unsafe {
             slice::from_raw_parts(
@@ -1254,11 +2425,181 @@ pub extern "C" fn FFI_ProfileKeyCredentialPresentation_getProfileKeyCiphertext(
     }
 }
 
+#[no_mangle]
+pub extern "C" fn FFI_ProfileKeyCredentialSelectivePresentation_checkValidContents(
+    profileKeyCredentialSelectivePresentation: *const u8,
+    profileKeyCredentialSelectivePresentationLen: u32,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let profile_key_credential_selective_presentation: &[u8] = unsafe {
+            slice::from_raw_parts(
+                profileKeyCredentialSelectivePresentation,
+                profileKeyCredentialSelectivePresentationLen as usize,
+            )
+        };
+
+        simpleapi::ProfileKeyCredentialSelectivePresentation_checkValidContents(
+            profile_key_credential_selective_presentation,
+        )
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn FFI_ProfileKeyCredentialSelectivePresentation_getUuidCiphertext(
+    profileKeyCredentialSelectivePresentation: *const u8,
+    profileKeyCredentialSelectivePresentationLen: u32,
+    uuidCiphertextOut: *mut u8,
+    uuidCiphertextLen: u32,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let profile_key_credential_selective_presentation: &[u8] = unsafe {
+            slice::from_raw_parts(
+                profileKeyCredentialSelectivePresentation,
+                profileKeyCredentialSelectivePresentationLen as usize,
+            )
+        };
+        let uuid_ciphertext: &mut [u8] =
+            unsafe { slice::from_raw_parts_mut(uuidCiphertextOut, uuidCiphertextLen as usize) };
+
+        simpleapi::ProfileKeyCredentialSelectivePresentation_getUuidCiphertext(
+            profile_key_credential_selective_presentation,
+            uuid_ciphertext,
+        )
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn FFI_GroupInviteLinkPassword_getCommitment(
+    groupInviteLinkPassword: *const u8,
+    groupInviteLinkPasswordLen: u32,
+    groupPublicParams: *const u8,
+    groupPublicParamsLen: u32,
+    groupInviteLinkPasswordCommitmentOut: *mut u8,
+    groupInviteLinkPasswordCommitmentLen: u32,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let group_invite_link_password: &[u8] = unsafe {
+            slice::from_raw_parts(groupInviteLinkPassword, groupInviteLinkPasswordLen as usize)
+        };
+        let group_public_params: &[u8] =
+            unsafe { slice::from_raw_parts(groupPublicParams, groupPublicParamsLen as usize) };
+        let group_invite_link_password_commitment: &mut [u8] = unsafe {
+            slice::from_raw_parts_mut(
+                groupInviteLinkPasswordCommitmentOut,
+                groupInviteLinkPasswordCommitmentLen as usize,
+            )
+        };
+
+        simpleapi::GroupInviteLinkPassword_getCommitment(
+            group_invite_link_password,
+            &group_public_params,
+            group_invite_link_password_commitment,
+        )
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn FFI_GroupInviteLinkPasswordCommitment_checkValidContents(
+    groupInviteLinkPasswordCommitment: *const u8,
+    groupInviteLinkPasswordCommitmentLen: u32,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let group_invite_link_password_commitment: &[u8] = unsafe {
+            slice::from_raw_parts(
+                groupInviteLinkPasswordCommitment,
+                groupInviteLinkPasswordCommitmentLen as usize,
+            )
+        };
+
+        simpleapi::GroupInviteLinkPasswordCommitment_checkValidContents(
+            group_invite_link_password_commitment,
+        )
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn FFI_GroupInviteLinkPresentation_checkValidContents(
+    groupInviteLinkPresentation: *const u8,
+    groupInviteLinkPresentationLen: u32,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let group_invite_link_presentation: &[u8] = unsafe {
+            slice::from_raw_parts(
+                groupInviteLinkPresentation,
+                groupInviteLinkPresentationLen as usize,
+            )
+        };
+
+        simpleapi::GroupInviteLinkPresentation_checkValidContents(group_invite_link_presentation)
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn FFI_GroupInviteLinkPresentation_getUuidCiphertext(
+    groupInviteLinkPresentation: *const u8,
+    groupInviteLinkPresentationLen: u32,
+    uuidCiphertextOut: *mut u8,
+    uuidCiphertextLen: u32,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let group_invite_link_presentation: &[u8] = unsafe {
+            slice::from_raw_parts(
+                groupInviteLinkPresentation,
+                groupInviteLinkPresentationLen as usize,
+            )
+        };
+        let uuid_ciphertext: &mut [u8] =
+            unsafe { slice::from_raw_parts_mut(uuidCiphertextOut, uuidCiphertextLen as usize) };
+
+        simpleapi::GroupInviteLinkPresentation_getUuidCiphertext(
+            group_invite_link_presentation,
+            uuid_ciphertext,
+        )
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn FFI_UuidCiphertext_checkValidContents(
     uuidCiphertext: *const u8,
     uuidCiphertextLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let uuid_ciphertext: &[u8] =
             unsafe { slice::from_raw_parts(uuidCiphertext, uuidCiphertextLen as usize) };
@@ -1277,6 +2618,7 @@ pub extern "C" fn FFI_ProfileKeyCiphertext_checkValidContents(
     profileKeyCiphertext: *const u8,
     profileKeyCiphertextLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let profile_key_ciphertext: &[u8] = unsafe {
             slice::from_raw_parts(profileKeyCiphertext, profileKeyCiphertextLen as usize)
@@ -1291,11 +2633,58 @@ pub extern "C" fn FFI_ProfileKeyCiphertext_checkValidContents(
     }
 }
 
+#[no_mangle]
+pub extern "C" fn FFI_RandomizedUuidCiphertext_checkValidContents(
+    randomizedUuidCiphertext: *const u8,
+    randomizedUuidCiphertextLen: u32,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let randomized_uuid_ciphertext: &[u8] = unsafe {
+            slice::from_raw_parts(
+                randomizedUuidCiphertext,
+                randomizedUuidCiphertextLen as usize,
+            )
+        };
+
+        simpleapi::RandomizedUuidCiphertext_checkValidContents(randomized_uuid_ciphertext)
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn FFI_UuidCiphertextMatchProof_checkValidContents(
+    uuidCiphertextMatchProof: *const u8,
+    uuidCiphertextMatchProofLen: u32,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let uuid_ciphertext_match_proof: &[u8] = unsafe {
+            slice::from_raw_parts(
+                uuidCiphertextMatchProof,
+                uuidCiphertextMatchProofLen as usize,
+            )
+        };
+
+        simpleapi::UuidCiphertextMatchProof_checkValidContents(uuid_ciphertext_match_proof)
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn FFI_Randomness_checkValidContents(
     randomness: *const u8,
     randomnessLen: u32,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let randomness: &[u8] =
             unsafe { slice::from_raw_parts(randomness, randomnessLen as usize) };
@@ -1311,6 +2700,7 @@ pub extern "C" fn FFI_Randomness_checkValidContents(
 
 #[no_mangle]
 pub extern "C" fn FFI_Uuid_checkValidContents(uuid: *const u8, uuidLen: u32) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let uuid: &[u8] = unsafe { slice::from_raw_parts(uuid, uuidLen as usize) };
 
@@ -11,6 +11,7 @@
 
 use std::panic;
 
+use crate::common::errors::{clear_last_error_code, get_last_error_code};
 use crate::ffi::constants::*;
 
 extern crate jni;
@@ -41,6 +42,16 @@ fn u8toi8(input: Vec<u8>) -> Vec<i8> {
     out
 }
 
+// The ZkGroupError code behind this thread's last failed call; see
+// errors::get_last_error_code
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_getLastErrorCodeJNI(
+    _env: JNIEnv,
+    _class: JClass,
+) -> i32 {
+    get_last_error_code()
+}
+
 #[no_mangle]
 pub extern "system" fn Java_org_signal_zkgroup_internal_Native_profileKeyGetCommitmentJNI(
     env: JNIEnv,
@@ -49,6 +60,7 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_profileKeyGetComm
     uuid: jbyteArray,
     profileKeyCommitmentOut: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let profile_key = env.convert_byte_array(profileKey).unwrap();
         let uuid = env.convert_byte_array(uuid).unwrap();
@@ -84,6 +96,7 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_profileKeyGetProf
     uuid: jbyteArray,
     profileKeyVersionOut: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let profile_key = env.convert_byte_array(profileKey).unwrap();
         let uuid = env.convert_byte_array(uuid).unwrap();
@@ -116,6 +129,7 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_profileKeyCommitm
     _class: JClass,
     profileKeyCommitment: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let profile_key_commitment = env.convert_byte_array(profileKeyCommitment).unwrap();
 
@@ -140,6 +154,7 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_groupSecretParams
     randomness: jbyteArray,
     groupSecretParamsOut: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let randomness = env.convert_byte_array(randomness).unwrap();
         let mut group_secret_params: Vec<u8> =
@@ -171,6 +186,7 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_groupSecretParams
     groupMasterKey: jbyteArray,
     groupSecretParamsOut: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let group_master_key = env.convert_byte_array(groupMasterKey).unwrap();
         let mut group_secret_params: Vec<u8> =
@@ -201,6 +217,7 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_groupSecretParams
     _class: JClass,
     groupSecretParams: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let group_secret_params = env.convert_byte_array(groupSecretParams).unwrap();
 
@@ -224,6 +241,7 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_groupSecretParams
     groupSecretParams: jbyteArray,
     groupMasterKeyOut: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let group_secret_params = env.convert_byte_array(groupSecretParams).unwrap();
         let mut group_master_key: Vec<u8> =
@@ -253,6 +271,7 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_groupSecretParams
     groupSecretParams: jbyteArray,
     groupPublicParamsOut: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let group_secret_params = env.convert_byte_array(groupSecretParams).unwrap();
         let mut group_public_params: Vec<u8> =
@@ -285,6 +304,7 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_groupSecretParams
     uuid: jbyteArray,
     uuidCiphertextOut: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let group_secret_params = env.convert_byte_array(groupSecretParams).unwrap();
         let uuid = env.convert_byte_array(uuid).unwrap();
@@ -319,6 +339,7 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_groupSecretParams
     uuidCiphertext: jbyteArray,
     uuidOut: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let group_secret_params = env.convert_byte_array(groupSecretParams).unwrap();
         let uuid_ciphertext = env.convert_byte_array(uuidCiphertext).unwrap();
@@ -353,6 +374,7 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_groupSecretParams
     uuid: jbyteArray,
     profileKeyCiphertextOut: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let group_secret_params = env.convert_byte_array(groupSecretParams).unwrap();
         let profile_key = env.convert_byte_array(profileKey).unwrap();
@@ -394,6 +416,7 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_groupSecretParams
     uuid: jbyteArray,
     profileKeyOut: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let group_secret_params = env.convert_byte_array(groupSecretParams).unwrap();
         let profile_key_ciphertext = env.convert_byte_array(profileKeyCiphertext).unwrap();
@@ -431,6 +454,7 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_groupSecretParams
     plaintext: jbyteArray,
     blobCiphertextOut: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let group_secret_params = env.convert_byte_array(groupSecretParams).unwrap();
         let randomness = env.convert_byte_array(randomness).unwrap();
@@ -467,6 +491,7 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_groupSecretParams
     blobCiphertext: jbyteArray,
     plaintextOut: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let group_secret_params = env.convert_byte_array(groupSecretParams).unwrap();
         let blob_ciphertext = env.convert_byte_array(blobCiphertext).unwrap();
@@ -493,26 +518,36 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_groupSecretParams
 }
 
 #[no_mangle]
-pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverSecretParamsGenerateDeterministicJNI(
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_groupSecretParamsEncryptBlobWithPaddingDeterministicJNI(
     env: JNIEnv,
     _class: JClass,
+    groupSecretParams: jbyteArray,
     randomness: jbyteArray,
-    serverSecretParamsOut: jbyteArray,
+    plaintext: jbyteArray,
+    paddedLength: jint,
+    blobCiphertextOut: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
+        let group_secret_params = env.convert_byte_array(groupSecretParams).unwrap();
         let randomness = env.convert_byte_array(randomness).unwrap();
-        let mut server_secret_params: Vec<u8> =
-            vec![0; env.get_array_length(serverSecretParamsOut).unwrap() as usize];
+        let plaintext = env.convert_byte_array(plaintext).unwrap();
+        let padded_length = paddedLength as u32;
+        let mut blob_ciphertext: Vec<u8> =
+            vec![0; env.get_array_length(blobCiphertextOut).unwrap() as usize];
 
-        let ffi_return = simpleapi::ServerSecretParams_generateDeterministic(
+        let ffi_return = simpleapi::GroupSecretParams_encryptBlobWithPaddingDeterministic(
+            &group_secret_params,
             &randomness,
-            &mut server_secret_params,
+            &plaintext,
+            padded_length,
+            &mut blob_ciphertext,
         );
         if ffi_return != FFI_RETURN_OK {
             return ffi_return;
         }
 
-        env.set_byte_array_region(serverSecretParamsOut, 0, &u8toi8(server_secret_params)[..])
+        env.set_byte_array_region(blobCiphertextOut, 0, &u8toi8(blob_ciphertext)[..])
             .unwrap();
         FFI_RETURN_OK
     });
@@ -524,18 +559,35 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverSecretParam
 }
 
 #[no_mangle]
-pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverSecretParamsCheckValidContentsJNI(
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_groupSecretParamsDecryptPaddedBlobJNI(
     env: JNIEnv,
     _class: JClass,
-    serverSecretParams: jbyteArray,
+    groupSecretParams: jbyteArray,
+    blobCiphertext: jbyteArray,
+    lengthPrefixedPlaintextOut: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
-        let server_secret_params = env.convert_byte_array(serverSecretParams).unwrap();
+        let group_secret_params = env.convert_byte_array(groupSecretParams).unwrap();
+        let blob_ciphertext = env.convert_byte_array(blobCiphertext).unwrap();
+        let mut length_prefixed_plaintext: Vec<u8> =
+            vec![0; env.get_array_length(lengthPrefixedPlaintextOut).unwrap() as usize];
 
-        let ffi_return = simpleapi::ServerSecretParams_checkValidContents(&server_secret_params);
+        let ffi_return = simpleapi::GroupSecretParams_decryptPaddedBlob(
+            &group_secret_params,
+            &blob_ciphertext,
+            &mut length_prefixed_plaintext,
+        );
         if ffi_return != FFI_RETURN_OK {
             return ffi_return;
         }
+
+        env.set_byte_array_region(
+            lengthPrefixedPlaintextOut,
+            0,
+            &u8toi8(length_prefixed_plaintext)[..],
+        )
+        .unwrap();
         FFI_RETURN_OK
     });
 
@@ -546,27 +598,45 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverSecretParam
 }
 
 #[no_mangle]
-pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverSecretParamsGetPublicParamsJNI(
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_groupSecretParamsCreateGroupInviteLinkPresentationDeterministicJNI(
     env: JNIEnv,
     _class: JClass,
-    serverSecretParams: jbyteArray,
-    serverPublicParamsOut: jbyteArray,
+    groupSecretParams: jbyteArray,
+    randomness: jbyteArray,
+    groupInviteLinkPassword: jbyteArray,
+    uuid: jbyteArray,
+    groupInviteLinkPresentationOut: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
-        let server_secret_params = env.convert_byte_array(serverSecretParams).unwrap();
-        let mut server_public_params: Vec<u8> =
-            vec![0; env.get_array_length(serverPublicParamsOut).unwrap() as usize];
+        let group_secret_params = env.convert_byte_array(groupSecretParams).unwrap();
+        let randomness = env.convert_byte_array(randomness).unwrap();
+        let group_invite_link_password = env.convert_byte_array(groupInviteLinkPassword).unwrap();
+        let uuid = env.convert_byte_array(uuid).unwrap();
+        let mut group_invite_link_presentation: Vec<u8> = vec![
+            0;
+            env.get_array_length(groupInviteLinkPresentationOut)
+                .unwrap() as usize
+        ];
 
-        let ffi_return = simpleapi::ServerSecretParams_getPublicParams(
-            &server_secret_params,
-            &mut server_public_params,
-        );
+        let ffi_return =
+            simpleapi::GroupSecretParams_createGroupInviteLinkPresentationDeterministic(
+                &group_secret_params,
+                &randomness,
+                &group_invite_link_password,
+                &uuid,
+                &mut group_invite_link_presentation,
+            );
         if ffi_return != FFI_RETURN_OK {
             return ffi_return;
         }
 
-        env.set_byte_array_region(serverPublicParamsOut, 0, &u8toi8(server_public_params)[..])
-            .unwrap();
+        env.set_byte_array_region(
+            groupInviteLinkPresentationOut,
+            0,
+            &u8toi8(group_invite_link_presentation)[..],
+        )
+        .unwrap();
         FFI_RETURN_OK
     });
 
@@ -577,33 +647,38 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverSecretParam
 }
 
 #[no_mangle]
-pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverSecretParamsSignDeterministicJNI(
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_groupSecretParamsEncryptUuidRandomizedDeterministicJNI(
     env: JNIEnv,
     _class: JClass,
-    serverSecretParams: jbyteArray,
+    groupSecretParams: jbyteArray,
     randomness: jbyteArray,
-    message: jbyteArray,
-    notarySignatureOut: jbyteArray,
+    uuid: jbyteArray,
+    randomizedUuidCiphertextOut: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
-        let server_secret_params = env.convert_byte_array(serverSecretParams).unwrap();
+        let group_secret_params = env.convert_byte_array(groupSecretParams).unwrap();
         let randomness = env.convert_byte_array(randomness).unwrap();
-        let message = env.convert_byte_array(message).unwrap();
-        let mut notary_signature: Vec<u8> =
-            vec![0; env.get_array_length(notarySignatureOut).unwrap() as usize];
+        let uuid = env.convert_byte_array(uuid).unwrap();
+        let mut randomized_uuid_ciphertext: Vec<u8> =
+            vec![0; env.get_array_length(randomizedUuidCiphertextOut).unwrap() as usize];
 
-        let ffi_return = simpleapi::ServerSecretParams_signDeterministic(
-            &server_secret_params,
+        let ffi_return = simpleapi::GroupSecretParams_encryptUuidRandomizedDeterministic(
+            &group_secret_params,
             &randomness,
-            &message,
-            &mut notary_signature,
+            &uuid,
+            &mut randomized_uuid_ciphertext,
         );
         if ffi_return != FFI_RETURN_OK {
             return ffi_return;
         }
 
-        env.set_byte_array_region(notarySignatureOut, 0, &u8toi8(notary_signature)[..])
-            .unwrap();
+        env.set_byte_array_region(
+            randomizedUuidCiphertextOut,
+            0,
+            &u8toi8(randomized_uuid_ciphertext)[..],
+        )
+        .unwrap();
         FFI_RETURN_OK
     });
 
@@ -614,35 +689,29 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverSecretParam
 }
 
 #[no_mangle]
-pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverPublicParamsReceiveAuthCredentialJNI(
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_groupSecretParamsDecryptRandomizedUuidJNI(
     env: JNIEnv,
     _class: JClass,
-    serverPublicParams: jbyteArray,
-    uuid: jbyteArray,
-    redemptionTime: jint,
-    authCredentialResponse: jbyteArray,
-    authCredentialOut: jbyteArray,
+    groupSecretParams: jbyteArray,
+    randomizedUuidCiphertext: jbyteArray,
+    uuidOut: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
-        let server_public_params = env.convert_byte_array(serverPublicParams).unwrap();
-        let uuid = env.convert_byte_array(uuid).unwrap();
-        let redemption_time = redemptionTime as u32;
-        let auth_credential_response = env.convert_byte_array(authCredentialResponse).unwrap();
-        let mut auth_credential: Vec<u8> =
-            vec![0; env.get_array_length(authCredentialOut).unwrap() as usize];
+        let group_secret_params = env.convert_byte_array(groupSecretParams).unwrap();
+        let randomized_uuid_ciphertext = env.convert_byte_array(randomizedUuidCiphertext).unwrap();
+        let mut uuid: Vec<u8> = vec![0; env.get_array_length(uuidOut).unwrap() as usize];
 
-        let ffi_return = simpleapi::ServerPublicParams_receiveAuthCredential(
-            &server_public_params,
-            &uuid,
-            redemption_time,
-            &auth_credential_response,
-            &mut auth_credential,
+        let ffi_return = simpleapi::GroupSecretParams_decryptRandomizedUuid(
+            &group_secret_params,
+            &randomized_uuid_ciphertext,
+            &mut uuid,
         );
         if ffi_return != FFI_RETURN_OK {
             return ffi_return;
         }
 
-        env.set_byte_array_region(authCredentialOut, 0, &u8toi8(auth_credential)[..])
+        env.set_byte_array_region(uuidOut, 0, &u8toi8(uuid)[..])
             .unwrap();
         FFI_RETURN_OK
     });
@@ -654,39 +723,39 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverPublicParam
 }
 
 #[no_mangle]
-pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverPublicParamsCreateAuthCredentialPresentationDeterministicJNI(
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_groupSecretParamsCreateUuidCiphertextMatchProofDeterministicJNI(
     env: JNIEnv,
     _class: JClass,
-    serverPublicParams: jbyteArray,
-    randomness: jbyteArray,
     groupSecretParams: jbyteArray,
-    authCredential: jbyteArray,
-    authCredentialPresentationOut: jbyteArray,
+    randomness: jbyteArray,
+    uuidCiphertext: jbyteArray,
+    randomizedUuidCiphertext: jbyteArray,
+    uuidCiphertextMatchProofOut: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
-        let server_public_params = env.convert_byte_array(serverPublicParams).unwrap();
-        let randomness = env.convert_byte_array(randomness).unwrap();
         let group_secret_params = env.convert_byte_array(groupSecretParams).unwrap();
-        let auth_credential = env.convert_byte_array(authCredential).unwrap();
-        let mut auth_credential_presentation: Vec<u8> =
-            vec![0; env.get_array_length(authCredentialPresentationOut).unwrap() as usize];
+        let randomness = env.convert_byte_array(randomness).unwrap();
+        let uuid_ciphertext = env.convert_byte_array(uuidCiphertext).unwrap();
+        let randomized_uuid_ciphertext = env.convert_byte_array(randomizedUuidCiphertext).unwrap();
+        let mut uuid_ciphertext_match_proof: Vec<u8> =
+            vec![0; env.get_array_length(uuidCiphertextMatchProofOut).unwrap() as usize];
 
-        let ffi_return =
-            simpleapi::ServerPublicParams_createAuthCredentialPresentationDeterministic(
-                &server_public_params,
-                &randomness,
-                &group_secret_params,
-                &auth_credential,
-                &mut auth_credential_presentation,
-            );
+        let ffi_return = simpleapi::GroupSecretParams_createUuidCiphertextMatchProofDeterministic(
+            &group_secret_params,
+            &randomness,
+            &uuid_ciphertext,
+            &randomized_uuid_ciphertext,
+            &mut uuid_ciphertext_match_proof,
+        );
         if ffi_return != FFI_RETURN_OK {
             return ffi_return;
         }
 
         env.set_byte_array_region(
-            authCredentialPresentationOut,
+            uuidCiphertextMatchProofOut,
             0,
-            &u8toi8(auth_credential_presentation)[..],
+            &u8toi8(uuid_ciphertext_match_proof)[..],
         )
         .unwrap();
         FFI_RETURN_OK
@@ -699,45 +768,37 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverPublicParam
 }
 
 #[no_mangle]
-pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverPublicParamsCreateProfileKeyCredentialRequestContextDeterministicJNI(
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_groupSecretParamsEncryptBlobWithAdDeterministicJNI(
     env: JNIEnv,
     _class: JClass,
-    serverPublicParams: jbyteArray,
+    groupSecretParams: jbyteArray,
     randomness: jbyteArray,
-    uuid: jbyteArray,
-    profileKey: jbyteArray,
-    profileKeyCredentialRequestContextOut: jbyteArray,
+    plaintext: jbyteArray,
+    associatedData: jbyteArray,
+    blobCiphertextOut: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
-        let server_public_params = env.convert_byte_array(serverPublicParams).unwrap();
+        let group_secret_params = env.convert_byte_array(groupSecretParams).unwrap();
         let randomness = env.convert_byte_array(randomness).unwrap();
-        let uuid = env.convert_byte_array(uuid).unwrap();
-        let profile_key = env.convert_byte_array(profileKey).unwrap();
-        let mut profile_key_credential_request_context: Vec<u8> = vec![
-            0;
-            env.get_array_length(profileKeyCredentialRequestContextOut)
-                .unwrap()
-                as usize
-        ];
+        let plaintext = env.convert_byte_array(plaintext).unwrap();
+        let associated_data = env.convert_byte_array(associatedData).unwrap();
+        let mut blob_ciphertext: Vec<u8> =
+            vec![0; env.get_array_length(blobCiphertextOut).unwrap() as usize];
 
-        let ffi_return =
-            simpleapi::ServerPublicParams_createProfileKeyCredentialRequestContextDeterministic(
-                &server_public_params,
-                &randomness,
-                &uuid,
-                &profile_key,
-                &mut profile_key_credential_request_context,
-            );
+        let ffi_return = simpleapi::GroupSecretParams_encryptBlobWithAdDeterministic(
+            &group_secret_params,
+            &randomness,
+            &plaintext,
+            &associated_data,
+            &mut blob_ciphertext,
+        );
         if ffi_return != FFI_RETURN_OK {
             return ffi_return;
         }
 
-        env.set_byte_array_region(
-            profileKeyCredentialRequestContextOut,
-            0,
-            &u8toi8(profile_key_credential_request_context)[..],
-        )
-        .unwrap();
+        env.set_byte_array_region(blobCiphertextOut, 0, &u8toi8(blob_ciphertext)[..])
+            .unwrap();
         FFI_RETURN_OK
     });
 
@@ -748,41 +809,33 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverPublicParam
 }
 
 #[no_mangle]
-pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverPublicParamsReceiveProfileKeyCredentialJNI(
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_groupSecretParamsDecryptBlobWithAdJNI(
     env: JNIEnv,
     _class: JClass,
-    serverPublicParams: jbyteArray,
-    profileKeyCredentialRequestContext: jbyteArray,
-    profileKeyCredentialResponse: jbyteArray,
-    profileKeyCredentialOut: jbyteArray,
+    groupSecretParams: jbyteArray,
+    blobCiphertext: jbyteArray,
+    associatedData: jbyteArray,
+    plaintextOut: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
-        let server_public_params = env.convert_byte_array(serverPublicParams).unwrap();
-        let profile_key_credential_request_context = env
-            .convert_byte_array(profileKeyCredentialRequestContext)
-            .unwrap();
-        let profile_key_credential_response = env
-            .convert_byte_array(profileKeyCredentialResponse)
-            .unwrap();
-        let mut profile_key_credential: Vec<u8> =
-            vec![0; env.get_array_length(profileKeyCredentialOut).unwrap() as usize];
+        let group_secret_params = env.convert_byte_array(groupSecretParams).unwrap();
+        let blob_ciphertext = env.convert_byte_array(blobCiphertext).unwrap();
+        let associated_data = env.convert_byte_array(associatedData).unwrap();
+        let mut plaintext: Vec<u8> = vec![0; env.get_array_length(plaintextOut).unwrap() as usize];
 
-        let ffi_return = simpleapi::ServerPublicParams_receiveProfileKeyCredential(
-            &server_public_params,
-            &profile_key_credential_request_context,
-            &profile_key_credential_response,
-            &mut profile_key_credential,
+        let ffi_return = simpleapi::GroupSecretParams_decryptBlobWithAd(
+            &group_secret_params,
+            &blob_ciphertext,
+            &associated_data,
+            &mut plaintext,
         );
         if ffi_return != FFI_RETURN_OK {
             return ffi_return;
         }
 
-        env.set_byte_array_region(
-            profileKeyCredentialOut,
-            0,
-            &u8toi8(profile_key_credential)[..],
-        )
-        .unwrap();
+        env.set_byte_array_region(plaintextOut, 0, &u8toi8(plaintext)[..])
+            .unwrap();
         FFI_RETURN_OK
     });
 
@@ -793,45 +846,28 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverPublicParam
 }
 
 #[no_mangle]
-pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverPublicParamsCreateProfileKeyCredentialPresentationDeterministicJNI(
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverSecretParamsGenerateDeterministicJNI(
     env: JNIEnv,
     _class: JClass,
-    serverPublicParams: jbyteArray,
     randomness: jbyteArray,
-    groupSecretParams: jbyteArray,
-    profileKeyCredential: jbyteArray,
-    profileKeyCredentialPresentationOut: jbyteArray,
+    serverSecretParamsOut: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
-        let server_public_params = env.convert_byte_array(serverPublicParams).unwrap();
         let randomness = env.convert_byte_array(randomness).unwrap();
-        let group_secret_params = env.convert_byte_array(groupSecretParams).unwrap();
-        let profile_key_credential = env.convert_byte_array(profileKeyCredential).unwrap();
-        let mut profile_key_credential_presentation: Vec<u8> = vec![
-            0;
-            env.get_array_length(profileKeyCredentialPresentationOut)
-                .unwrap()
-                as usize
-        ];
+        let mut server_secret_params: Vec<u8> =
+            vec![0; env.get_array_length(serverSecretParamsOut).unwrap() as usize];
 
-        let ffi_return =
-            simpleapi::ServerPublicParams_createProfileKeyCredentialPresentationDeterministic(
-                &server_public_params,
-                &randomness,
-                &group_secret_params,
-                &profile_key_credential,
-                &mut profile_key_credential_presentation,
-            );
+        let ffi_return = simpleapi::ServerSecretParams_generateDeterministic(
+            &randomness,
+            &mut server_secret_params,
+        );
         if ffi_return != FFI_RETURN_OK {
             return ffi_return;
         }
 
-        env.set_byte_array_region(
-            profileKeyCredentialPresentationOut,
-            0,
-            &u8toi8(profile_key_credential_presentation)[..],
-        )
-        .unwrap();
+        env.set_byte_array_region(serverSecretParamsOut, 0, &u8toi8(server_secret_params)[..])
+            .unwrap();
         FFI_RETURN_OK
     });
 
@@ -842,38 +878,174 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverPublicParam
 }
 
 #[no_mangle]
-pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverSecretParamsIssueAuthCredentialDeterministicJNI(
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverSecretParamsCheckValidContentsJNI(
+    env: JNIEnv,
+    _class: JClass,
+    serverSecretParams: jbyteArray,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let server_secret_params = env.convert_byte_array(serverSecretParams).unwrap();
+
+        let ffi_return = simpleapi::ServerSecretParams_checkValidContents(&server_secret_params);
+        if ffi_return != FFI_RETURN_OK {
+            return ffi_return;
+        }
+        FFI_RETURN_OK
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverSecretParamsGetPublicParamsJNI(
+    env: JNIEnv,
+    _class: JClass,
+    serverSecretParams: jbyteArray,
+    serverPublicParamsOut: jbyteArray,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let server_secret_params = env.convert_byte_array(serverSecretParams).unwrap();
+        let mut server_public_params: Vec<u8> =
+            vec![0; env.get_array_length(serverPublicParamsOut).unwrap() as usize];
+
+        let ffi_return = simpleapi::ServerSecretParams_getPublicParams(
+            &server_secret_params,
+            &mut server_public_params,
+        );
+        if ffi_return != FFI_RETURN_OK {
+            return ffi_return;
+        }
+
+        env.set_byte_array_region(serverPublicParamsOut, 0, &u8toi8(server_public_params)[..])
+            .unwrap();
+        FFI_RETURN_OK
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverSecretParamsSignDeterministicJNI(
     env: JNIEnv,
     _class: JClass,
     serverSecretParams: jbyteArray,
     randomness: jbyteArray,
-    uuid: jbyteArray,
-    redemptionTime: jint,
-    authCredentialResponseOut: jbyteArray,
+    message: jbyteArray,
+    notarySignatureOut: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let server_secret_params = env.convert_byte_array(serverSecretParams).unwrap();
         let randomness = env.convert_byte_array(randomness).unwrap();
-        let uuid = env.convert_byte_array(uuid).unwrap();
-        let redemption_time = redemptionTime as u32;
-        let mut auth_credential_response: Vec<u8> =
-            vec![0; env.get_array_length(authCredentialResponseOut).unwrap() as usize];
+        let message = env.convert_byte_array(message).unwrap();
+        let mut notary_signature: Vec<u8> =
+            vec![0; env.get_array_length(notarySignatureOut).unwrap() as usize];
 
-        let ffi_return = simpleapi::ServerSecretParams_issueAuthCredentialDeterministic(
+        let ffi_return = simpleapi::ServerSecretParams_signDeterministic(
             &server_secret_params,
             &randomness,
+            &message,
+            &mut notary_signature,
+        );
+        if ffi_return != FFI_RETURN_OK {
+            return ffi_return;
+        }
+
+        env.set_byte_array_region(notarySignatureOut, 0, &u8toi8(notary_signature)[..])
+            .unwrap();
+        FFI_RETURN_OK
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverPublicParamsReceiveAuthCredentialJNI(
+    env: JNIEnv,
+    _class: JClass,
+    serverPublicParams: jbyteArray,
+    uuid: jbyteArray,
+    redemptionTime: jint,
+    authCredentialResponse: jbyteArray,
+    authCredentialOut: jbyteArray,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let server_public_params = env.convert_byte_array(serverPublicParams).unwrap();
+        let uuid = env.convert_byte_array(uuid).unwrap();
+        let redemption_time = redemptionTime as u32;
+        let auth_credential_response = env.convert_byte_array(authCredentialResponse).unwrap();
+        let mut auth_credential: Vec<u8> =
+            vec![0; env.get_array_length(authCredentialOut).unwrap() as usize];
+
+        let ffi_return = simpleapi::ServerPublicParams_receiveAuthCredential(
+            &server_public_params,
             &uuid,
             redemption_time,
-            &mut auth_credential_response,
+            &auth_credential_response,
+            &mut auth_credential,
         );
         if ffi_return != FFI_RETURN_OK {
             return ffi_return;
         }
 
+        env.set_byte_array_region(authCredentialOut, 0, &u8toi8(auth_credential)[..])
+            .unwrap();
+        FFI_RETURN_OK
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverPublicParamsCreateAuthCredentialPresentationDeterministicJNI(
+    env: JNIEnv,
+    _class: JClass,
+    serverPublicParams: jbyteArray,
+    randomness: jbyteArray,
+    groupSecretParams: jbyteArray,
+    authCredential: jbyteArray,
+    authCredentialPresentationOut: jbyteArray,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let server_public_params = env.convert_byte_array(serverPublicParams).unwrap();
+        let randomness = env.convert_byte_array(randomness).unwrap();
+        let group_secret_params = env.convert_byte_array(groupSecretParams).unwrap();
+        let auth_credential = env.convert_byte_array(authCredential).unwrap();
+        let mut auth_credential_presentation: Vec<u8> =
+            vec![0; env.get_array_length(authCredentialPresentationOut).unwrap() as usize];
+
+        let ffi_return =
+            simpleapi::ServerPublicParams_createAuthCredentialPresentationDeterministic(
+                &server_public_params,
+                &randomness,
+                &group_secret_params,
+                &auth_credential,
+                &mut auth_credential_presentation,
+            );
+        if ffi_return != FFI_RETURN_OK {
+            return ffi_return;
+        }
+
         env.set_byte_array_region(
-            authCredentialResponseOut,
+            authCredentialPresentationOut,
             0,
-            &u8toi8(auth_credential_response)[..],
+            &u8toi8(auth_credential_presentation)[..],
         )
         .unwrap();
         FFI_RETURN_OK
@@ -886,27 +1058,45 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverSecretParam
 }
 
 #[no_mangle]
-pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverSecretParamsVerifyAuthCredentialPresentationJNI(
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverPublicParamsCreateAuthCredentialPresentationWithContextDeterministicJNI(
     env: JNIEnv,
     _class: JClass,
-    serverSecretParams: jbyteArray,
-    groupPublicParams: jbyteArray,
-    authCredentialPresentation: jbyteArray,
+    serverPublicParams: jbyteArray,
+    randomness: jbyteArray,
+    groupSecretParams: jbyteArray,
+    authCredential: jbyteArray,
+    context: jbyteArray,
+    authCredentialPresentationOut: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
-        let server_secret_params = env.convert_byte_array(serverSecretParams).unwrap();
-        let group_public_params = env.convert_byte_array(groupPublicParams).unwrap();
-        let auth_credential_presentation =
-            env.convert_byte_array(authCredentialPresentation).unwrap();
+        let server_public_params = env.convert_byte_array(serverPublicParams).unwrap();
+        let randomness = env.convert_byte_array(randomness).unwrap();
+        let group_secret_params = env.convert_byte_array(groupSecretParams).unwrap();
+        let auth_credential = env.convert_byte_array(authCredential).unwrap();
+        let context = env.convert_byte_array(context).unwrap();
+        let mut auth_credential_presentation: Vec<u8> =
+            vec![0; env.get_array_length(authCredentialPresentationOut).unwrap() as usize];
 
-        let ffi_return = simpleapi::ServerSecretParams_verifyAuthCredentialPresentation(
-            &server_secret_params,
-            &group_public_params,
-            &auth_credential_presentation,
-        );
+        let ffi_return =
+            simpleapi::ServerPublicParams_createAuthCredentialPresentationWithContextDeterministic(
+                &server_public_params,
+                &randomness,
+                &group_secret_params,
+                &auth_credential,
+                &context,
+                &mut auth_credential_presentation,
+            );
         if ffi_return != FFI_RETURN_OK {
             return ffi_return;
         }
+
+        env.set_byte_array_region(
+            authCredentialPresentationOut,
+            0,
+            &u8toi8(auth_credential_presentation)[..],
+        )
+        .unwrap();
         FFI_RETURN_OK
     });
 
@@ -917,45 +1107,38 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverSecretParam
 }
 
 #[no_mangle]
-pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverSecretParamsIssueProfileKeyCredentialDeterministicJNI(
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverPublicParamsCreateRateLimitCredentialRequestContextDeterministicJNI(
     env: JNIEnv,
     _class: JClass,
-    serverSecretParams: jbyteArray,
+    serverPublicParams: jbyteArray,
     randomness: jbyteArray,
-    profileKeyCredentialRequest: jbyteArray,
-    uuid: jbyteArray,
-    profileKeyCommitment: jbyteArray,
-    profileKeyCredentialResponseOut: jbyteArray,
+    rateLimitCredentialRequestContextOut: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
-        let server_secret_params = env.convert_byte_array(serverSecretParams).unwrap();
+        let server_public_params = env.convert_byte_array(serverPublicParams).unwrap();
         let randomness = env.convert_byte_array(randomness).unwrap();
-        let profile_key_credential_request =
-            env.convert_byte_array(profileKeyCredentialRequest).unwrap();
-        let uuid = env.convert_byte_array(uuid).unwrap();
-        let profile_key_commitment = env.convert_byte_array(profileKeyCommitment).unwrap();
-        let mut profile_key_credential_response: Vec<u8> = vec![
+        let mut rate_limit_credential_request_context: Vec<u8> = vec![
             0;
-            env.get_array_length(profileKeyCredentialResponseOut)
-                .unwrap() as usize
+            env.get_array_length(rateLimitCredentialRequestContextOut)
+                .unwrap()
+                as usize
         ];
 
-        let ffi_return = simpleapi::ServerSecretParams_issueProfileKeyCredentialDeterministic(
-            &server_secret_params,
-            &randomness,
-            &profile_key_credential_request,
-            &uuid,
-            &profile_key_commitment,
-            &mut profile_key_credential_response,
-        );
+        let ffi_return =
+            simpleapi::ServerPublicParams_createRateLimitCredentialRequestContextDeterministic(
+                &server_public_params,
+                &randomness,
+                &mut rate_limit_credential_request_context,
+            );
         if ffi_return != FFI_RETURN_OK {
             return ffi_return;
         }
 
         env.set_byte_array_region(
-            profileKeyCredentialResponseOut,
+            rateLimitCredentialRequestContextOut,
             0,
-            &u8toi8(profile_key_credential_response)[..],
+            &u8toi8(rate_limit_credential_request_context)[..],
         )
         .unwrap();
         FFI_RETURN_OK
@@ -968,28 +1151,41 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverSecretParam
 }
 
 #[no_mangle]
-pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverSecretParamsVerifyProfileKeyCredentialPresentationJNI(
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverPublicParamsReceiveRateLimitCredentialJNI(
     env: JNIEnv,
     _class: JClass,
-    serverSecretParams: jbyteArray,
-    groupPublicParams: jbyteArray,
-    profileKeyCredentialPresentation: jbyteArray,
+    serverPublicParams: jbyteArray,
+    rateLimitCredentialRequestContext: jbyteArray,
+    rateLimitCredentialResponse: jbyteArray,
+    rateLimitCredentialOut: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
-        let server_secret_params = env.convert_byte_array(serverSecretParams).unwrap();
-        let group_public_params = env.convert_byte_array(groupPublicParams).unwrap();
-        let profile_key_credential_presentation = env
-            .convert_byte_array(profileKeyCredentialPresentation)
+        let server_public_params = env.convert_byte_array(serverPublicParams).unwrap();
+        let rate_limit_credential_request_context = env
+            .convert_byte_array(rateLimitCredentialRequestContext)
             .unwrap();
+        let rate_limit_credential_response =
+            env.convert_byte_array(rateLimitCredentialResponse).unwrap();
+        let mut rate_limit_credential: Vec<u8> =
+            vec![0; env.get_array_length(rateLimitCredentialOut).unwrap() as usize];
 
-        let ffi_return = simpleapi::ServerSecretParams_verifyProfileKeyCredentialPresentation(
-            &server_secret_params,
-            &group_public_params,
-            &profile_key_credential_presentation,
+        let ffi_return = simpleapi::ServerPublicParams_receiveRateLimitCredential(
+            &server_public_params,
+            &rate_limit_credential_request_context,
+            &rate_limit_credential_response,
+            &mut rate_limit_credential,
         );
         if ffi_return != FFI_RETURN_OK {
             return ffi_return;
         }
+
+        env.set_byte_array_region(
+            rateLimitCredentialOut,
+            0,
+            &u8toi8(rate_limit_credential)[..],
+        )
+        .unwrap();
         FFI_RETURN_OK
     });
 
@@ -1000,11 +1196,742 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverSecretParam
 }
 
 #[no_mangle]
-pub extern "system" fn Java_org_signal_zkgroup_internal_Native_groupPublicParamsCheckValidContentsJNI(
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverPublicParamsCreateRateLimitTokenDeterministicJNI(
     env: JNIEnv,
     _class: JClass,
-    groupPublicParams: jbyteArray,
+    serverPublicParams: jbyteArray,
+    randomness: jbyteArray,
+    rateLimitCredential: jbyteArray,
+    rateLimitTokenOut: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let server_public_params = env.convert_byte_array(serverPublicParams).unwrap();
+        let randomness = env.convert_byte_array(randomness).unwrap();
+        let rate_limit_credential = env.convert_byte_array(rateLimitCredential).unwrap();
+        let mut rate_limit_token: Vec<u8> =
+            vec![0; env.get_array_length(rateLimitTokenOut).unwrap() as usize];
+
+        let ffi_return = simpleapi::ServerPublicParams_createRateLimitTokenDeterministic(
+            &server_public_params,
+            &randomness,
+            &rate_limit_credential,
+            &mut rate_limit_token,
+        );
+        if ffi_return != FFI_RETURN_OK {
+            return ffi_return;
+        }
+
+        env.set_byte_array_region(rateLimitTokenOut, 0, &u8toi8(rate_limit_token)[..])
+            .unwrap();
+        FFI_RETURN_OK
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverPublicParamsCreateProfileKeyCredentialRequestContextDeterministicJNI(
+    env: JNIEnv,
+    _class: JClass,
+    serverPublicParams: jbyteArray,
+    randomness: jbyteArray,
+    uuid: jbyteArray,
+    profileKey: jbyteArray,
+    profileKeyCredentialRequestContextOut: jbyteArray,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let server_public_params = env.convert_byte_array(serverPublicParams).unwrap();
+        let randomness = env.convert_byte_array(randomness).unwrap();
+        let uuid = env.convert_byte_array(uuid).unwrap();
+        let profile_key = env.convert_byte_array(profileKey).unwrap();
+        let mut profile_key_credential_request_context: Vec<u8> = vec![
+            0;
+            env.get_array_length(profileKeyCredentialRequestContextOut)
+                .unwrap()
+                as usize
+        ];
+
+        let ffi_return =
+            simpleapi::ServerPublicParams_createProfileKeyCredentialRequestContextDeterministic(
+                &server_public_params,
+                &randomness,
+                &uuid,
+                &profile_key,
+                &mut profile_key_credential_request_context,
+            );
+        if ffi_return != FFI_RETURN_OK {
+            return ffi_return;
+        }
+
+        env.set_byte_array_region(
+            profileKeyCredentialRequestContextOut,
+            0,
+            &u8toi8(profile_key_credential_request_context)[..],
+        )
+        .unwrap();
+        FFI_RETURN_OK
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverPublicParamsReceiveProfileKeyCredentialJNI(
+    env: JNIEnv,
+    _class: JClass,
+    serverPublicParams: jbyteArray,
+    profileKeyCredentialRequestContext: jbyteArray,
+    profileKeyCredentialResponse: jbyteArray,
+    profileKeyCredentialOut: jbyteArray,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let server_public_params = env.convert_byte_array(serverPublicParams).unwrap();
+        let profile_key_credential_request_context = env
+            .convert_byte_array(profileKeyCredentialRequestContext)
+            .unwrap();
+        let profile_key_credential_response = env
+            .convert_byte_array(profileKeyCredentialResponse)
+            .unwrap();
+        let mut profile_key_credential: Vec<u8> =
+            vec![0; env.get_array_length(profileKeyCredentialOut).unwrap() as usize];
+
+        let ffi_return = simpleapi::ServerPublicParams_receiveProfileKeyCredential(
+            &server_public_params,
+            &profile_key_credential_request_context,
+            &profile_key_credential_response,
+            &mut profile_key_credential,
+        );
+        if ffi_return != FFI_RETURN_OK {
+            return ffi_return;
+        }
+
+        env.set_byte_array_region(
+            profileKeyCredentialOut,
+            0,
+            &u8toi8(profile_key_credential)[..],
+        )
+        .unwrap();
+        FFI_RETURN_OK
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverPublicParamsCreateProfileKeyCredentialPresentationDeterministicJNI(
+    env: JNIEnv,
+    _class: JClass,
+    serverPublicParams: jbyteArray,
+    randomness: jbyteArray,
+    groupSecretParams: jbyteArray,
+    profileKeyCredential: jbyteArray,
+    profileKeyCredentialPresentationOut: jbyteArray,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let server_public_params = env.convert_byte_array(serverPublicParams).unwrap();
+        let randomness = env.convert_byte_array(randomness).unwrap();
+        let group_secret_params = env.convert_byte_array(groupSecretParams).unwrap();
+        let profile_key_credential = env.convert_byte_array(profileKeyCredential).unwrap();
+        let mut profile_key_credential_presentation: Vec<u8> = vec![
+            0;
+            env.get_array_length(profileKeyCredentialPresentationOut)
+                .unwrap()
+                as usize
+        ];
+
+        let ffi_return =
+            simpleapi::ServerPublicParams_createProfileKeyCredentialPresentationDeterministic(
+                &server_public_params,
+                &randomness,
+                &group_secret_params,
+                &profile_key_credential,
+                &mut profile_key_credential_presentation,
+            );
+        if ffi_return != FFI_RETURN_OK {
+            return ffi_return;
+        }
+
+        env.set_byte_array_region(
+            profileKeyCredentialPresentationOut,
+            0,
+            &u8toi8(profile_key_credential_presentation)[..],
+        )
+        .unwrap();
+        FFI_RETURN_OK
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverPublicParamsCreateProfileKeyCredentialPresentationWithContextDeterministicJNI(
+    env: JNIEnv,
+    _class: JClass,
+    serverPublicParams: jbyteArray,
+    randomness: jbyteArray,
+    groupSecretParams: jbyteArray,
+    profileKeyCredential: jbyteArray,
+    context: jbyteArray,
+    profileKeyCredentialPresentationOut: jbyteArray,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let server_public_params = env.convert_byte_array(serverPublicParams).unwrap();
+        let randomness = env.convert_byte_array(randomness).unwrap();
+        let group_secret_params = env.convert_byte_array(groupSecretParams).unwrap();
+        let profile_key_credential = env.convert_byte_array(profileKeyCredential).unwrap();
+        let context = env.convert_byte_array(context).unwrap();
+        let mut profile_key_credential_presentation: Vec<u8> = vec![
+            0;
+            env.get_array_length(profileKeyCredentialPresentationOut)
+                .unwrap()
+                as usize
+        ];
+
+        let ffi_return = simpleapi::ServerPublicParams_createProfileKeyCredentialPresentationWithContextDeterministic(&server_public_params, &randomness, &group_secret_params, &profile_key_credential, &context,  &mut profile_key_credential_presentation);
+        if ffi_return != FFI_RETURN_OK {
+            return ffi_return;
+        }
+
+        env.set_byte_array_region(
+            profileKeyCredentialPresentationOut,
+            0,
+            &u8toi8(profile_key_credential_presentation)[..],
+        )
+        .unwrap();
+        FFI_RETURN_OK
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverPublicParamsCreateProfileKeyCredentialSelectivePresentationDeterministicJNI(
+    env: JNIEnv,
+    _class: JClass,
+    serverPublicParams: jbyteArray,
+    randomness: jbyteArray,
+    groupSecretParams: jbyteArray,
+    profileKeyCredential: jbyteArray,
+    profileKeyCredentialSelectivePresentationOut: jbyteArray,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let server_public_params = env.convert_byte_array(serverPublicParams).unwrap();
+        let randomness = env.convert_byte_array(randomness).unwrap();
+        let group_secret_params = env.convert_byte_array(groupSecretParams).unwrap();
+        let profile_key_credential = env.convert_byte_array(profileKeyCredential).unwrap();
+        let mut profile_key_credential_selective_presentation: Vec<u8> = vec![
+            0;
+            env.get_array_length(profileKeyCredentialSelectivePresentationOut)
+                .unwrap()
+                as usize
+        ];
+
+        let ffi_return = simpleapi::ServerPublicParams_createProfileKeyCredentialSelectivePresentationDeterministic(&server_public_params, &randomness, &group_secret_params, &profile_key_credential,  &mut profile_key_credential_selective_presentation);
+        if ffi_return != FFI_RETURN_OK {
+            return ffi_return;
+        }
+
+        env.set_byte_array_region(
+            profileKeyCredentialSelectivePresentationOut,
+            0,
+            &u8toi8(profile_key_credential_selective_presentation)[..],
+        )
+        .unwrap();
+        FFI_RETURN_OK
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverPublicParamsCreateProfileKeyCredentialSelectivePresentationWithContextDeterministicJNI(
+    env: JNIEnv,
+    _class: JClass,
+    serverPublicParams: jbyteArray,
+    randomness: jbyteArray,
+    groupSecretParams: jbyteArray,
+    profileKeyCredential: jbyteArray,
+    context: jbyteArray,
+    profileKeyCredentialSelectivePresentationOut: jbyteArray,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let server_public_params = env.convert_byte_array(serverPublicParams).unwrap();
+        let randomness = env.convert_byte_array(randomness).unwrap();
+        let group_secret_params = env.convert_byte_array(groupSecretParams).unwrap();
+        let profile_key_credential = env.convert_byte_array(profileKeyCredential).unwrap();
+        let context = env.convert_byte_array(context).unwrap();
+        let mut profile_key_credential_selective_presentation: Vec<u8> = vec![
+            0;
+            env.get_array_length(profileKeyCredentialSelectivePresentationOut)
+                .unwrap()
+                as usize
+        ];
+
+        let ffi_return = simpleapi::ServerPublicParams_createProfileKeyCredentialSelectivePresentationWithContextDeterministic(&server_public_params, &randomness, &group_secret_params, &profile_key_credential, &context,  &mut profile_key_credential_selective_presentation);
+        if ffi_return != FFI_RETURN_OK {
+            return ffi_return;
+        }
+
+        env.set_byte_array_region(
+            profileKeyCredentialSelectivePresentationOut,
+            0,
+            &u8toi8(profile_key_credential_selective_presentation)[..],
+        )
+        .unwrap();
+        FFI_RETURN_OK
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverSecretParamsIssueAuthCredentialDeterministicJNI(
+    env: JNIEnv,
+    _class: JClass,
+    serverSecretParams: jbyteArray,
+    randomness: jbyteArray,
+    uuid: jbyteArray,
+    redemptionTime: jint,
+    authCredentialResponseOut: jbyteArray,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let server_secret_params = env.convert_byte_array(serverSecretParams).unwrap();
+        let randomness = env.convert_byte_array(randomness).unwrap();
+        let uuid = env.convert_byte_array(uuid).unwrap();
+        let redemption_time = redemptionTime as u32;
+        let mut auth_credential_response: Vec<u8> =
+            vec![0; env.get_array_length(authCredentialResponseOut).unwrap() as usize];
+
+        let ffi_return = simpleapi::ServerSecretParams_issueAuthCredentialDeterministic(
+            &server_secret_params,
+            &randomness,
+            &uuid,
+            redemption_time,
+            &mut auth_credential_response,
+        );
+        if ffi_return != FFI_RETURN_OK {
+            return ffi_return;
+        }
+
+        env.set_byte_array_region(
+            authCredentialResponseOut,
+            0,
+            &u8toi8(auth_credential_response)[..],
+        )
+        .unwrap();
+        FFI_RETURN_OK
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverSecretParamsVerifyAuthCredentialPresentationJNI(
+    env: JNIEnv,
+    _class: JClass,
+    serverSecretParams: jbyteArray,
+    groupPublicParams: jbyteArray,
+    authCredentialPresentation: jbyteArray,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let server_secret_params = env.convert_byte_array(serverSecretParams).unwrap();
+        let group_public_params = env.convert_byte_array(groupPublicParams).unwrap();
+        let auth_credential_presentation =
+            env.convert_byte_array(authCredentialPresentation).unwrap();
+
+        let ffi_return = simpleapi::ServerSecretParams_verifyAuthCredentialPresentation(
+            &server_secret_params,
+            &group_public_params,
+            &auth_credential_presentation,
+        );
+        if ffi_return != FFI_RETURN_OK {
+            return ffi_return;
+        }
+        FFI_RETURN_OK
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverSecretParamsVerifyAuthCredentialPresentationWithContextJNI(
+    env: JNIEnv,
+    _class: JClass,
+    serverSecretParams: jbyteArray,
+    groupPublicParams: jbyteArray,
+    authCredentialPresentation: jbyteArray,
+    context: jbyteArray,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let server_secret_params = env.convert_byte_array(serverSecretParams).unwrap();
+        let group_public_params = env.convert_byte_array(groupPublicParams).unwrap();
+        let auth_credential_presentation =
+            env.convert_byte_array(authCredentialPresentation).unwrap();
+        let context = env.convert_byte_array(context).unwrap();
+
+        let ffi_return = simpleapi::ServerSecretParams_verifyAuthCredentialPresentationWithContext(
+            &server_secret_params,
+            &group_public_params,
+            &auth_credential_presentation,
+            &context,
+        );
+        if ffi_return != FFI_RETURN_OK {
+            return ffi_return;
+        }
+        FFI_RETURN_OK
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverSecretParamsIssueRateLimitCredentialDeterministicJNI(
+    env: JNIEnv,
+    _class: JClass,
+    serverSecretParams: jbyteArray,
+    randomness: jbyteArray,
+    rateLimitCredentialRequest: jbyteArray,
+    redemptionTime: jint,
+    rateLimitCredentialResponseOut: jbyteArray,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let server_secret_params = env.convert_byte_array(serverSecretParams).unwrap();
+        let randomness = env.convert_byte_array(randomness).unwrap();
+        let rate_limit_credential_request =
+            env.convert_byte_array(rateLimitCredentialRequest).unwrap();
+        let redemption_time = redemptionTime as u32;
+        let mut rate_limit_credential_response: Vec<u8> = vec![
+            0;
+            env.get_array_length(rateLimitCredentialResponseOut)
+                .unwrap() as usize
+        ];
+
+        let ffi_return = simpleapi::ServerSecretParams_issueRateLimitCredentialDeterministic(
+            &server_secret_params,
+            &randomness,
+            &rate_limit_credential_request,
+            redemption_time,
+            &mut rate_limit_credential_response,
+        );
+        if ffi_return != FFI_RETURN_OK {
+            return ffi_return;
+        }
+
+        env.set_byte_array_region(
+            rateLimitCredentialResponseOut,
+            0,
+            &u8toi8(rate_limit_credential_response)[..],
+        )
+        .unwrap();
+        FFI_RETURN_OK
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverSecretParamsVerifyRateLimitTokenJNI(
+    env: JNIEnv,
+    _class: JClass,
+    serverSecretParams: jbyteArray,
+    rateLimitToken: jbyteArray,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let server_secret_params = env.convert_byte_array(serverSecretParams).unwrap();
+        let rate_limit_token = env.convert_byte_array(rateLimitToken).unwrap();
+
+        let ffi_return = simpleapi::ServerSecretParams_verifyRateLimitToken(
+            &server_secret_params,
+            &rate_limit_token,
+        );
+        if ffi_return != FFI_RETURN_OK {
+            return ffi_return;
+        }
+        FFI_RETURN_OK
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverSecretParamsVerifyGroupInviteLinkPresentationJNI(
+    env: JNIEnv,
+    _class: JClass,
+    serverSecretParams: jbyteArray,
+    groupPublicParams: jbyteArray,
+    groupInviteLinkPasswordCommitment: jbyteArray,
+    groupInviteLinkPresentation: jbyteArray,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let server_secret_params = env.convert_byte_array(serverSecretParams).unwrap();
+        let group_public_params = env.convert_byte_array(groupPublicParams).unwrap();
+        let group_invite_link_password_commitment = env
+            .convert_byte_array(groupInviteLinkPasswordCommitment)
+            .unwrap();
+        let group_invite_link_presentation =
+            env.convert_byte_array(groupInviteLinkPresentation).unwrap();
+
+        let ffi_return = simpleapi::ServerSecretParams_verifyGroupInviteLinkPresentation(
+            &server_secret_params,
+            &group_public_params,
+            &group_invite_link_password_commitment,
+            &group_invite_link_presentation,
+        );
+        if ffi_return != FFI_RETURN_OK {
+            return ffi_return;
+        }
+        FFI_RETURN_OK
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverSecretParamsIssueProfileKeyCredentialDeterministicJNI(
+    env: JNIEnv,
+    _class: JClass,
+    serverSecretParams: jbyteArray,
+    randomness: jbyteArray,
+    profileKeyCredentialRequest: jbyteArray,
+    uuid: jbyteArray,
+    profileKeyCommitment: jbyteArray,
+    profileKeyCredentialResponseOut: jbyteArray,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let server_secret_params = env.convert_byte_array(serverSecretParams).unwrap();
+        let randomness = env.convert_byte_array(randomness).unwrap();
+        let profile_key_credential_request =
+            env.convert_byte_array(profileKeyCredentialRequest).unwrap();
+        let uuid = env.convert_byte_array(uuid).unwrap();
+        let profile_key_commitment = env.convert_byte_array(profileKeyCommitment).unwrap();
+        let mut profile_key_credential_response: Vec<u8> = vec![
+            0;
+            env.get_array_length(profileKeyCredentialResponseOut)
+                .unwrap() as usize
+        ];
+
+        let ffi_return = simpleapi::ServerSecretParams_issueProfileKeyCredentialDeterministic(
+            &server_secret_params,
+            &randomness,
+            &profile_key_credential_request,
+            &uuid,
+            &profile_key_commitment,
+            &mut profile_key_credential_response,
+        );
+        if ffi_return != FFI_RETURN_OK {
+            return ffi_return;
+        }
+
+        env.set_byte_array_region(
+            profileKeyCredentialResponseOut,
+            0,
+            &u8toi8(profile_key_credential_response)[..],
+        )
+        .unwrap();
+        FFI_RETURN_OK
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverSecretParamsVerifyProfileKeyCredentialPresentationJNI(
+    env: JNIEnv,
+    _class: JClass,
+    serverSecretParams: jbyteArray,
+    groupPublicParams: jbyteArray,
+    profileKeyCredentialPresentation: jbyteArray,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let server_secret_params = env.convert_byte_array(serverSecretParams).unwrap();
+        let group_public_params = env.convert_byte_array(groupPublicParams).unwrap();
+        let profile_key_credential_presentation = env
+            .convert_byte_array(profileKeyCredentialPresentation)
+            .unwrap();
+
+        let ffi_return = simpleapi::ServerSecretParams_verifyProfileKeyCredentialPresentation(
+            &server_secret_params,
+            &group_public_params,
+            &profile_key_credential_presentation,
+        );
+        if ffi_return != FFI_RETURN_OK {
+            return ffi_return;
+        }
+        FFI_RETURN_OK
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverSecretParamsVerifyProfileKeyCredentialPresentationWithContextJNI(
+    env: JNIEnv,
+    _class: JClass,
+    serverSecretParams: jbyteArray,
+    groupPublicParams: jbyteArray,
+    profileKeyCredentialPresentation: jbyteArray,
+    context: jbyteArray,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let server_secret_params = env.convert_byte_array(serverSecretParams).unwrap();
+        let group_public_params = env.convert_byte_array(groupPublicParams).unwrap();
+        let profile_key_credential_presentation = env
+            .convert_byte_array(profileKeyCredentialPresentation)
+            .unwrap();
+        let context = env.convert_byte_array(context).unwrap();
+
+        let ffi_return =
+            simpleapi::ServerSecretParams_verifyProfileKeyCredentialPresentationWithContext(
+                &server_secret_params,
+                &group_public_params,
+                &profile_key_credential_presentation,
+                &context,
+            );
+        if ffi_return != FFI_RETURN_OK {
+            return ffi_return;
+        }
+        FFI_RETURN_OK
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverSecretParamsVerifyProfileKeyCredentialSelectivePresentationJNI(
+    env: JNIEnv,
+    _class: JClass,
+    serverSecretParams: jbyteArray,
+    groupPublicParams: jbyteArray,
+    profileKeyCredentialSelectivePresentation: jbyteArray,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let server_secret_params = env.convert_byte_array(serverSecretParams).unwrap();
+        let group_public_params = env.convert_byte_array(groupPublicParams).unwrap();
+        let profile_key_credential_selective_presentation = env
+            .convert_byte_array(profileKeyCredentialSelectivePresentation)
+            .unwrap();
+
+        let ffi_return =
+            simpleapi::ServerSecretParams_verifyProfileKeyCredentialSelectivePresentation(
+                &server_secret_params,
+                &group_public_params,
+                &profile_key_credential_selective_presentation,
+            );
+        if ffi_return != FFI_RETURN_OK {
+            return ffi_return;
+        }
+        FFI_RETURN_OK
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverSecretParamsVerifyProfileKeyCredentialSelectivePresentationWithContextJNI(
+    env: JNIEnv,
+    _class: JClass,
+    serverSecretParams: jbyteArray,
+    groupPublicParams: jbyteArray,
+    profileKeyCredentialSelectivePresentation: jbyteArray,
+    context: jbyteArray,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let server_secret_params = env.convert_byte_array(serverSecretParams).unwrap();
+        let group_public_params = env.convert_byte_array(groupPublicParams).unwrap();
+        let profile_key_credential_selective_presentation = env
+            .convert_byte_array(profileKeyCredentialSelectivePresentation)
+            .unwrap();
+        let context = env.convert_byte_array(context).unwrap();
+
+        let ffi_return = simpleapi::ServerSecretParams_verifyProfileKeyCredentialSelectivePresentationWithContext(&server_secret_params, &group_public_params, &profile_key_credential_selective_presentation, &context);
+        if ffi_return != FFI_RETURN_OK {
+            return ffi_return;
+        }
+        FFI_RETURN_OK
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_groupPublicParamsCheckValidContentsJNI(
+    env: JNIEnv,
+    _class: JClass,
+    groupPublicParams: jbyteArray,
+) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let group_public_params = env.convert_byte_array(groupPublicParams).unwrap();
 
@@ -1022,27 +1949,281 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_groupPublicParams
 }
 
 #[no_mangle]
-pub extern "system" fn Java_org_signal_zkgroup_internal_Native_groupPublicParamsGetGroupIdentifierJNI(
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_groupPublicParamsGetGroupIdentifierJNI(
+    env: JNIEnv,
+    _class: JClass,
+    groupPublicParams: jbyteArray,
+    groupIdentifierOut: jbyteArray,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let group_public_params = env.convert_byte_array(groupPublicParams).unwrap();
+        let mut group_identifier: Vec<u8> =
+            vec![0; env.get_array_length(groupIdentifierOut).unwrap() as usize];
+
+        let ffi_return = simpleapi::GroupPublicParams_getGroupIdentifier(
+            &group_public_params,
+            &mut group_identifier,
+        );
+        if ffi_return != FFI_RETURN_OK {
+            return ffi_return;
+        }
+
+        env.set_byte_array_region(groupIdentifierOut, 0, &u8toi8(group_identifier)[..])
+            .unwrap();
+        FFI_RETURN_OK
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_groupPublicParamsVerifyUuidCiphertextMatchJNI(
+    env: JNIEnv,
+    _class: JClass,
+    groupPublicParams: jbyteArray,
+    uuidCiphertext: jbyteArray,
+    randomizedUuidCiphertext: jbyteArray,
+    uuidCiphertextMatchProof: jbyteArray,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let group_public_params = env.convert_byte_array(groupPublicParams).unwrap();
+        let uuid_ciphertext = env.convert_byte_array(uuidCiphertext).unwrap();
+        let randomized_uuid_ciphertext = env.convert_byte_array(randomizedUuidCiphertext).unwrap();
+        let uuid_ciphertext_match_proof = env.convert_byte_array(uuidCiphertextMatchProof).unwrap();
+
+        let ffi_return = simpleapi::GroupPublicParams_verifyUuidCiphertextMatch(
+            &group_public_params,
+            &uuid_ciphertext,
+            &randomized_uuid_ciphertext,
+            &uuid_ciphertext_match_proof,
+        );
+        if ffi_return != FFI_RETURN_OK {
+            return ffi_return;
+        }
+        FFI_RETURN_OK
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverPublicParamsCheckValidContentsJNI(
+    env: JNIEnv,
+    _class: JClass,
+    serverPublicParams: jbyteArray,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let server_public_params = env.convert_byte_array(serverPublicParams).unwrap();
+
+        let ffi_return = simpleapi::ServerPublicParams_checkValidContents(&server_public_params);
+        if ffi_return != FFI_RETURN_OK {
+            return ffi_return;
+        }
+        FFI_RETURN_OK
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverPublicParamsVerifySignatureJNI(
+    env: JNIEnv,
+    _class: JClass,
+    serverPublicParams: jbyteArray,
+    message: jbyteArray,
+    notarySignature: jbyteArray,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let server_public_params = env.convert_byte_array(serverPublicParams).unwrap();
+        let message = env.convert_byte_array(message).unwrap();
+        let notary_signature = env.convert_byte_array(notarySignature).unwrap();
+
+        let ffi_return = simpleapi::ServerPublicParams_verifySignature(
+            &server_public_params,
+            &message,
+            &notary_signature,
+        );
+        if ffi_return != FFI_RETURN_OK {
+            return ffi_return;
+        }
+        FFI_RETURN_OK
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_authCredentialResponseCheckValidContentsJNI(
+    env: JNIEnv,
+    _class: JClass,
+    authCredentialResponse: jbyteArray,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let auth_credential_response = env.convert_byte_array(authCredentialResponse).unwrap();
+
+        let ffi_return =
+            simpleapi::AuthCredentialResponse_checkValidContents(&auth_credential_response);
+        if ffi_return != FFI_RETURN_OK {
+            return ffi_return;
+        }
+        FFI_RETURN_OK
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_authCredentialCheckValidContentsJNI(
+    env: JNIEnv,
+    _class: JClass,
+    authCredential: jbyteArray,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let auth_credential = env.convert_byte_array(authCredential).unwrap();
+
+        let ffi_return = simpleapi::AuthCredential_checkValidContents(&auth_credential);
+        if ffi_return != FFI_RETURN_OK {
+            return ffi_return;
+        }
+        FFI_RETURN_OK
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_authCredentialPresentationCheckValidContentsJNI(
+    env: JNIEnv,
+    _class: JClass,
+    authCredentialPresentation: jbyteArray,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let auth_credential_presentation =
+            env.convert_byte_array(authCredentialPresentation).unwrap();
+
+        let ffi_return =
+            simpleapi::AuthCredentialPresentation_checkValidContents(&auth_credential_presentation);
+        if ffi_return != FFI_RETURN_OK {
+            return ffi_return;
+        }
+        FFI_RETURN_OK
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_authCredentialPresentationGetUuidCiphertextJNI(
+    env: JNIEnv,
+    _class: JClass,
+    authCredentialPresentation: jbyteArray,
+    uuidCiphertextOut: jbyteArray,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let auth_credential_presentation =
+            env.convert_byte_array(authCredentialPresentation).unwrap();
+        let mut uuid_ciphertext: Vec<u8> =
+            vec![0; env.get_array_length(uuidCiphertextOut).unwrap() as usize];
+
+        let ffi_return = simpleapi::AuthCredentialPresentation_getUuidCiphertext(
+            &auth_credential_presentation,
+            &mut uuid_ciphertext,
+        );
+        if ffi_return != FFI_RETURN_OK {
+            return ffi_return;
+        }
+
+        env.set_byte_array_region(uuidCiphertextOut, 0, &u8toi8(uuid_ciphertext)[..])
+            .unwrap();
+        FFI_RETURN_OK
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_authCredentialPresentationGetRedemptionTimeJNI(
+    env: JNIEnv,
+    _class: JClass,
+    authCredentialPresentation: jbyteArray,
+    redemptionTimeOut: jbyteArray,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let auth_credential_presentation =
+            env.convert_byte_array(authCredentialPresentation).unwrap();
+        let mut redemption_time: Vec<u8> =
+            vec![0; env.get_array_length(redemptionTimeOut).unwrap() as usize];
+
+        let ffi_return = simpleapi::AuthCredentialPresentation_getRedemptionTime(
+            &auth_credential_presentation,
+            &mut redemption_time,
+        );
+        if ffi_return != FFI_RETURN_OK {
+            return ffi_return;
+        }
+
+        env.set_byte_array_region(redemptionTimeOut, 0, &u8toi8(redemption_time)[..])
+            .unwrap();
+        FFI_RETURN_OK
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_rateLimitCredentialRequestContextCheckValidContentsJNI(
     env: JNIEnv,
     _class: JClass,
-    groupPublicParams: jbyteArray,
-    groupIdentifierOut: jbyteArray,
+    rateLimitCredentialRequestContext: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
-        let group_public_params = env.convert_byte_array(groupPublicParams).unwrap();
-        let mut group_identifier: Vec<u8> =
-            vec![0; env.get_array_length(groupIdentifierOut).unwrap() as usize];
+        let rate_limit_credential_request_context = env
+            .convert_byte_array(rateLimitCredentialRequestContext)
+            .unwrap();
 
-        let ffi_return = simpleapi::GroupPublicParams_getGroupIdentifier(
-            &group_public_params,
-            &mut group_identifier,
+        let ffi_return = simpleapi::RateLimitCredentialRequestContext_checkValidContents(
+            &rate_limit_credential_request_context,
         );
         if ffi_return != FFI_RETURN_OK {
             return ffi_return;
         }
-
-        env.set_byte_array_region(groupIdentifierOut, 0, &u8toi8(group_identifier)[..])
-            .unwrap();
         FFI_RETURN_OK
     });
 
@@ -1053,18 +2234,34 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_groupPublicParams
 }
 
 #[no_mangle]
-pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverPublicParamsCheckValidContentsJNI(
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_rateLimitCredentialRequestContextGetRequestJNI(
     env: JNIEnv,
     _class: JClass,
-    serverPublicParams: jbyteArray,
+    rateLimitCredentialRequestContext: jbyteArray,
+    rateLimitCredentialRequestOut: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
-        let server_public_params = env.convert_byte_array(serverPublicParams).unwrap();
+        let rate_limit_credential_request_context = env
+            .convert_byte_array(rateLimitCredentialRequestContext)
+            .unwrap();
+        let mut rate_limit_credential_request: Vec<u8> =
+            vec![0; env.get_array_length(rateLimitCredentialRequestOut).unwrap() as usize];
 
-        let ffi_return = simpleapi::ServerPublicParams_checkValidContents(&server_public_params);
+        let ffi_return = simpleapi::RateLimitCredentialRequestContext_getRequest(
+            &rate_limit_credential_request_context,
+            &mut rate_limit_credential_request,
+        );
         if ffi_return != FFI_RETURN_OK {
             return ffi_return;
         }
+
+        env.set_byte_array_region(
+            rateLimitCredentialRequestOut,
+            0,
+            &u8toi8(rate_limit_credential_request)[..],
+        )
+        .unwrap();
         FFI_RETURN_OK
     });
 
@@ -1075,22 +2272,18 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverPublicParam
 }
 
 #[no_mangle]
-pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverPublicParamsVerifySignatureJNI(
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_rateLimitCredentialRequestCheckValidContentsJNI(
     env: JNIEnv,
     _class: JClass,
-    serverPublicParams: jbyteArray,
-    message: jbyteArray,
-    notarySignature: jbyteArray,
+    rateLimitCredentialRequest: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
-        let server_public_params = env.convert_byte_array(serverPublicParams).unwrap();
-        let message = env.convert_byte_array(message).unwrap();
-        let notary_signature = env.convert_byte_array(notarySignature).unwrap();
+        let rate_limit_credential_request =
+            env.convert_byte_array(rateLimitCredentialRequest).unwrap();
 
-        let ffi_return = simpleapi::ServerPublicParams_verifySignature(
-            &server_public_params,
-            &message,
-            &notary_signature,
+        let ffi_return = simpleapi::RateLimitCredentialRequest_checkValidContents(
+            &rate_limit_credential_request,
         );
         if ffi_return != FFI_RETURN_OK {
             return ffi_return;
@@ -1105,16 +2298,19 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_serverPublicParam
 }
 
 #[no_mangle]
-pub extern "system" fn Java_org_signal_zkgroup_internal_Native_authCredentialResponseCheckValidContentsJNI(
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_rateLimitCredentialResponseCheckValidContentsJNI(
     env: JNIEnv,
     _class: JClass,
-    authCredentialResponse: jbyteArray,
+    rateLimitCredentialResponse: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
-        let auth_credential_response = env.convert_byte_array(authCredentialResponse).unwrap();
+        let rate_limit_credential_response =
+            env.convert_byte_array(rateLimitCredentialResponse).unwrap();
 
-        let ffi_return =
-            simpleapi::AuthCredentialResponse_checkValidContents(&auth_credential_response);
+        let ffi_return = simpleapi::RateLimitCredentialResponse_checkValidContents(
+            &rate_limit_credential_response,
+        );
         if ffi_return != FFI_RETURN_OK {
             return ffi_return;
         }
@@ -1128,15 +2324,16 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_authCredentialRes
 }
 
 #[no_mangle]
-pub extern "system" fn Java_org_signal_zkgroup_internal_Native_authCredentialCheckValidContentsJNI(
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_rateLimitCredentialCheckValidContentsJNI(
     env: JNIEnv,
     _class: JClass,
-    authCredential: jbyteArray,
+    rateLimitCredential: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
-        let auth_credential = env.convert_byte_array(authCredential).unwrap();
+        let rate_limit_credential = env.convert_byte_array(rateLimitCredential).unwrap();
 
-        let ffi_return = simpleapi::AuthCredential_checkValidContents(&auth_credential);
+        let ffi_return = simpleapi::RateLimitCredential_checkValidContents(&rate_limit_credential);
         if ffi_return != FFI_RETURN_OK {
             return ffi_return;
         }
@@ -1150,17 +2347,16 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_authCredentialChe
 }
 
 #[no_mangle]
-pub extern "system" fn Java_org_signal_zkgroup_internal_Native_authCredentialPresentationCheckValidContentsJNI(
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_rateLimitTokenCheckValidContentsJNI(
     env: JNIEnv,
     _class: JClass,
-    authCredentialPresentation: jbyteArray,
+    rateLimitToken: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
-        let auth_credential_presentation =
-            env.convert_byte_array(authCredentialPresentation).unwrap();
+        let rate_limit_token = env.convert_byte_array(rateLimitToken).unwrap();
 
-        let ffi_return =
-            simpleapi::AuthCredentialPresentation_checkValidContents(&auth_credential_presentation);
+        let ffi_return = simpleapi::RateLimitToken_checkValidContents(&rate_limit_token);
         if ffi_return != FFI_RETURN_OK {
             return ffi_return;
         }
@@ -1174,27 +2370,24 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_authCredentialPre
 }
 
 #[no_mangle]
-pub extern "system" fn Java_org_signal_zkgroup_internal_Native_authCredentialPresentationGetUuidCiphertextJNI(
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_rateLimitTokenGetTagJNI(
     env: JNIEnv,
     _class: JClass,
-    authCredentialPresentation: jbyteArray,
-    uuidCiphertextOut: jbyteArray,
+    rateLimitToken: jbyteArray,
+    rateLimitTagOut: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
-        let auth_credential_presentation =
-            env.convert_byte_array(authCredentialPresentation).unwrap();
-        let mut uuid_ciphertext: Vec<u8> =
-            vec![0; env.get_array_length(uuidCiphertextOut).unwrap() as usize];
+        let rate_limit_token = env.convert_byte_array(rateLimitToken).unwrap();
+        let mut rate_limit_tag: Vec<u8> =
+            vec![0; env.get_array_length(rateLimitTagOut).unwrap() as usize];
 
-        let ffi_return = simpleapi::AuthCredentialPresentation_getUuidCiphertext(
-            &auth_credential_presentation,
-            &mut uuid_ciphertext,
-        );
+        let ffi_return = simpleapi::RateLimitToken_getTag(&rate_limit_token, &mut rate_limit_tag);
         if ffi_return != FFI_RETURN_OK {
             return ffi_return;
         }
 
-        env.set_byte_array_region(uuidCiphertextOut, 0, &u8toi8(uuid_ciphertext)[..])
+        env.set_byte_array_region(rateLimitTagOut, 0, &u8toi8(rate_limit_tag)[..])
             .unwrap();
         FFI_RETURN_OK
     });
@@ -1206,22 +2399,20 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_authCredentialPre
 }
 
 #[no_mangle]
-pub extern "system" fn Java_org_signal_zkgroup_internal_Native_authCredentialPresentationGetRedemptionTimeJNI(
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_rateLimitTokenGetRedemptionTimeJNI(
     env: JNIEnv,
     _class: JClass,
-    authCredentialPresentation: jbyteArray,
+    rateLimitToken: jbyteArray,
     redemptionTimeOut: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
-        let auth_credential_presentation =
-            env.convert_byte_array(authCredentialPresentation).unwrap();
+        let rate_limit_token = env.convert_byte_array(rateLimitToken).unwrap();
         let mut redemption_time: Vec<u8> =
             vec![0; env.get_array_length(redemptionTimeOut).unwrap() as usize];
 
-        let ffi_return = simpleapi::AuthCredentialPresentation_getRedemptionTime(
-            &auth_credential_presentation,
-            &mut redemption_time,
-        );
+        let ffi_return =
+            simpleapi::RateLimitToken_getRedemptionTime(&rate_limit_token, &mut redemption_time);
         if ffi_return != FFI_RETURN_OK {
             return ffi_return;
         }
@@ -1243,6 +2434,7 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_profileKeyCredent
     _class: JClass,
     profileKeyCredentialRequestContext: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let profile_key_credential_request_context = env
             .convert_byte_array(profileKeyCredentialRequestContext)
@@ -1270,6 +2462,7 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_profileKeyCredent
     profileKeyCredentialRequestContext: jbyteArray,
     profileKeyCredentialRequestOut: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let profile_key_credential_request_context = env
             .convert_byte_array(profileKeyCredentialRequestContext)
@@ -1309,6 +2502,7 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_profileKeyCredent
     _class: JClass,
     profileKeyCredentialRequest: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let profile_key_credential_request =
             env.convert_byte_array(profileKeyCredentialRequest).unwrap();
@@ -1334,6 +2528,7 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_profileKeyCredent
     _class: JClass,
     profileKeyCredentialResponse: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let profile_key_credential_response = env
             .convert_byte_array(profileKeyCredentialResponse)
@@ -1360,6 +2555,7 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_profileKeyCredent
     _class: JClass,
     profileKeyCredential: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let profile_key_credential = env.convert_byte_array(profileKeyCredential).unwrap();
 
@@ -1383,6 +2579,7 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_profileKeyCredent
     _class: JClass,
     profileKeyCredentialPresentation: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let profile_key_credential_presentation = env
             .convert_byte_array(profileKeyCredentialPresentation)
@@ -1410,6 +2607,7 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_profileKeyCredent
     profileKeyCredentialPresentation: jbyteArray,
     uuidCiphertextOut: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let profile_key_credential_presentation = env
             .convert_byte_array(profileKeyCredentialPresentation)
@@ -1443,6 +2641,7 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_profileKeyCredent
     profileKeyCredentialPresentation: jbyteArray,
     profileKeyCiphertextOut: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let profile_key_credential_presentation = env
             .convert_byte_array(profileKeyCredentialPresentation)
@@ -1473,12 +2672,203 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_profileKeyCredent
     }
 }
 
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_profileKeyCredentialSelectivePresentationCheckValidContentsJNI(
+    env: JNIEnv,
+    _class: JClass,
+    profileKeyCredentialSelectivePresentation: jbyteArray,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let profile_key_credential_selective_presentation = env
+            .convert_byte_array(profileKeyCredentialSelectivePresentation)
+            .unwrap();
+
+        let ffi_return = simpleapi::ProfileKeyCredentialSelectivePresentation_checkValidContents(
+            &profile_key_credential_selective_presentation,
+        );
+        if ffi_return != FFI_RETURN_OK {
+            return ffi_return;
+        }
+        FFI_RETURN_OK
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_profileKeyCredentialSelectivePresentationGetUuidCiphertextJNI(
+    env: JNIEnv,
+    _class: JClass,
+    profileKeyCredentialSelectivePresentation: jbyteArray,
+    uuidCiphertextOut: jbyteArray,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let profile_key_credential_selective_presentation = env
+            .convert_byte_array(profileKeyCredentialSelectivePresentation)
+            .unwrap();
+        let mut uuid_ciphertext: Vec<u8> =
+            vec![0; env.get_array_length(uuidCiphertextOut).unwrap() as usize];
+
+        let ffi_return = simpleapi::ProfileKeyCredentialSelectivePresentation_getUuidCiphertext(
+            &profile_key_credential_selective_presentation,
+            &mut uuid_ciphertext,
+        );
+        if ffi_return != FFI_RETURN_OK {
+            return ffi_return;
+        }
+
+        env.set_byte_array_region(uuidCiphertextOut, 0, &u8toi8(uuid_ciphertext)[..])
+            .unwrap();
+        FFI_RETURN_OK
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_groupInviteLinkPasswordGetCommitmentJNI(
+    env: JNIEnv,
+    _class: JClass,
+    groupInviteLinkPassword: jbyteArray,
+    groupPublicParams: jbyteArray,
+    groupInviteLinkPasswordCommitmentOut: jbyteArray,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let group_invite_link_password = env.convert_byte_array(groupInviteLinkPassword).unwrap();
+        let group_public_params = env.convert_byte_array(groupPublicParams).unwrap();
+        let mut group_invite_link_password_commitment: Vec<u8> = vec![
+            0;
+            env.get_array_length(groupInviteLinkPasswordCommitmentOut)
+                .unwrap()
+                as usize
+        ];
+
+        let ffi_return = simpleapi::GroupInviteLinkPassword_getCommitment(
+            &group_invite_link_password,
+            &group_public_params,
+            &mut group_invite_link_password_commitment,
+        );
+        if ffi_return != FFI_RETURN_OK {
+            return ffi_return;
+        }
+
+        env.set_byte_array_region(
+            groupInviteLinkPasswordCommitmentOut,
+            0,
+            &u8toi8(group_invite_link_password_commitment)[..],
+        )
+        .unwrap();
+        FFI_RETURN_OK
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_groupInviteLinkPasswordCommitmentCheckValidContentsJNI(
+    env: JNIEnv,
+    _class: JClass,
+    groupInviteLinkPasswordCommitment: jbyteArray,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let group_invite_link_password_commitment = env
+            .convert_byte_array(groupInviteLinkPasswordCommitment)
+            .unwrap();
+
+        let ffi_return = simpleapi::GroupInviteLinkPasswordCommitment_checkValidContents(
+            &group_invite_link_password_commitment,
+        );
+        if ffi_return != FFI_RETURN_OK {
+            return ffi_return;
+        }
+        FFI_RETURN_OK
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_groupInviteLinkPresentationCheckValidContentsJNI(
+    env: JNIEnv,
+    _class: JClass,
+    groupInviteLinkPresentation: jbyteArray,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let group_invite_link_presentation =
+            env.convert_byte_array(groupInviteLinkPresentation).unwrap();
+
+        let ffi_return = simpleapi::GroupInviteLinkPresentation_checkValidContents(
+            &group_invite_link_presentation,
+        );
+        if ffi_return != FFI_RETURN_OK {
+            return ffi_return;
+        }
+        FFI_RETURN_OK
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_groupInviteLinkPresentationGetUuidCiphertextJNI(
+    env: JNIEnv,
+    _class: JClass,
+    groupInviteLinkPresentation: jbyteArray,
+    uuidCiphertextOut: jbyteArray,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let group_invite_link_presentation =
+            env.convert_byte_array(groupInviteLinkPresentation).unwrap();
+        let mut uuid_ciphertext: Vec<u8> =
+            vec![0; env.get_array_length(uuidCiphertextOut).unwrap() as usize];
+
+        let ffi_return = simpleapi::GroupInviteLinkPresentation_getUuidCiphertext(
+            &group_invite_link_presentation,
+            &mut uuid_ciphertext,
+        );
+        if ffi_return != FFI_RETURN_OK {
+            return ffi_return;
+        }
+
+        env.set_byte_array_region(uuidCiphertextOut, 0, &u8toi8(uuid_ciphertext)[..])
+            .unwrap();
+        FFI_RETURN_OK
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
 #[no_mangle]
 pub extern "system" fn Java_org_signal_zkgroup_internal_Native_uuidCiphertextCheckValidContentsJNI(
     env: JNIEnv,
     _class: JClass,
     uuidCiphertext: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let uuid_ciphertext = env.convert_byte_array(uuidCiphertext).unwrap();
 
@@ -1501,6 +2891,7 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_profileKeyCiphert
     _class: JClass,
     profileKeyCiphertext: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let profile_key_ciphertext = env.convert_byte_array(profileKeyCiphertext).unwrap();
 
@@ -1518,12 +2909,61 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_profileKeyCiphert
     }
 }
 
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_randomizedUuidCiphertextCheckValidContentsJNI(
+    env: JNIEnv,
+    _class: JClass,
+    randomizedUuidCiphertext: jbyteArray,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let randomized_uuid_ciphertext = env.convert_byte_array(randomizedUuidCiphertext).unwrap();
+
+        let ffi_return =
+            simpleapi::RandomizedUuidCiphertext_checkValidContents(&randomized_uuid_ciphertext);
+        if ffi_return != FFI_RETURN_OK {
+            return ffi_return;
+        }
+        FFI_RETURN_OK
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_org_signal_zkgroup_internal_Native_uuidCiphertextMatchProofCheckValidContentsJNI(
+    env: JNIEnv,
+    _class: JClass,
+    uuidCiphertextMatchProof: jbyteArray,
+) -> i32 {
+    clear_last_error_code();
+    let result = panic::catch_unwind(|| {
+        let uuid_ciphertext_match_proof = env.convert_byte_array(uuidCiphertextMatchProof).unwrap();
+
+        let ffi_return =
+            simpleapi::UuidCiphertextMatchProof_checkValidContents(&uuid_ciphertext_match_proof);
+        if ffi_return != FFI_RETURN_OK {
+            return ffi_return;
+        }
+        FFI_RETURN_OK
+    });
+
+    match result {
+        Ok(result) => result,
+        Err(_) => FFI_RETURN_INTERNAL_ERROR,
+    }
+}
+
 #[no_mangle]
 pub extern "system" fn Java_org_signal_zkgroup_internal_Native_randomnessCheckValidContentsJNI(
     env: JNIEnv,
     _class: JClass,
     randomness: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let randomness = env.convert_byte_array(randomness).unwrap();
 
@@ -1546,6 +2986,7 @@ pub extern "system" fn Java_org_signal_zkgroup_internal_Native_uuidCheckValidCon
     _class: JClass,
     uuid: jbyteArray,
 ) -> i32 {
+    clear_last_error_code();
     let result = panic::catch_unwind(|| {
         let uuid = env.convert_byte_array(uuid).unwrap();
 
@@ -11,6 +11,7 @@
 
 use crate::api;
 use crate::common::constants::*;
+use crate::common::serialization;
 use crate::common::simple_types;
 use crate::ffi::constants::*;
 
@@ -29,7 +30,10 @@ pub fn ProfileKey_getCommitment(
         Err(_) => return FFI_RETURN_INPUT_ERROR,
     };
     let profile_key_commitment = profile_key.get_commitment(uuid);
-    profileKeyCommitmentOut.copy_from_slice(&bincode::serialize(&profile_key_commitment).unwrap());
+    match bincode::serialize(&profile_key_commitment) {
+        Ok(result) => profileKeyCommitmentOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
     FFI_RETURN_OK
 }
 
@@ -48,16 +52,19 @@ pub fn ProfileKey_getProfileKeyVersion(
         Err(_) => return FFI_RETURN_INPUT_ERROR,
     };
     let profile_key_version = profile_key.get_profile_key_version(uuid);
-    profileKeyVersionOut.copy_from_slice(&bincode::serialize(&profile_key_version).unwrap());
+    match bincode::serialize(&profile_key_version) {
+        Ok(result) => profileKeyVersionOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
     FFI_RETURN_OK
 }
 
 pub fn ProfileKeyCommitment_checkValidContents(profileKeyCommitmentIn: &[u8]) -> i32 {
-    let _: api::profiles::ProfileKeyCommitment = match bincode::deserialize(profileKeyCommitmentIn)
-    {
-        Ok(result) => result,
-        Err(_) => return FFI_RETURN_INPUT_ERROR,
-    };
+    let _: api::profiles::ProfileKeyCommitment =
+        match serialization::deserialize(profileKeyCommitmentIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
 
     FFI_RETURN_OK
 }
@@ -71,7 +78,10 @@ pub fn GroupSecretParams_generateDeterministic(
         Err(_) => return FFI_RETURN_INPUT_ERROR,
     };
     let group_secret_params = api::groups::GroupSecretParams::generate(randomness);
-    groupSecretParamsOut.copy_from_slice(&bincode::serialize(&group_secret_params).unwrap());
+    match bincode::serialize(&group_secret_params) {
+        Ok(result) => groupSecretParamsOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
     FFI_RETURN_OK
 }
 
@@ -86,14 +96,17 @@ pub fn GroupSecretParams_deriveFromMasterKey(
     };
     let group_secret_params =
         api::groups::GroupSecretParams::derive_from_master_key(group_master_key);
-    groupSecretParamsOut.copy_from_slice(&bincode::serialize(&group_secret_params).unwrap());
+    match bincode::serialize(&group_secret_params) {
+        Ok(result) => groupSecretParamsOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
     FFI_RETURN_OK
 }
 
 pub fn GroupSecretParams_checkValidContents(groupSecretParamsIn: &[u8]) -> i32 {
-    let _: api::groups::GroupSecretParams = match bincode::deserialize(groupSecretParamsIn) {
+    let _: api::groups::GroupSecretParams = match serialization::deserialize(groupSecretParamsIn) {
         Ok(result) => result,
-        Err(_) => return FFI_RETURN_INPUT_ERROR,
+        Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
     };
 
     FFI_RETURN_OK
@@ -104,12 +117,15 @@ pub fn GroupSecretParams_getMasterKey(
     groupMasterKeyOut: &mut [u8],
 ) -> i32 {
     let group_secret_params: api::groups::GroupSecretParams =
-        match bincode::deserialize(groupSecretParamsIn) {
+        match serialization::deserialize(groupSecretParamsIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
         };
     let group_master_key = group_secret_params.get_master_key();
-    groupMasterKeyOut.copy_from_slice(&bincode::serialize(&group_master_key).unwrap());
+    match bincode::serialize(&group_master_key) {
+        Ok(result) => groupMasterKeyOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
     FFI_RETURN_OK
 }
 
@@ -118,12 +134,15 @@ pub fn GroupSecretParams_getPublicParams(
     groupPublicParamsOut: &mut [u8],
 ) -> i32 {
     let group_secret_params: api::groups::GroupSecretParams =
-        match bincode::deserialize(groupSecretParamsIn) {
+        match serialization::deserialize(groupSecretParamsIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
         };
     let group_public_params = group_secret_params.get_public_params();
-    groupPublicParamsOut.copy_from_slice(&bincode::serialize(&group_public_params).unwrap());
+    match bincode::serialize(&group_public_params) {
+        Ok(result) => groupPublicParamsOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
     FFI_RETURN_OK
 }
 
@@ -133,9 +152,9 @@ pub fn GroupSecretParams_encryptUuid(
     uuidCiphertextOut: &mut [u8],
 ) -> i32 {
     let group_secret_params: api::groups::GroupSecretParams =
-        match bincode::deserialize(groupSecretParamsIn) {
+        match serialization::deserialize(groupSecretParamsIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
         };
 
     let uuid: simple_types::UidBytes = match bincode::deserialize(uuidIn) {
@@ -143,7 +162,10 @@ pub fn GroupSecretParams_encryptUuid(
         Err(_) => return FFI_RETURN_INPUT_ERROR,
     };
     let uuid_ciphertext = group_secret_params.encrypt_uuid(uuid);
-    uuidCiphertextOut.copy_from_slice(&bincode::serialize(&uuid_ciphertext).unwrap());
+    match bincode::serialize(&uuid_ciphertext) {
+        Ok(result) => uuidCiphertextOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
     FFI_RETURN_OK
 }
 
@@ -153,21 +175,24 @@ pub fn GroupSecretParams_decryptUuid(
     uuidOut: &mut [u8],
 ) -> i32 {
     let group_secret_params: api::groups::GroupSecretParams =
-        match bincode::deserialize(groupSecretParamsIn) {
+        match serialization::deserialize(groupSecretParamsIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
         };
 
-    let uuid_ciphertext: api::groups::UuidCiphertext = match bincode::deserialize(uuidCiphertextIn)
-    {
-        Ok(result) => result,
-        Err(_) => return FFI_RETURN_INPUT_ERROR,
-    };
+    let uuid_ciphertext: api::groups::UuidCiphertext =
+        match serialization::deserialize(uuidCiphertextIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
     let uuid = match group_secret_params.decrypt_uuid(uuid_ciphertext) {
         Ok(result) => result,
-        Err(_) => return FFI_RETURN_INPUT_ERROR,
+        Err(e) => return e.get_ffi_return_code(),
     };
-    uuidOut.copy_from_slice(&bincode::serialize(&uuid).unwrap());
+    match bincode::serialize(&uuid) {
+        Ok(result) => uuidOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
     FFI_RETURN_OK
 }
 
@@ -178,9 +203,9 @@ pub fn GroupSecretParams_encryptProfileKey(
     profileKeyCiphertextOut: &mut [u8],
 ) -> i32 {
     let group_secret_params: api::groups::GroupSecretParams =
-        match bincode::deserialize(groupSecretParamsIn) {
+        match serialization::deserialize(groupSecretParamsIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
         };
 
     let profile_key: api::profiles::ProfileKey = match bincode::deserialize(profileKeyIn) {
@@ -192,8 +217,11 @@ pub fn GroupSecretParams_encryptProfileKey(
         Ok(result) => result,
         Err(_) => return FFI_RETURN_INPUT_ERROR,
     };
-    let profile_key_ciphertext = group_secret_params.encrypt_profile_key(profile_key, uuid);
-    profileKeyCiphertextOut.copy_from_slice(&bincode::serialize(&profile_key_ciphertext).unwrap());
+    let profile_key_ciphertext = group_secret_params.encrypt_profile_key(&profile_key, uuid);
+    match bincode::serialize(&profile_key_ciphertext) {
+        Ok(result) => profileKeyCiphertextOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
     FFI_RETURN_OK
 }
 
@@ -204,15 +232,15 @@ pub fn GroupSecretParams_decryptProfileKey(
     profileKeyOut: &mut [u8],
 ) -> i32 {
     let group_secret_params: api::groups::GroupSecretParams =
-        match bincode::deserialize(groupSecretParamsIn) {
+        match serialization::deserialize(groupSecretParamsIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
         };
 
     let profile_key_ciphertext: api::groups::ProfileKeyCiphertext =
-        match bincode::deserialize(profileKeyCiphertextIn) {
+        match serialization::deserialize(profileKeyCiphertextIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INPUT_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
         };
 
     let uuid: simple_types::UidBytes = match bincode::deserialize(uuidIn) {
@@ -221,9 +249,12 @@ pub fn GroupSecretParams_decryptProfileKey(
     };
     let profile_key = match group_secret_params.decrypt_profile_key(profile_key_ciphertext, uuid) {
         Ok(result) => result,
-        Err(_) => return FFI_RETURN_INPUT_ERROR,
+        Err(e) => return e.get_ffi_return_code(),
     };
-    profileKeyOut.copy_from_slice(&bincode::serialize(&profile_key).unwrap());
+    match bincode::serialize(&profile_key) {
+        Ok(result) => profileKeyOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
     FFI_RETURN_OK
 }
 
@@ -234,9 +265,9 @@ pub fn GroupSecretParams_encryptBlobDeterministic(
     blobCiphertextOut: &mut [u8],
 ) -> i32 {
     let group_secret_params: api::groups::GroupSecretParams =
-        match bincode::deserialize(groupSecretParamsIn) {
+        match serialization::deserialize(groupSecretParamsIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
         };
 
     let randomness: simple_types::RandomnessBytes = match bincode::deserialize(randomnessIn) {
@@ -246,7 +277,7 @@ pub fn GroupSecretParams_encryptBlobDeterministic(
     let plaintext = plaintextIn;
     let blob_ciphertext = match group_secret_params.encrypt_blob(randomness, plaintext) {
         Ok(result) => result,
-        Err(_) => return FFI_RETURN_INPUT_ERROR,
+        Err(e) => return e.get_ffi_return_code(),
     };
     blobCiphertextOut.copy_from_slice(&blob_ciphertext);
     FFI_RETURN_OK
@@ -258,15 +289,278 @@ pub fn GroupSecretParams_decryptBlob(
     plaintextOut: &mut [u8],
 ) -> i32 {
     let group_secret_params: api::groups::GroupSecretParams =
-        match bincode::deserialize(groupSecretParamsIn) {
+        match serialization::deserialize(groupSecretParamsIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
+        };
+    let blob_ciphertext = blobCiphertextIn;
+    let plaintext = match group_secret_params.decrypt_blob(blob_ciphertext) {
+        Ok(result) => result,
+        Err(e) => return e.get_ffi_return_code(),
+    };
+    // A padded blob's plaintext is shorter than the ciphertext suggests, so it
+    // has to go through decrypt_padded_blob instead
+    if plaintext.len() != plaintextOut.len() {
+        return FFI_RETURN_INPUT_ERROR;
+    }
+    plaintextOut.copy_from_slice(&plaintext);
+    FFI_RETURN_OK
+}
+
+pub fn GroupSecretParams_encryptBlobWithPaddingDeterministic(
+    groupSecretParamsIn: &[u8],
+    randomnessIn: &[u8],
+    plaintextIn: &[u8],
+    paddedLengthIn: u32,
+    blobCiphertextOut: &mut [u8],
+) -> i32 {
+    let group_secret_params: api::groups::GroupSecretParams =
+        match serialization::deserialize(groupSecretParamsIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
+        };
+
+    let randomness: simple_types::RandomnessBytes = match bincode::deserialize(randomnessIn) {
+        Ok(result) => result,
+        Err(_) => return FFI_RETURN_INPUT_ERROR,
+    };
+    let plaintext = plaintextIn;
+    let padded_length = paddedLengthIn;
+    // The caller sizes the output as padded_length + 29, so the plaintext and
+    // its padding marker have to fit in padded_length exactly
+    if plaintext.len() >= padded_length as usize {
+        return FFI_RETURN_INPUT_ERROR;
+    }
+    let padding_policy = api::groups::BlobPaddingPolicy::Buckets(vec![padded_length as usize]);
+    let blob_ciphertext =
+        match group_secret_params.encrypt_blob_with_padding(randomness, plaintext, &padding_policy)
+        {
+            Ok(result) => result,
+            Err(e) => return e.get_ffi_return_code(),
+        };
+    blobCiphertextOut.copy_from_slice(&blob_ciphertext);
+    FFI_RETURN_OK
+}
+
+pub fn GroupSecretParams_decryptPaddedBlob(
+    groupSecretParamsIn: &[u8],
+    blobCiphertextIn: &[u8],
+    lengthPrefixedPlaintextOut: &mut [u8],
+) -> i32 {
+    let group_secret_params: api::groups::GroupSecretParams =
+        match serialization::deserialize(groupSecretParamsIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
         };
     let blob_ciphertext = blobCiphertextIn;
     let plaintext = match group_secret_params.decrypt_blob(blob_ciphertext) {
+        Ok(result) => result,
+        Err(e) => return e.get_ffi_return_code(),
+    };
+    // The caller can't know the unpadded length, so the output is sized as
+    // the ciphertext length - 25 and holds a 4-byte big-endian length, the
+    // plaintext, then zeros
+    if plaintext.len() + 4 > lengthPrefixedPlaintextOut.len() {
+        return FFI_RETURN_INPUT_ERROR;
+    }
+    let mut length_prefixed_plaintext = vec![0u8; lengthPrefixedPlaintextOut.len()];
+    length_prefixed_plaintext[..4].copy_from_slice(&(plaintext.len() as u32).to_be_bytes());
+    length_prefixed_plaintext[4..4 + plaintext.len()].copy_from_slice(&plaintext);
+    lengthPrefixedPlaintextOut.copy_from_slice(&length_prefixed_plaintext);
+    FFI_RETURN_OK
+}
+
+pub fn GroupSecretParams_createGroupInviteLinkPresentationDeterministic(
+    groupSecretParamsIn: &[u8],
+    randomnessIn: &[u8],
+    groupInviteLinkPasswordIn: &[u8],
+    uuidIn: &[u8],
+    groupInviteLinkPresentationOut: &mut [u8],
+) -> i32 {
+    let group_secret_params: api::groups::GroupSecretParams =
+        match serialization::deserialize(groupSecretParamsIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
+        };
+
+    let randomness: simple_types::RandomnessBytes = match bincode::deserialize(randomnessIn) {
+        Ok(result) => result,
+        Err(_) => return FFI_RETURN_INPUT_ERROR,
+    };
+
+    let group_invite_link_password: api::groups::GroupInviteLinkPassword =
+        match bincode::deserialize(groupInviteLinkPasswordIn) {
+            Ok(result) => result,
+            Err(_) => return FFI_RETURN_INPUT_ERROR,
+        };
+
+    let uuid: simple_types::UidBytes = match bincode::deserialize(uuidIn) {
+        Ok(result) => result,
+        Err(_) => return FFI_RETURN_INPUT_ERROR,
+    };
+    let group_invite_link_presentation = match group_secret_params
+        .create_group_invite_link_presentation(randomness, &group_invite_link_password, uuid)
+    {
+        Ok(result) => result,
+        Err(e) => return e.get_ffi_return_code(),
+    };
+    match bincode::serialize(&group_invite_link_presentation) {
+        Ok(result) => groupInviteLinkPresentationOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
+    FFI_RETURN_OK
+}
+
+pub fn GroupSecretParams_encryptUuidRandomizedDeterministic(
+    groupSecretParamsIn: &[u8],
+    randomnessIn: &[u8],
+    uuidIn: &[u8],
+    randomizedUuidCiphertextOut: &mut [u8],
+) -> i32 {
+    let group_secret_params: api::groups::GroupSecretParams =
+        match serialization::deserialize(groupSecretParamsIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
+        };
+
+    let randomness: simple_types::RandomnessBytes = match bincode::deserialize(randomnessIn) {
+        Ok(result) => result,
+        Err(_) => return FFI_RETURN_INPUT_ERROR,
+    };
+
+    let uuid: simple_types::UidBytes = match bincode::deserialize(uuidIn) {
+        Ok(result) => result,
+        Err(_) => return FFI_RETURN_INPUT_ERROR,
+    };
+    let randomized_uuid_ciphertext = group_secret_params.encrypt_uuid_randomized(randomness, uuid);
+    match bincode::serialize(&randomized_uuid_ciphertext) {
+        Ok(result) => randomizedUuidCiphertextOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
+    FFI_RETURN_OK
+}
+
+pub fn GroupSecretParams_decryptRandomizedUuid(
+    groupSecretParamsIn: &[u8],
+    randomizedUuidCiphertextIn: &[u8],
+    uuidOut: &mut [u8],
+) -> i32 {
+    let group_secret_params: api::groups::GroupSecretParams =
+        match serialization::deserialize(groupSecretParamsIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
+        };
+
+    let randomized_uuid_ciphertext: api::groups::RandomizedUuidCiphertext =
+        match serialization::deserialize(randomizedUuidCiphertextIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
+    let uuid = match group_secret_params.decrypt_randomized_uuid(randomized_uuid_ciphertext) {
+        Ok(result) => result,
+        Err(e) => return e.get_ffi_return_code(),
+    };
+    match bincode::serialize(&uuid) {
+        Ok(result) => uuidOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
+    FFI_RETURN_OK
+}
+
+pub fn GroupSecretParams_createUuidCiphertextMatchProofDeterministic(
+    groupSecretParamsIn: &[u8],
+    randomnessIn: &[u8],
+    uuidCiphertextIn: &[u8],
+    randomizedUuidCiphertextIn: &[u8],
+    uuidCiphertextMatchProofOut: &mut [u8],
+) -> i32 {
+    let group_secret_params: api::groups::GroupSecretParams =
+        match serialization::deserialize(groupSecretParamsIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
+        };
+
+    let randomness: simple_types::RandomnessBytes = match bincode::deserialize(randomnessIn) {
+        Ok(result) => result,
+        Err(_) => return FFI_RETURN_INPUT_ERROR,
+    };
+
+    let uuid_ciphertext: api::groups::UuidCiphertext =
+        match serialization::deserialize(uuidCiphertextIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
+
+    let randomized_uuid_ciphertext: api::groups::RandomizedUuidCiphertext =
+        match serialization::deserialize(randomizedUuidCiphertextIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
+    let uuid_ciphertext_match_proof = match group_secret_params.create_uuid_ciphertext_match_proof(
+        randomness,
+        uuid_ciphertext,
+        randomized_uuid_ciphertext,
+    ) {
+        Ok(result) => result,
+        Err(e) => return e.get_ffi_return_code(),
+    };
+    match bincode::serialize(&uuid_ciphertext_match_proof) {
+        Ok(result) => uuidCiphertextMatchProofOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
+    FFI_RETURN_OK
+}
+
+pub fn GroupSecretParams_encryptBlobWithAdDeterministic(
+    groupSecretParamsIn: &[u8],
+    randomnessIn: &[u8],
+    plaintextIn: &[u8],
+    associatedDataIn: &[u8],
+    blobCiphertextOut: &mut [u8],
+) -> i32 {
+    let group_secret_params: api::groups::GroupSecretParams =
+        match serialization::deserialize(groupSecretParamsIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
+        };
+
+    let randomness: simple_types::RandomnessBytes = match bincode::deserialize(randomnessIn) {
         Ok(result) => result,
         Err(_) => return FFI_RETURN_INPUT_ERROR,
     };
+    let plaintext = plaintextIn;
+    let associated_data = associatedDataIn;
+    let blob_ciphertext =
+        match group_secret_params.encrypt_blob_with_ad(randomness, plaintext, associated_data) {
+            Ok(result) => result,
+            Err(e) => return e.get_ffi_return_code(),
+        };
+    blobCiphertextOut.copy_from_slice(&blob_ciphertext);
+    FFI_RETURN_OK
+}
+
+pub fn GroupSecretParams_decryptBlobWithAd(
+    groupSecretParamsIn: &[u8],
+    blobCiphertextIn: &[u8],
+    associatedDataIn: &[u8],
+    plaintextOut: &mut [u8],
+) -> i32 {
+    let group_secret_params: api::groups::GroupSecretParams =
+        match serialization::deserialize(groupSecretParamsIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
+        };
+    let blob_ciphertext = blobCiphertextIn;
+    let associated_data = associatedDataIn;
+    let plaintext = match group_secret_params.decrypt_blob_with_ad(blob_ciphertext, associated_data)
+    {
+        Ok(result) => result,
+        Err(e) => return e.get_ffi_return_code(),
+    };
+    // As in decrypt_blob, a padded plaintext doesn't fill the output
+    if plaintext.len() != plaintextOut.len() {
+        return FFI_RETURN_INPUT_ERROR;
+    }
     plaintextOut.copy_from_slice(&plaintext);
     FFI_RETURN_OK
 }
@@ -279,15 +573,21 @@ pub fn ServerSecretParams_generateDeterministic(
         Ok(result) => result,
         Err(_) => return FFI_RETURN_INPUT_ERROR,
     };
-    let server_secret_params = api::ServerSecretParams::generate(randomness);
-    serverSecretParamsOut.copy_from_slice(&bincode::serialize(&server_secret_params).unwrap());
+    let server_secret_params = match api::ServerSecretParams::generate(randomness) {
+        Ok(result) => result,
+        Err(e) => return e.get_ffi_return_code(),
+    };
+    match bincode::serialize(&server_secret_params) {
+        Ok(result) => serverSecretParamsOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
     FFI_RETURN_OK
 }
 
 pub fn ServerSecretParams_checkValidContents(serverSecretParamsIn: &[u8]) -> i32 {
-    let _: api::ServerSecretParams = match bincode::deserialize(serverSecretParamsIn) {
+    let _: api::ServerSecretParams = match serialization::deserialize(serverSecretParamsIn) {
         Ok(result) => result,
-        Err(_) => return FFI_RETURN_INPUT_ERROR,
+        Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
     };
 
     FFI_RETURN_OK
@@ -298,12 +598,15 @@ pub fn ServerSecretParams_getPublicParams(
     serverPublicParamsOut: &mut [u8],
 ) -> i32 {
     let server_secret_params: api::ServerSecretParams =
-        match bincode::deserialize(serverSecretParamsIn) {
+        match serialization::deserialize(serverSecretParamsIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
         };
     let server_public_params = server_secret_params.get_public_params();
-    serverPublicParamsOut.copy_from_slice(&bincode::serialize(&server_public_params).unwrap());
+    match bincode::serialize(&server_public_params) {
+        Ok(result) => serverPublicParamsOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
     FFI_RETURN_OK
 }
 
@@ -314,9 +617,9 @@ pub fn ServerSecretParams_signDeterministic(
     notarySignatureOut: &mut [u8],
 ) -> i32 {
     let server_secret_params: api::ServerSecretParams =
-        match bincode::deserialize(serverSecretParamsIn) {
+        match serialization::deserialize(serverSecretParamsIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
         };
 
     let randomness: simple_types::RandomnessBytes = match bincode::deserialize(randomnessIn) {
@@ -326,7 +629,7 @@ pub fn ServerSecretParams_signDeterministic(
     let message = messageIn;
     let notary_signature = match server_secret_params.sign(randomness, message) {
         Ok(result) => result,
-        Err(_) => return FFI_RETURN_INPUT_ERROR,
+        Err(e) => return e.get_ffi_return_code(),
     };
     notarySignatureOut.copy_from_slice(&notary_signature);
     FFI_RETURN_OK
@@ -340,9 +643,9 @@ pub fn ServerPublicParams_receiveAuthCredential(
     authCredentialOut: &mut [u8],
 ) -> i32 {
     let server_public_params: api::ServerPublicParams =
-        match bincode::deserialize(serverPublicParamsIn) {
+        match serialization::deserialize(serverPublicParamsIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
         };
 
     let uuid: simple_types::UidBytes = match bincode::deserialize(uuidIn) {
@@ -352,9 +655,9 @@ pub fn ServerPublicParams_receiveAuthCredential(
     let redemption_time = redemptionTimeIn;
 
     let auth_credential_response: api::auth::AuthCredentialResponse =
-        match bincode::deserialize(authCredentialResponseIn) {
+        match serialization::deserialize(authCredentialResponseIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INPUT_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
         };
     let auth_credential = match server_public_params.receive_auth_credential(
         uuid,
@@ -362,9 +665,12 @@ pub fn ServerPublicParams_receiveAuthCredential(
         &auth_credential_response,
     ) {
         Ok(result) => result,
-        Err(_) => return FFI_RETURN_INPUT_ERROR,
+        Err(e) => return e.get_ffi_return_code(),
     };
-    authCredentialOut.copy_from_slice(&bincode::serialize(&auth_credential).unwrap());
+    match bincode::serialize(&auth_credential) {
+        Ok(result) => authCredentialOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
     FFI_RETURN_OK
 }
 
@@ -376,9 +682,9 @@ pub fn ServerPublicParams_createAuthCredentialPresentationDeterministic(
     authCredentialPresentationOut: &mut [u8],
 ) -> i32 {
     let server_public_params: api::ServerPublicParams =
-        match bincode::deserialize(serverPublicParamsIn) {
+        match serialization::deserialize(serverPublicParamsIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
         };
 
     let randomness: simple_types::RandomnessBytes = match bincode::deserialize(randomnessIn) {
@@ -387,36 +693,41 @@ pub fn ServerPublicParams_createAuthCredentialPresentationDeterministic(
     };
 
     let group_secret_params: api::groups::GroupSecretParams =
-        match bincode::deserialize(groupSecretParamsIn) {
+        match serialization::deserialize(groupSecretParamsIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INPUT_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
         };
 
-    let auth_credential: api::auth::AuthCredential = match bincode::deserialize(authCredentialIn) {
+    let auth_credential: api::auth::AuthCredential =
+        match serialization::deserialize(authCredentialIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
+    let auth_credential_presentation = match server_public_params
+        .create_auth_credential_presentation(randomness, &group_secret_params, auth_credential)
+    {
         Ok(result) => result,
-        Err(_) => return FFI_RETURN_INPUT_ERROR,
+        Err(e) => return e.get_ffi_return_code(),
     };
-    let auth_credential_presentation = server_public_params.create_auth_credential_presentation(
-        randomness,
-        group_secret_params,
-        auth_credential,
-    );
-    authCredentialPresentationOut
-        .copy_from_slice(&bincode::serialize(&auth_credential_presentation).unwrap());
+    match bincode::serialize(&auth_credential_presentation) {
+        Ok(result) => authCredentialPresentationOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
     FFI_RETURN_OK
 }
 
-pub fn ServerPublicParams_createProfileKeyCredentialRequestContextDeterministic(
+pub fn ServerPublicParams_createAuthCredentialPresentationWithContextDeterministic(
     serverPublicParamsIn: &[u8],
     randomnessIn: &[u8],
-    uuidIn: &[u8],
-    profileKeyIn: &[u8],
-    profileKeyCredentialRequestContextOut: &mut [u8],
+    groupSecretParamsIn: &[u8],
+    authCredentialIn: &[u8],
+    contextIn: &[u8],
+    authCredentialPresentationOut: &mut [u8],
 ) -> i32 {
     let server_public_params: api::ServerPublicParams =
-        match bincode::deserialize(serverPublicParamsIn) {
+        match serialization::deserialize(serverPublicParamsIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
         };
 
     let randomness: simple_types::RandomnessBytes = match bincode::deserialize(randomnessIn) {
@@ -424,19 +735,167 @@ pub fn ServerPublicParams_createProfileKeyCredentialRequestContextDeterministic(
         Err(_) => return FFI_RETURN_INPUT_ERROR,
     };
 
-    let uuid: simple_types::UidBytes = match bincode::deserialize(uuidIn) {
-        Ok(result) => result,
-        Err(_) => return FFI_RETURN_INPUT_ERROR,
-    };
+    let group_secret_params: api::groups::GroupSecretParams =
+        match serialization::deserialize(groupSecretParamsIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
 
-    let profile_key: api::profiles::ProfileKey = match bincode::deserialize(profileKeyIn) {
+    let auth_credential: api::auth::AuthCredential =
+        match serialization::deserialize(authCredentialIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
+    let context = contextIn;
+    let auth_credential_presentation = match server_public_params
+        .create_auth_credential_presentation_with_context(
+            randomness,
+            &group_secret_params,
+            auth_credential,
+            context,
+        ) {
         Ok(result) => result,
-        Err(_) => return FFI_RETURN_INPUT_ERROR,
+        Err(e) => return e.get_ffi_return_code(),
     };
-    let profile_key_credential_request_context = server_public_params
-        .create_profile_key_credential_request_context(randomness, uuid, profile_key);
-    profileKeyCredentialRequestContextOut
-        .copy_from_slice(&bincode::serialize(&profile_key_credential_request_context).unwrap());
+    match bincode::serialize(&auth_credential_presentation) {
+        Ok(result) => authCredentialPresentationOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
+    FFI_RETURN_OK
+}
+
+pub fn ServerPublicParams_createRateLimitCredentialRequestContextDeterministic(
+    serverPublicParamsIn: &[u8],
+    randomnessIn: &[u8],
+    rateLimitCredentialRequestContextOut: &mut [u8],
+) -> i32 {
+    let server_public_params: api::ServerPublicParams =
+        match serialization::deserialize(serverPublicParamsIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
+        };
+
+    let randomness: simple_types::RandomnessBytes = match bincode::deserialize(randomnessIn) {
+        Ok(result) => result,
+        Err(_) => return FFI_RETURN_INPUT_ERROR,
+    };
+    let rate_limit_credential_request_context =
+        server_public_params.create_rate_limit_credential_request_context(randomness);
+    match bincode::serialize(&rate_limit_credential_request_context) {
+        Ok(result) => rateLimitCredentialRequestContextOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
+    FFI_RETURN_OK
+}
+
+pub fn ServerPublicParams_receiveRateLimitCredential(
+    serverPublicParamsIn: &[u8],
+    rateLimitCredentialRequestContextIn: &[u8],
+    rateLimitCredentialResponseIn: &[u8],
+    rateLimitCredentialOut: &mut [u8],
+) -> i32 {
+    let server_public_params: api::ServerPublicParams =
+        match serialization::deserialize(serverPublicParamsIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
+        };
+
+    let rate_limit_credential_request_context: api::auth::RateLimitCredentialRequestContext =
+        match serialization::deserialize(rateLimitCredentialRequestContextIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
+
+    let rate_limit_credential_response: api::auth::RateLimitCredentialResponse =
+        match serialization::deserialize(rateLimitCredentialResponseIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
+    let rate_limit_credential = match server_public_params.receive_rate_limit_credential(
+        &rate_limit_credential_request_context,
+        &rate_limit_credential_response,
+    ) {
+        Ok(result) => result,
+        Err(e) => return e.get_ffi_return_code(),
+    };
+    match bincode::serialize(&rate_limit_credential) {
+        Ok(result) => rateLimitCredentialOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
+    FFI_RETURN_OK
+}
+
+pub fn ServerPublicParams_createRateLimitTokenDeterministic(
+    serverPublicParamsIn: &[u8],
+    randomnessIn: &[u8],
+    rateLimitCredentialIn: &[u8],
+    rateLimitTokenOut: &mut [u8],
+) -> i32 {
+    let server_public_params: api::ServerPublicParams =
+        match serialization::deserialize(serverPublicParamsIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
+        };
+
+    let randomness: simple_types::RandomnessBytes = match bincode::deserialize(randomnessIn) {
+        Ok(result) => result,
+        Err(_) => return FFI_RETURN_INPUT_ERROR,
+    };
+
+    let rate_limit_credential: api::auth::RateLimitCredential =
+        match serialization::deserialize(rateLimitCredentialIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
+    let rate_limit_token =
+        match server_public_params.create_rate_limit_token(randomness, &rate_limit_credential) {
+            Ok(result) => result,
+            Err(e) => return e.get_ffi_return_code(),
+        };
+    match bincode::serialize(&rate_limit_token) {
+        Ok(result) => rateLimitTokenOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
+    FFI_RETURN_OK
+}
+
+pub fn ServerPublicParams_createProfileKeyCredentialRequestContextDeterministic(
+    serverPublicParamsIn: &[u8],
+    randomnessIn: &[u8],
+    uuidIn: &[u8],
+    profileKeyIn: &[u8],
+    profileKeyCredentialRequestContextOut: &mut [u8],
+) -> i32 {
+    let server_public_params: api::ServerPublicParams =
+        match serialization::deserialize(serverPublicParamsIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
+        };
+
+    let randomness: simple_types::RandomnessBytes = match bincode::deserialize(randomnessIn) {
+        Ok(result) => result,
+        Err(_) => return FFI_RETURN_INPUT_ERROR,
+    };
+
+    let uuid: simple_types::UidBytes = match bincode::deserialize(uuidIn) {
+        Ok(result) => result,
+        Err(_) => return FFI_RETURN_INPUT_ERROR,
+    };
+
+    let profile_key: api::profiles::ProfileKey = match bincode::deserialize(profileKeyIn) {
+        Ok(result) => result,
+        Err(_) => return FFI_RETURN_INPUT_ERROR,
+    };
+    let profile_key_credential_request_context = match server_public_params
+        .create_profile_key_credential_request_context(randomness, uuid, &profile_key)
+    {
+        Ok(result) => result,
+        Err(e) => return e.get_ffi_return_code(),
+    };
+    match bincode::serialize(&profile_key_credential_request_context) {
+        Ok(result) => profileKeyCredentialRequestContextOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
     FFI_RETURN_OK
 }
 
@@ -447,30 +906,33 @@ pub fn ServerPublicParams_receiveProfileKeyCredential(
     profileKeyCredentialOut: &mut [u8],
 ) -> i32 {
     let server_public_params: api::ServerPublicParams =
-        match bincode::deserialize(serverPublicParamsIn) {
+        match serialization::deserialize(serverPublicParamsIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
         };
 
     let profile_key_credential_request_context: api::profiles::ProfileKeyCredentialRequestContext =
-        match bincode::deserialize(profileKeyCredentialRequestContextIn) {
+        match serialization::deserialize(profileKeyCredentialRequestContextIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INPUT_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
         };
 
     let profile_key_credential_response: api::profiles::ProfileKeyCredentialResponse =
-        match bincode::deserialize(profileKeyCredentialResponseIn) {
+        match serialization::deserialize(profileKeyCredentialResponseIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INPUT_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
         };
     let profile_key_credential = match server_public_params.receive_profile_key_credential(
         &profile_key_credential_request_context,
         &profile_key_credential_response,
     ) {
         Ok(result) => result,
-        Err(_) => return FFI_RETURN_INPUT_ERROR,
+        Err(e) => return e.get_ffi_return_code(),
     };
-    profileKeyCredentialOut.copy_from_slice(&bincode::serialize(&profile_key_credential).unwrap());
+    match bincode::serialize(&profile_key_credential) {
+        Ok(result) => profileKeyCredentialOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
     FFI_RETURN_OK
 }
 
@@ -482,9 +944,9 @@ pub fn ServerPublicParams_createProfileKeyCredentialPresentationDeterministic(
     profileKeyCredentialPresentationOut: &mut [u8],
 ) -> i32 {
     let server_public_params: api::ServerPublicParams =
-        match bincode::deserialize(serverPublicParamsIn) {
+        match serialization::deserialize(serverPublicParamsIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
         };
 
     let randomness: simple_types::RandomnessBytes = match bincode::deserialize(randomnessIn) {
@@ -493,24 +955,170 @@ pub fn ServerPublicParams_createProfileKeyCredentialPresentationDeterministic(
     };
 
     let group_secret_params: api::groups::GroupSecretParams =
-        match bincode::deserialize(groupSecretParamsIn) {
+        match serialization::deserialize(groupSecretParamsIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INPUT_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
         };
 
     let profile_key_credential: api::profiles::ProfileKeyCredential =
-        match bincode::deserialize(profileKeyCredentialIn) {
+        match serialization::deserialize(profileKeyCredentialIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INPUT_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
         };
-    let profile_key_credential_presentation = server_public_params
+    let profile_key_credential_presentation = match server_public_params
         .create_profile_key_credential_presentation(
             randomness,
-            group_secret_params,
+            &group_secret_params,
+            profile_key_credential,
+        ) {
+        Ok(result) => result,
+        Err(e) => return e.get_ffi_return_code(),
+    };
+    match bincode::serialize(&profile_key_credential_presentation) {
+        Ok(result) => profileKeyCredentialPresentationOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
+    FFI_RETURN_OK
+}
+
+pub fn ServerPublicParams_createProfileKeyCredentialPresentationWithContextDeterministic(
+    serverPublicParamsIn: &[u8],
+    randomnessIn: &[u8],
+    groupSecretParamsIn: &[u8],
+    profileKeyCredentialIn: &[u8],
+    contextIn: &[u8],
+    profileKeyCredentialPresentationOut: &mut [u8],
+) -> i32 {
+    let server_public_params: api::ServerPublicParams =
+        match serialization::deserialize(serverPublicParamsIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
+        };
+
+    let randomness: simple_types::RandomnessBytes = match bincode::deserialize(randomnessIn) {
+        Ok(result) => result,
+        Err(_) => return FFI_RETURN_INPUT_ERROR,
+    };
+
+    let group_secret_params: api::groups::GroupSecretParams =
+        match serialization::deserialize(groupSecretParamsIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
+
+    let profile_key_credential: api::profiles::ProfileKeyCredential =
+        match serialization::deserialize(profileKeyCredentialIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
+    let context = contextIn;
+    let profile_key_credential_presentation = match server_public_params
+        .create_profile_key_credential_presentation_with_context(
+            randomness,
+            &group_secret_params,
+            profile_key_credential,
+            context,
+        ) {
+        Ok(result) => result,
+        Err(e) => return e.get_ffi_return_code(),
+    };
+    match bincode::serialize(&profile_key_credential_presentation) {
+        Ok(result) => profileKeyCredentialPresentationOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
+    FFI_RETURN_OK
+}
+
+pub fn ServerPublicParams_createProfileKeyCredentialSelectivePresentationDeterministic(
+    serverPublicParamsIn: &[u8],
+    randomnessIn: &[u8],
+    groupSecretParamsIn: &[u8],
+    profileKeyCredentialIn: &[u8],
+    profileKeyCredentialSelectivePresentationOut: &mut [u8],
+) -> i32 {
+    let server_public_params: api::ServerPublicParams =
+        match serialization::deserialize(serverPublicParamsIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
+        };
+
+    let randomness: simple_types::RandomnessBytes = match bincode::deserialize(randomnessIn) {
+        Ok(result) => result,
+        Err(_) => return FFI_RETURN_INPUT_ERROR,
+    };
+
+    let group_secret_params: api::groups::GroupSecretParams =
+        match serialization::deserialize(groupSecretParamsIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
+
+    let profile_key_credential: api::profiles::ProfileKeyCredential =
+        match serialization::deserialize(profileKeyCredentialIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
+    let profile_key_credential_selective_presentation = match server_public_params
+        .create_profile_key_credential_selective_presentation(
+            randomness,
+            &group_secret_params,
             profile_key_credential,
-        );
-    profileKeyCredentialPresentationOut
-        .copy_from_slice(&bincode::serialize(&profile_key_credential_presentation).unwrap());
+        ) {
+        Ok(result) => result,
+        Err(e) => return e.get_ffi_return_code(),
+    };
+    match bincode::serialize(&profile_key_credential_selective_presentation) {
+        Ok(result) => profileKeyCredentialSelectivePresentationOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
+    FFI_RETURN_OK
+}
+
+pub fn ServerPublicParams_createProfileKeyCredentialSelectivePresentationWithContextDeterministic(
+    serverPublicParamsIn: &[u8],
+    randomnessIn: &[u8],
+    groupSecretParamsIn: &[u8],
+    profileKeyCredentialIn: &[u8],
+    contextIn: &[u8],
+    profileKeyCredentialSelectivePresentationOut: &mut [u8],
+) -> i32 {
+    let server_public_params: api::ServerPublicParams =
+        match serialization::deserialize(serverPublicParamsIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
+        };
+
+    let randomness: simple_types::RandomnessBytes = match bincode::deserialize(randomnessIn) {
+        Ok(result) => result,
+        Err(_) => return FFI_RETURN_INPUT_ERROR,
+    };
+
+    let group_secret_params: api::groups::GroupSecretParams =
+        match serialization::deserialize(groupSecretParamsIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
+
+    let profile_key_credential: api::profiles::ProfileKeyCredential =
+        match serialization::deserialize(profileKeyCredentialIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
+    let context = contextIn;
+    let profile_key_credential_selective_presentation = match server_public_params
+        .create_profile_key_credential_selective_presentation_with_context(
+            randomness,
+            &group_secret_params,
+            profile_key_credential,
+            context,
+        ) {
+        Ok(result) => result,
+        Err(e) => return e.get_ffi_return_code(),
+    };
+    match bincode::serialize(&profile_key_credential_selective_presentation) {
+        Ok(result) => profileKeyCredentialSelectivePresentationOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
     FFI_RETURN_OK
 }
 
@@ -522,9 +1130,9 @@ pub fn ServerSecretParams_issueAuthCredentialDeterministic(
     authCredentialResponseOut: &mut [u8],
 ) -> i32 {
     let server_secret_params: api::ServerSecretParams =
-        match bincode::deserialize(serverSecretParamsIn) {
+        match serialization::deserialize(serverSecretParamsIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
         };
 
     let randomness: simple_types::RandomnessBytes = match bincode::deserialize(randomnessIn) {
@@ -538,9 +1146,14 @@ pub fn ServerSecretParams_issueAuthCredentialDeterministic(
     };
     let redemption_time = redemptionTimeIn;
     let auth_credential_response =
-        server_secret_params.issue_auth_credential(randomness, uuid, redemption_time);
-    authCredentialResponseOut
-        .copy_from_slice(&bincode::serialize(&auth_credential_response).unwrap());
+        match server_secret_params.issue_auth_credential(randomness, uuid, redemption_time) {
+            Ok(result) => result,
+            Err(e) => return e.get_ffi_return_code(),
+        };
+    match bincode::serialize(&auth_credential_response) {
+        Ok(result) => authCredentialResponseOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
     FFI_RETURN_OK
 }
 
@@ -550,27 +1163,163 @@ pub fn ServerSecretParams_verifyAuthCredentialPresentation(
     authCredentialPresentationIn: &[u8],
 ) -> i32 {
     let server_secret_params: api::ServerSecretParams =
-        match bincode::deserialize(serverSecretParamsIn) {
+        match serialization::deserialize(serverSecretParamsIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
         };
 
     let group_public_params: api::groups::GroupPublicParams =
-        match bincode::deserialize(groupPublicParamsIn) {
+        match serialization::deserialize(groupPublicParamsIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INPUT_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
         };
 
     let auth_credential_presentation: api::auth::AuthCredentialPresentation =
-        match bincode::deserialize(authCredentialPresentationIn) {
+        match serialization::deserialize(authCredentialPresentationIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INPUT_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
         };
     match server_secret_params
         .verify_auth_credential_presentation(group_public_params, &auth_credential_presentation)
     {
         Ok(_) => (),
+        Err(e) => return e.get_ffi_return_code(),
+    }
+    FFI_RETURN_OK
+}
+
+pub fn ServerSecretParams_verifyAuthCredentialPresentationWithContext(
+    serverSecretParamsIn: &[u8],
+    groupPublicParamsIn: &[u8],
+    authCredentialPresentationIn: &[u8],
+    contextIn: &[u8],
+) -> i32 {
+    let server_secret_params: api::ServerSecretParams =
+        match serialization::deserialize(serverSecretParamsIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
+        };
+
+    let group_public_params: api::groups::GroupPublicParams =
+        match serialization::deserialize(groupPublicParamsIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
+
+    let auth_credential_presentation: api::auth::AuthCredentialPresentation =
+        match serialization::deserialize(authCredentialPresentationIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
+    let context = contextIn;
+    match server_secret_params.verify_auth_credential_presentation_with_context(
+        group_public_params,
+        &auth_credential_presentation,
+        context,
+    ) {
+        Ok(_) => (),
+        Err(e) => return e.get_ffi_return_code(),
+    }
+    FFI_RETURN_OK
+}
+
+pub fn ServerSecretParams_issueRateLimitCredentialDeterministic(
+    serverSecretParamsIn: &[u8],
+    randomnessIn: &[u8],
+    rateLimitCredentialRequestIn: &[u8],
+    redemptionTimeIn: u32,
+    rateLimitCredentialResponseOut: &mut [u8],
+) -> i32 {
+    let server_secret_params: api::ServerSecretParams =
+        match serialization::deserialize(serverSecretParamsIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
+        };
+
+    let randomness: simple_types::RandomnessBytes = match bincode::deserialize(randomnessIn) {
+        Ok(result) => result,
         Err(_) => return FFI_RETURN_INPUT_ERROR,
+    };
+
+    let rate_limit_credential_request: api::auth::RateLimitCredentialRequest =
+        match serialization::deserialize(rateLimitCredentialRequestIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
+    let redemption_time = redemptionTimeIn;
+    let rate_limit_credential_response = match server_secret_params.issue_rate_limit_credential(
+        randomness,
+        &rate_limit_credential_request,
+        redemption_time,
+    ) {
+        Ok(result) => result,
+        Err(e) => return e.get_ffi_return_code(),
+    };
+    match bincode::serialize(&rate_limit_credential_response) {
+        Ok(result) => rateLimitCredentialResponseOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
+    FFI_RETURN_OK
+}
+
+pub fn ServerSecretParams_verifyRateLimitToken(
+    serverSecretParamsIn: &[u8],
+    rateLimitTokenIn: &[u8],
+) -> i32 {
+    let server_secret_params: api::ServerSecretParams =
+        match serialization::deserialize(serverSecretParamsIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
+        };
+
+    let rate_limit_token: api::auth::RateLimitToken =
+        match serialization::deserialize(rateLimitTokenIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
+    match server_secret_params.verify_rate_limit_token(&rate_limit_token) {
+        Ok(_) => (),
+        Err(e) => return e.get_ffi_return_code(),
+    }
+    FFI_RETURN_OK
+}
+
+pub fn ServerSecretParams_verifyGroupInviteLinkPresentation(
+    serverSecretParamsIn: &[u8],
+    groupPublicParamsIn: &[u8],
+    groupInviteLinkPasswordCommitmentIn: &[u8],
+    groupInviteLinkPresentationIn: &[u8],
+) -> i32 {
+    let server_secret_params: api::ServerSecretParams =
+        match serialization::deserialize(serverSecretParamsIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
+        };
+
+    let group_public_params: api::groups::GroupPublicParams =
+        match serialization::deserialize(groupPublicParamsIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
+
+    let group_invite_link_password_commitment: api::groups::GroupInviteLinkPasswordCommitment =
+        match serialization::deserialize(groupInviteLinkPasswordCommitmentIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
+
+    let group_invite_link_presentation: api::groups::GroupInviteLinkPresentation =
+        match serialization::deserialize(groupInviteLinkPresentationIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
+    match server_secret_params.verify_group_invite_link_presentation(
+        group_public_params,
+        group_invite_link_password_commitment,
+        &group_invite_link_presentation,
+    ) {
+        Ok(_) => (),
+        Err(e) => return e.get_ffi_return_code(),
     }
     FFI_RETURN_OK
 }
@@ -584,9 +1333,9 @@ pub fn ServerSecretParams_issueProfileKeyCredentialDeterministic(
     profileKeyCredentialResponseOut: &mut [u8],
 ) -> i32 {
     let server_secret_params: api::ServerSecretParams =
-        match bincode::deserialize(serverSecretParamsIn) {
+        match serialization::deserialize(serverSecretParamsIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
         };
 
     let randomness: simple_types::RandomnessBytes = match bincode::deserialize(randomnessIn) {
@@ -595,9 +1344,9 @@ pub fn ServerSecretParams_issueProfileKeyCredentialDeterministic(
     };
 
     let profile_key_credential_request: api::profiles::ProfileKeyCredentialRequest =
-        match bincode::deserialize(profileKeyCredentialRequestIn) {
+        match serialization::deserialize(profileKeyCredentialRequestIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INPUT_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
         };
 
     let uuid: simple_types::UidBytes = match bincode::deserialize(uuidIn) {
@@ -606,9 +1355,9 @@ pub fn ServerSecretParams_issueProfileKeyCredentialDeterministic(
     };
 
     let profile_key_commitment: api::profiles::ProfileKeyCommitment =
-        match bincode::deserialize(profileKeyCommitmentIn) {
+        match serialization::deserialize(profileKeyCommitmentIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INPUT_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
         };
     let profile_key_credential_response = match server_secret_params.issue_profile_key_credential(
         randomness,
@@ -617,10 +1366,12 @@ pub fn ServerSecretParams_issueProfileKeyCredentialDeterministic(
         profile_key_commitment,
     ) {
         Ok(result) => result,
-        Err(_) => return FFI_RETURN_INPUT_ERROR,
+        Err(e) => return e.get_ffi_return_code(),
     };
-    profileKeyCredentialResponseOut
-        .copy_from_slice(&bincode::serialize(&profile_key_credential_response).unwrap());
+    match bincode::serialize(&profile_key_credential_response) {
+        Ok(result) => profileKeyCredentialResponseOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
     FFI_RETURN_OK
 }
 
@@ -630,36 +1381,136 @@ pub fn ServerSecretParams_verifyProfileKeyCredentialPresentation(
     profileKeyCredentialPresentationIn: &[u8],
 ) -> i32 {
     let server_secret_params: api::ServerSecretParams =
-        match bincode::deserialize(serverSecretParamsIn) {
+        match serialization::deserialize(serverSecretParamsIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
         };
 
     let group_public_params: api::groups::GroupPublicParams =
-        match bincode::deserialize(groupPublicParamsIn) {
+        match serialization::deserialize(groupPublicParamsIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INPUT_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
         };
 
     let profile_key_credential_presentation: api::profiles::ProfileKeyCredentialPresentation =
-        match bincode::deserialize(profileKeyCredentialPresentationIn) {
+        match serialization::deserialize(profileKeyCredentialPresentationIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INPUT_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
         };
     match server_secret_params.verify_profile_key_credential_presentation(
         group_public_params,
         &profile_key_credential_presentation,
     ) {
         Ok(_) => (),
-        Err(_) => return FFI_RETURN_INPUT_ERROR,
+        Err(e) => return e.get_ffi_return_code(),
+    }
+    FFI_RETURN_OK
+}
+
+pub fn ServerSecretParams_verifyProfileKeyCredentialPresentationWithContext(
+    serverSecretParamsIn: &[u8],
+    groupPublicParamsIn: &[u8],
+    profileKeyCredentialPresentationIn: &[u8],
+    contextIn: &[u8],
+) -> i32 {
+    let server_secret_params: api::ServerSecretParams =
+        match serialization::deserialize(serverSecretParamsIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
+        };
+
+    let group_public_params: api::groups::GroupPublicParams =
+        match serialization::deserialize(groupPublicParamsIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
+
+    let profile_key_credential_presentation: api::profiles::ProfileKeyCredentialPresentation =
+        match serialization::deserialize(profileKeyCredentialPresentationIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
+    let context = contextIn;
+    match server_secret_params.verify_profile_key_credential_presentation_with_context(
+        group_public_params,
+        &profile_key_credential_presentation,
+        context,
+    ) {
+        Ok(_) => (),
+        Err(e) => return e.get_ffi_return_code(),
+    }
+    FFI_RETURN_OK
+}
+
+pub fn ServerSecretParams_verifyProfileKeyCredentialSelectivePresentation(
+    serverSecretParamsIn: &[u8],
+    groupPublicParamsIn: &[u8],
+    profileKeyCredentialSelectivePresentationIn: &[u8],
+) -> i32 {
+    let server_secret_params: api::ServerSecretParams =
+        match serialization::deserialize(serverSecretParamsIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
+        };
+
+    let group_public_params: api::groups::GroupPublicParams =
+        match serialization::deserialize(groupPublicParamsIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
+
+    let profile_key_credential_selective_presentation: api::profiles::ProfileKeyCredentialSelectivePresentation = match serialization::deserialize(profileKeyCredentialSelectivePresentationIn) {
+        Ok(result) => result,
+        Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+    };
+    match server_secret_params.verify_profile_key_credential_selective_presentation(
+        group_public_params,
+        &profile_key_credential_selective_presentation,
+    ) {
+        Ok(_) => (),
+        Err(e) => return e.get_ffi_return_code(),
+    }
+    FFI_RETURN_OK
+}
+
+pub fn ServerSecretParams_verifyProfileKeyCredentialSelectivePresentationWithContext(
+    serverSecretParamsIn: &[u8],
+    groupPublicParamsIn: &[u8],
+    profileKeyCredentialSelectivePresentationIn: &[u8],
+    contextIn: &[u8],
+) -> i32 {
+    let server_secret_params: api::ServerSecretParams =
+        match serialization::deserialize(serverSecretParamsIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
+        };
+
+    let group_public_params: api::groups::GroupPublicParams =
+        match serialization::deserialize(groupPublicParamsIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
+
+    let profile_key_credential_selective_presentation: api::profiles::ProfileKeyCredentialSelectivePresentation = match serialization::deserialize(profileKeyCredentialSelectivePresentationIn) {
+        Ok(result) => result,
+        Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+    };
+    let context = contextIn;
+    match server_secret_params.verify_profile_key_credential_selective_presentation_with_context(
+        group_public_params,
+        &profile_key_credential_selective_presentation,
+        context,
+    ) {
+        Ok(_) => (),
+        Err(e) => return e.get_ffi_return_code(),
     }
     FFI_RETURN_OK
 }
 
 pub fn GroupPublicParams_checkValidContents(groupPublicParamsIn: &[u8]) -> i32 {
-    let _: api::groups::GroupPublicParams = match bincode::deserialize(groupPublicParamsIn) {
+    let _: api::groups::GroupPublicParams = match serialization::deserialize(groupPublicParamsIn) {
         Ok(result) => result,
-        Err(_) => return FFI_RETURN_INPUT_ERROR,
+        Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
     };
 
     FFI_RETURN_OK
@@ -670,19 +1521,62 @@ pub fn GroupPublicParams_getGroupIdentifier(
     groupIdentifierOut: &mut [u8],
 ) -> i32 {
     let group_public_params: api::groups::GroupPublicParams =
-        match bincode::deserialize(groupPublicParamsIn) {
+        match serialization::deserialize(groupPublicParamsIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
         };
     let group_identifier = group_public_params.get_group_identifier();
-    groupIdentifierOut.copy_from_slice(&bincode::serialize(&group_identifier).unwrap());
+    match bincode::serialize(&group_identifier) {
+        Ok(result) => groupIdentifierOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
+    FFI_RETURN_OK
+}
+
+pub fn GroupPublicParams_verifyUuidCiphertextMatch(
+    groupPublicParamsIn: &[u8],
+    uuidCiphertextIn: &[u8],
+    randomizedUuidCiphertextIn: &[u8],
+    uuidCiphertextMatchProofIn: &[u8],
+) -> i32 {
+    let group_public_params: api::groups::GroupPublicParams =
+        match serialization::deserialize(groupPublicParamsIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
+        };
+
+    let uuid_ciphertext: api::groups::UuidCiphertext =
+        match serialization::deserialize(uuidCiphertextIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
+
+    let randomized_uuid_ciphertext: api::groups::RandomizedUuidCiphertext =
+        match serialization::deserialize(randomizedUuidCiphertextIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
+
+    let uuid_ciphertext_match_proof: api::groups::UuidCiphertextMatchProof =
+        match serialization::deserialize(uuidCiphertextMatchProofIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
+    match group_public_params.verify_uuid_ciphertext_match(
+        uuid_ciphertext,
+        randomized_uuid_ciphertext,
+        &uuid_ciphertext_match_proof,
+    ) {
+        Ok(_) => (),
+        Err(e) => return e.get_ffi_return_code(),
+    }
     FFI_RETURN_OK
 }
 
 pub fn ServerPublicParams_checkValidContents(serverPublicParamsIn: &[u8]) -> i32 {
-    let _: api::ServerPublicParams = match bincode::deserialize(serverPublicParamsIn) {
+    let _: api::ServerPublicParams = match serialization::deserialize(serverPublicParamsIn) {
         Ok(result) => result,
-        Err(_) => return FFI_RETURN_INPUT_ERROR,
+        Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
     };
 
     FFI_RETURN_OK
@@ -694,34 +1588,34 @@ pub fn ServerPublicParams_verifySignature(
     notarySignatureIn: &[u8],
 ) -> i32 {
     let server_public_params: api::ServerPublicParams =
-        match bincode::deserialize(serverPublicParamsIn) {
+        match serialization::deserialize(serverPublicParamsIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
         };
     let message = messageIn;
     let mut notary_signature: simple_types::NotarySignatureBytes = [0u8; SIGNATURE_LEN];
     notary_signature.copy_from_slice(notarySignatureIn);
     match server_public_params.verify_signature(message, notary_signature) {
         Ok(_) => (),
-        _ => return FFI_RETURN_INPUT_ERROR,
+        Err(e) => return e.get_ffi_return_code(),
     };
     FFI_RETURN_OK
 }
 
 pub fn AuthCredentialResponse_checkValidContents(authCredentialResponseIn: &[u8]) -> i32 {
-    let _: api::auth::AuthCredentialResponse = match bincode::deserialize(authCredentialResponseIn)
-    {
-        Ok(result) => result,
-        Err(_) => return FFI_RETURN_INPUT_ERROR,
-    };
+    let _: api::auth::AuthCredentialResponse =
+        match serialization::deserialize(authCredentialResponseIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
 
     FFI_RETURN_OK
 }
 
 pub fn AuthCredential_checkValidContents(authCredentialIn: &[u8]) -> i32 {
-    let _: api::auth::AuthCredential = match bincode::deserialize(authCredentialIn) {
+    let _: api::auth::AuthCredential = match serialization::deserialize(authCredentialIn) {
         Ok(result) => result,
-        Err(_) => return FFI_RETURN_INPUT_ERROR,
+        Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
     };
 
     FFI_RETURN_OK
@@ -729,9 +1623,9 @@ pub fn AuthCredential_checkValidContents(authCredentialIn: &[u8]) -> i32 {
 
 pub fn AuthCredentialPresentation_checkValidContents(authCredentialPresentationIn: &[u8]) -> i32 {
     let _: api::auth::AuthCredentialPresentation =
-        match bincode::deserialize(authCredentialPresentationIn) {
+        match serialization::deserialize(authCredentialPresentationIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INPUT_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
         };
 
     FFI_RETURN_OK
@@ -742,12 +1636,15 @@ pub fn AuthCredentialPresentation_getUuidCiphertext(
     uuidCiphertextOut: &mut [u8],
 ) -> i32 {
     let auth_credential_presentation: api::auth::AuthCredentialPresentation =
-        match bincode::deserialize(authCredentialPresentationIn) {
+        match serialization::deserialize(authCredentialPresentationIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
         };
     let uuid_ciphertext = auth_credential_presentation.get_uuid_ciphertext();
-    uuidCiphertextOut.copy_from_slice(&bincode::serialize(&uuid_ciphertext).unwrap());
+    match bincode::serialize(&uuid_ciphertext) {
+        Ok(result) => uuidCiphertextOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
     FFI_RETURN_OK
 }
 
@@ -756,22 +1653,118 @@ pub fn AuthCredentialPresentation_getRedemptionTime(
     redemptionTimeOut: &mut [u8],
 ) -> i32 {
     let auth_credential_presentation: api::auth::AuthCredentialPresentation =
-        match bincode::deserialize(authCredentialPresentationIn) {
+        match serialization::deserialize(authCredentialPresentationIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
         };
     let redemption_time = auth_credential_presentation.get_redemption_time();
     redemptionTimeOut.copy_from_slice(&redemption_time.to_be_bytes());
     FFI_RETURN_OK
 }
 
+pub fn RateLimitCredentialRequestContext_checkValidContents(
+    rateLimitCredentialRequestContextIn: &[u8],
+) -> i32 {
+    let _: api::auth::RateLimitCredentialRequestContext =
+        match serialization::deserialize(rateLimitCredentialRequestContextIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
+
+    FFI_RETURN_OK
+}
+
+pub fn RateLimitCredentialRequestContext_getRequest(
+    rateLimitCredentialRequestContextIn: &[u8],
+    rateLimitCredentialRequestOut: &mut [u8],
+) -> i32 {
+    let rate_limit_credential_request_context: api::auth::RateLimitCredentialRequestContext =
+        match serialization::deserialize(rateLimitCredentialRequestContextIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
+        };
+    let rate_limit_credential_request = rate_limit_credential_request_context.get_request();
+    match bincode::serialize(&rate_limit_credential_request) {
+        Ok(result) => rateLimitCredentialRequestOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
+    FFI_RETURN_OK
+}
+
+pub fn RateLimitCredentialRequest_checkValidContents(rateLimitCredentialRequestIn: &[u8]) -> i32 {
+    let _: api::auth::RateLimitCredentialRequest =
+        match serialization::deserialize(rateLimitCredentialRequestIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
+
+    FFI_RETURN_OK
+}
+
+pub fn RateLimitCredentialResponse_checkValidContents(rateLimitCredentialResponseIn: &[u8]) -> i32 {
+    let _: api::auth::RateLimitCredentialResponse =
+        match serialization::deserialize(rateLimitCredentialResponseIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
+
+    FFI_RETURN_OK
+}
+
+pub fn RateLimitCredential_checkValidContents(rateLimitCredentialIn: &[u8]) -> i32 {
+    let _: api::auth::RateLimitCredential = match serialization::deserialize(rateLimitCredentialIn)
+    {
+        Ok(result) => result,
+        Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+    };
+
+    FFI_RETURN_OK
+}
+
+pub fn RateLimitToken_checkValidContents(rateLimitTokenIn: &[u8]) -> i32 {
+    let _: api::auth::RateLimitToken = match serialization::deserialize(rateLimitTokenIn) {
+        Ok(result) => result,
+        Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+    };
+
+    FFI_RETURN_OK
+}
+
+pub fn RateLimitToken_getTag(rateLimitTokenIn: &[u8], rateLimitTagOut: &mut [u8]) -> i32 {
+    let rate_limit_token: api::auth::RateLimitToken =
+        match serialization::deserialize(rateLimitTokenIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
+        };
+    let rate_limit_tag = rate_limit_token.get_tag();
+    match bincode::serialize(&rate_limit_tag) {
+        Ok(result) => rateLimitTagOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
+    FFI_RETURN_OK
+}
+
+pub fn RateLimitToken_getRedemptionTime(
+    rateLimitTokenIn: &[u8],
+    redemptionTimeOut: &mut [u8],
+) -> i32 {
+    let rate_limit_token: api::auth::RateLimitToken =
+        match serialization::deserialize(rateLimitTokenIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
+        };
+    let redemption_time = rate_limit_token.get_redemption_time();
+    redemptionTimeOut.copy_from_slice(&redemption_time.to_be_bytes());
+    FFI_RETURN_OK
+}
+
 pub fn ProfileKeyCredentialRequestContext_checkValidContents(
     profileKeyCredentialRequestContextIn: &[u8],
 ) -> i32 {
     let _: api::profiles::ProfileKeyCredentialRequestContext =
-        match bincode::deserialize(profileKeyCredentialRequestContextIn) {
+        match serialization::deserialize(profileKeyCredentialRequestContextIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INPUT_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
         };
 
     FFI_RETURN_OK
@@ -782,21 +1775,23 @@ pub fn ProfileKeyCredentialRequestContext_getRequest(
     profileKeyCredentialRequestOut: &mut [u8],
 ) -> i32 {
     let profile_key_credential_request_context: api::profiles::ProfileKeyCredentialRequestContext =
-        match bincode::deserialize(profileKeyCredentialRequestContextIn) {
+        match serialization::deserialize(profileKeyCredentialRequestContextIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
         };
     let profile_key_credential_request = profile_key_credential_request_context.get_request();
-    profileKeyCredentialRequestOut
-        .copy_from_slice(&bincode::serialize(&profile_key_credential_request).unwrap());
+    match bincode::serialize(&profile_key_credential_request) {
+        Ok(result) => profileKeyCredentialRequestOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
     FFI_RETURN_OK
 }
 
 pub fn ProfileKeyCredentialRequest_checkValidContents(profileKeyCredentialRequestIn: &[u8]) -> i32 {
     let _: api::profiles::ProfileKeyCredentialRequest =
-        match bincode::deserialize(profileKeyCredentialRequestIn) {
+        match serialization::deserialize(profileKeyCredentialRequestIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INPUT_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
         };
 
     FFI_RETURN_OK
@@ -806,20 +1801,20 @@ pub fn ProfileKeyCredentialResponse_checkValidContents(
     profileKeyCredentialResponseIn: &[u8],
 ) -> i32 {
     let _: api::profiles::ProfileKeyCredentialResponse =
-        match bincode::deserialize(profileKeyCredentialResponseIn) {
+        match serialization::deserialize(profileKeyCredentialResponseIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INPUT_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
         };
 
     FFI_RETURN_OK
 }
 
 pub fn ProfileKeyCredential_checkValidContents(profileKeyCredentialIn: &[u8]) -> i32 {
-    let _: api::profiles::ProfileKeyCredential = match bincode::deserialize(profileKeyCredentialIn)
-    {
-        Ok(result) => result,
-        Err(_) => return FFI_RETURN_INPUT_ERROR,
-    };
+    let _: api::profiles::ProfileKeyCredential =
+        match serialization::deserialize(profileKeyCredentialIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
 
     FFI_RETURN_OK
 }
@@ -828,9 +1823,9 @@ pub fn ProfileKeyCredentialPresentation_checkValidContents(
     profileKeyCredentialPresentationIn: &[u8],
 ) -> i32 {
     let _: api::profiles::ProfileKeyCredentialPresentation =
-        match bincode::deserialize(profileKeyCredentialPresentationIn) {
+        match serialization::deserialize(profileKeyCredentialPresentationIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INPUT_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
         };
 
     FFI_RETURN_OK
@@ -841,12 +1836,15 @@ pub fn ProfileKeyCredentialPresentation_getUuidCiphertext(
     uuidCiphertextOut: &mut [u8],
 ) -> i32 {
     let profile_key_credential_presentation: api::profiles::ProfileKeyCredentialPresentation =
-        match bincode::deserialize(profileKeyCredentialPresentationIn) {
+        match serialization::deserialize(profileKeyCredentialPresentationIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
         };
     let uuid_ciphertext = profile_key_credential_presentation.get_uuid_ciphertext();
-    uuidCiphertextOut.copy_from_slice(&bincode::serialize(&uuid_ciphertext).unwrap());
+    match bincode::serialize(&uuid_ciphertext) {
+        Ok(result) => uuidCiphertextOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
     FFI_RETURN_OK
 }
 
@@ -855,33 +1853,149 @@ pub fn ProfileKeyCredentialPresentation_getProfileKeyCiphertext(
     profileKeyCiphertextOut: &mut [u8],
 ) -> i32 {
     let profile_key_credential_presentation: api::profiles::ProfileKeyCredentialPresentation =
-        match bincode::deserialize(profileKeyCredentialPresentationIn) {
+        match serialization::deserialize(profileKeyCredentialPresentationIn) {
             Ok(result) => result,
-            Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
         };
     let profile_key_ciphertext = profile_key_credential_presentation.get_profile_key_ciphertext();
-    profileKeyCiphertextOut.copy_from_slice(&bincode::serialize(&profile_key_ciphertext).unwrap());
+    match bincode::serialize(&profile_key_ciphertext) {
+        Ok(result) => profileKeyCiphertextOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
     FFI_RETURN_OK
 }
 
-pub fn UuidCiphertext_checkValidContents(uuidCiphertextIn: &[u8]) -> i32 {
-    let _: api::groups::UuidCiphertext = match bincode::deserialize(uuidCiphertextIn) {
+pub fn ProfileKeyCredentialSelectivePresentation_checkValidContents(
+    profileKeyCredentialSelectivePresentationIn: &[u8],
+) -> i32 {
+    let _: api::profiles::ProfileKeyCredentialSelectivePresentation =
+        match serialization::deserialize(profileKeyCredentialSelectivePresentationIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
+
+    FFI_RETURN_OK
+}
+
+pub fn ProfileKeyCredentialSelectivePresentation_getUuidCiphertext(
+    profileKeyCredentialSelectivePresentationIn: &[u8],
+    uuidCiphertextOut: &mut [u8],
+) -> i32 {
+    let profile_key_credential_selective_presentation: api::profiles::ProfileKeyCredentialSelectivePresentation = match serialization::deserialize(profileKeyCredentialSelectivePresentationIn) {
         Ok(result) => result,
-        Err(_) => return FFI_RETURN_INPUT_ERROR,
+        Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
     };
+    let uuid_ciphertext = profile_key_credential_selective_presentation.get_uuid_ciphertext();
+    match bincode::serialize(&uuid_ciphertext) {
+        Ok(result) => uuidCiphertextOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
+    FFI_RETURN_OK
+}
+
+pub fn GroupInviteLinkPassword_getCommitment(
+    groupInviteLinkPasswordIn: &[u8],
+    groupPublicParamsIn: &[u8],
+    groupInviteLinkPasswordCommitmentOut: &mut [u8],
+) -> i32 {
+    let group_invite_link_password: api::groups::GroupInviteLinkPassword =
+        match bincode::deserialize(groupInviteLinkPasswordIn) {
+            Ok(result) => result,
+            Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+        };
+
+    let group_public_params: api::groups::GroupPublicParams =
+        match serialization::deserialize(groupPublicParamsIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
+    let group_invite_link_password_commitment =
+        group_invite_link_password.get_commitment(group_public_params);
+    match bincode::serialize(&group_invite_link_password_commitment) {
+        Ok(result) => groupInviteLinkPasswordCommitmentOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
+    FFI_RETURN_OK
+}
+
+pub fn GroupInviteLinkPasswordCommitment_checkValidContents(
+    groupInviteLinkPasswordCommitmentIn: &[u8],
+) -> i32 {
+    let _: api::groups::GroupInviteLinkPasswordCommitment =
+        match serialization::deserialize(groupInviteLinkPasswordCommitmentIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
 
     FFI_RETURN_OK
 }
 
-pub fn ProfileKeyCiphertext_checkValidContents(profileKeyCiphertextIn: &[u8]) -> i32 {
-    let _: api::groups::ProfileKeyCiphertext = match bincode::deserialize(profileKeyCiphertextIn) {
+pub fn GroupInviteLinkPresentation_checkValidContents(groupInviteLinkPresentationIn: &[u8]) -> i32 {
+    let _: api::groups::GroupInviteLinkPresentation =
+        match serialization::deserialize(groupInviteLinkPresentationIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
+
+    FFI_RETURN_OK
+}
+
+pub fn GroupInviteLinkPresentation_getUuidCiphertext(
+    groupInviteLinkPresentationIn: &[u8],
+    uuidCiphertextOut: &mut [u8],
+) -> i32 {
+    let group_invite_link_presentation: api::groups::GroupInviteLinkPresentation =
+        match serialization::deserialize(groupInviteLinkPresentationIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INTERNAL_ERROR),
+        };
+    let uuid_ciphertext = group_invite_link_presentation.get_uuid_ciphertext();
+    match bincode::serialize(&uuid_ciphertext) {
+        Ok(result) => uuidCiphertextOut.copy_from_slice(&result),
+        Err(_) => return FFI_RETURN_INTERNAL_ERROR,
+    }
+    FFI_RETURN_OK
+}
+
+pub fn UuidCiphertext_checkValidContents(uuidCiphertextIn: &[u8]) -> i32 {
+    let _: api::groups::UuidCiphertext = match serialization::deserialize(uuidCiphertextIn) {
         Ok(result) => result,
-        Err(_) => return FFI_RETURN_INPUT_ERROR,
+        Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
     };
 
     FFI_RETURN_OK
 }
 
+pub fn ProfileKeyCiphertext_checkValidContents(profileKeyCiphertextIn: &[u8]) -> i32 {
+    let _: api::groups::ProfileKeyCiphertext =
+        match serialization::deserialize(profileKeyCiphertextIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
+
+    FFI_RETURN_OK
+}
+
+pub fn RandomizedUuidCiphertext_checkValidContents(randomizedUuidCiphertextIn: &[u8]) -> i32 {
+    let _: api::groups::RandomizedUuidCiphertext =
+        match serialization::deserialize(randomizedUuidCiphertextIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
+
+    FFI_RETURN_OK
+}
+
+pub fn UuidCiphertextMatchProof_checkValidContents(uuidCiphertextMatchProofIn: &[u8]) -> i32 {
+    let _: api::groups::UuidCiphertextMatchProof =
+        match serialization::deserialize(uuidCiphertextMatchProofIn) {
+            Ok(result) => result,
+            Err(e) => return e.record_last_error(FFI_RETURN_INPUT_ERROR),
+        };
+
+    FFI_RETURN_OK
+}
+
 pub fn Randomness_checkValidContents(randomnessIn: &[u8]) -> i32 {
     let _: simple_types::RandomnessBytes = match bincode::deserialize(randomnessIn) {
         Ok(result) => result,
@@ -5,11 +5,13 @@
 // SPDX-License-Identifier: GPL-3.0-only
 //
 
-pub mod api;
+#[macro_use]
 pub mod common;
+pub mod api;
 pub mod crypto;
 pub mod ffi;
 pub use api::*;
 pub use common::constants::*;
 pub use common::errors::*;
+pub use common::serialization::*;
 pub use common::simple_types::*;
@@ -102,7 +102,8 @@ pub const PROFILE_KEY_CREDENTIAL_PRESENTATION_RESULT: [u8;
 
 #[test]
 fn test_integration_auth() {
-    let server_secret_params = zkgroup::ServerSecretParams::generate(zkgroup::TEST_ARRAY_32);
+    let server_secret_params =
+        zkgroup::ServerSecretParams::generate(zkgroup::TEST_ARRAY_32).unwrap();
     let server_public_params = server_secret_params.get_public_params();
 
     let master_key = zkgroup::groups::GroupMasterKey::new(zkgroup::TEST_ARRAY_32_1);
@@ -117,8 +118,9 @@ fn test_integration_auth() {
     // SERVER
     // Issue credential
     let randomness = zkgroup::TEST_ARRAY_32_2;
-    let auth_credential_response =
-        server_secret_params.issue_auth_credential(randomness, uid, redemption_time);
+    let auth_credential_response = server_secret_params
+        .issue_auth_credential(randomness, uid, redemption_time)
+        .unwrap();
 
     // CLIENT
     let auth_credential = server_public_params
@@ -133,11 +135,9 @@ fn test_integration_auth() {
     // Create and receive presentation
     let randomness = zkgroup::TEST_ARRAY_32_5;
 
-    let presentation = server_public_params.create_auth_credential_presentation(
-        randomness,
-        group_secret_params,
-        auth_credential,
-    );
+    let presentation = server_public_params
+        .create_auth_credential_presentation(randomness, &group_secret_params, auth_credential)
+        .unwrap();
 
     let presentation_bytes = &bincode::serialize(&presentation).unwrap();
 
@@ -150,6 +150,14 @@ fn test_integration_auth() {
         .verify_auth_credential_presentation(group_public_params, &presentation)
         .unwrap();
 
+    // The vector is the baseline encoding, and reads back as it was
+    let baseline_presentation: zkgroup::auth::AuthCredentialPresentation =
+        zkgroup::deserialize(&AUTH_CREDENTIAL_PRESENTATION_RESULT).unwrap();
+    assert!(baseline_presentation.get_key_id() == zkgroup::DEFAULT_SERVER_KEY_ID);
+    server_secret_params
+        .verify_auth_credential_presentation(group_public_params, &baseline_presentation)
+        .unwrap();
+
     // test encoding
     // these tests will also discover if the serialized sizes change,
     //   necessitating an update to the LEN constants
@@ -183,13 +191,436 @@ fn test_integration_auth() {
     randomness_bytes.copy_from_slice(&bincode::serialize(&randomness).unwrap());
 }
 
+#[test]
+fn test_integration_auth_redemption_window() {
+    let server_secret_params =
+        zkgroup::ServerSecretParams::generate(zkgroup::TEST_ARRAY_32).unwrap();
+    let server_public_params = server_secret_params.get_public_params();
+
+    let master_key = zkgroup::groups::GroupMasterKey::new(zkgroup::TEST_ARRAY_32_1);
+    let group_secret_params =
+        zkgroup::groups::GroupSecretParams::derive_from_master_key(master_key);
+    let group_public_params = group_secret_params.get_public_params();
+
+    let uid = zkgroup::TEST_ARRAY_16;
+    let redemption_time = 123456u32;
+
+    let auth_credential_response = server_secret_params
+        .issue_auth_credential(zkgroup::TEST_ARRAY_32_2, uid, redemption_time)
+        .unwrap();
+    let auth_credential = server_public_params
+        .receive_auth_credential(uid, redemption_time, &auth_credential_response)
+        .unwrap();
+    let presentation = server_public_params
+        .create_auth_credential_presentation(
+            zkgroup::TEST_ARRAY_32_5,
+            &group_secret_params,
+            auth_credential,
+        )
+        .unwrap();
+
+    let redemption_window = zkgroup::auth::RedemptionWindow::default();
+    let redemption_seconds = u64::from(redemption_time) * zkgroup::SECONDS_PER_DAY;
+
+    // Within the window
+    for current_time in &[
+        redemption_seconds - zkgroup::SECONDS_PER_DAY,
+        redemption_seconds,
+        redemption_seconds + 2 * zkgroup::SECONDS_PER_DAY,
+    ] {
+        server_secret_params
+            .verify_auth_credential_presentation_with_time(
+                group_public_params,
+                &presentation,
+                *current_time,
+                redemption_window,
+            )
+            .unwrap();
+    }
+
+    // Expired or not yet valid
+    for current_time in &[
+        redemption_seconds - zkgroup::SECONDS_PER_DAY - 1,
+        redemption_seconds + 2 * zkgroup::SECONDS_PER_DAY + 1,
+    ] {
+        match server_secret_params.verify_auth_credential_presentation_with_time(
+            group_public_params,
+            &presentation,
+            *current_time,
+            redemption_window,
+        ) {
+            Err(zkgroup::ZkGroupError::InvalidRedemptionTime) => (),
+            _ => assert!(false),
+        }
+    }
+
+    // Custom window
+    let narrow_window = zkgroup::auth::RedemptionWindow::new(0, 0);
+    server_secret_params
+        .verify_auth_credential_presentation_with_time(
+            group_public_params,
+            &presentation,
+            redemption_seconds,
+            narrow_window,
+        )
+        .unwrap();
+    match server_secret_params.verify_auth_credential_presentation_with_time(
+        group_public_params,
+        &presentation,
+        redemption_seconds + 1,
+        narrow_window,
+    ) {
+        Err(zkgroup::ZkGroupError::InvalidRedemptionTime) => (),
+        _ => assert!(false),
+    }
+}
+
+#[test]
+fn test_integration_auth_batch_issuance() {
+    let server_secret_params =
+        zkgroup::ServerSecretParams::generate(zkgroup::TEST_ARRAY_32).unwrap();
+    let server_public_params = server_secret_params.get_public_params();
+
+    let master_key = zkgroup::groups::GroupMasterKey::new(zkgroup::TEST_ARRAY_32_1);
+    let group_secret_params =
+        zkgroup::groups::GroupSecretParams::derive_from_master_key(master_key);
+    let group_public_params = group_secret_params.get_public_params();
+
+    let uid = zkgroup::TEST_ARRAY_16;
+    let redemption_times: Vec<u32> = (123456u32..123463u32).collect();
+
+    let response = server_secret_params
+        .issue_auth_credentials(zkgroup::TEST_ARRAY_32_2, uid, &redemption_times)
+        .unwrap();
+    let auth_credentials = server_public_params
+        .receive_auth_credentials(uid, &redemption_times, &response)
+        .unwrap();
+    assert!(auth_credentials.len() == redemption_times.len());
+
+    for (auth_credential, redemption_time) in auth_credentials.iter().zip(&redemption_times) {
+        let presentation = server_public_params
+            .create_auth_credential_presentation(
+                zkgroup::TEST_ARRAY_32_5,
+                &group_secret_params,
+                *auth_credential,
+            )
+            .unwrap();
+        assert!(presentation.get_redemption_time() == *redemption_time);
+        server_secret_params
+            .verify_auth_credential_presentation(group_public_params, &presentation)
+            .unwrap();
+    }
+
+    // The client must ask for the same redemption times the server issued
+    match server_public_params.receive_auth_credentials(uid, &redemption_times[1..], &response) {
+        Err(zkgroup::ZkGroupError::ProofVerificationFailure(
+            "AuthCredentialBatchIssuanceProof",
+        )) => (),
+        _ => assert!(false),
+    }
+
+    match server_secret_params.issue_auth_credentials(zkgroup::TEST_ARRAY_32_2, uid, &[]) {
+        Err(zkgroup::ZkGroupError::BadArgs) => (),
+        _ => assert!(false),
+    }
+    let too_many_redemption_times = vec![123456u32; zkgroup::MAX_AUTH_CREDENTIAL_BATCH_SIZE + 1];
+    match server_secret_params.issue_auth_credentials(
+        zkgroup::TEST_ARRAY_32_2,
+        uid,
+        &too_many_redemption_times,
+    ) {
+        Err(zkgroup::ZkGroupError::BadArgs) => (),
+        _ => assert!(false),
+    }
+}
+
+#[test]
+fn test_integration_rate_limit_token() {
+    let server_secret_params =
+        zkgroup::ServerSecretParams::generate(zkgroup::TEST_ARRAY_32).unwrap();
+    let server_public_params = server_secret_params.get_public_params();
+
+    let redemption_time = 123456u32;
+
+    let receive = |randomness, redemption_time| {
+        let context = server_public_params.create_rate_limit_credential_request_context(randomness);
+        let request = context.get_request();
+        let request_bytes = bincode::serialize(&request).unwrap();
+        assert!(request_bytes.len() == zkgroup::RATE_LIMIT_CREDENTIAL_REQUEST_LEN);
+        let response = server_secret_params
+            .issue_rate_limit_credential(zkgroup::TEST_ARRAY_32_2, &request, redemption_time)
+            .unwrap();
+        server_public_params
+            .receive_rate_limit_credential(&context, &response)
+            .unwrap()
+    };
+    let rate_limit_credential = receive(zkgroup::TEST_ARRAY_32_1, redemption_time);
+    let credential_bytes = bincode::serialize(&rate_limit_credential).unwrap();
+    assert!(credential_bytes.len() == zkgroup::RATE_LIMIT_CREDENTIAL_LEN);
+    assert!(rate_limit_credential.get_redemption_time() == redemption_time);
+
+    // CLIENT
+    let token = server_public_params
+        .create_rate_limit_token(zkgroup::TEST_ARRAY_32_5, &rate_limit_credential)
+        .unwrap();
+    let token_bytes = bincode::serialize(&token).unwrap();
+    assert!(token_bytes.len() == zkgroup::RATE_LIMIT_TOKEN_LEN);
+    let token: zkgroup::auth::RateLimitToken = zkgroup::deserialize(&token_bytes).unwrap();
+    assert!(token.get_redemption_time() == redemption_time);
+
+    // SERVER
+    server_secret_params
+        .verify_rate_limit_token(&token)
+        .unwrap();
+    let redemption_seconds = u64::from(redemption_time) * zkgroup::SECONDS_PER_DAY;
+    server_secret_params
+        .verify_rate_limit_token_with_time(
+            &token,
+            redemption_seconds,
+            zkgroup::auth::RedemptionWindow::default(),
+        )
+        .unwrap();
+    match server_secret_params.verify_rate_limit_token_with_time(
+        &token,
+        redemption_seconds + 3 * zkgroup::SECONDS_PER_DAY,
+        zkgroup::auth::RedemptionWindow::default(),
+    ) {
+        Err(zkgroup::ZkGroupError::InvalidRedemptionTime) => (),
+        _ => assert!(false),
+    }
+
+    // Tokens with fresh randomness share the tag, but nothing else
+    let second_token = server_public_params
+        .create_rate_limit_token(zkgroup::TEST_ARRAY_32_4, &rate_limit_credential)
+        .unwrap();
+    server_secret_params
+        .verify_rate_limit_token(&second_token)
+        .unwrap();
+    assert!(second_token.get_tag() == token.get_tag());
+    assert!(bincode::serialize(&second_token).unwrap()[..2 + 32] != token_bytes[..2 + 32]);
+
+    // Another day or another credential gets another tag
+    let next_day_token = server_public_params
+        .create_rate_limit_token(
+            zkgroup::TEST_ARRAY_32_5,
+            &receive(zkgroup::TEST_ARRAY_32_1, redemption_time + 1),
+        )
+        .unwrap();
+    assert!(next_day_token.get_tag() != token.get_tag());
+    let other_token = server_public_params
+        .create_rate_limit_token(
+            zkgroup::TEST_ARRAY_32_5,
+            &receive(zkgroup::TEST_ARRAY_32_3, redemption_time),
+        )
+        .unwrap();
+    assert!(other_token.get_tag() != token.get_tag());
+
+    // Swapping in another credential's tag breaks the proof
+    let tag_offset = token_bytes.len() - 4 - 32;
+    let mut forged_bytes = token_bytes.clone();
+    forged_bytes[tag_offset..tag_offset + 32].copy_from_slice(&other_token.get_tag());
+    let forged: zkgroup::auth::RateLimitToken = zkgroup::deserialize(&forged_bytes).unwrap();
+    assert!(server_secret_params
+        .verify_rate_limit_token(&forged)
+        .is_err());
+
+    // As does claiming another day
+    let mut forged_bytes = token_bytes.clone();
+    let day_offset = token_bytes.len() - 4;
+    forged_bytes[day_offset..].copy_from_slice(&(redemption_time + 1).to_le_bytes());
+    let forged: zkgroup::auth::RateLimitToken = zkgroup::deserialize(&forged_bytes).unwrap();
+    assert!(server_secret_params
+        .verify_rate_limit_token(&forged)
+        .is_err());
+
+    // And a credential from another server key
+    let other_secret_params =
+        zkgroup::ServerSecretParams::generate(zkgroup::TEST_ARRAY_32_1).unwrap();
+    let mut forged_bytes = token_bytes;
+    forged_bytes[1] = other_secret_params.get_key_id();
+    let forged: zkgroup::auth::RateLimitToken = zkgroup::deserialize(&forged_bytes).unwrap();
+    assert!(other_secret_params
+        .verify_rate_limit_token(&forged)
+        .is_err());
+}
+
+#[test]
+fn test_integration_rate_limit_tag_hidden_from_server() {
+    let server_secret_params =
+        zkgroup::ServerSecretParams::generate(zkgroup::TEST_ARRAY_32).unwrap();
+    let server_public_params = server_secret_params.get_public_params();
+
+    let redemption_time = 123456u32;
+
+    // The same user asks twice for the same day.  Everything the server knows
+    // when issuing (whichever UID it authenticated, the day, its own key and
+    // randomness) is identical, yet the tags differ, so the tag is no
+    // function of the UID that the server could recompute.
+    let get_tag = |client_randomness| {
+        let context =
+            server_public_params.create_rate_limit_credential_request_context(client_randomness);
+        let response = server_secret_params
+            .issue_rate_limit_credential(
+                zkgroup::TEST_ARRAY_32_2,
+                &context.get_request(),
+                redemption_time,
+            )
+            .unwrap();
+        let credential = server_public_params
+            .receive_rate_limit_credential(&context, &response)
+            .unwrap();
+        let token = server_public_params
+            .create_rate_limit_token(zkgroup::TEST_ARRAY_32_5, &credential)
+            .unwrap();
+        server_secret_params
+            .verify_rate_limit_token(&token)
+            .unwrap();
+        (
+            bincode::serialize(&context.get_request()).unwrap(),
+            token.get_tag(),
+        )
+    };
+    let (request_bytes, tag) = get_tag(zkgroup::TEST_ARRAY_32_3);
+    let (other_request_bytes, other_tag) = get_tag(zkgroup::TEST_ARRAY_32_4);
+    assert!(tag != other_tag);
+
+    // Nor does the tag appear in what the server was sent
+    for request_bytes in &[request_bytes, other_request_bytes] {
+        assert!(!request_bytes.windows(tag.len()).any(|w| w == &tag[..]));
+        assert!(!request_bytes
+            .windows(tag.len())
+            .any(|w| w == &other_tag[..]));
+    }
+}
+
+#[test]
+fn test_integration_server_key_rotation() {
+    let old_server_secret_params =
+        zkgroup::ServerSecretParams::generate_with_key_id(zkgroup::TEST_ARRAY_32, 1).unwrap();
+    let old_server_public_params = old_server_secret_params.get_public_params();
+    assert!(old_server_public_params.get_key_id() == 1);
+
+    let mut keyring = zkgroup::ServerSecretParamsKeyring::new(old_server_secret_params.clone(), 1);
+
+    let master_key = zkgroup::groups::GroupMasterKey::new(zkgroup::TEST_ARRAY_32_1);
+    let group_secret_params =
+        zkgroup::groups::GroupSecretParams::derive_from_master_key(master_key);
+    let group_public_params = group_secret_params.get_public_params();
+
+    let uid = zkgroup::TEST_ARRAY_16;
+    let redemption_time = 123456u32;
+
+    // Credential issued before the rotation
+    let auth_credential_response = keyring
+        .issue_auth_credential(zkgroup::TEST_ARRAY_32_2, uid, redemption_time)
+        .unwrap();
+    let auth_credential = old_server_public_params
+        .receive_auth_credential(uid, redemption_time, &auth_credential_response)
+        .unwrap();
+    let old_presentation = old_server_public_params
+        .create_auth_credential_presentation(
+            zkgroup::TEST_ARRAY_32_5,
+            &group_secret_params,
+            auth_credential,
+        )
+        .unwrap();
+    assert!(old_presentation.get_key_id() == 1);
+
+    // Objects from before key ids are written as version 1, followed by the
+    // key id, once it isn't the default
+    let response_bytes = bincode::serialize(&auth_credential_response).unwrap();
+    assert!(response_bytes.len() == zkgroup::AUTH_CREDENTIAL_RESPONSE_LEN + 1);
+    assert!(response_bytes[..2] == [1, 1]);
+    let presentation_bytes = bincode::serialize(&old_presentation).unwrap();
+    assert!(presentation_bytes.len() == zkgroup::AUTH_CREDENTIAL_PRESENTATION_LEN + 1);
+    assert!(presentation_bytes[..2] == [1, 1]);
+    let presentation: zkgroup::auth::AuthCredentialPresentation =
+        zkgroup::deserialize(&presentation_bytes).unwrap();
+    assert!(presentation.get_key_id() == 1);
+
+    // Version 1 never carries the default key id
+    let mut default_bytes = presentation_bytes;
+    default_bytes[1] = zkgroup::DEFAULT_SERVER_KEY_ID;
+    match zkgroup::deserialize::<zkgroup::auth::AuthCredentialPresentation>(&default_bytes) {
+        Err(zkgroup::ZkGroupError::DeserializationFailure { .. }) => (),
+        _ => assert!(false),
+    }
+
+    // Rotate; the old key is still accepted during the grace period
+    let new_server_secret_params =
+        zkgroup::ServerSecretParams::generate_with_key_id(zkgroup::TEST_ARRAY_32_3, 2).unwrap();
+    keyring.rotate(new_server_secret_params).unwrap();
+    let new_server_public_params = keyring.get_public_params();
+    assert!(new_server_public_params.get_key_id() == 2);
+
+    keyring
+        .verify_auth_credential_presentation(group_public_params, &old_presentation)
+        .unwrap();
+
+    // Responses from the new key can't be received with the old public params
+    let auth_credential_response = keyring
+        .issue_auth_credential(zkgroup::TEST_ARRAY_32_2, uid, redemption_time)
+        .unwrap();
+    match old_server_public_params.receive_auth_credential(
+        uid,
+        redemption_time,
+        &auth_credential_response,
+    ) {
+        Err(zkgroup::ZkGroupError::KeyIdMismatch) => (),
+        _ => assert!(false),
+    }
+    let auth_credential = new_server_public_params
+        .receive_auth_credential(uid, redemption_time, &auth_credential_response)
+        .unwrap();
+    let new_presentation = new_server_public_params
+        .create_auth_credential_presentation(
+            zkgroup::TEST_ARRAY_32_5,
+            &group_secret_params,
+            auth_credential,
+        )
+        .unwrap();
+    keyring
+        .verify_auth_credential_presentation(group_public_params, &new_presentation)
+        .unwrap();
+    match old_server_secret_params
+        .verify_auth_credential_presentation(group_public_params, &new_presentation)
+    {
+        Err(zkgroup::ZkGroupError::KeyIdMismatch) => (),
+        _ => assert!(false),
+    }
+
+    // Reusing a key id that is still in the keyring is rejected
+    match keyring.rotate(
+        zkgroup::ServerSecretParams::generate_with_key_id(zkgroup::TEST_ARRAY_32_4, 1).unwrap(),
+    ) {
+        Err(zkgroup::ZkGroupError::BadArgs) => (),
+        _ => assert!(false),
+    }
+
+    // A second rotation evicts the oldest key
+    keyring
+        .rotate(
+            zkgroup::ServerSecretParams::generate_with_key_id(zkgroup::TEST_ARRAY_32_4, 3).unwrap(),
+        )
+        .unwrap();
+    assert!(keyring.get(1).is_none());
+    keyring
+        .verify_auth_credential_presentation(group_public_params, &new_presentation)
+        .unwrap();
+    match keyring.verify_auth_credential_presentation(group_public_params, &old_presentation) {
+        Err(zkgroup::ZkGroupError::KeyIdMismatch) => (),
+        _ => assert!(false),
+    }
+}
+
 #[test]
 fn test_integration_profile() {
     // Random UID and issueTime
     let _uid = zkgroup::TEST_ARRAY_16;
 
     // SERVER
-    let server_secret_params = zkgroup::ServerSecretParams::generate(zkgroup::TEST_ARRAY_32);
+    let server_secret_params =
+        zkgroup::ServerSecretParams::generate(zkgroup::TEST_ARRAY_32).unwrap();
     let server_public_params = server_secret_params.get_public_params();
 
     // CLIENT
@@ -206,11 +637,9 @@ fn test_integration_profile() {
     // Create context and request
     let randomness = zkgroup::TEST_ARRAY_32_3;
 
-    let context = server_public_params.create_profile_key_credential_request_context(
-        randomness,
-        uid,
-        profile_key,
-    );
+    let context = server_public_params
+        .create_profile_key_credential_request_context(randomness, uid, &profile_key)
+        .unwrap();
     let request = context.get_request();
 
     // SERVER
@@ -231,7 +660,7 @@ fn test_integration_profile() {
     let plaintext = group_secret_params.decrypt_uuid(uuid_ciphertext).unwrap();
     assert!(plaintext == uid);
 
-    let profile_key_ciphertext = group_secret_params.encrypt_profile_key(profile_key, uid);
+    let profile_key_ciphertext = group_secret_params.encrypt_profile_key(&profile_key, uid);
     let decrypted_profile_key = group_secret_params
         .decrypt_profile_key(profile_key_ciphertext, uid)
         .unwrap();
@@ -241,11 +670,13 @@ fn test_integration_profile() {
     // Create presentation
     let randomness = zkgroup::TEST_ARRAY_32_5;
 
-    let presentation = server_public_params.create_profile_key_credential_presentation(
-        randomness,
-        group_secret_params,
-        profile_key_credential,
-    );
+    let presentation = server_public_params
+        .create_profile_key_credential_presentation(
+            randomness,
+            &group_secret_params,
+            profile_key_credential,
+        )
+        .unwrap();
 
     let presentation_bytes = &bincode::serialize(&presentation).unwrap();
     //for b in presentation_bytes.iter() {
@@ -258,6 +689,13 @@ fn test_integration_profile() {
         .verify_profile_key_credential_presentation(group_public_params, &presentation)
         .unwrap();
 
+    let baseline_presentation: zkgroup::profiles::ProfileKeyCredentialPresentation =
+        zkgroup::deserialize(&PROFILE_KEY_CREDENTIAL_PRESENTATION_RESULT).unwrap();
+    assert!(baseline_presentation.get_key_id() == zkgroup::DEFAULT_SERVER_KEY_ID);
+    server_secret_params
+        .verify_profile_key_credential_presentation(group_public_params, &baseline_presentation)
+        .unwrap();
+
     // test encoding
     // these tests will also discover if the serialized sizes change,
     //   necessitating an update to the LEN constants
@@ -288,61 +726,1439 @@ fn test_integration_profile() {
 }
 
 #[test]
-fn test_server_sigs() {
+fn test_integration_profile_selective_presentation() {
     let server_secret_params =
-        zkgroup::api::server_params::ServerSecretParams::generate(zkgroup::TEST_ARRAY_32);
+        zkgroup::ServerSecretParams::generate(zkgroup::TEST_ARRAY_32).unwrap();
     let server_public_params = server_secret_params.get_public_params();
-    let randomness = zkgroup::TEST_ARRAY_32_2;
-    let message = zkgroup::TEST_ARRAY_32_1;
-    let signature = server_secret_params.sign(randomness, &message).unwrap();
-    //println!("signature = {:#x?}", &signature[..]);
-    for b in signature.iter() {
-        print!("0x{:02x}, ", b);
-    }
+
+    let master_key = zkgroup::groups::GroupMasterKey::new(zkgroup::TEST_ARRAY_32_1);
+    let group_secret_params =
+        zkgroup::groups::GroupSecretParams::derive_from_master_key(master_key);
+    let group_public_params = group_secret_params.get_public_params();
+
+    let uid = zkgroup::TEST_ARRAY_16;
+    let profile_key = zkgroup::profiles::ProfileKey::create(zkgroup::TEST_ARRAY_32_1);
+    let profile_key_commitment = profile_key.get_commitment(uid);
+
+    let context = server_public_params
+        .create_profile_key_credential_request_context(zkgroup::TEST_ARRAY_32_3, uid, &profile_key)
+        .unwrap();
+    let response = server_secret_params
+        .issue_profile_key_credential(
+            zkgroup::TEST_ARRAY_32_4,
+            &context.get_request(),
+            uid,
+            profile_key_commitment,
+        )
+        .unwrap();
+    let profile_key_credential = server_public_params
+        .receive_profile_key_credential(&context, &response)
+        .unwrap();
+
+    let presentation = server_public_params
+        .create_profile_key_credential_selective_presentation(
+            zkgroup::TEST_ARRAY_32_5,
+            &group_secret_params,
+            profile_key_credential,
+        )
+        .unwrap();
+
+    let presentation_bytes = bincode::serialize(&presentation).unwrap();
     assert!(
-        &signature[..]
-            == &[
-                0x87, 0xd3, 0x54, 0x56, 0x4d, 0x35, 0xef, 0x91, 0xed, 0xba, 0x85, 0x1e, 0x08, 0x15,
-                0x61, 0x2e, 0x86, 0x4c, 0x22, 0x7a, 0x04, 0x71, 0xd5, 0x0c, 0x27, 0x06, 0x98, 0x60,
-                0x44, 0x06, 0xd0, 0x03, 0xa5, 0x54, 0x73, 0xf5, 0x76, 0xcf, 0x24, 0x1f, 0xc6, 0xb4,
-                0x1c, 0x6b, 0x16, 0xe5, 0xe6, 0x3b, 0x33, 0x3c, 0x02, 0xfe, 0x4a, 0x33, 0x85, 0x80,
-                0x22, 0xfd, 0xd7, 0xa4, 0xab, 0x36, 0x7b, 0x06,
-            ][..]
+        presentation_bytes.len()
+            == zkgroup::common::constants::PROFILE_KEY_CREDENTIAL_SELECTIVE_PRESENTATION_LEN
     );
-    server_public_params
-        .verify_signature(&message, signature)
+
+    // Only the UUID is revealed, encrypted as usual
+    assert!(presentation.get_uuid_ciphertext() == group_secret_params.encrypt_uuid(uid));
+
+    server_secret_params
+        .verify_profile_key_credential_selective_presentation(group_public_params, &presentation)
         .unwrap();
+
+    // The proof is bound to the group it was made for
+    let other_group_public_params = zkgroup::groups::GroupSecretParams::derive_from_master_key(
+        zkgroup::groups::GroupMasterKey::new(zkgroup::TEST_ARRAY_32_2),
+    )
+    .get_public_params();
+    assert!(server_secret_params
+        .verify_profile_key_credential_selective_presentation(
+            other_group_public_params,
+            &presentation
+        )
+        .is_err());
+
+    // The withheld profile key attributes are still bound by the MAC
+    let C_y3_start = 1 + 1 + 4 * 32;
+    let mut bad_bytes = presentation_bytes.clone();
+    bad_bytes[C_y3_start..C_y3_start + 32].copy_from_slice(
+        &RistrettoPoint::lizard_encode::<Sha256>(&zkgroup::TEST_ARRAY_16_1)
+            .compress()
+            .to_bytes(),
+    );
+    let bad_presentation: zkgroup::profiles::ProfileKeyCredentialSelectivePresentation =
+        bincode::deserialize(&bad_bytes).unwrap();
+    assert!(server_secret_params
+        .verify_profile_key_credential_selective_presentation(
+            group_public_params,
+            &bad_presentation
+        )
+        .is_err());
 }
 
 #[test]
-fn test_blob_encryption() {
+fn test_integration_presentation_context() {
+    let server_secret_params =
+        zkgroup::ServerSecretParams::generate(zkgroup::TEST_ARRAY_32).unwrap();
+    let server_public_params = server_secret_params.get_public_params();
+
     let master_key = zkgroup::groups::GroupMasterKey::new(zkgroup::TEST_ARRAY_32_1);
     let group_secret_params =
         zkgroup::groups::GroupSecretParams::derive_from_master_key(master_key);
-    let randomness = zkgroup::TEST_ARRAY_32_2;
+    let group_public_params = group_secret_params.get_public_params();
 
-    let plaintext_vec = vec![
-        0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17,
-        0x18, 0x19,
-    ];
+    let uid = zkgroup::TEST_ARRAY_16;
+    let redemption_time = 123456u32;
+    let context = b"PUT /v1/groups/";
+    let other_context = b"PATCH /v1/groups/";
 
-    // WARNING: THIS VECTOR DOES *NOT* MATCH JAVA/SWIFT/NODE AS THEY IMPLEMENT PADDING
-    let ciphertext_vec = vec![
-        0xe9, 0x58, 0x07, 0xb1, 0x90, 0xd4, 0x78, 0xd7, 0xbe, 0x3a, 0x77, 0xb2, 0x29, 0x27, 0x13,
-        0x2e, 0xeb, 0xa5, 0x1c, 0x73, 0x9c, 0xd5, 0x70, 0x73, 0x17, 0xf7, 0x3e, 0x59, 0x1a, 0x91,
-        0x5f, 0xff, 0x1f, 0x20, 0xa3, 0x02, 0x69, 0x2a, 0xfd, 0xc7, 0x08, 0x7f, 0x10, 0x19, 0x60,
-        0x00,
-    ];
+    let auth_credential_response = server_secret_params
+        .issue_auth_credential(zkgroup::TEST_ARRAY_32_2, uid, redemption_time)
+        .unwrap();
+    let auth_credential = server_public_params
+        .receive_auth_credential(uid, redemption_time, &auth_credential_response)
+        .unwrap();
 
-    let calc_ciphertext_vec = group_secret_params
-        .encrypt_blob(randomness, &plaintext_vec)
+    // An empty context is the same as none
+    let presentation = server_public_params
+        .create_auth_credential_presentation(
+            zkgroup::TEST_ARRAY_32_5,
+            &group_secret_params,
+            auth_credential,
+        )
         .unwrap();
-    let calc_plaintext_vec = group_secret_params
-        .decrypt_blob(&calc_ciphertext_vec)
+    let empty_context_presentation = server_public_params
+        .create_auth_credential_presentation_with_context(
+            zkgroup::TEST_ARRAY_32_5,
+            &group_secret_params,
+            auth_credential,
+            &[],
+        )
         .unwrap();
-    assert!(calc_plaintext_vec == plaintext_vec);
-    for b in calc_ciphertext_vec.iter() {
-        print!("0x{:02x}, ", b);
-    }
-    assert!(calc_ciphertext_vec == ciphertext_vec);
+    assert!(
+        bincode::serialize(&presentation).unwrap()
+            == bincode::serialize(&empty_context_presentation).unwrap()
+    );
+
+    let presentation = server_public_params
+        .create_auth_credential_presentation_with_context(
+            zkgroup::TEST_ARRAY_32_5,
+            &group_secret_params,
+            auth_credential,
+            context,
+        )
+        .unwrap();
+    server_secret_params
+        .verify_auth_credential_presentation_with_context(
+            group_public_params,
+            &presentation,
+            context,
+        )
+        .unwrap();
+    assert!(server_secret_params
+        .verify_auth_credential_presentation_with_context(
+            group_public_params,
+            &presentation,
+            other_context,
+        )
+        .is_err());
+    assert!(server_secret_params
+        .verify_auth_credential_presentation(group_public_params, &presentation)
+        .is_err());
+
+    let profile_key = zkgroup::profiles::ProfileKey::create(zkgroup::TEST_ARRAY_32_1);
+    let request_context = server_public_params
+        .create_profile_key_credential_request_context(zkgroup::TEST_ARRAY_32_3, uid, &profile_key)
+        .unwrap();
+    let response = server_secret_params
+        .issue_profile_key_credential(
+            zkgroup::TEST_ARRAY_32_4,
+            &request_context.get_request(),
+            uid,
+            profile_key.get_commitment(uid),
+        )
+        .unwrap();
+    let profile_key_credential = server_public_params
+        .receive_profile_key_credential(&request_context, &response)
+        .unwrap();
+
+    let presentation = server_public_params
+        .create_profile_key_credential_presentation_with_context(
+            zkgroup::TEST_ARRAY_32_5,
+            &group_secret_params,
+            profile_key_credential,
+            context,
+        )
+        .unwrap();
+    server_secret_params
+        .verify_profile_key_credential_presentation_with_context(
+            group_public_params,
+            &presentation,
+            context,
+        )
+        .unwrap();
+    assert!(server_secret_params
+        .verify_profile_key_credential_presentation_with_context(
+            group_public_params,
+            &presentation,
+            other_context,
+        )
+        .is_err());
+    assert!(server_secret_params
+        .verify_profile_key_credential_presentation(group_public_params, &presentation)
+        .is_err());
+
+    let presentation = server_public_params
+        .create_profile_key_credential_selective_presentation_with_context(
+            zkgroup::TEST_ARRAY_32_5,
+            &group_secret_params,
+            profile_key_credential,
+            context,
+        )
+        .unwrap();
+    server_secret_params
+        .verify_profile_key_credential_selective_presentation_with_context(
+            group_public_params,
+            &presentation,
+            context,
+        )
+        .unwrap();
+    assert!(server_secret_params
+        .verify_profile_key_credential_selective_presentation(group_public_params, &presentation)
+        .is_err());
+}
+
+// Every server entry point that takes a context must use it, rather than
+// verifying against an empty one
+#[test]
+fn test_integration_presentation_context_entry_points() {
+    use zkgroup::ffi::constants::*;
+    use zkgroup::ffi::simpleapi;
+
+    let server_secret_params =
+        zkgroup::ServerSecretParams::generate(zkgroup::TEST_ARRAY_32).unwrap();
+    let server_public_params = server_secret_params.get_public_params();
+    let keyring = zkgroup::ServerSecretParamsKeyring::new(server_secret_params.clone(), 1);
+
+    let master_key = zkgroup::groups::GroupMasterKey::new(zkgroup::TEST_ARRAY_32_1);
+    let group_secret_params =
+        zkgroup::groups::GroupSecretParams::derive_from_master_key(master_key);
+    let group_public_params = group_secret_params.get_public_params();
+
+    let uid = zkgroup::TEST_ARRAY_16;
+    let redemption_time = 123456u32;
+    let context = b"PUT /v1/groups/";
+
+    let auth_credential_response = server_secret_params
+        .issue_auth_credential(zkgroup::TEST_ARRAY_32_2, uid, redemption_time)
+        .unwrap();
+    let auth_credential = server_public_params
+        .receive_auth_credential(uid, redemption_time, &auth_credential_response)
+        .unwrap();
+    let presentation = server_public_params
+        .create_auth_credential_presentation_with_context(
+            zkgroup::TEST_ARRAY_32_5,
+            &group_secret_params,
+            auth_credential,
+            context,
+        )
+        .unwrap();
+
+    keyring
+        .verify_auth_credential_presentation_with_context(
+            group_public_params,
+            &presentation,
+            context,
+        )
+        .unwrap();
+    assert!(keyring
+        .verify_auth_credential_presentation(group_public_params, &presentation)
+        .is_err());
+
+    let redemption_window = zkgroup::auth::RedemptionWindow::default();
+    let redemption_seconds = u64::from(redemption_time) * zkgroup::SECONDS_PER_DAY;
+    keyring
+        .verify_auth_credential_presentation_with_time_and_context(
+            group_public_params,
+            &presentation,
+            redemption_seconds,
+            redemption_window,
+            context,
+        )
+        .unwrap();
+    assert!(keyring
+        .verify_auth_credential_presentation_with_time(
+            group_public_params,
+            &presentation,
+            redemption_seconds,
+            redemption_window,
+        )
+        .is_err());
+
+    let server_secret_params_bytes = zkgroup::serialize(&server_secret_params).unwrap();
+    let group_public_params_bytes = zkgroup::serialize(&group_public_params).unwrap();
+    let presentation_bytes = zkgroup::serialize(&presentation).unwrap();
+    assert!(
+        simpleapi::ServerSecretParams_verifyAuthCredentialPresentationWithContext(
+            &server_secret_params_bytes,
+            &group_public_params_bytes,
+            &presentation_bytes,
+            context,
+        ) == FFI_RETURN_OK
+    );
+    assert!(
+        simpleapi::ServerSecretParams_verifyAuthCredentialPresentationWithContext(
+            &server_secret_params_bytes,
+            &group_public_params_bytes,
+            &presentation_bytes,
+            &[],
+        ) != FFI_RETURN_OK
+    );
+
+    let profile_key = zkgroup::profiles::ProfileKey::create(zkgroup::TEST_ARRAY_32_1);
+    let request_context = server_public_params
+        .create_profile_key_credential_request_context(zkgroup::TEST_ARRAY_32_3, uid, &profile_key)
+        .unwrap();
+    let response = keyring
+        .issue_profile_key_credential(
+            zkgroup::TEST_ARRAY_32_4,
+            &request_context.get_request(),
+            uid,
+            profile_key.get_commitment(uid),
+        )
+        .unwrap();
+    let profile_key_credential = server_public_params
+        .receive_profile_key_credential(&request_context, &response)
+        .unwrap();
+
+    let presentation = server_public_params
+        .create_profile_key_credential_presentation_with_context(
+            zkgroup::TEST_ARRAY_32_5,
+            &group_secret_params,
+            profile_key_credential,
+            context,
+        )
+        .unwrap();
+    keyring
+        .verify_profile_key_credential_presentation_with_context(
+            group_public_params,
+            &presentation,
+            context,
+        )
+        .unwrap();
+    assert!(keyring
+        .verify_profile_key_credential_presentation(group_public_params, &presentation)
+        .is_err());
+
+    let presentation = server_public_params
+        .create_profile_key_credential_selective_presentation_with_context(
+            zkgroup::TEST_ARRAY_32_5,
+            &group_secret_params,
+            profile_key_credential,
+            context,
+        )
+        .unwrap();
+    keyring
+        .verify_profile_key_credential_selective_presentation_with_context(
+            group_public_params,
+            &presentation,
+            context,
+        )
+        .unwrap();
+    assert!(keyring
+        .verify_profile_key_credential_selective_presentation(group_public_params, &presentation)
+        .is_err());
+}
+
+#[test]
+fn test_integration_presentation_challenge() {
+    use zkgroup::challenges::ReplayCache;
+
+    let server_secret_params =
+        zkgroup::ServerSecretParams::generate(zkgroup::TEST_ARRAY_32).unwrap();
+    let server_public_params = server_secret_params.get_public_params();
+
+    let master_key = zkgroup::groups::GroupMasterKey::new(zkgroup::TEST_ARRAY_32_1);
+    let group_secret_params =
+        zkgroup::groups::GroupSecretParams::derive_from_master_key(master_key);
+    let group_public_params = group_secret_params.get_public_params();
+
+    let uid = zkgroup::TEST_ARRAY_16;
+    let redemption_time = 123456u32;
+    let current_time = 1_600_000_000u64;
+    let expiration_time = current_time + 300;
+
+    let auth_credential_response = server_secret_params
+        .issue_auth_credential(zkgroup::TEST_ARRAY_32_2, uid, redemption_time)
+        .unwrap();
+    let auth_credential = server_public_params
+        .receive_auth_credential(uid, redemption_time, &auth_credential_response)
+        .unwrap();
+
+    // SERVER
+    let challenge = server_secret_params
+        .issue_presentation_challenge(zkgroup::TEST_ARRAY_32_3, expiration_time)
+        .unwrap();
+    let challenge_bytes = bincode::serialize(&challenge).unwrap();
+    assert!(challenge_bytes.len() == zkgroup::PRESENTATION_CHALLENGE_LEN);
+    let challenge: zkgroup::challenges::PresentationChallenge =
+        zkgroup::deserialize(&challenge_bytes).unwrap();
+    assert!(challenge.get_expiration_time() == expiration_time);
+
+    // CLIENT
+    let presentation = server_public_params
+        .create_auth_credential_presentation_with_context(
+            zkgroup::TEST_ARRAY_32_5,
+            &group_secret_params,
+            auth_credential,
+            &challenge.get_context(),
+        )
+        .unwrap();
+
+    // SERVER
+    let mut replay_cache = zkgroup::challenges::ExpiringReplayCache::new(16);
+
+    // A presentation bound to another challenge doesn't verify, and doesn't use
+    // up this one
+    let other_challenge = server_secret_params
+        .issue_presentation_challenge(zkgroup::TEST_ARRAY_32_4, expiration_time)
+        .unwrap();
+    assert!(other_challenge.get_nonce() != challenge.get_nonce());
+    assert!(server_secret_params
+        .verify_auth_credential_presentation_with_challenge(
+            group_public_params,
+            &presentation,
+            &other_challenge,
+            current_time,
+            &mut replay_cache,
+        )
+        .is_err());
+    assert!(replay_cache.is_empty());
+
+    server_secret_params
+        .verify_auth_credential_presentation_with_challenge(
+            group_public_params,
+            &presentation,
+            &challenge,
+            current_time,
+            &mut replay_cache,
+        )
+        .unwrap();
+    match server_secret_params.verify_auth_credential_presentation_with_challenge(
+        group_public_params,
+        &presentation,
+        &challenge,
+        current_time,
+        &mut replay_cache,
+    ) {
+        Err(zkgroup::ZkGroupError::ChallengeReplayed) => (),
+        _ => assert!(false),
+    }
+
+    // The expiration time itself is still acceptable
+    let mut fresh_cache = zkgroup::challenges::ExpiringReplayCache::new(16);
+    server_secret_params
+        .verify_presentation_challenge(&challenge, expiration_time)
+        .unwrap();
+    match server_secret_params.verify_auth_credential_presentation_with_challenge(
+        group_public_params,
+        &presentation,
+        &challenge,
+        expiration_time + 1,
+        &mut fresh_cache,
+    ) {
+        Err(zkgroup::ZkGroupError::ChallengeExpired) => (),
+        _ => assert!(false),
+    }
+    assert!(fresh_cache.is_empty());
+
+    // Changing the expiration time breaks the signature
+    let mut tampered_bytes = challenge_bytes.clone();
+    tampered_bytes[1 + 1 + zkgroup::CHALLENGE_NONCE_LEN] ^= 1;
+    let tampered: zkgroup::challenges::PresentationChallenge =
+        zkgroup::deserialize(&tampered_bytes).unwrap();
+    match server_secret_params.verify_presentation_challenge(&tampered, current_time) {
+        Err(zkgroup::ZkGroupError::SignatureVerificationFailure) => (),
+        _ => assert!(false),
+    }
+
+    // Challenges are signed with a key of their own, so a signature from
+    // ServerSecretParams::sign over the same bytes doesn't pass as one, and a
+    // challenge's signature isn't one from ServerSecretParams::sign
+    let signature_start = challenge_bytes.len() - zkgroup::SIGNATURE_LEN;
+    let mut challenge_signature = [0u8; zkgroup::SIGNATURE_LEN];
+    challenge_signature.copy_from_slice(&challenge_bytes[signature_start..]);
+    assert!(server_public_params
+        .verify_signature(&challenge.get_context(), challenge_signature)
+        .is_err());
+    let signature = server_secret_params
+        .sign(zkgroup::TEST_ARRAY_32_4, &challenge.get_context())
+        .unwrap();
+    let mut forged_bytes = challenge_bytes.clone();
+    forged_bytes[signature_start..].copy_from_slice(&signature);
+    let forged: zkgroup::challenges::PresentationChallenge =
+        zkgroup::deserialize(&forged_bytes).unwrap();
+    match server_secret_params.verify_presentation_challenge(&forged, current_time) {
+        Err(zkgroup::ZkGroupError::SignatureVerificationFailure) => (),
+        _ => assert!(false),
+    }
+
+    // Challenges from another server's params are rejected
+    let other_server_secret_params =
+        zkgroup::ServerSecretParams::generate_with_key_id(zkgroup::TEST_ARRAY_32_1, 1).unwrap();
+    match other_server_secret_params.verify_presentation_challenge(&challenge, current_time) {
+        Err(zkgroup::ZkGroupError::KeyIdMismatch) => (),
+        _ => assert!(false),
+    }
+
+    // The replay cache is pluggable; a trait object works too
+    let replay_cache: &mut dyn ReplayCache = &mut zkgroup::challenges::ExpiringReplayCache::new(16);
+    server_secret_params
+        .verify_auth_credential_presentation_with_challenge(
+            group_public_params,
+            &presentation,
+            &challenge,
+            current_time,
+            replay_cache,
+        )
+        .unwrap();
+    match replay_cache.insert(challenge.get_nonce(), expiration_time, current_time) {
+        Err(zkgroup::ZkGroupError::ChallengeReplayed) => (),
+        _ => assert!(false),
+    }
+}
+
+#[test]
+fn test_server_sigs() {
+    let server_secret_params =
+        zkgroup::api::server_params::ServerSecretParams::generate(zkgroup::TEST_ARRAY_32).unwrap();
+    let server_public_params = server_secret_params.get_public_params();
+    let randomness = zkgroup::TEST_ARRAY_32_2;
+    let message = zkgroup::TEST_ARRAY_32_1;
+    let signature = server_secret_params.sign(randomness, &message).unwrap();
+    //println!("signature = {:#x?}", &signature[..]);
+    for b in signature.iter() {
+        print!("0x{:02x}, ", b);
+    }
+    assert!(
+        &signature[..]
+            == &[
+                0x87, 0xd3, 0x54, 0x56, 0x4d, 0x35, 0xef, 0x91, 0xed, 0xba, 0x85, 0x1e, 0x08, 0x15,
+                0x61, 0x2e, 0x86, 0x4c, 0x22, 0x7a, 0x04, 0x71, 0xd5, 0x0c, 0x27, 0x06, 0x98, 0x60,
+                0x44, 0x06, 0xd0, 0x03, 0xa5, 0x54, 0x73, 0xf5, 0x76, 0xcf, 0x24, 0x1f, 0xc6, 0xb4,
+                0x1c, 0x6b, 0x16, 0xe5, 0xe6, 0x3b, 0x33, 0x3c, 0x02, 0xfe, 0x4a, 0x33, 0x85, 0x80,
+                0x22, 0xfd, 0xd7, 0xa4, 0xab, 0x36, 0x7b, 0x06,
+            ][..]
+    );
+    server_public_params
+        .verify_signature(&message, signature)
+        .unwrap();
+}
+
+#[test]
+fn test_blob_encryption() {
+    let master_key = zkgroup::groups::GroupMasterKey::new(zkgroup::TEST_ARRAY_32_1);
+    let group_secret_params =
+        zkgroup::groups::GroupSecretParams::derive_from_master_key(master_key);
+    let randomness = zkgroup::TEST_ARRAY_32_2;
+
+    let plaintext_vec = vec![
+        0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17,
+        0x18, 0x19,
+    ];
+
+    // WARNING: THIS VECTOR DOES *NOT* MATCH JAVA/SWIFT/NODE AS THEY IMPLEMENT PADDING
+    let ciphertext_vec = vec![
+        0xe9, 0x58, 0x07, 0xb1, 0x90, 0xd4, 0x78, 0xd7, 0xbe, 0x3a, 0x77, 0xb2, 0x29, 0x27, 0x13,
+        0x2e, 0xeb, 0xa5, 0x1c, 0x73, 0x9c, 0xd5, 0x70, 0x73, 0x17, 0xf7, 0x3e, 0x59, 0x1a, 0x91,
+        0x5f, 0xff, 0x1f, 0x20, 0xa3, 0x02, 0x69, 0x2a, 0xfd, 0xc7, 0x08, 0x7f, 0x10, 0x19, 0x60,
+        0x00,
+    ];
+
+    let calc_ciphertext_vec = group_secret_params
+        .encrypt_blob(randomness, &plaintext_vec)
+        .unwrap();
+    let calc_plaintext_vec = group_secret_params
+        .decrypt_blob(&calc_ciphertext_vec)
+        .unwrap();
+    assert!(calc_plaintext_vec == plaintext_vec);
+    for b in calc_ciphertext_vec.iter() {
+        print!("0x{:02x}, ", b);
+    }
+    assert!(calc_ciphertext_vec == ciphertext_vec);
+}
+
+#[test]
+fn test_blob_stream_encryption() {
+    let master_key = zkgroup::groups::GroupMasterKey::new(zkgroup::TEST_ARRAY_32_1);
+    let group_secret_params =
+        zkgroup::groups::GroupSecretParams::derive_from_master_key(master_key);
+    let randomness = zkgroup::TEST_ARRAY_32_2;
+
+    let plaintext_vec: Vec<u8> = (0..2 * zkgroup::BLOB_STREAM_CHUNK_LEN + 100)
+        .map(|i| i as u8)
+        .collect();
+
+    let mut ciphertext_vec = Vec::new();
+    group_secret_params
+        .encrypt_blob_stream(randomness, &plaintext_vec[..], &mut ciphertext_vec)
+        .unwrap();
+    let mut calc_plaintext_vec = Vec::new();
+    group_secret_params
+        .decrypt_blob_stream(&ciphertext_vec[..], &mut calc_plaintext_vec)
+        .unwrap();
+    assert!(calc_plaintext_vec == plaintext_vec);
+
+    // Another group's key
+    let other_group_secret_params = zkgroup::groups::GroupSecretParams::derive_from_master_key(
+        zkgroup::groups::GroupMasterKey::new(zkgroup::TEST_ARRAY_32_3),
+    );
+    assert!(other_group_secret_params
+        .decrypt_blob_stream(&ciphertext_vec[..], &mut Vec::new())
+        .is_err());
+
+    // Blobs from encrypt_blob are a different format
+    let blob = group_secret_params
+        .encrypt_blob(randomness, &plaintext_vec[..100])
+        .unwrap();
+    assert!(group_secret_params
+        .decrypt_blob_stream(&blob[..], &mut Vec::new())
+        .is_err());
+}
+
+#[test]
+fn test_blob_encryption_with_padding() {
+    let master_key = zkgroup::groups::GroupMasterKey::new(zkgroup::TEST_ARRAY_32_1);
+    let group_secret_params =
+        zkgroup::groups::GroupSecretParams::derive_from_master_key(master_key);
+    let randomness = zkgroup::TEST_ARRAY_32_2;
+    let padding_policy = zkgroup::groups::BlobPaddingPolicy::Buckets(vec![32, 64, 256]);
+
+    let short_plaintext_vec = b"Group title".to_vec();
+    let long_plaintext_vec = b"A somewhat longer group title!".to_vec();
+
+    let short_ciphertext_vec = group_secret_params
+        .encrypt_blob_with_padding(randomness, &short_plaintext_vec, &padding_policy)
+        .unwrap();
+    let long_ciphertext_vec = group_secret_params
+        .encrypt_blob_with_padding(randomness, &long_plaintext_vec, &padding_policy)
+        .unwrap();
+
+    // Both fall in the 32-byte bucket
+    assert!(short_ciphertext_vec.len() == long_ciphertext_vec.len());
+    assert!(
+        short_ciphertext_vec.len() == 32 + zkgroup::AESGCM_TAG_LEN + zkgroup::AESGCM_NONCE_LEN + 1
+    );
+
+    assert!(
+        group_secret_params
+            .decrypt_blob(&short_ciphertext_vec)
+            .unwrap()
+            == short_plaintext_vec
+    );
+    assert!(
+        group_secret_params
+            .decrypt_blob(&long_ciphertext_vec)
+            .unwrap()
+            == long_plaintext_vec
+    );
+
+    // Unpadded blobs still decrypt
+    let unpadded_ciphertext_vec = group_secret_params
+        .encrypt_blob(randomness, &short_plaintext_vec)
+        .unwrap();
+    assert!(
+        group_secret_params
+            .decrypt_blob(&unpadded_ciphertext_vec)
+            .unwrap()
+            == short_plaintext_vec
+    );
+
+    // The format byte is authenticated
+    let mut modified_ciphertext_vec = short_ciphertext_vec.clone();
+    *modified_ciphertext_vec.last_mut().unwrap() = 0;
+    assert!(group_secret_params
+        .decrypt_blob(&modified_ciphertext_vec)
+        .is_err());
+    let mut modified_ciphertext_vec = unpadded_ciphertext_vec;
+    *modified_ciphertext_vec.last_mut().unwrap() = 2;
+    assert!(group_secret_params
+        .decrypt_blob(&modified_ciphertext_vec)
+        .is_err());
+}
+
+#[test]
+fn test_ffi_padded_blob() {
+    use zkgroup::ffi::constants::*;
+    use zkgroup::ffi::simpleapi;
+
+    let master_key = zkgroup::groups::GroupMasterKey::new(zkgroup::TEST_ARRAY_32_1);
+    let group_secret_params =
+        zkgroup::groups::GroupSecretParams::derive_from_master_key(master_key);
+    let group_secret_params_bytes = zkgroup::serialize(&group_secret_params).unwrap();
+    let randomness = zkgroup::TEST_ARRAY_32_2;
+    let plaintext_vec = b"Group title".to_vec();
+    let padded_len = 32;
+
+    let mut ciphertext_vec = vec![0u8; padded_len + 29];
+    assert!(
+        simpleapi::GroupSecretParams_encryptBlobWithPaddingDeterministic(
+            &group_secret_params_bytes,
+            &randomness,
+            &plaintext_vec,
+            padded_len as u32,
+            &mut ciphertext_vec,
+        ) == FFI_RETURN_OK
+    );
+    assert!(group_secret_params.decrypt_blob(&ciphertext_vec).unwrap() == plaintext_vec);
+
+    // The plaintext and padding marker must fit in the padded length
+    let mut short_ciphertext_vec = vec![0u8; plaintext_vec.len() + 29];
+    assert!(
+        simpleapi::GroupSecretParams_encryptBlobWithPaddingDeterministic(
+            &group_secret_params_bytes,
+            &randomness,
+            &plaintext_vec,
+            plaintext_vec.len() as u32,
+            &mut short_ciphertext_vec,
+        ) == FFI_RETURN_INPUT_ERROR
+    );
+
+    let mut length_prefixed_plaintext_vec = vec![0u8; ciphertext_vec.len() - 25];
+    assert!(
+        simpleapi::GroupSecretParams_decryptPaddedBlob(
+            &group_secret_params_bytes,
+            &ciphertext_vec,
+            &mut length_prefixed_plaintext_vec,
+        ) == FFI_RETURN_OK
+    );
+    assert!(length_prefixed_plaintext_vec[..4] == (plaintext_vec.len() as u32).to_be_bytes());
+    assert!(length_prefixed_plaintext_vec[4..4 + plaintext_vec.len()] == plaintext_vec[..]);
+    assert!(length_prefixed_plaintext_vec[4 + plaintext_vec.len()..]
+        .iter()
+        .all(|b| *b == 0));
+
+    // Sized for an unpadded blob, the output doesn't match, which is an
+    // input error rather than a panic
+    let mut plaintext_out = vec![0u8; ciphertext_vec.len() - 29];
+    assert!(
+        simpleapi::GroupSecretParams_decryptBlob(
+            &group_secret_params_bytes,
+            &ciphertext_vec,
+            &mut plaintext_out,
+        ) == FFI_RETURN_INPUT_ERROR
+    );
+
+    // Unpadded blobs come out of decrypt_padded_blob the same way
+    let unpadded_ciphertext_vec = group_secret_params
+        .encrypt_blob(randomness, &plaintext_vec)
+        .unwrap();
+    let mut length_prefixed_plaintext_vec = vec![0u8; unpadded_ciphertext_vec.len() - 25];
+    assert!(
+        simpleapi::GroupSecretParams_decryptPaddedBlob(
+            &group_secret_params_bytes,
+            &unpadded_ciphertext_vec,
+            &mut length_prefixed_plaintext_vec,
+        ) == FFI_RETURN_OK
+    );
+    assert!(length_prefixed_plaintext_vec[4..] == plaintext_vec[..]);
+
+    // Likewise for blobs with associated data
+    let padded_ciphertext_vec = group_secret_params
+        .encrypt_blob_with_padding_and_ad(
+            randomness,
+            &plaintext_vec,
+            &zkgroup::groups::BlobPaddingPolicy::Buckets(vec![padded_len]),
+            b"title:1",
+        )
+        .unwrap();
+    let mut plaintext_out = vec![0u8; padded_ciphertext_vec.len() - 29];
+    assert!(
+        simpleapi::GroupSecretParams_decryptBlobWithAd(
+            &group_secret_params_bytes,
+            &padded_ciphertext_vec,
+            b"title:1",
+            &mut plaintext_out,
+        ) == FFI_RETURN_INPUT_ERROR
+    );
+}
+
+#[test]
+fn test_ffi_last_error_code() {
+    use zkgroup::ffi::constants::*;
+    use zkgroup::ffi::ffiapi;
+
+    let group_secret_params = zkgroup::groups::GroupSecretParams::generate(zkgroup::TEST_ARRAY_32);
+    let group_secret_params_bytes = zkgroup::serialize(&group_secret_params).unwrap();
+    let plaintext_vec = b"Group title".to_vec();
+    let mut ciphertext_vec = group_secret_params
+        .encrypt_blob(zkgroup::TEST_ARRAY_32_1, &plaintext_vec)
+        .unwrap();
+    let mut plaintext_out = vec![0u8; plaintext_vec.len()];
+
+    let decrypt = |ciphertext: &[u8], plaintext_out: &mut [u8]| {
+        ffiapi::FFI_GroupSecretParams_decryptBlob(
+            group_secret_params_bytes.as_ptr(),
+            group_secret_params_bytes.len() as u32,
+            ciphertext.as_ptr(),
+            ciphertext.len() as u32,
+            plaintext_out.as_mut_ptr(),
+            plaintext_out.len() as u32,
+        )
+    };
+
+    ciphertext_vec[0] ^= 1;
+    assert!(decrypt(&ciphertext_vec, &mut plaintext_out) == FFI_RETURN_INPUT_ERROR);
+    assert!(ffiapi::FFI_getLastErrorCode() == zkgroup::ZkGroupError::DecryptionFailure.get_code());
+
+    // The next call clears it
+    ciphertext_vec[0] ^= 1;
+    assert!(decrypt(&ciphertext_vec, &mut plaintext_out) == FFI_RETURN_OK);
+    assert!(plaintext_out == plaintext_vec);
+    assert!(ffiapi::FFI_getLastErrorCode() == 0);
+
+    // Decoding failures are recorded too
+    let mut uuid_ciphertext_bytes =
+        zkgroup::serialize(&group_secret_params.encrypt_uuid(zkgroup::TEST_ARRAY_16)).unwrap();
+    uuid_ciphertext_bytes[0] += 1;
+    assert!(
+        ffiapi::FFI_UuidCiphertext_checkValidContents(
+            uuid_ciphertext_bytes.as_ptr(),
+            uuid_ciphertext_bytes.len() as u32,
+        ) == FFI_RETURN_INPUT_ERROR
+    );
+    match zkgroup::deserialize::<zkgroup::groups::UuidCiphertext>(&uuid_ciphertext_bytes) {
+        Err(e) => assert!(ffiapi::FFI_getLastErrorCode() == e.get_code()),
+        Ok(_) => panic!(),
+    }
+}
+
+#[test]
+fn test_blob_encryption_with_ad() {
+    let master_key = zkgroup::groups::GroupMasterKey::new(zkgroup::TEST_ARRAY_32_1);
+    let group_secret_params =
+        zkgroup::groups::GroupSecretParams::derive_from_master_key(master_key);
+    let randomness = zkgroup::TEST_ARRAY_32_2;
+    let plaintext_vec = b"Group title".to_vec();
+
+    let ciphertext_vec = group_secret_params
+        .encrypt_blob_with_ad(randomness, &plaintext_vec, b"title:1")
+        .unwrap();
+    assert!(
+        group_secret_params
+            .decrypt_blob_with_ad(&ciphertext_vec, b"title:1")
+            .unwrap()
+            == plaintext_vec
+    );
+
+    // A blob can't be moved to a different field or version
+    assert!(group_secret_params
+        .decrypt_blob_with_ad(&ciphertext_vec, b"title:2")
+        .is_err());
+    assert!(group_secret_params
+        .decrypt_blob_with_ad(&ciphertext_vec, b"description:1")
+        .is_err());
+    assert!(group_secret_params.decrypt_blob(&ciphertext_vec).is_err());
+
+    // Empty associated data is the same as plain encrypt_blob
+    let plain_ciphertext_vec = group_secret_params
+        .encrypt_blob(randomness, &plaintext_vec)
+        .unwrap();
+    assert!(
+        group_secret_params
+            .encrypt_blob_with_ad(randomness, &plaintext_vec, &[])
+            .unwrap()
+            == plain_ciphertext_vec
+    );
+    assert!(
+        group_secret_params
+            .decrypt_blob_with_ad(&plain_ciphertext_vec, &[])
+            .unwrap()
+            == plaintext_vec
+    );
+
+    // Padded blobs are bound to their associated data too
+    let padding_policy = zkgroup::groups::BlobPaddingPolicy::Buckets(vec![32]);
+    let padded_ciphertext_vec = group_secret_params
+        .encrypt_blob_with_padding_and_ad(randomness, &plaintext_vec, &padding_policy, b"title:1")
+        .unwrap();
+    assert!(padded_ciphertext_vec.len() == 32 + 29);
+    assert!(
+        group_secret_params
+            .decrypt_blob_with_ad(&padded_ciphertext_vec, b"title:1")
+            .unwrap()
+            == plaintext_vec
+    );
+    assert!(group_secret_params
+        .decrypt_blob_with_ad(&padded_ciphertext_vec, b"title:2")
+        .is_err());
+    assert!(group_secret_params
+        .decrypt_blob(&padded_ciphertext_vec)
+        .is_err());
+    assert!(
+        group_secret_params
+            .encrypt_blob_with_padding_and_ad(randomness, &plaintext_vec, &padding_policy, &[])
+            .unwrap()
+            == group_secret_params
+                .encrypt_blob_with_padding(randomness, &plaintext_vec, &padding_policy)
+                .unwrap()
+    );
+}
+
+#[test]
+fn test_group_rekeying() {
+    let old_params = zkgroup::groups::GroupSecretParams::derive_from_master_key(
+        zkgroup::groups::GroupMasterKey::new(zkgroup::TEST_ARRAY_32_1),
+    );
+    let new_params = zkgroup::groups::GroupSecretParams::derive_from_master_key(
+        zkgroup::groups::GroupMasterKey::new(zkgroup::TEST_ARRAY_32_2),
+    );
+    let rekeying = zkgroup::groups::GroupRekeying::new(old_params.clone(), new_params.clone());
+    let randomness = zkgroup::TEST_ARRAY_32_3;
+
+    // UUIDs
+    let uid = zkgroup::TEST_ARRAY_16;
+    let old_uuid_ciphertext = old_params.encrypt_uuid(uid);
+    let new_uuid_ciphertext = rekeying.rekey_uuid(old_uuid_ciphertext).unwrap();
+    assert!(new_uuid_ciphertext == new_params.encrypt_uuid(uid));
+    assert!(new_params.decrypt_uuid(new_uuid_ciphertext).unwrap() == uid);
+    assert!(rekeying.rekey_uuid(new_uuid_ciphertext).is_err());
+
+    let other_uuid_ciphertext = old_params.encrypt_uuid(zkgroup::TEST_ARRAY_16_1);
+    let rekeyed = rekeying
+        .rekey_uuids(&[old_uuid_ciphertext, other_uuid_ciphertext])
+        .unwrap();
+    assert!(rekeyed[0] == new_uuid_ciphertext);
+    assert!(new_params.decrypt_uuid(rekeyed[1]).unwrap() == zkgroup::TEST_ARRAY_16_1);
+
+    // UUIDs with proof
+    let (proven_uuid_ciphertext, proof) = rekeying
+        .rekey_uuid_with_proof(randomness, old_uuid_ciphertext)
+        .unwrap();
+    assert!(proven_uuid_ciphertext == new_uuid_ciphertext);
+    let old_public_params = old_params.get_public_params();
+    let new_public_params = new_params.get_public_params();
+    old_public_params
+        .verify_uuid_ciphertext_equality(
+            old_uuid_ciphertext,
+            new_public_params,
+            new_uuid_ciphertext,
+            &proof,
+        )
+        .unwrap();
+    assert!(old_public_params
+        .verify_uuid_ciphertext_equality(
+            other_uuid_ciphertext,
+            new_public_params,
+            new_uuid_ciphertext,
+            &proof,
+        )
+        .is_err());
+    assert!(
+        old_public_params
+            .verify_uuid_ciphertext_equality(
+                old_uuid_ciphertext,
+                new_public_params,
+                rekeyed[1],
+                &proof,
+            )
+            .is_err()
+    );
+
+    let proof_bytes = bincode::serialize(&proof).unwrap();
+    let proof2: zkgroup::groups::UuidCiphertextEqualityProof =
+        bincode::deserialize(&proof_bytes).unwrap();
+    old_public_params
+        .verify_uuid_ciphertext_equality(
+            old_uuid_ciphertext,
+            new_public_params,
+            new_uuid_ciphertext,
+            &proof2,
+        )
+        .unwrap();
+
+    // Profile keys
+    let profile_key = zkgroup::profiles::ProfileKey::create(zkgroup::TEST_ARRAY_32_4);
+    let old_profile_key_ciphertext = old_params.encrypt_profile_key(&profile_key, uid);
+    let new_profile_key_ciphertext = rekeying
+        .rekey_profile_key(old_profile_key_ciphertext, uid)
+        .unwrap();
+    assert!(
+        new_params
+            .decrypt_profile_key(new_profile_key_ciphertext, uid)
+            .unwrap()
+            .get_bytes()
+            == profile_key.get_bytes()
+    );
+    assert!(rekeying
+        .rekey_profile_key(old_profile_key_ciphertext, zkgroup::TEST_ARRAY_16_1)
+        .is_err());
+
+    // Blobs
+    let plaintext_vec = b"Group title".to_vec();
+    let old_blob = old_params
+        .encrypt_blob_with_ad(randomness, &plaintext_vec, b"title:1")
+        .unwrap();
+    let new_blob = rekeying
+        .rekey_blob(randomness, &old_blob, b"title:1")
+        .unwrap();
+    assert!(
+        new_params
+            .decrypt_blob_with_ad(&new_blob, b"title:1")
+            .unwrap()
+            == plaintext_vec
+    );
+    assert!(old_params
+        .decrypt_blob_with_ad(&new_blob, b"title:1")
+        .is_err());
+    assert!(rekeying
+        .rekey_blob(randomness, &old_blob, b"title:2")
+        .is_err());
+
+    let padding_policy = zkgroup::groups::BlobPaddingPolicy::PowerOfTwo { min_len: 64 };
+    let old_padded_blob = old_params
+        .encrypt_blob_with_padding_and_ad(randomness, &plaintext_vec, &padding_policy, b"title:1")
+        .unwrap();
+    let new_padded_blob = rekeying
+        .rekey_blob_with_padding(randomness, &old_padded_blob, &padding_policy, b"title:1")
+        .unwrap();
+    assert!(new_padded_blob.len() == old_padded_blob.len());
+    assert!(
+        new_params
+            .decrypt_blob_with_ad(&new_padded_blob, b"title:1")
+            .unwrap()
+            == plaintext_vec
+    );
+    assert!(new_params.decrypt_blob(&new_padded_blob).is_err());
+    assert!(rekeying
+        .rekey_blob_with_padding(randomness, &old_padded_blob, &padding_policy, b"title:2")
+        .is_err());
+}
+
+#[test]
+fn test_uuid_ciphertext_equality() {
+    let group_secret_params = zkgroup::groups::GroupSecretParams::derive_from_master_key(
+        zkgroup::groups::GroupMasterKey::new(zkgroup::TEST_ARRAY_32_1),
+    );
+    let other_group_secret_params = zkgroup::groups::GroupSecretParams::derive_from_master_key(
+        zkgroup::groups::GroupMasterKey::new(zkgroup::TEST_ARRAY_32_2),
+    );
+    let group_public_params = group_secret_params.get_public_params();
+    let other_group_public_params = other_group_secret_params.get_public_params();
+    let randomness = zkgroup::TEST_ARRAY_32_3;
+
+    let uuid_ciphertext = group_secret_params.encrypt_uuid(zkgroup::TEST_ARRAY_16);
+    let other_member_ciphertext = group_secret_params.encrypt_uuid(zkgroup::TEST_ARRAY_16_1);
+
+    // Same group
+    let proof = group_secret_params
+        .create_uuid_ciphertext_equality_proof(randomness, uuid_ciphertext, uuid_ciphertext)
+        .unwrap();
+    group_public_params
+        .verify_uuid_ciphertext_equality(
+            uuid_ciphertext,
+            group_public_params,
+            uuid_ciphertext,
+            &proof,
+        )
+        .unwrap();
+    assert!(group_public_params
+        .verify_uuid_ciphertext_equality(
+            uuid_ciphertext,
+            group_public_params,
+            other_member_ciphertext,
+            &proof,
+        )
+        .is_err());
+    assert!(group_secret_params
+        .create_uuid_ciphertext_equality_proof(randomness, uuid_ciphertext, other_member_ciphertext)
+        .is_err());
+
+    // Different groups
+    let other_uuid_ciphertext = other_group_secret_params.encrypt_uuid(zkgroup::TEST_ARRAY_16);
+    let proof = group_secret_params
+        .create_uuid_ciphertext_equality_proof_across_groups(
+            randomness,
+            uuid_ciphertext,
+            &other_group_secret_params,
+            other_uuid_ciphertext,
+        )
+        .unwrap();
+    group_public_params
+        .verify_uuid_ciphertext_equality(
+            uuid_ciphertext,
+            other_group_public_params,
+            other_uuid_ciphertext,
+            &proof,
+        )
+        .unwrap();
+
+    // The proof is tied to the keys and the order of the ciphertexts
+    assert!(other_group_public_params
+        .verify_uuid_ciphertext_equality(
+            other_uuid_ciphertext,
+            group_public_params,
+            uuid_ciphertext,
+            &proof,
+        )
+        .is_err());
+    assert!(group_public_params
+        .verify_uuid_ciphertext_equality(
+            uuid_ciphertext,
+            group_public_params,
+            other_uuid_ciphertext,
+            &proof,
+        )
+        .is_err());
+
+    // Ciphertexts under the wrong params are rejected
+    assert!(group_secret_params
+        .create_uuid_ciphertext_equality_proof_across_groups(
+            randomness,
+            uuid_ciphertext,
+            &other_group_secret_params,
+            uuid_ciphertext,
+        )
+        .is_err());
+}
+
+#[test]
+fn test_uuid_ciphertext_match() {
+    let group_secret_params = zkgroup::groups::GroupSecretParams::derive_from_master_key(
+        zkgroup::groups::GroupMasterKey::new(zkgroup::TEST_ARRAY_32_1),
+    );
+    let other_group_secret_params = zkgroup::groups::GroupSecretParams::derive_from_master_key(
+        zkgroup::groups::GroupMasterKey::new(zkgroup::TEST_ARRAY_32_2),
+    );
+    let group_public_params = group_secret_params.get_public_params();
+    let uid = zkgroup::TEST_ARRAY_16;
+    let other_uid = zkgroup::TEST_ARRAY_16_1;
+
+    // The group state holds randomized entries, which differ each time
+    let entries = vec![
+        group_secret_params.encrypt_uuid_randomized(zkgroup::TEST_ARRAY_32_3, other_uid),
+        group_secret_params.encrypt_uuid_randomized(zkgroup::TEST_ARRAY_32_4, uid),
+    ];
+    let reencrypted = group_secret_params.encrypt_uuid_randomized(zkgroup::TEST_ARRAY_32_5, uid);
+    assert!(reencrypted != entries[1]);
+    assert!(
+        bincode::serialize(&entries[1]).unwrap().len() == zkgroup::RANDOMIZED_UUID_CIPHERTEXT_LEN
+    );
+    assert!(
+        group_secret_params
+            .decrypt_randomized_uuid(entries[1])
+            .unwrap()
+            == uid
+    );
+    assert!(
+        group_secret_params
+            .decrypt_randomized_uuid(reencrypted)
+            .unwrap()
+            == uid
+    );
+    assert!(other_group_secret_params
+        .decrypt_randomized_uuid(entries[1])
+        .is_err());
+
+    // A member finds their entry and proves their (deterministic) ciphertext,
+    // e.g. from an auth credential presentation, matches it
+    let index = group_secret_params
+        .find_randomized_uuid(uid, &entries)
+        .unwrap();
+    assert!(index == 1);
+    assert!(group_secret_params
+        .find_randomized_uuid(zkgroup::TEST_ARRAY_16_1, &entries[1..])
+        .is_none());
+
+    let uuid_ciphertext = group_secret_params.encrypt_uuid(uid);
+    let proof = group_secret_params
+        .create_uuid_ciphertext_match_proof(
+            zkgroup::TEST_ARRAY_32_3,
+            uuid_ciphertext,
+            entries[index],
+        )
+        .unwrap();
+    assert!(bincode::serialize(&proof).unwrap().len() == zkgroup::UUID_CIPHERTEXT_MATCH_PROOF_LEN);
+    group_public_params
+        .verify_uuid_ciphertext_match(uuid_ciphertext, entries[index], &proof)
+        .unwrap();
+
+    // The proof is tied to the entry, the ciphertext, and the group
+    assert!(group_public_params
+        .verify_uuid_ciphertext_match(uuid_ciphertext, entries[0], &proof)
+        .is_err());
+    assert!(group_public_params
+        .verify_uuid_ciphertext_match(uuid_ciphertext, reencrypted, &proof)
+        .is_err());
+    assert!(group_public_params
+        .verify_uuid_ciphertext_match(
+            group_secret_params.encrypt_uuid(other_uid),
+            entries[index],
+            &proof
+        )
+        .is_err());
+    assert!(other_group_secret_params
+        .get_public_params()
+        .verify_uuid_ciphertext_match(uuid_ciphertext, entries[index], &proof)
+        .is_err());
+
+    // Only a matching entry gets a proof
+    assert!(group_secret_params
+        .create_uuid_ciphertext_match_proof(zkgroup::TEST_ARRAY_32_3, uuid_ciphertext, entries[0])
+        .is_err());
+}
+
+#[test]
+fn test_integration_group_invite_link() {
+    let server_secret_params =
+        zkgroup::ServerSecretParams::generate(zkgroup::TEST_ARRAY_32).unwrap();
+
+    let group_secret_params = zkgroup::groups::GroupSecretParams::derive_from_master_key(
+        zkgroup::groups::GroupMasterKey::new(zkgroup::TEST_ARRAY_32_1),
+    );
+    let group_public_params = group_secret_params.get_public_params();
+
+    // An admin creates the link and registers the commitment with the server
+    let password = zkgroup::groups::GroupInviteLinkPassword::generate(zkgroup::TEST_ARRAY_32_2);
+    let commitment = password.get_commitment(group_public_params);
+    let commitment_bytes = bincode::serialize(&commitment).unwrap();
+    assert!(commitment_bytes.len() == zkgroup::GROUP_INVITE_LINK_PASSWORD_COMMITMENT_LEN);
+
+    // A joiner with the link presents the password
+    let uid = zkgroup::TEST_ARRAY_16;
+    let presentation = group_secret_params
+        .create_group_invite_link_presentation(zkgroup::TEST_ARRAY_32_3, &password, uid)
+        .unwrap();
+    let presentation_bytes = bincode::serialize(&presentation).unwrap();
+    assert!(presentation_bytes.len() == zkgroup::GROUP_INVITE_LINK_PRESENTATION_LEN);
+    let presentation: zkgroup::groups::GroupInviteLinkPresentation =
+        bincode::deserialize(&presentation_bytes).unwrap();
+    assert!(presentation.get_uuid_ciphertext() == group_secret_params.encrypt_uuid(uid));
+
+    server_secret_params
+        .verify_group_invite_link_presentation(group_public_params, commitment, &presentation)
+        .unwrap();
+
+    // A different password (e.g. after the link was reset)
+    let new_password = zkgroup::groups::GroupInviteLinkPassword::generate(zkgroup::TEST_ARRAY_32_4);
+    let new_commitment = new_password.get_commitment(group_public_params);
+    assert!(new_commitment != commitment);
+    assert!(server_secret_params
+        .verify_group_invite_link_presentation(group_public_params, new_commitment, &presentation)
+        .is_err());
+
+    // The same password for a different group
+    let other_group_public_params = zkgroup::groups::GroupSecretParams::derive_from_master_key(
+        zkgroup::groups::GroupMasterKey::new(zkgroup::TEST_ARRAY_32_5),
+    )
+    .get_public_params();
+    assert!(password.get_commitment(other_group_public_params) != commitment);
+    assert!(server_secret_params
+        .verify_group_invite_link_presentation(other_group_public_params, commitment, &presentation)
+        .is_err());
+
+    // The presentation can't be moved to another joiner
+    let mut modified_presentation_bytes = presentation_bytes.clone();
+    let ciphertext_start = presentation_bytes.len() - zkgroup::UUID_CIPHERTEXT_LEN + 1;
+    let other_ciphertext_bytes =
+        bincode::serialize(&group_secret_params.encrypt_uuid(zkgroup::TEST_ARRAY_16_1)).unwrap();
+    modified_presentation_bytes[ciphertext_start..].copy_from_slice(&other_ciphertext_bytes[1..]);
+    let modified_presentation: zkgroup::groups::GroupInviteLinkPresentation =
+        bincode::deserialize(&modified_presentation_bytes).unwrap();
+    assert!(server_secret_params
+        .verify_group_invite_link_presentation(
+            group_public_params,
+            commitment,
+            &modified_presentation
+        )
+        .is_err());
+}
+
+#[test]
+fn test_integration_pending_member_invitation() {
+    let server_secret_params =
+        zkgroup::ServerSecretParams::generate(zkgroup::TEST_ARRAY_32).unwrap();
+    let server_public_params = server_secret_params.get_public_params();
+
+    let group_secret_params = zkgroup::groups::GroupSecretParams::derive_from_master_key(
+        zkgroup::groups::GroupMasterKey::new(zkgroup::TEST_ARRAY_32_1),
+    );
+    let group_public_params = group_secret_params.get_public_params();
+
+    let inviter_uid = zkgroup::TEST_ARRAY_16_1;
+    let invitee_uid = zkgroup::TEST_ARRAY_16;
+
+    // The inviter adds the invitee as pending, without their profile key
+    let redemption_time = 123456u32;
+    let auth_credential_response = server_secret_params
+        .issue_auth_credential(zkgroup::TEST_ARRAY_32_2, inviter_uid, redemption_time)
+        .unwrap();
+    let auth_credential = server_public_params
+        .receive_auth_credential(inviter_uid, redemption_time, &auth_credential_response)
+        .unwrap();
+    let inviter_presentation = server_public_params
+        .create_auth_credential_presentation(
+            zkgroup::TEST_ARRAY_32_5,
+            &group_secret_params,
+            auth_credential,
+        )
+        .unwrap();
+
+    let invitation = group_secret_params.create_pending_member_invitation(invitee_uid, inviter_uid);
+    let invitation_bytes = bincode::serialize(&invitation).unwrap();
+    assert!(invitation_bytes.len() == zkgroup::PENDING_MEMBER_INVITATION_LEN);
+    let invitation: zkgroup::groups::PendingMemberInvitation =
+        bincode::deserialize(&invitation_bytes).unwrap();
+    assert!(invitation.get_uuid_ciphertext() == group_secret_params.encrypt_uuid(invitee_uid));
+    assert!(
+        invitation.get_inviter_uuid_ciphertext() == group_secret_params.encrypt_uuid(inviter_uid)
+    );
+
+    server_secret_params
+        .verify_pending_member_invitation(group_public_params, &inviter_presentation, invitation)
+        .unwrap();
+
+    // Someone other than the named inviter can't add it
+    let forged_invitation =
+        group_secret_params.create_pending_member_invitation(invitee_uid, invitee_uid);
+    assert!(server_secret_params
+        .verify_pending_member_invitation(
+            group_public_params,
+            &inviter_presentation,
+            forged_invitation
+        )
+        .is_err());
+
+    // The invitee accepts with a profile key credential
+    let profile_key = zkgroup::profiles::ProfileKey::create(zkgroup::TEST_ARRAY_32_1);
+    let profile_key_commitment = profile_key.get_commitment(invitee_uid);
+    let context = server_public_params
+        .create_profile_key_credential_request_context(
+            zkgroup::TEST_ARRAY_32_3,
+            invitee_uid,
+            &profile_key,
+        )
+        .unwrap();
+    let response = server_secret_params
+        .issue_profile_key_credential(
+            zkgroup::TEST_ARRAY_32_4,
+            &context.get_request(),
+            invitee_uid,
+            profile_key_commitment,
+        )
+        .unwrap();
+    let profile_key_credential = server_public_params
+        .receive_profile_key_credential(&context, &response)
+        .unwrap();
+    let presentation = server_public_params
+        .create_profile_key_credential_presentation(
+            zkgroup::TEST_ARRAY_32_5,
+            &group_secret_params,
+            profile_key_credential,
+        )
+        .unwrap();
+
+    assert!(invitation.matches(&presentation));
+    server_secret_params
+        .verify_pending_member_invitation_acceptance(group_public_params, invitation, &presentation)
+        .unwrap();
+
+    // An invitation for someone else can't be accepted with it
+    let other_invitation =
+        group_secret_params.create_pending_member_invitation(zkgroup::TEST_ARRAY_16_1, inviter_uid);
+    assert!(!other_invitation.matches(&presentation));
+    assert!(server_secret_params
+        .verify_pending_member_invitation_acceptance(
+            group_public_params,
+            other_invitation,
+            &presentation
+        )
+        .is_err());
+}
+
+#[test]
+fn test_integration_receipt() {
+    let server_secret_params =
+        zkgroup::ServerSecretParams::generate(zkgroup::TEST_ARRAY_32).unwrap();
+    let server_public_params = server_secret_params.get_public_params();
+
+    // CLIENT
+    let receipt_serial_bytes = zkgroup::TEST_ARRAY_16;
+    let context = server_public_params
+        .create_receipt_credential_request_context(zkgroup::TEST_ARRAY_32_1, receipt_serial_bytes);
+    let request = context.get_request();
+
+    // SERVER, once the payment has been checked
+    let receipt_expiration_time = 31536000u64;
+    let receipt_level = 3u64;
+    let response = server_secret_params
+        .issue_receipt_credential(
+            zkgroup::TEST_ARRAY_32_2,
+            &request,
+            receipt_expiration_time,
+            receipt_level,
+        )
+        .unwrap();
+
+    // CLIENT
+    let receipt_credential = server_public_params
+        .receive_receipt_credential(&context, &response)
+        .unwrap();
+    assert!(receipt_credential.get_receipt_expiration_time() == receipt_expiration_time);
+    assert!(receipt_credential.get_receipt_level() == receipt_level);
+
+    let presentation = server_public_params
+        .create_receipt_credential_presentation(zkgroup::TEST_ARRAY_32_3, &receipt_credential)
+        .unwrap();
+    assert!(presentation.get_receipt_serial_bytes() == receipt_serial_bytes);
+
+    // SERVER
+    server_secret_params
+        .verify_receipt_credential_presentation(&presentation)
+        .unwrap();
+
+    // A response for a different level can't be passed off as this one
+    let mut modified_response_bytes = bincode::serialize(&response).unwrap();
+    modified_response_bytes[10] ^= 1;
+    let modified_response: zkgroup::receipts::ReceiptCredentialResponse =
+        bincode::deserialize(&modified_response_bytes).unwrap();
+    assert!(server_public_params
+        .receive_receipt_credential(&context, &modified_response)
+        .is_err());
+
+    // Nor can a presentation have its level raised
+    let presentation_bytes = bincode::serialize(&presentation).unwrap();
+    let mut modified_presentation_bytes = presentation_bytes.clone();
+    let level_start = presentation_bytes.len() - zkgroup::RECEIPT_SERIAL_LEN - 8;
+    modified_presentation_bytes[level_start] ^= 1;
+    let modified_presentation: zkgroup::receipts::ReceiptCredentialPresentation =
+        bincode::deserialize(&modified_presentation_bytes).unwrap();
+    assert!(modified_presentation.get_receipt_level() != receipt_level);
+    assert!(server_secret_params
+        .verify_receipt_credential_presentation(&modified_presentation)
+        .is_err());
+
+    // test encoding
+    assert!(
+        bincode::serialize(&context).unwrap().len()
+            == zkgroup::RECEIPT_CREDENTIAL_REQUEST_CONTEXT_LEN
+    );
+    assert!(bincode::serialize(&request).unwrap().len() == zkgroup::RECEIPT_CREDENTIAL_REQUEST_LEN);
+    assert!(
+        bincode::serialize(&response).unwrap().len() == zkgroup::RECEIPT_CREDENTIAL_RESPONSE_LEN
+    );
+    assert!(
+        bincode::serialize(&receipt_credential).unwrap().len() == zkgroup::RECEIPT_CREDENTIAL_LEN
+    );
+    assert!(presentation_bytes.len() == zkgroup::RECEIPT_CREDENTIAL_PRESENTATION_LEN);
 }